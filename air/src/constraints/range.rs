@@ -1,7 +1,8 @@
 use crate::{
     chiplets::{ChipletsFrameExt, MemoryFrameExt},
     trace::range::{
-        P0_COL_IDX, P1_COL_IDX, Q_COL_IDX, S0_COL_IDX, S1_COL_IDX, T_COL_IDX, V_COL_IDX,
+        RangeCheckMode, P0_COL_IDX, P1_COL_IDX, Q_COL_IDX, S0_COL_IDX, S1_COL_IDX, T_COL_IDX,
+        V_COL_IDX,
     },
     utils::{are_equal, binary_not, is_binary},
     Assertion, EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree,
@@ -48,8 +49,14 @@ pub fn get_assertions_first_step(result: &mut Vec<Assertion<Felt>>) {
 }
 
 /// Returns the range checker's boundary assertions for the main trace at the last step.
-pub fn get_assertions_last_step(result: &mut Vec<Assertion<Felt>>, step: usize) {
-    result.push(Assertion::single(V_COL_IDX, step, Felt::new(65535)));
+///
+/// The last value of the table depends on the mode in which the range checker trace was built.
+pub fn get_assertions_last_step(
+    result: &mut Vec<Assertion<Felt>>,
+    step: usize,
+    mode: RangeCheckMode,
+) {
+    result.push(Assertion::single(V_COL_IDX, step, Felt::from(mode.max_value())));
 }
 
 // --- AUXILIARY COLUMNS (FOR MULTISET CHECKS) ----------------------------------------------------
@@ -241,7 +241,8 @@ impl<E: FieldElement> OpFlags<E> {
         degree5_op_flags[6] = not_0_yes_1 * frame.op_bit(2); // SPAN
         degree5_op_flags[7] = yes_0_yes_1 * frame.op_bit(2); // JOIN
 
-        // the second half of the degree 5 flags share the same lower 3 bits as the first half.
+        // the second half of the degree 5 flags share the same lower 3 bits as the first half
+        // (the only operation in the second half is DYN, which corresponds to index 8).
         degree5_op_flags.copy_within(0..8, 8);
 
         // update the intermediate values of the degree 5 operation flags with the values of
@@ -287,6 +288,7 @@ impl<E: FieldElement> OpFlags<E> {
             + degree5_op_flags[1] // MPVERIFY
             + degree5_op_flags[6] // SPAN
             + degree5_op_flags[7] // JOIN
+            + degree5_op_flags[8] // DYN
             + degree4_op_flags[6] // RESPAN
             + degree4_op_flags[7] // HALT
             + degree4_op_flags[3] // CALL
@@ -399,6 +401,7 @@ impl<E: FieldElement> OpFlags<E> {
         // Flag if the current operation being executed is a control flow operation.
         // first row: SPAN, JOIN, SPLIT, LOOP
         let control_flow = frame.op_bit_extra(0) * not_3 * frame.op_bit(2)
+            + degree5_op_flags[8] // DYN op
             + frame.op_bit_extra(1) * frame.op_bit(4) // END, REPEAT, RESPAN, HALT
             + degree4_op_flags[2]  // SYSCALL op
             + degree4_op_flags[3]; // CALL op
@@ -624,7 +627,7 @@ impl<E: FieldElement> OpFlags<E> {
     /// Operation Flag of ASSERT operation.
     #[inline(always)]
     pub fn assert(&self) -> E {
-        self.degree7_op_flags[get_op_index(Operation::Assert.op_code())]
+        self.degree7_op_flags[get_op_index(Operation::Assert(ZERO).op_code())]
     }
 
     /// Operation Flag of EQ operation.
@@ -838,7 +841,7 @@ impl<E: FieldElement> OpFlags<E> {
     /// Operation Flag of U32ASSERT2 operation.
     #[inline(always)]
     pub fn u32assert2(&self) -> E {
-        self.degree6_op_flags[get_op_index(Operation::U32assert2.op_code())]
+        self.degree6_op_flags[get_op_index(Operation::U32assert2(ZERO).op_code())]
     }
 
     /// Operation Flag of U32ADD3 operation.
@@ -891,6 +894,12 @@ impl<E: FieldElement> OpFlags<E> {
         self.degree5_op_flags[get_op_index(Operation::Join.op_code())]
     }
 
+    /// Operation Flag of DYN operation.
+    #[inline(always)]
+    pub fn dyn_op(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::Dyn.op_code())]
+    }
+
     // ------ Degree 4 stack operations  ----------------------------------------------------------
 
     /// Operation Flag of MRUPDATE operation.
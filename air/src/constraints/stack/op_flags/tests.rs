@@ -145,7 +145,7 @@ fn degree_4_op_flags() {
 fn composite_flags() {
     // ------ no change 0 ---------------------------------------------------------------------
 
-    let op_no_change_0 = [Operation::MpVerify, Operation::Span, Operation::Halt];
+    let op_no_change_0 = [Operation::MpVerify, Operation::Span, Operation::Halt, Operation::Dyn];
     for op in op_no_change_0 {
         // frame initialised with an op operation.
         let frame = generate_evaluation_frame(op.op_code().into());
@@ -171,7 +171,7 @@ fn composite_flags() {
 
         if op == Operation::MpVerify {
             assert_eq!(op_flags.control_flow(), ZERO);
-        } else if op == Operation::Span || op == Operation::Halt {
+        } else if op == Operation::Span || op == Operation::Halt || op == Operation::Dyn {
             assert_eq!(op_flags.control_flow(), ONE);
         } else {
             unreachable!("unexpected op");
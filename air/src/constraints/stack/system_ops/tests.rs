@@ -3,7 +3,7 @@ use super::{
     enforce_constraints, EvaluationFrame, NUM_CONSTRAINTS,
 };
 use crate::stack::op_flags::{generate_evaluation_frame, OpFlags};
-use vm_core::{Felt, FieldElement, Operation, ONE};
+use vm_core::{Felt, FieldElement, Operation, ONE, ZERO};
 
 use proptest::prelude::*;
 
@@ -102,7 +102,7 @@ pub fn get_fmpupdate_test_frame(a: u64) -> EvaluationFrame<Felt> {
 /// returns an EvaluationFrame for testing.
 pub fn get_assert_test_frame() -> EvaluationFrame<Felt> {
     // frame initialised with a fmpupdate operation using it's unique opcode.
-    let mut frame = generate_evaluation_frame(Operation::Assert.op_code() as usize);
+    let mut frame = generate_evaluation_frame(Operation::Assert(ZERO).op_code() as usize);
 
     // Set the output. The top element in the current frame of the stack should be ONE.
     frame.current_mut()[STACK_TRACE_OFFSET] = ONE;
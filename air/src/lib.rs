@@ -28,7 +28,8 @@ use utils::TransitionConstraintRange;
 // EXPORTS
 // ================================================================================================
 
-pub use proof::{ExecutionProof, HashFunction, ProofOptions};
+pub use proof::{ExecutionProof, HashFunction, ProofMetadata, ProofOptions};
+pub use trace::range::RangeCheckMode;
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -43,6 +44,7 @@ pub struct ProcessorAir {
     context: AirContext<Felt>,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    range_check_mode: RangeCheckMode,
     constraint_ranges: TransitionConstraintRange,
 }
 
@@ -58,6 +60,9 @@ impl Air for ProcessorAir {
     type PublicInputs = PublicInputs;
 
     fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: WinterProofOptions) -> Self {
+        // the mode of the range checker is recorded in the trace metadata
+        let range_check_mode = RangeCheckMode::from_trace_meta(trace_info.meta());
+
         // --- system -----------------------------------------------------------------------------
         let mut main_degrees = vec![
             TransitionConstraintDegree::new(1), // clk' = clk + 1
@@ -108,6 +113,7 @@ impl Air for ProcessorAir {
             context,
             stack_inputs: pub_inputs.stack_inputs,
             stack_outputs: pub_inputs.stack_outputs,
+            range_check_mode,
             constraint_ranges,
         }
     }
@@ -147,7 +153,7 @@ impl Air for ProcessorAir {
         stack::get_assertions_last_step(&mut result, last_step, &self.stack_outputs);
 
         // Add the range checker's assertions for the last step.
-        range::get_assertions_last_step(&mut result, last_step);
+        range::get_assertions_last_step(&mut result, last_step, self.range_check_mode);
 
         result
     }
@@ -1,7 +1,8 @@
 use super::DeserializationError;
+use core::fmt;
 use vm_core::{
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
-    utils::collections::Vec,
+    utils::{collections::Vec, string::String},
 };
 use winter_air::{proof::StarkProof, FieldExtension, ProofOptions as WinterProofOptions};
 
@@ -50,6 +51,26 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns parameters of this proof, such as the shape of the execution trace and the STARK
+    /// protocol parameters used to generate the proof.
+    ///
+    /// The metadata is read from the proof as is, and thus, it can be inspected without verifying
+    /// the proof.
+    pub fn metadata(&self) -> ProofMetadata {
+        let options = self.proof.options();
+        let layout = self.proof.trace_layout();
+        ProofMetadata {
+            trace_length_log2: self.proof.trace_length().ilog2(),
+            trace_width: layout.main_trace_width() + layout.aux_trace_width(),
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            field_extension_degree: options.field_extension().degree(),
+            hash_fn: self.hash_fn,
+            security_level: self.security_level(),
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -81,6 +102,78 @@ impl ExecutionProof {
     }
 }
 
+// PROOF METADATA
+// ================================================================================================
+
+/// Parameters of an [ExecutionProof], describing the execution trace the proof was generated for
+/// and the STARK protocol parameters used to generate it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofMetadata {
+    trace_length_log2: u32,
+    trace_width: usize,
+    num_queries: usize,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    field_extension_degree: u32,
+    hash_fn: HashFunction,
+    security_level: u32,
+}
+
+impl ProofMetadata {
+    /// Returns the base 2 logarithm of the length of the execution trace.
+    pub const fn trace_length_log2(&self) -> u32 {
+        self.trace_length_log2
+    }
+
+    /// Returns the number of columns of the execution trace, including the auxiliary columns.
+    pub const fn trace_width(&self) -> usize {
+        self.trace_width
+    }
+
+    /// Returns the number of queries made by the verifier.
+    pub const fn num_queries(&self) -> usize {
+        self.num_queries
+    }
+
+    /// Returns the factor by which the execution trace was extended during low-degree extension.
+    pub const fn blowup_factor(&self) -> usize {
+        self.blowup_factor
+    }
+
+    /// Returns the number of bits of proof-of-work performed by the prover.
+    pub const fn grinding_factor(&self) -> u32 {
+        self.grinding_factor
+    }
+
+    /// Returns the degree of the field extension used for drawing random values.
+    pub const fn field_extension_degree(&self) -> u32 {
+        self.field_extension_degree
+    }
+
+    /// Returns the hash function used during proof generation process.
+    pub const fn hash_fn(&self) -> HashFunction {
+        self.hash_fn
+    }
+
+    /// Returns conjectured security level of the proof in bits.
+    pub const fn security_level(&self) -> u32 {
+        self.security_level
+    }
+}
+
+impl fmt::Display for ProofMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "trace length:     2^{}", self.trace_length_log2)?;
+        writeln!(f, "trace width:      {} columns", self.trace_width)?;
+        writeln!(f, "queries:          {}", self.num_queries)?;
+        writeln!(f, "blowup factor:    {}", self.blowup_factor)?;
+        writeln!(f, "grinding factor:  {} bits", self.grinding_factor)?;
+        writeln!(f, "field extension:  degree {}", self.field_extension_degree)?;
+        writeln!(f, "hash function:    {}", self.hash_fn)?;
+        write!(f, "security level:   {} bits", self.security_level)
+    }
+}
+
 // PROOF OPTIONS
 // ================================================================================================
 
@@ -92,6 +185,15 @@ pub struct ProofOptions {
 }
 
 impl ProofOptions {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Maximum grinding factor (in bits) supported by the prover.
+    pub const MAX_GRINDING_FACTOR: u32 = 32;
+
+    /// Names of the presets accepted by [ProofOptions::from_preset()].
+    pub const PRESETS: [&'static str; 3] = ["standard", "high", "paranoid"];
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -158,6 +260,71 @@ impl ProofOptions {
         }
     }
 
+    /// Creates a new instance of [ProofOptions] from the preset with the specified name.
+    ///
+    /// The following presets are recognized (see [ProofOptions::PRESETS]):
+    /// - `standard`: same as [ProofOptions::with_96_bit_security()] for non-recursive proofs.
+    /// - `high`: same as [ProofOptions::with_128_bit_security()] for non-recursive proofs.
+    /// - `paranoid`: parameters of the `high` preset with twice as many queries. This does not
+    ///   increase the conjectured security level, which is limited by the hash function, but
+    ///   provides a larger margin for the proven security level at the expense of roughly twice
+    ///   as large proofs.
+    ///
+    /// # Errors
+    /// Returns an error listing the valid preset names if `name` is not one of them.
+    pub fn from_preset(name: &str) -> Result<Self, String> {
+        match name {
+            "standard" => Ok(Self::with_96_bit_security(false)),
+            "high" => Ok(Self::with_128_bit_security(false)),
+            "paranoid" => {
+                Ok(Self::new(54, 16, 21, FieldExtension::Cubic, 8, 255, HashFunction::Blake3_256))
+            }
+            _ => Err(format!(
+                "unknown proof options preset '{name}'; valid presets are: {}",
+                Self::PRESETS.join(", ")
+            )),
+        }
+    }
+
+    /// Returns a copy of these options with the grinding (proof-of-work) factor set to the
+    /// specified number of bits; all other parameters are left unchanged.
+    ///
+    /// Before drawing query positions, the prover must find a nonce such that the hash of the
+    /// nonce and the current state of the public coin has at least `grinding_factor` leading
+    /// zeros. This increases the conjectured security level of the proof by up to
+    /// `grinding_factor` bits and makes generating proofs more expensive for a malicious prover.
+    ///
+    /// The cost of grinding for the prover grows exponentially: on average, 2^grinding_factor
+    /// hashes need to be computed, and thus, every additional bit doubles the expected time
+    /// spent on grinding. For the default factors (16 and 21 bits) this time is negligible
+    /// compared to the rest of proof generation, but factors close to the maximum of 32 bits may
+    /// add minutes to proof generation. For the verifier, the cost of checking the proof-of-work
+    /// is a single hash regardless of the grinding factor.
+    ///
+    /// # Panics
+    /// Panics if `grinding_factor` is greater than [ProofOptions::MAX_GRINDING_FACTOR].
+    pub fn with_grinding(self, grinding_factor: u32) -> Self {
+        assert!(
+            grinding_factor <= Self::MAX_GRINDING_FACTOR,
+            "grinding factor cannot be greater than {}, but was {grinding_factor}",
+            Self::MAX_GRINDING_FACTOR
+        );
+
+        let fri_options = self.options.to_fri_options();
+        let options = WinterProofOptions::new(
+            self.options.num_queries(),
+            self.options.blowup_factor(),
+            grinding_factor,
+            self.options.field_extension(),
+            fri_options.folding_factor(),
+            fri_options.remainder_max_degree(),
+        );
+        Self {
+            options,
+            hash_fn: self.hash_fn,
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -165,6 +332,11 @@ impl ProofOptions {
     pub const fn hash_fn(&self) -> HashFunction {
         self.hash_fn
     }
+
+    /// Returns the number of bits of proof-of-work the prover is required to perform.
+    pub fn grinding_factor(&self) -> u32 {
+        self.options.grinding_factor()
+    }
 }
 
 impl Default for ProofOptions {
@@ -211,6 +383,16 @@ impl HashFunction {
     }
 }
 
+impl fmt::Display for HashFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashFunction::Blake3_192 => write!(f, "BLAKE3-192"),
+            HashFunction::Blake3_256 => write!(f, "BLAKE3-256"),
+            HashFunction::Rpo256 => write!(f, "RPO-256"),
+        }
+    }
+}
+
 impl TryFrom<u8> for HashFunction {
     type Error = DeserializationError;
 
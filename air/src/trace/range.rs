@@ -1,4 +1,5 @@
 use super::{RANGE_CHECK_AUX_TRACE_OFFSET, RANGE_CHECK_TRACE_OFFSET};
+use vm_core::utils::collections::Vec;
 
 // CONSTANTS
 // ================================================================================================
@@ -29,3 +30,56 @@ pub const P1_COL_IDX: usize = P0_COL_IDX + 1;
 /// An auxiliary trace column of intermediate values used to enforce AIR constraints on `p1`. It
 /// contains the product of the lookups performed by the Stack processor at each cycle.
 pub const Q_COL_IDX: usize = P1_COL_IDX + 1;
+
+// RANGE CHECK MODE
+// ================================================================================================
+
+/// Specifies the largest value the 16-bit segment of the range checker table must span.
+///
+/// In the default 16-bit mode, the 16-bit segment of the table must end with value 65535. Since
+/// the values in this segment can be at most 255 apart, the segment always contains more than 256
+/// rows, even if the values range-checked by a program are all small.
+///
+/// In the 8-bit mode, the 16-bit segment of the table ends with value 255 instead. Thus, the
+/// segment can be as short as a few rows, but the mode can be used only by executions in which
+/// all range-checked values fit into 8 bits.
+///
+/// The mode is recorded in the metadata of the execution trace, so that the verifier can apply
+/// the corresponding boundary constraint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RangeCheckMode {
+    #[default]
+    Bits16,
+    Bits8,
+}
+
+impl RangeCheckMode {
+    /// Returns the value with which the 16-bit segment of the range checker table must end.
+    pub const fn max_value(&self) -> u16 {
+        match self {
+            Self::Bits16 => u16::MAX,
+            Self::Bits8 => u8::MAX as u16,
+        }
+    }
+
+    /// Returns the execution trace metadata describing this mode.
+    ///
+    /// The metadata for the 16-bit mode is empty, so that traces built in the default mode are
+    /// not affected by the mode being recorded.
+    pub fn to_trace_meta(&self) -> Vec<u8> {
+        match self {
+            Self::Bits16 => Vec::new(),
+            Self::Bits8 => vec![8],
+        }
+    }
+
+    /// Returns the mode described by the specified execution trace metadata.
+    ///
+    /// Metadata which does not describe the 8-bit mode is interpreted as the 16-bit mode.
+    pub fn from_trace_meta(meta: &[u8]) -> Self {
+        match meta {
+            [8] => Self::Bits8,
+            _ => Self::Bits16,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use super::{
     AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, Procedure,
-    ProcedureCache, ProcedureId, ToString, Vec,
+    ProcedureCache, ProcedureId, StackEffect, ToString, Vec,
 };
 use crate::ProcedureName;
+use core::iter;
 
 // ASSEMBLY CONTEXT
 // ================================================================================================
@@ -71,7 +72,8 @@ impl AssemblyContext {
     /// dependencies.
     ///
     /// # Errors
-    /// Returns an error if a module with the same path already exists in the module stack.
+    /// Returns an error if a module with the same path already exists in the module stack. The
+    /// error describes the chain of imports which leads from this module back to itself.
     pub fn begin_module(&mut self, module_path: &LibraryPath) -> Result<(), AssemblyError> {
         if self.is_kernel && self.module_stack.is_empty() {
             // a kernel context must be initialized with a kernel module path
@@ -82,9 +84,18 @@ impl AssemblyContext {
         }
 
         // make sure this module is not in the chain of modules which are currently being compiled
-        if self.module_stack.iter().any(|m| &m.path == module_path) {
-            let dep_chain =
-                self.module_stack.iter().map(|m| m.path.to_string()).collect::<Vec<_>>();
+        if let Some(pos) = self.module_stack.iter().position(|m| &m.path == module_path) {
+            // the module imports itself directly
+            if pos == self.module_stack.len() - 1 {
+                return Err(AssemblyError::self_import(module_path.as_str()));
+            }
+
+            // the chain of imports starts and ends with this module
+            let dep_chain = self.module_stack[pos..]
+                .iter()
+                .map(|m| m.path.to_string())
+                .chain(iter::once(module_path.to_string()))
+                .collect::<Vec<_>>();
             return Err(AssemblyError::circular_module_dependency(&dep_chain));
         }
 
@@ -141,8 +152,14 @@ impl AssemblyContext {
 
     /// Completes compilation of the current procedure and adds the compiled procedure to the list
     /// of the current module's compiled procedures.
-    pub fn complete_proc(&mut self, code_root: CodeBlock) {
-        self.module_stack.last_mut().expect("no modules").complete_proc(code_root);
+    ///
+    /// The stack effect of the procedure is None if it was not computed or could not be
+    /// determined statically.
+    pub fn complete_proc(&mut self, code_root: CodeBlock, stack_effect: Option<StackEffect>) {
+        self.module_stack
+            .last_mut()
+            .expect("no modules")
+            .complete_proc(code_root, stack_effect);
     }
 
     // CALL PROCESSORS
@@ -206,6 +223,43 @@ impl AssemblyContext {
         Ok(())
     }
 
+    /// Registers a call to a procedure which is not available to the assembler (i.e., a phantom
+    /// call), specified by its MAST root.
+    ///
+    /// Since the body of the procedure is not known, the callset of the procedure currently being
+    /// compiled is not updated, and the procedure will not be a part of the code block table of
+    /// the compiled program.
+    ///
+    /// # Errors
+    /// Returns an error if we are compiling a kernel.
+    pub fn register_phantom_call(&self) -> Result<(), AssemblyError> {
+        // non-inlined calls (i.e., `call` instructions) cannot be executed in a kernel
+        if self.is_kernel {
+            let proc_name = &self.current_proc_context().expect("no procedure").name;
+            return Err(AssemblyError::call_in_kernel(proc_name));
+        }
+
+        Ok(())
+    }
+
+    /// Registers a dynamic call to a procedure specified via the stack (i.e., a `dyncall`
+    /// instruction).
+    ///
+    /// Since the target of a dynamic call is not known at compile time, the callset of the
+    /// procedure currently being compiled is not updated.
+    ///
+    /// # Errors
+    /// Returns an error if we are compiling a kernel.
+    pub fn register_dynamic_call(&self) -> Result<(), AssemblyError> {
+        // dynamic calls cannot be executed in a kernel for the same reasons as `call` instructions
+        if self.is_kernel {
+            let proc_name = &self.current_proc_context().expect("no procedure").name;
+            return Err(AssemblyError::call_in_kernel(proc_name));
+        }
+
+        Ok(())
+    }
+
     // CONTEXT FINALIZERS
     // --------------------------------------------------------------------------------------------
 
@@ -259,6 +313,23 @@ impl AssemblyContext {
         Ok(cb_table)
     }
 
+    // UNROLLING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of instructions the `repeat` blocks of the procedure currently being
+    /// compiled have been unrolled into so far.
+    pub fn num_unrolled_instructions(&self) -> usize {
+        self.current_proc_context().map_or(0, |proc| proc.num_unrolled_instructions)
+    }
+
+    /// Sets the number of instructions the `repeat` blocks of the procedure currently being
+    /// compiled have been unrolled into.
+    pub fn set_num_unrolled_instructions(&mut self, num_instructions: usize) {
+        if let Some(proc) = self.module_stack.last_mut().and_then(|m| m.proc_stack.last_mut()) {
+            proc.num_unrolled_instructions = num_instructions;
+        }
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -268,12 +339,24 @@ impl AssemblyContext {
         self.module_stack.last().and_then(|m| m.proc_stack.last())
     }
 
+    /// Returns a compiled local procedure at the specified index in the module currently being
+    /// compiled, or None if such procedure has not been compiled yet.
+    pub(crate) fn get_local_proc(&self, proc_idx: u16) -> Option<&Procedure> {
+        self.module_stack.last().and_then(|m| m.compiled_procs.get(proc_idx as usize))
+    }
+
     /// Returns the name of the current procedure, or the reserved name for the main block.
     pub(crate) fn current_context_name(&self) -> &str {
         self.current_proc_context()
             .map(|p| p.name().as_ref())
             .expect("library compilation mode is currently not supported!")
     }
+
+    /// Returns the path of the module currently being compiled, or None if the executable module
+    /// of a program is being compiled.
+    pub(crate) fn current_module_path(&self) -> Option<&LibraryPath> {
+        self.module_stack.last().filter(|m| !m.is_executable()).map(|m| &m.path)
+    }
 }
 
 // MODULE CONTEXT
@@ -373,7 +456,7 @@ impl ModuleContext {
     /// compiled procedure, and adds it to the list of compiled procedures.
     ///
     /// This also updates module callset to include the callset of the newly compiled procedure.
-    pub fn complete_proc(&mut self, code_root: CodeBlock) {
+    pub fn complete_proc(&mut self, code_root: CodeBlock, stack_effect: Option<StackEffect>) {
         let proc_context = self.proc_stack.pop().expect("no procedures");
 
         // build an ID for the procedure as follows:
@@ -386,7 +469,7 @@ impl ModuleContext {
             ProcedureId::from_index(proc_idx, &self.path)
         };
 
-        let proc = proc_context.into_procedure(proc_id, code_root);
+        let proc = proc_context.into_procedure(proc_id, code_root, stack_effect);
         self.callset.append(proc.callset());
         self.compiled_procs.push(proc);
     }
@@ -480,6 +563,7 @@ struct ProcedureContext {
     is_export: bool,
     num_locals: u16,
     callset: CallSet,
+    num_unrolled_instructions: usize,
 }
 
 impl ProcedureContext {
@@ -489,6 +573,7 @@ impl ProcedureContext {
             is_export,
             num_locals,
             callset: CallSet::default(),
+            num_unrolled_instructions: 0,
         }
     }
 
@@ -503,14 +588,21 @@ impl ProcedureContext {
         &self.name
     }
 
-    pub fn into_procedure(self, id: ProcedureId, code_root: CodeBlock) -> Procedure {
+    pub fn into_procedure(
+        self,
+        id: ProcedureId,
+        code_root: CodeBlock,
+        stack_effect: Option<StackEffect>,
+    ) -> Procedure {
         let Self {
             name,
             is_export,
             num_locals,
             callset,
+            ..
         } = self;
 
         Procedure::new(id, name, is_export, num_locals as u32, code_root, callset)
+            .with_stack_effect(stack_effect)
     }
 }
@@ -0,0 +1,259 @@
+use super::{
+    collect_local_calls, find_reachable_procs, Assembler, AssemblyWarning, BTreeMap, BTreeSet,
+    CodeBody, Instruction, LibraryPath, Module, Node, ProcedureId, ProcedureName, ProgramAst,
+    SourceLocation, String, Vec,
+};
+use crate::{Felt, StarkField};
+use core::iter;
+use vm_core::WORD_SIZE;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Prefix of the doc comment line which describes the inputs of a procedure.
+const DOC_INPUT_PREFIX: &str = "Input:";
+
+/// Prefix of the doc comment line which describes the outputs of a procedure.
+const DOC_OUTPUT_PREFIX: &str = "Output:";
+
+// DIAGNOSTICS
+// ================================================================================================
+
+impl Assembler {
+    /// Returns warnings about the provided program.
+    ///
+    /// The program is expected to have been compiled successfully by this assembler, and thus,
+    /// all of its imported procedures are expected to be available via the module provider.
+    /// `import_locations` maps the names under which the imported modules can be referenced to
+    /// the locations of the corresponding `use` statements.
+    pub(super) fn collect_warnings(
+        &self,
+        program: &ProgramAst,
+        import_locations: &BTreeMap<String, SourceLocation>,
+    ) -> Vec<AssemblyWarning> {
+        let mut warnings = Vec::new();
+        let bodies = iter::once(program.body()).chain(program.procedures().iter().map(|p| &p.body));
+
+        // imported modules which are never referenced; a module is considered to be referenced
+        // even if it is referenced only from an unused local procedure
+        let mut imported_calls = Vec::new();
+        for body in bodies.clone() {
+            collect_imported_calls(body, &mut imported_calls);
+        }
+        let used_modules = imported_calls
+            .iter()
+            .filter_map(|proc_id| self.module_provider.get_module(proc_id))
+            .map(|module| &module.path)
+            .collect::<BTreeSet<_>>();
+        for (name, module_path) in program.imports() {
+            if !used_modules.contains(module_path) {
+                let location = import_locations.get(name).copied().unwrap_or_default();
+                warnings.push(AssemblyWarning::unused_import(module_path, location));
+            }
+        }
+
+        // local procedures which are not reachable from the program body
+        let mut roots = Vec::new();
+        collect_local_calls(program.body(), &mut roots);
+        let reachable = find_reachable_procs(program.procedures(), roots);
+        for (proc_idx, proc) in program.procedures().iter().enumerate() {
+            if !reachable.contains(&(proc_idx as u16)) {
+                warnings.push(AssemblyWarning::unused_procedure(&proc.name, proc.start));
+            }
+        }
+
+        // local procedures with the same names as procedures exported from imported modules
+        for proc in program.procedures() {
+            for module_path in program.imports().values() {
+                if self.exports_procedure(module_path, &proc.name) {
+                    warnings.push(AssemblyWarning::shadowed_procedure(
+                        &proc.name,
+                        module_path,
+                        proc.start,
+                    ));
+                }
+            }
+        }
+
+        // constant arithmetic which wraps around the field modulus
+        for body in bodies {
+            collect_reduced_immediates(body, &mut warnings);
+        }
+
+        warnings
+    }
+
+    /// Returns warnings about the procedures exported from the provided library modules.
+    ///
+    /// The procedures are expected to have been compiled by this assembler. A warning is reported
+    /// for every procedure whose documented change of the stack depth differs from the one
+    /// computed by the stack depth analysis; procedures compiled without the analysis are skipped.
+    pub(super) fn collect_library_warnings(&self, modules: &[Module]) -> Vec<AssemblyWarning> {
+        let mut warnings = Vec::new();
+        let proc_cache = self.proc_cache.borrow();
+        for module in modules {
+            for proc in module.ast.procs().iter().filter(|proc| proc.is_export) {
+                let documented = match proc.docs.as_deref().and_then(documented_depth_change) {
+                    Some(depth_change) => depth_change,
+                    None => continue,
+                };
+                let proc_id = ProcedureId::from_name(&proc.name, &module.path);
+                let actual = proc_cache
+                    .get_by_id(&proc_id)
+                    .and_then(|proc| proc.stack_effect())
+                    .map(|effect| effect.depth_change());
+                if let Some(actual) = actual.filter(|&actual| actual != documented) {
+                    warnings.push(AssemblyWarning::stack_effect_mismatch(
+                        &module.path.append_unchecked(&proc.name),
+                        documented,
+                        actual,
+                        proc.start,
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Returns true if the module at the specified path exports (or re-exports) a procedure with
+    /// the specified name.
+    fn exports_procedure(&self, module_path: &LibraryPath, proc_name: &ProcedureName) -> bool {
+        let proc_id = ProcedureId::from_name(proc_name, module_path);
+        match self.module_provider.get_module(&proc_id) {
+            Some(module) => {
+                module.ast.procs().iter().any(|proc| proc.is_export && &proc.name == proc_name)
+                    || module.ast.reexported_procs().iter().any(|proc| proc.name() == proc_name)
+            }
+            None => false,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Collects IDs of all imported procedures invoked or referenced from the specified body.
+fn collect_imported_calls(body: &CodeBody, calls: &mut Vec<ProcedureId>) {
+    for node in body.nodes() {
+        match node {
+            Node::Instruction(Instruction::ExecImported(proc_id))
+            | Node::Instruction(Instruction::CallImported(proc_id))
+            | Node::Instruction(Instruction::ProcRefImported(proc_id)) => calls.push(*proc_id),
+            Node::Instruction(_) => (),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_imported_calls(true_case, calls);
+                collect_imported_calls(false_case, calls);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => collect_imported_calls(body, calls),
+        }
+    }
+}
+
+/// Adds a warning for every pair of consecutive instructions in the specified body which apply
+/// an immediate value to a constant pushed onto the stack, if the result of this operation is
+/// reduced modulo the field (e.g., `push.1 sub.2`).
+fn collect_reduced_immediates(body: &CodeBody, warnings: &mut Vec<AssemblyWarning>) {
+    let nodes = body.nodes();
+    let locations = body.source_locations();
+    for (idx, node) in nodes.iter().enumerate() {
+        match node {
+            Node::Instruction(instruction) => {
+                let prev = match idx.checked_sub(1).and_then(|i| nodes.get(i)) {
+                    Some(Node::Instruction(prev)) => prev,
+                    _ => continue,
+                };
+                if let Some(value) = pushed_value(prev) {
+                    if is_reduced(value, instruction) {
+                        let instructions = format!("{prev} {instruction}");
+                        let location = locations.get(idx).copied().unwrap_or_default();
+                        warnings.push(AssemblyWarning::reduced_immediate(&instructions, location));
+                    }
+                }
+            }
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_reduced_immediates(true_case, warnings);
+                collect_reduced_immediates(false_case, warnings);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => {
+                collect_reduced_immediates(body, warnings)
+            }
+        }
+    }
+}
+
+/// Returns the value pushed onto the stack by the specified instruction if it pushes a single
+/// constant.
+fn pushed_value(instruction: &Instruction) -> Option<u64> {
+    match instruction {
+        Instruction::PushU8(value) => Some(*value as u64),
+        Instruction::PushU16(value) => Some(*value as u64),
+        Instruction::PushU32(value) => Some(*value as u64),
+        Instruction::PushFelt(value) => Some(value.as_int()),
+        _ => None,
+    }
+}
+
+/// Returns true if applying the specified instruction to the specified constant produces a result
+/// which is reduced modulo the field.
+fn is_reduced(value: u64, instruction: &Instruction) -> bool {
+    let value = value as u128;
+    let modulus = Felt::MODULUS as u128;
+    match instruction {
+        Instruction::Incr => value + 1 >= modulus,
+        Instruction::AddImm(imm) => value + imm.as_int() as u128 >= modulus,
+        Instruction::SubImm(imm) => value < imm.as_int() as u128,
+        Instruction::MulImm(imm) => value * imm.as_int() as u128 >= modulus,
+        _ => false,
+    }
+}
+
+/// Returns the net change of the stack depth documented in the provided doc comment, if the doc
+/// comment describes both the inputs and the outputs of a procedure (e.g., `Input: [a, B, ...]`
+/// and `Output: [c, ...]`).
+fn documented_depth_change(docs: &str) -> Option<i64> {
+    let mut inputs = None;
+    let mut outputs = None;
+    for line in docs.lines().map(str::trim) {
+        if let Some(stack) = line.strip_prefix(DOC_INPUT_PREFIX) {
+            inputs = inputs.or(Some(stack));
+        } else if let Some(stack) = line.strip_prefix(DOC_OUTPUT_PREFIX) {
+            outputs = outputs.or(Some(stack));
+        }
+    }
+
+    let num_inputs = count_stack_items(inputs?)? as i64;
+    let num_outputs = count_stack_items(outputs?)? as i64;
+    Some(num_outputs - num_inputs)
+}
+
+/// Returns the number of stack elements described by the provided stack description.
+///
+/// The description must be a list of item names terminated by `...` (e.g., `[a, B, ...]`). Names
+/// without lowercase letters which start with an uppercase letter denote words (e.g., `B` or
+/// `HASH'`), and all other names denote single elements. None is returned for descriptions which
+/// do not follow this convention.
+fn count_stack_items(stack: &str) -> Option<usize> {
+    let (items, _) = stack.trim().strip_prefix('[')?.split_once(']')?;
+    let mut items = items.split(',').map(str::trim).collect::<Vec<_>>();
+    if items.pop()? != "..." {
+        return None;
+    }
+
+    items.into_iter().try_fold(0, |count, item| {
+        let is_name = !item.is_empty()
+            && item.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\'');
+        if !is_name {
+            return None;
+        }
+        let is_word = item.starts_with(|c: char| c.is_ascii_uppercase())
+            && !item.chars().any(|c| c.is_ascii_lowercase());
+        Some(count + if is_word { WORD_SIZE } else { 1 })
+    })
+}
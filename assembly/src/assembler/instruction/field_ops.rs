@@ -3,6 +3,7 @@ use super::{
     StarkField, ONE, ZERO,
 };
 use crate::MAX_EXP_BITS;
+use vm_core::AdviceInjector::ILog2;
 
 /// Field element representing TWO in the base field of the VM.
 const TWO: Felt = Felt::new(2);
@@ -14,7 +15,14 @@ const TWO: Felt = Felt::new(2);
 ///
 /// VM cycles: 11 cycles
 pub fn assertw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
-    span.add_ops([MovUp4, Eq, Assert, MovUp3, Eq, Assert, MovUp2, Eq, Assert, Eq, Assert])
+    #[rustfmt::skip]
+    let ops = [
+        MovUp4, Eq, Assert(ZERO),
+        MovUp3, Eq, Assert(ZERO),
+        MovUp2, Eq, Assert(ZERO),
+        Eq, Assert(ZERO),
+    ];
+    span.add_ops(ops)
 }
 
 // BASIC ARITHMETIC OPERATIONS
@@ -110,7 +118,45 @@ pub fn append_pow2_op(span: &mut SpanBuilder) {
     // drop the top two elements bit and exp value of the latest bit.
     span.push_ops([Drop, Drop]);
     // taking `b` to the top and asserting if it's equal to ZERO after all the right shifts.
-    span.push_ops([Swap, Eqz, Assert]);
+    span.push_ops([Swap, Eqz, Assert(ZERO)]);
+}
+
+/// Appends a sequence of operations to compute the base 2 logarithm (rounded down) of the value
+/// at the top of the stack.
+///
+/// The logarithm k is provided non-deterministically via the advice stack, and the operations
+/// verify that 2^k <= a < 2^(k + 1). This is done by checking that both a - 2^k and
+/// 2^(k + 1) - 1 - a are smaller than 2^62: if any of the inequalities does not hold, one of
+/// these values wraps around the field modulus and fails the check. The execution fails if the
+/// value is ZERO or if it is greater than or equal to 2^63.
+///
+/// VM cycles: 48 cycles
+pub fn ilog2(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // read the logarithm from the advice stack and compute 2^k: [a, ...] -> [2^k, k, a, ...]
+    span.push_advice_injector(ILog2);
+    span.push_ops([AdvPop, Dup0]);
+    append_pow2_op(span);
+
+    // check that a - 2^k is in the range [0, 2^62)
+    span.push_ops([Dup2, Dup1, Neg, Add]);
+    append_lt_2_62_check(span);
+
+    // check that 2^(k + 1) - 1 - a is in the range [0, 2^62)
+    span.push_ops([Dup0, Dup0, Add, Dup3, Incr, Neg, Add]);
+    append_lt_2_62_check(span);
+
+    // drop everything except for the logarithm: [2^k, k, a, ...] -> [k, ...]
+    span.add_ops([Drop, Swap, Drop])
+}
+
+/// Appends a sequence of operations which pops the value at the top of the stack and fails if
+/// this value is greater than or equal to 2^62.
+///
+/// VM cycles: 8 cycles
+fn append_lt_2_62_check(span: &mut SpanBuilder) {
+    // the value is smaller than 2^62 if and only if 4 * hi is a 32-bit value, where hi is the
+    // upper 32-bit limb of the value
+    span.push_ops([U32split, Push(Felt::new(4)), Mul, U32split, Eqz, Assert(ZERO), Drop, Drop]);
 }
 
 // EXPONENTIATION OPERATION
@@ -139,7 +185,7 @@ pub fn exp(span: &mut SpanBuilder, num_pow_bits: u8) -> Result<Option<CodeBlock>
     span.push_ops([Drop, Drop]);
 
     // taking `b` to the top and asserting if it's equal to ZERO after all the right shifts.
-    span.push_ops([Swap, Eqz, Assert]);
+    span.push_ops([Swap, Eqz, Assert(ZERO)]);
     Ok(None)
 }
 
@@ -105,12 +105,16 @@ pub fn mem_write_imm(
 /// - 2 VM cycles if index != 1
 ///
 /// # Errors
-/// Returns an error if index is greater than the number of procedure locals.
+/// Returns an error if the procedure has no locals, or if index is greater than the number of
+/// procedure locals.
 pub fn local_to_absolute_addr(
     span: &mut SpanBuilder,
     index: u16,
     num_proc_locals: u16,
 ) -> Result<(), AssemblyError> {
+    if num_proc_locals == 0 {
+        return Err(AssemblyError::locals_not_allocated(index));
+    }
     let max = num_proc_locals - 1;
     validate_param(index, 0..=max)?;
 
@@ -1,10 +1,10 @@
 use super::{
     Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, Operation,
-    ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
+    ProcedureId, RpoDigest, SourceLocation, SpanBuilder, ONE, ZERO,
 };
 use crate::utils::bound_into_included_u64;
 use core::ops::RangeBounds;
-use vm_core::{FieldElement, StarkField};
+use vm_core::{Decorator, FieldElement, StarkField};
 
 mod adv_ops;
 mod crypto_ops;
@@ -24,6 +24,7 @@ impl Assembler {
     pub(super) fn compile_instruction(
         &self,
         instruction: &Instruction,
+        location: Option<&SourceLocation>,
         span: &mut SpanBuilder,
         ctx: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
@@ -33,14 +34,17 @@ impl Assembler {
         // this will allow us to map the instruction to the sequence of operations which were
         // executed as a part of this instruction.
         if self.in_debug_mode() {
-            span.track_instruction(instruction, ctx);
+            span.track_instruction(instruction, location, ctx);
         }
 
         let result = match instruction {
-            Instruction::Assert => span.add_op(Assert),
-            Instruction::AssertEq => span.add_ops([Eq, Assert]),
+            Instruction::Assert => span.add_op(Assert(ZERO)),
+            Instruction::AssertWithError(err_code) => span.add_op(Assert(*err_code)),
+            Instruction::AssertEq => span.add_ops([Eq, Assert(ZERO)]),
+            Instruction::AssertEqWithError(err_code) => span.add_ops([Eq, Assert(*err_code)]),
             Instruction::AssertEqw => field_ops::assertw(span),
-            Instruction::Assertz => span.add_ops([Eqz, Assert]),
+            Instruction::Assertz => span.add_ops([Eqz, Assert(ZERO)]),
+            Instruction::AssertzWithError(err_code) => span.add_ops([Eqz, Assert(*err_code)]),
 
             Instruction::Add => span.add_op(Add),
             Instruction::AddImm(imm) => field_ops::add_imm(span, *imm),
@@ -55,6 +59,7 @@ impl Assembler {
             Instruction::Incr => span.add_op(Incr),
 
             Instruction::Pow2 => field_ops::pow2(span),
+            Instruction::ILog2 => field_ops::ilog2(span),
             Instruction::Exp => field_ops::exp(span, 64),
             Instruction::ExpImm(pow) => field_ops::exp_imm(span, *pow),
             Instruction::ExpBitLength(num_pow_bits) => field_ops::exp(span, *num_pow_bits),
@@ -86,8 +91,12 @@ impl Assembler {
             // ----- u32 manipulation -------------------------------------------------------------
             Instruction::U32Test => span.add_ops([Dup0, U32split, Swap, Drop, Eqz]),
             Instruction::U32TestW => u32_ops::u32testw(span),
-            Instruction::U32Assert => span.add_ops([Pad, U32assert2, Drop]),
-            Instruction::U32Assert2 => span.add_op(U32assert2),
+            Instruction::U32Assert => span.add_ops([Pad, U32assert2(ZERO), Drop]),
+            Instruction::U32AssertWithError(err_code) => {
+                span.add_ops([Pad, U32assert2(*err_code), Drop])
+            }
+            Instruction::U32Assert2 => span.add_op(U32assert2(ZERO)),
+            Instruction::U32Assert2WithError(err_code) => span.add_op(U32assert2(*err_code)),
             Instruction::U32AssertW => u32_ops::u32assertw(span),
             Instruction::U32Cast => span.add_ops([U32split, Drop]),
             Instruction::U32Split => span.add_op(U32split),
@@ -138,10 +147,14 @@ impl Assembler {
             Instruction::U32CheckedShlImm(v) => u32_ops::u32shl(span, Checked, Some(*v)),
             Instruction::U32UncheckedShl => u32_ops::u32shl(span, Unchecked, None),
             Instruction::U32UncheckedShlImm(v) => u32_ops::u32shl(span, Unchecked, Some(*v)),
+            Instruction::U32OverflowingShl => u32_ops::u32shl(span, Overflowing, None),
+            Instruction::U32OverflowingShlImm(v) => u32_ops::u32shl(span, Overflowing, Some(*v)),
             Instruction::U32CheckedShr => u32_ops::u32shr(span, Checked, None),
             Instruction::U32CheckedShrImm(v) => u32_ops::u32shr(span, Checked, Some(*v)),
             Instruction::U32UncheckedShr => u32_ops::u32shr(span, Unchecked, None),
             Instruction::U32UncheckedShrImm(v) => u32_ops::u32shr(span, Unchecked, Some(*v)),
+            Instruction::U32OverflowingShr => u32_ops::u32shr(span, Overflowing, None),
+            Instruction::U32OverflowingShrImm(v) => u32_ops::u32shr(span, Overflowing, Some(*v)),
             Instruction::U32CheckedRotl => u32_ops::u32rotl(span, Checked, None),
             Instruction::U32CheckedRotlImm(v) => u32_ops::u32rotl(span, Checked, Some(*v)),
             Instruction::U32UncheckedRotl => u32_ops::u32rotl(span, Unchecked, None),
@@ -305,15 +318,29 @@ impl Assembler {
             Instruction::CallMastRoot(root) => self.call_mast_root(root, ctx),
             Instruction::CallImported(id) => self.call_imported(id, ctx),
             Instruction::SysCall(id) => self.syscall(id, ctx),
+            Instruction::DynExec => self.dynexec(),
+            Instruction::DynCall => self.dyncall(ctx),
+            Instruction::ProcRefLocal(idx) => self.procref_local(*idx, ctx, span),
+            Instruction::ProcRefImported(id) => self.procref_imported(id, ctx, span),
 
             // ----- debug decorators -------------------------------------------------------------
             Instruction::Breakpoint => {
                 if self.in_debug_mode() {
                     span.add_op(Noop)?;
-                    span.track_instruction(instruction, ctx);
+                    span.track_instruction(instruction, location, ctx);
                 }
                 Ok(None)
             }
+            Instruction::Debug(options) => {
+                if self.in_debug_mode() {
+                    span.push_decorator(Decorator::Debug(*options));
+                }
+                Ok(None)
+            }
+            Instruction::Trace(trace_id) => {
+                span.push_decorator(Decorator::Trace(*trace_id));
+                Ok(None)
+            }
         };
 
         // compute and update the cycle count of the instruction which just finished executing
@@ -1,4 +1,7 @@
-use super::{Assembler, AssemblyContext, AssemblyError, CodeBlock, ProcedureId, RpoDigest};
+use super::{
+    push_felt, Assembler, AssemblyContext, AssemblyError, CodeBlock, ProcedureId, RpoDigest,
+    SpanBuilder,
+};
 
 // PROCEDURE INVOCATIONS
 // ================================================================================================
@@ -68,20 +71,23 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
 
-        // TODO: consider relaxing the restriction that mast roots must exist in the procedure
-        // cache; see https://github.com/0xPolygonMiden/miden-vm/pull/911/files#r1202962206
-        let proc = proc_cache
-            .get_by_hash(root)
-            .ok_or(AssemblyError::proc_mast_root_not_found(root))?;
-        debug_assert!(proc.is_export(), "not imported procedure");
-
-        // register and "non-inlined" call to the procedure; this updates the callset of the
-        // procedure currently being compiled
-        context.register_external_call(proc, false)?;
+        match proc_cache.get_by_hash(root) {
+            Some(proc) => {
+                debug_assert!(proc.is_export(), "not imported procedure");
+
+                // register and "non-inlined" call to the procedure; this updates the callset of
+                // the procedure currently being compiled
+                context.register_external_call(proc, false)?;
+            }
+            None => {
+                // the procedure is not known to the assembler, and thus, this is a phantom call;
+                // the code block of the procedure is expected to be supplied at runtime
+                context.register_phantom_call()?;
+            }
+        }
 
         // create a new CALL block for the procedure call and return
-        let digest = proc.code_root().hash();
-        Ok(Some(CodeBlock::new_call(digest)))
+        Ok(Some(CodeBlock::new_call(*root)))
     }
 
     pub(super) fn call_imported(
@@ -132,4 +138,72 @@ impl Assembler {
         let digest = proc.code_root().hash();
         Ok(Some(CodeBlock::new_syscall(digest)))
     }
+
+    pub(super) fn dynexec(&self) -> Result<Option<CodeBlock>, AssemblyError> {
+        // create a new DYN block for the dynamic code execution and return
+        Ok(Some(CodeBlock::new_dyn()))
+    }
+
+    pub(super) fn dyncall(
+        &self,
+        context: &mut AssemblyContext,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        // the target of the call is not known at compile time, and thus, the callset of the
+        // procedure currently being compiled is not updated
+        context.register_dynamic_call()?;
+
+        // create a new CALL block whose target is a DYN block and return
+        Ok(Some(CodeBlock::new_dyncall()))
+    }
+
+    // PROCEDURE REFERENCES
+    // --------------------------------------------------------------------------------------------
+
+    pub(super) fn procref_local(
+        &self,
+        proc_idx: u16,
+        context: &mut AssemblyContext,
+        span: &mut SpanBuilder,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        // register a "non-inlined" call to the procedure at the specified index in the module
+        // currently being compiled; this adds the procedure to the code block table of the
+        // program so that it can be invoked dynamically via its MAST root
+        let proc = context.register_local_call(proc_idx, false)?;
+        let digest = proc.code_root().hash();
+        procref(digest, span)
+    }
+
+    pub(super) fn procref_imported(
+        &self,
+        proc_id: &ProcedureId,
+        context: &mut AssemblyContext,
+        span: &mut SpanBuilder,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        // make sure the procedure is in procedure cache
+        self.ensure_procedure_is_in_cache(proc_id, context)?;
+
+        // get the procedure from the assembler
+        let proc_cache = self.proc_cache.borrow();
+        let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        debug_assert!(proc.is_export(), "not imported procedure");
+
+        // register a "non-inlined" call to the procedure; as above, this makes the procedure
+        // available for dynamic invocation
+        context.register_external_call(proc, false)?;
+
+        let digest = proc.code_root().hash();
+        procref(digest, span)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends operations which push the elements of the specified MAST root onto the stack, such that
+/// the last element of the root ends up at the top of the stack.
+fn procref(digest: RpoDigest, span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    for element in digest.as_elements() {
+        push_felt(span, *element);
+    }
+    Ok(None)
 }
@@ -2,7 +2,7 @@ use super::{
     field_ops::append_pow2_op,
     push_u32_value, validate_param, AssemblyError, CodeBlock, Felt,
     Operation::{self, *},
-    SpanBuilder,
+    SpanBuilder, ZERO,
 };
 use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
 
@@ -51,13 +51,13 @@ pub fn u32assertw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyE
     #[rustfmt::skip]
     let ops = [
         // Test the first and the second elements
-        U32assert2,
+        U32assert2(ZERO),
 
         // Move 3 and 4 to the top of the stack
         MovUp3, MovUp3,
 
         // Test them
-        U32assert2,
+        U32assert2(ZERO),
 
         // Move the elements back into place
         MovUp3, MovUp3,
@@ -207,7 +207,7 @@ pub fn u32not(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError
     let ops = [
         // Perform the operation
         Push(Felt::from(u32::MAX)),
-        U32assert2,
+        U32assert2(ZERO),
         Swap,
         U32sub,
 
@@ -221,38 +221,55 @@ pub fn u32not(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError
 ///
 /// The operation is implemented by putting a power of 2 on the stack, then multiplying it with
 /// the value to be shifted and splitting the result. For checked variants, the shift value is
-/// asserted to be between 0-31 and the value to be shifted is asserted to be a 32-bit value.
+/// asserted to be between 0-31 and the value to be shifted is asserted to be a 32-bit value. For
+/// overflowing variants, the bits shifted out of the value are left on the stack.
 ///
 /// VM cycles per mode:
 /// - u32checked_shl: 19 cycles
 /// - u32checked_shl.b: 4 cycles
 /// - u32unchecked_shl: 18 cycles
 /// - u32unchecked_shl.b: 3 cycles
+/// - u32overflowing_shl: 17 cycles
+/// - u32overflowing_shl.b: 2 cycles
 pub fn u32shl(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, [U32mul, Drop])
+    match op_mode {
+        U32OpMode::Overflowing => {
+            prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, &[U32mul])
+        }
+        _ => prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, &[U32mul, Drop]),
+    }
 }
 
 /// Translates u32shr assembly instructions to VM operations.
 ///
 /// The operation is implemented by putting a power of 2 on the stack, then dividing the value to
 /// be shifted by it and returning the quotient. For checked variants, the shift value is asserted
-/// to be between 0-31 and the value to be shifted is asserted to be a 32-bit value.
+/// to be between 0-31 and the value to be shifted is asserted to be a 32-bit value. For
+/// overflowing variants, the remainder of the division (i.e., the bits shifted out of the value)
+/// is left on the stack.
 ///
 /// VM cycles per mode:
 /// - u32checked_shr: 19 cycles
 /// - u32checked_shr.b: 4 cycles
 /// - u32unchecked_shr: 18 cycles
 /// - u32unchecked_shr.b: 3 cycles
+/// - u32overflowing_shr: 17 cycles
+/// - u32overflowing_shr.b: 2 cycles
 pub fn u32shr(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, [U32div, Drop])
+    match op_mode {
+        U32OpMode::Overflowing => {
+            prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, &[U32div])
+        }
+        _ => prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, &[U32div, Drop]),
+    }
 }
 
 /// Translates u32rotl assembly instructions to VM operations.
@@ -272,7 +289,7 @@ pub fn u32rotl(
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_ROTATE_VALUE>(span, imm, op_mode, [U32mul, Add])
+    prepare_bitwise::<MAX_U32_ROTATE_VALUE>(span, imm, op_mode, &[U32mul, Add])
 }
 
 /// Translates u32rotr assembly instructions to VM operations.
@@ -295,12 +312,12 @@ pub fn u32rotr(
     match (imm, op_mode) {
         (Some(imm), U32OpMode::Checked) if imm == 0 => {
             // if rotation is performed by 0, just verify that stack top is u32
-            span.push_ops([Pad, U32assert2, Drop]);
+            span.push_ops([Pad, U32assert2(ZERO), Drop]);
             return Ok(None);
         }
         (Some(imm), U32OpMode::Checked) => {
             validate_param(imm, 1..=MAX_U32_ROTATE_VALUE)?;
-            span.push_ops([Push(Felt::new(1 << (32 - imm))), U32assert2]);
+            span.push_ops([Push(Felt::new(1 << (32 - imm))), U32assert2(ZERO)]);
         }
         (Some(imm), U32OpMode::Unchecked) if imm == 0 => {
             // if rotation is performed by 0, do nothing (Noop)
@@ -315,10 +332,10 @@ pub fn u32rotr(
             #[rustfmt::skip]
             span.push_ops([
                 // Verify both b and a are u32.
-                U32assert2,
+                U32assert2(ZERO),
 
                 // Calculate 32 - b and assert that the shift value b <= 31.
-                Push(Felt::from(MAX_U32_ROTATE_VALUE)), Dup1, U32sub, Not, Assert, Incr, Dup1,
+                Push(Felt::from(MAX_U32_ROTATE_VALUE)), Dup1, U32sub, Not, Assert(ZERO), Incr, Dup1,
 
                 // If 32-b = 32, replace it with 0.
                 Eqz, Not, CSwap, Drop,
@@ -345,7 +362,7 @@ pub fn u32popcnt(
     op_mode: U32OpMode,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
     match op_mode {
-        U32OpMode::Checked => span.push_ops([Pad, U32assert2, Drop]),
+        U32OpMode::Checked => span.push_ops([Pad, U32assert2(ZERO), Drop]),
         U32OpMode::Unchecked => (),
         _ => unreachable!("unsupported operation mode"),
     }
@@ -404,7 +421,7 @@ fn handle_arithmetic_operation(
 
     match op_mode {
         U32OpMode::Checked => {
-            span.push_op(U32assert2);
+            span.push_op(U32assert2(ZERO));
             assert_u32_res = true;
         }
         U32OpMode::Wrapping => {
@@ -417,7 +434,7 @@ fn handle_arithmetic_operation(
     span.push_op(op);
 
     if assert_u32_res {
-        span.add_ops([Eqz, Assert])
+        span.add_ops([Eqz, Assert(ZERO)])
     } else if drop_high_bits {
         span.add_op(Drop)
     } else {
@@ -441,7 +458,7 @@ fn handle_division(
 
     match op_mode {
         U32OpMode::Checked => {
-            span.push_op(U32assert2);
+            span.push_op(U32assert2(ZERO));
         }
         U32OpMode::Unchecked => {}
         _ => unreachable!("unsupported operation mode"),
@@ -461,38 +478,46 @@ fn handle_division(
 /// to be greater than `u32::MAX`; therefore, the maximum valid value must be `31`, as defined in
 /// the helper constants.
 ///
-/// This function supports only checked and unchecked modes; if some other mode is provided, it
-/// will panic.
+/// In overflowing mode, the inputs are handled the same way as in unchecked mode, except that a
+/// shift by 0 pushes a ZERO onto the stack to stand in for the bits shifted out of the value.
+///
+/// This function supports only checked, unchecked, and overflowing modes; if some other mode is
+/// provided, it will panic.
 fn prepare_bitwise<const MAX_VALUE: u8>(
     span: &mut SpanBuilder,
     imm: Option<u8>,
     op_mode: U32OpMode,
-    final_ops: [Operation; 2],
+    final_ops: &[Operation],
 ) -> Result<Option<CodeBlock>, AssemblyError> {
     match (imm, op_mode) {
         (Some(imm), U32OpMode::Checked) if imm == 0 => {
             // if shift/rotation is performed by 0, just verify that stack top is u32
-            span.push_ops([Pad, U32assert2, Drop]);
+            span.push_ops([Pad, U32assert2(ZERO), Drop]);
             return Ok(None);
         }
         (Some(imm), U32OpMode::Checked) => {
             validate_param(imm, 1..=MAX_VALUE)?;
-            span.push_ops([Push(Felt::new(1 << imm)), U32assert2]);
+            span.push_ops([Push(Felt::new(1 << imm)), U32assert2(ZERO)]);
         }
         (Some(imm), U32OpMode::Unchecked) if imm == 0 => {
             // if shift/rotation is performed by 0, do nothing (Noop)
             span.push_op(Noop);
             return Ok(None);
         }
-        (Some(imm), U32OpMode::Unchecked) => {
+        (Some(imm), U32OpMode::Overflowing) if imm == 0 => {
+            // if shift is performed by 0, no bits are shifted out of the value
+            span.push_op(Pad);
+            return Ok(None);
+        }
+        (Some(imm), U32OpMode::Unchecked | U32OpMode::Overflowing) => {
             span.push_op(Push(Felt::new(1 << imm)));
         }
         (None, U32OpMode::Checked) => {
             // Assume the dynamic shift value b is on top of the stack.
             append_pow2_op(span);
-            span.push_op(U32assert2);
+            span.push_op(U32assert2(ZERO));
         }
-        (None, U32OpMode::Unchecked) => append_pow2_op(span),
+        (None, U32OpMode::Unchecked | U32OpMode::Overflowing) => append_pow2_op(span),
         _ => unreachable!("unsupported operation mode"),
     }
     span.add_ops(final_ops)
@@ -513,7 +538,7 @@ pub fn u32eq(span: &mut SpanBuilder, imm: Option<u32>) -> Result<Option<CodeBloc
         push_u32_value(span, imm);
     }
 
-    span.add_ops([U32assert2, Eq])
+    span.add_ops([U32assert2(ZERO), Eq])
 }
 
 /// Translates u32checked_neq assembly instruction to VM operations.
@@ -532,7 +557,7 @@ pub fn u32neq(
         push_u32_value(span, imm);
     }
 
-    span.add_ops([U32assert2, Eq, Not])
+    span.add_ops([U32assert2(ZERO), Eq, Not])
 }
 
 /// Translates u32lt assembly instructions to VM operations.
@@ -663,7 +688,7 @@ pub fn u32max(
 /// Handles u32 assertion and unchecked mode for any u32 operation.
 fn handle_u32_and_unchecked_mode(span: &mut SpanBuilder, op_mode: U32OpMode) {
     if op_mode == U32OpMode::Checked {
-        span.push_op(U32assert2);
+        span.push_op(U32assert2(ZERO));
     }
 }
 
@@ -684,7 +709,7 @@ fn compute_max_and_min(span: &mut SpanBuilder, op_mode: U32OpMode) {
     // Copy top two elements of the stack.
     span.push_ops([Dup1, Dup1]);
     if op_mode == U32OpMode::Checked {
-        span.push_op(U32assert2);
+        span.push_op(U32assert2(ZERO));
     }
 
     #[rustfmt::skip]
@@ -1,12 +1,16 @@
 use super::{
-    ast::{Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    ast::{
+        self, CodeBody, Instruction, ModuleAst, Node, ProcReExport, ProcedureAst, ProgramAst,
+        SourceLocation,
+    },
     btree_map,
     crypto::hash::RpoDigest,
-    AssemblyError, BTreeMap, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library,
-    LibraryError, LibraryPath, Module, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ToString, Vec, ONE, ZERO,
+    AssemblyError, AssemblyWarning, BTreeMap, BTreeSet, CallSet, CodeBlock, CodeBlockTable,
+    CompiledLibrary, Felt, Kernel, Library, LibraryError, LibraryNamespace, LibraryPath, Module,
+    Operation, Procedure, ProcedureId, ProcedureName, Program, String, ToString, Vec,
+    MAX_UNROLLED_INSTRUCTIONS, ONE, ZERO,
 };
-use core::{borrow::Borrow, cell::RefCell};
+use core::cell::RefCell;
 use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
 
 mod instruction;
@@ -23,6 +27,13 @@ pub use context::{AssemblyContext, AssemblyContextType};
 mod procedure_cache;
 use procedure_cache::ProcedureCache;
 
+mod optimizer;
+
+mod stack_effect;
+pub use stack_effect::StackEffect;
+
+mod diagnostics;
+
 #[cfg(test)]
 mod tests;
 
@@ -34,12 +45,47 @@ mod tests;
 /// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
 ///   instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
-#[derive(Default)]
+/// - If `with_max_unrolled_instructions()` method is not used, the `repeat` blocks of a single
+///   procedure will not be allowed to expand into more than 2^16 instructions in total.
+/// - If `with_dead_code_elimination()` method is not used, procedures which are not reachable from
+///   the code being compiled are not compiled.
+/// - If `with_optimizations()` method is not used, SPAN blocks contain the operations exactly as
+///   emitted for each instruction.
+/// - If `with_stack_depth_checks()` method is not used, the effect of procedures on the depth of
+///   the operand stack is not analyzed.
+/// - If `with_strict_mode()` method is not used, warnings detected while compiling a program do
+///   not cause the compilation to fail.
+///
+/// Procedures compiled by the assembler are cached, and the cache persists across calls to
+/// `compile()`. Thus, when the same assembler is used to compile many programs, each imported
+/// procedure is compiled only once. The cache can be pre-warmed by adding libraries via
+/// `with_precompiled_library()`.
 pub struct Assembler {
     kernel: Kernel,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    max_unrolled_instructions: usize,
+    eliminate_dead_code: bool,
+    optimize: bool,
+    check_stack_depth: bool,
+    strict: bool,
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Self {
+            kernel: Kernel::default(),
+            module_provider: ModuleProvider::default(),
+            proc_cache: RefCell::default(),
+            in_debug_mode: false,
+            max_unrolled_instructions: MAX_UNROLLED_INSTRUCTIONS,
+            eliminate_dead_code: true,
+            optimize: false,
+            check_stack_depth: false,
+            strict: false,
+        }
+    }
 }
 
 impl Assembler {
@@ -52,22 +98,176 @@ impl Assembler {
         self
     }
 
+    /// Sets the maximum number of instructions the `repeat` blocks of a single procedure (or of
+    /// the body of a program) can be unrolled into.
+    ///
+    /// The limit applies to all `repeat` blocks of a procedure together, with nested blocks
+    /// counted in their fully expanded form. Compiling a procedure whose `repeat` blocks expand
+    /// into more instructions than this will result in an error. Invocations of procedures via
+    /// `exec` are counted as a single instruction as the bodies of procedures are shared by all
+    /// of their invocations.
+    pub fn with_max_unrolled_instructions(mut self, max_unrolled_instructions: usize) -> Self {
+        self.max_unrolled_instructions = max_unrolled_instructions;
+        self
+    }
+
+    /// Enables or disables dead code elimination.
+    ///
+    /// When dead code elimination is enabled (the default), the assembler compiles only the
+    /// procedures which are reachable from the code being compiled:
+    /// - For programs, local procedures which are not invoked (directly or transitively) from the
+    ///   program body are skipped.
+    /// - For imported modules, only the procedures needed by the invoked procedure are compiled.
+    ///   Other procedures of the module are compiled if and when they are invoked.
+    ///
+    /// Procedures which are not reachable are never a part of the compiled MAST, and thus, this
+    /// affects only compilation time and errors in unreachable procedures, but not the compiled
+    /// programs. All procedures exported from kernels and from libraries compiled via
+    /// [Assembler::compile_library()] are always compiled.
+    pub fn with_dead_code_elimination(mut self, eliminate_dead_code: bool) -> Self {
+        self.eliminate_dead_code = eliminate_dead_code;
+        self
+    }
+
+    /// Enables or disables peephole optimization of SPAN blocks.
+    ///
+    /// When optimizations are enabled, operations of each SPAN block are simplified before the
+    /// block is hashed, and thus, the MAST roots of the compiled code reflect the optimized
+    /// operations. Specifically, arithmetic on constants is folded (e.g., `push.5 push.3 add`
+    /// becomes `push.8`), additive and multiplicative identities are removed (e.g., `push.0 add`),
+    /// and values which are pushed onto the stack only to be dropped are removed (e.g.,
+    /// `dup drop`). Operations are never folded across control flow boundaries or decorators, and
+    /// assertions are removed only when the asserted value is a known constant 1.
+    ///
+    /// Optimizations apply only to the procedures compiled after this method is called.
+    pub fn with_optimizations(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
+    /// Enables or disables static stack depth analysis.
+    ///
+    /// When enabled, the assembler computes the minimum stack depth required by each compiled
+    /// procedure and the net change of the stack depth caused by it (see [StackEffect]).
+    /// Compilation fails if:
+    /// - The branches of an if/else block change the stack depth by different amounts.
+    /// - An iteration of a while loop changes the stack depth (i.e., the loop body does not
+    ///   leave exactly one new loop condition on the stack).
+    /// - A procedure invoked via `call` or `syscall` requires more than 16 stack elements.
+    ///
+    /// Procedures which intentionally vary the stack depth can opt out of the first two checks
+    /// by including a `stack-unsafe` line in their doc comment. The stack effect of such
+    /// procedures is not known if they contain unbalanced control flow.
+    ///
+    /// Stack depth checks apply only to the procedures compiled after this method is called.
+    pub fn with_stack_depth_checks(mut self, check_stack_depth: bool) -> Self {
+        self.check_stack_depth = check_stack_depth;
+        self
+    }
+
+    /// Enables or disables strict mode.
+    ///
+    /// In strict mode, [Assembler::compile()] and [Assembler::compile_with_diagnostics()] return
+    /// an error for the first warning detected in the program being compiled (see
+    /// [AssemblyWarning]).
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
+    ///
+    /// If a library with the same namespace has already been added to this assembler, it is
+    /// replaced by the provided library. In this case, all procedures compiled by this assembler
+    /// so far (except for kernel procedures) are removed from the procedure cache as they may
+    /// have been compiled against the replaced library.
+    ///
+    /// If the library records the MAST roots of its exported procedures (e.g., a
+    /// [CompiledLibrary]), these procedures are compiled right away, and thus the libraries they
+    /// depend on must have been added to this assembler before.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be added to the assembler, or if the exported
+    /// procedures of the library do not compile to the MAST roots recorded in it.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
         L: Library,
     {
+        if self.module_provider.remove_library(library.root_ns()) {
+            self.proc_cache.get_mut().retain_roots(self.kernel.proc_hashes());
+        }
         self.module_provider.add_library(library)?;
+
+        if !library.exported_roots().is_empty() {
+            self.verify_exported_roots(library)?;
+        }
         Ok(self)
     }
 
+    /// Adds the library to provide modules for the compilation, and compiles all procedures
+    /// exported from the library ahead of time.
+    ///
+    /// Compiled procedures are cached by the assembler and are re-used by all subsequent calls
+    /// to [Assembler::compile()]. Thus, pre-compiling a library removes the cost of compiling its
+    /// procedures from the first compilation of a program which uses them.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be added to the assembler, or if compilation of
+    /// any of the procedures exported from the library fails.
+    pub fn with_precompiled_library<L>(self, library: &L) -> Result<Self, AssemblyError>
+    where
+        L: Library,
+    {
+        let assembler = self.with_library(library)?;
+        for module in library.modules() {
+            assembler.ensure_exports_are_in_cache(module)?;
+        }
+        Ok(assembler)
+    }
+
     /// Adds a library bundle to provide modules for the compilation.
-    pub fn with_libraries<I, L>(self, mut libraries: I) -> Result<Self, AssemblyError>
+    ///
+    /// Libraries are added in the order in which they are yielded by the iterator. Unlike
+    /// [Assembler::with_library()], a library in the bundle never replaces another library from
+    /// the same bundle.
+    ///
+    /// # Errors
+    /// Returns an error if any of the libraries cannot be added to the assembler. In particular,
+    /// if a library has the same namespace as a library loaded before it from the same bundle,
+    /// the error names the namespace of the library and the conflicting module path.
+    pub fn with_libraries<I, L>(self, libraries: I) -> Result<Self, AssemblyError>
     where
         L: Library,
         I: Iterator<Item = L>,
     {
-        libraries.try_fold(self, |slf, library| slf.with_library(&library))
+        let mut assembler = self;
+        let mut loaded: Vec<(LibraryNamespace, Vec<LibraryPath>)> = Vec::new();
+        for library in libraries {
+            let namespace = library.root_ns();
+
+            // a library with the same namespace would silently replace a previously loaded one;
+            // report the first module of the new library which clashes with the loaded modules
+            if let Some((_, paths)) = loaded.iter().find(|(ns, _)| ns == namespace) {
+                let conflict = library
+                    .modules()
+                    .map(|module| &module.path)
+                    .find(|path| paths.contains(path))
+                    .map(|path| path.to_string())
+                    .unwrap_or_else(|| namespace.to_string());
+                return Err(AssemblyError::library_conflict(namespace, &conflict));
+            }
+
+            assembler = assembler.with_library(&library).map_err(|err| match err {
+                AssemblyError::LibraryError(msg) => AssemblyError::LibraryError(format!(
+                    "failed to load library '{}': {msg}",
+                    namespace.as_ref()
+                )),
+                err => err,
+            })?;
+            let paths = library.modules().map(|module| module.path.clone()).collect();
+            loaded.push((namespace.clone(), paths));
+        }
+        Ok(assembler)
     }
 
     /// Sets the kernel for the assembler to the kernel defined by the provided source.
@@ -107,6 +307,26 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns true if this assembler skips compilation of unreachable procedures.
+    pub fn eliminates_dead_code(&self) -> bool {
+        self.eliminate_dead_code
+    }
+
+    /// Returns true if this assembler applies peephole optimizations to SPAN blocks.
+    pub fn optimizations_enabled(&self) -> bool {
+        self.optimize
+    }
+
+    /// Returns true if this assembler checks the effect of procedures on the stack depth.
+    pub fn stack_depth_checks_enabled(&self) -> bool {
+        self.check_stack_depth
+    }
+
+    /// Returns true if this assembler treats warnings as errors.
+    pub fn in_strict_mode(&self) -> bool {
+        self.strict
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -119,19 +339,66 @@ impl Assembler {
     /// Compiles the provided source code into a [Program]. The resulting program can be executed
     /// on Miden VM.
     ///
+    /// This is equivalent to parsing the source code into a [ProgramAst] and compiling the AST
+    /// via [Assembler::compile_ast()].
+    ///
     /// # Errors
-    /// Returns an error if parsing or compilation of the specified program fails.
+    /// Returns an error if parsing or compilation of the specified program fails, or if the
+    /// assembler is in strict mode and a warning is detected in the program.
     pub fn compile<S>(&self, source: S) -> Result<Program, AssemblyError>
     where
         S: AsRef<str>,
     {
-        // parse the program into an AST
-        let source = source.as_ref();
-        let program = ProgramAst::parse(source)?;
+        if self.strict {
+            return self.compile_with_diagnostics(source).map(|(program, _)| program);
+        }
+        let program = ProgramAst::parse(source.as_ref())?;
+        self.compile_ast(&program)
+    }
+
+    /// Compiles the provided source code into a [Program] and returns it together with the
+    /// warnings detected in the source code.
+    ///
+    /// The following issues are reported as warnings:
+    /// - Imported modules from which no procedures are invoked or referenced.
+    /// - Local procedures which are not reachable from the program body.
+    /// - Local procedures with the same name as a procedure exported from an imported module.
+    /// - Immediate values applied to constants such that the result is reduced modulo the field
+    ///   (e.g., `push.1 sub.2`).
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the specified program fails, or if the
+    /// assembler is in strict mode and a warning is detected in the program.
+    pub fn compile_with_diagnostics<S>(
+        &self,
+        source: S,
+    ) -> Result<(Program, Vec<AssemblyWarning>), AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        let program_ast = ProgramAst::parse(source.as_ref())?;
+        let program = self.compile_ast(&program_ast)?;
+
+        let import_locations = ast::parse_import_locations(source.as_ref())?;
+        let warnings = self.collect_warnings(&program_ast, &import_locations);
+        if self.strict {
+            if let Some(warning) = warnings.first() {
+                return Err(AssemblyError::Warning(warning.clone()));
+            }
+        }
+
+        Ok((program, warnings))
+    }
 
+    /// Compiles the provided [ProgramAst] into a [Program]. The resulting program can be executed
+    /// on Miden VM.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of the specified program fails.
+    pub fn compile_ast(&self, program: &ProgramAst) -> Result<Program, AssemblyError> {
         // compile the program
         let mut context = AssemblyContext::new(AssemblyContextType::Program);
-        let program_root = self.compile_in_context(&program, &mut context)?;
+        let program_root = self.compile_in_context(program, &mut context)?;
 
         // convert the context into a call block table for the program
         let cb_table = context.into_cb_table(&self.proc_cache.borrow())?;
@@ -159,19 +426,110 @@ impl Assembler {
             return Err(AssemblyError::InvalidProgramAssemblyContext);
         }
 
-        // compile all local procedures; this will add the procedures to the specified context
-        for proc_ast in program.procedures() {
+        // determine which local procedures are invoked from the program body
+        let reachable = self.eliminate_dead_code.then(|| {
+            let mut roots = Vec::new();
+            collect_local_calls(program.body(), &mut roots);
+            find_reachable_procs(program.procedures(), roots)
+        });
+
+        // compile all reachable local procedures; this will add the procedures to the specified
+        // context
+        for (proc_idx, proc_ast) in program.procedures().iter().enumerate() {
             if proc_ast.is_export {
                 return Err(AssemblyError::exported_proc_in_program(&proc_ast.name));
             }
-            self.compile_procedure(proc_ast, context)?;
+            if is_reachable(&reachable, proc_idx) {
+                self.compile_procedure(proc_ast, context)?;
+            } else {
+                self.skip_procedure(proc_ast, context)?;
+            }
         }
 
         // compile the program body
-        let program_root = self.compile_body(program.body().nodes().iter(), context, None)?;
+        let program_root = self.compile_body(program.body(), context, None)?;
+        if self.check_stack_depth {
+            self.analyze_stack_effect(program.body(), false, context)?;
+        }
 
         Ok(program_root)
     }
+    // LIBRARY COMPILER
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the provided modules into a [CompiledLibrary] with the specified namespace.
+    ///
+    /// Modules of the library may import each other as well as modules of the libraries which
+    /// are available to this assembler. The MAST roots of all procedures exported from the
+    /// library are recorded in the returned [CompiledLibrary]. This assembler is not modified.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The modules vector is empty, or any of the modules does not belong to the specified
+    ///   namespace.
+    /// - A module with the same path is already available to this assembler.
+    /// - Compilation of any of the exported procedures fails.
+    /// - The assembler is in strict mode and a warning is detected in the modules.
+    pub fn compile_library(
+        &self,
+        namespace: LibraryNamespace,
+        modules: Vec<Module>,
+    ) -> Result<CompiledLibrary, AssemblyError> {
+        self.compile_library_with_diagnostics(namespace, modules)
+            .map(|(library, _)| library)
+    }
+
+    /// Compiles the provided modules into a [CompiledLibrary] and returns it together with the
+    /// warnings detected in the modules.
+    ///
+    /// Currently, the only issue reported for libraries is an exported procedure which documents
+    /// its stack effect via `Input:` and `Output:` lines of its doc comment (e.g.,
+    /// `Input: [a, B, ...]`, where `B` is a word), if the documented change of the stack depth
+    /// differs from the one computed by the static stack depth analysis. Thus, warnings are
+    /// detected only if stack depth checks are enabled.
+    ///
+    /// # Errors
+    /// Returns an error under the same conditions as [Assembler::compile_library()].
+    pub fn compile_library_with_diagnostics(
+        &self,
+        namespace: LibraryNamespace,
+        modules: Vec<Module>,
+    ) -> Result<(CompiledLibrary, Vec<AssemblyWarning>), AssemblyError> {
+        // the library is compiled by an assembler which has access to the modules of the library
+        // in addition to everything available to this assembler
+        let mut module_provider = self.module_provider.clone();
+        for module in modules.iter() {
+            module.check_namespace(&namespace)?;
+            module_provider.add_module(module.clone())?;
+        }
+        let assembler = Self {
+            kernel: self.kernel.clone(),
+            module_provider,
+            proc_cache: self.proc_cache.clone(),
+            in_debug_mode: self.in_debug_mode,
+            max_unrolled_instructions: self.max_unrolled_instructions,
+            eliminate_dead_code: self.eliminate_dead_code,
+            optimize: self.optimize,
+            check_stack_depth: self.check_stack_depth,
+            strict: self.strict,
+        };
+
+        // compile all exported procedures and record their MAST roots
+        let mut exports = Vec::new();
+        for module in modules.iter() {
+            exports.append(&mut assembler.ensure_exports_are_in_cache(module)?);
+        }
+
+        let warnings = assembler.collect_library_warnings(&modules);
+        if self.strict {
+            if let Some(warning) = warnings.first() {
+                return Err(AssemblyError::Warning(warning.clone()));
+            }
+        }
+
+        Ok((CompiledLibrary::new(namespace, modules, exports)?, warnings))
+    }
+
     // MODULE COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -186,21 +544,55 @@ impl Assembler {
         &self,
         module: &Module,
         context: &mut AssemblyContext,
+    ) -> Result<Vec<RpoDigest>, AssemblyError> {
+        self.compile_module_procs(module, None, context)
+    }
+
+    /// Compiles procedures in the specified module and adds them to the procedure cache. Returns a
+    /// vector of procedure digests for all compiled procedures exported from the module.
+    ///
+    /// If `target` is specified, only the procedure with this ID (and the local procedures it
+    /// invokes) is compiled; otherwise, all procedures in the module are compiled. Procedures
+    /// which are already in the procedure cache are not added to it again.
+    fn compile_module_procs(
+        &self,
+        module: &Module,
+        target: Option<&ProcedureId>,
+        context: &mut AssemblyContext,
     ) -> Result<Vec<RpoDigest>, AssemblyError> {
         // a variable to track MAST roots of all procedures exported from this module
         let mut proc_roots = Vec::new();
 
         context.begin_module(&module.path)?;
 
+        let is_target = |proc_name: &ProcedureName| match target {
+            Some(proc_id) => proc_id == &ProcedureId::from_name(proc_name, &module.path),
+            None => true,
+        };
+
         // process all re-exported procedures
         for reexporteed_proc in module.ast.reexported_procs().iter() {
+            if !is_target(reexporteed_proc.name()) {
+                continue;
+            }
+
+            // make sure the chain of re-exports does not loop back onto itself
+            self.check_reexport_chain(&module.path, reexporteed_proc)?;
+
+            // if the re-exported procedure has already been added to the procedure cache by a
+            // previous compilation of this module, there is nothing else to do
+            let proc_name = reexporteed_proc.name();
+            let alias_proc_id = ProcedureId::from_name(proc_name, &module.path);
+            if let Some(proc) = self.proc_cache.borrow().get_by_id(&alias_proc_id) {
+                proc_roots.push(proc.code_root().hash());
+                continue;
+            }
+
             // make sure the re-exported procedure is loaded into the procedure cache
             let ref_proc_id = reexporteed_proc.proc_id();
             self.ensure_procedure_is_in_cache(&ref_proc_id, context)?;
 
-            // build procedure ID for the alias, and add it to the procedure cache
-            let proc_name = reexporteed_proc.name();
-            let alias_proc_id = ProcedureId::from_name(proc_name, &module.path);
+            // add the alias for the re-exported procedure to the procedure cache
             let proc_mast_root = self
                 .proc_cache
                 .try_borrow_mut()
@@ -212,10 +604,26 @@ impl Assembler {
             proc_roots.push(proc_mast_root);
         }
 
-        // compile all local procedures in the module; once the compilation is complete, we get
-        // all compiled procedures (and their combined callset) from the context
-        for proc_ast in module.ast.procs().iter() {
-            self.compile_procedure(proc_ast, context)?;
+        // determine which local procedures are needed for the target procedure
+        let reachable = target.map(|_| {
+            let roots = module
+                .ast
+                .procs()
+                .iter()
+                .enumerate()
+                .filter(|(_, proc)| proc.is_export && is_target(&proc.name))
+                .map(|(proc_idx, _)| proc_idx as u16);
+            find_reachable_procs(module.ast.procs(), roots)
+        });
+
+        // compile all needed local procedures in the module; once the compilation is complete, we
+        // get all compiled procedures (and their combined callset) from the context
+        for (proc_idx, proc_ast) in module.ast.procs().iter().enumerate() {
+            if is_reachable(&reachable, proc_idx) {
+                self.compile_procedure(proc_ast, context)?;
+            } else {
+                self.skip_procedure(proc_ast, context)?;
+            }
         }
         let (module_procs, module_callset) = context.complete_module();
 
@@ -224,23 +632,67 @@ impl Assembler {
         // - a procedure is exported from the module, or
         // - a procedure is present in the combined callset - i.e., it is an internal procedure
         //   which has been invoked via a local call instruction.
-        for proc in module_procs.into_iter() {
+        // skipped procedures are never added to the cache.
+        let mut proc_cache =
+            self.proc_cache.try_borrow_mut().map_err(|_| AssemblyError::InvalidCacheLock)?;
+        for (proc_idx, proc) in module_procs.into_iter().enumerate() {
+            if !is_reachable(&reachable, proc_idx) {
+                continue;
+            }
+
             if proc.is_export() {
                 proc_roots.push(proc.code_root().hash());
             }
 
-            if proc.is_export() || module_callset.contains(proc.id()) {
-                // this is safe because we fail if the cache is borrowed.
-                self.proc_cache
-                    .try_borrow_mut()
-                    .map_err(|_| AssemblyError::InvalidCacheLock)?
-                    .insert(proc)?;
+            if (proc.is_export() || module_callset.contains(proc.id()))
+                && !proc_cache.contains_id(proc.id())
+            {
+                proc_cache.insert(proc)?;
             }
         }
 
         Ok(proc_roots)
     }
 
+    /// Follows the chain of re-exports starting at the specified procedure re-exported from the
+    /// module at `module_path`.
+    ///
+    /// # Errors
+    /// Returns an error if the chain leads back to a procedure which has already been visited,
+    /// i.e., if the re-exports form a cycle.
+    fn check_reexport_chain(
+        &self,
+        module_path: &LibraryPath,
+        reexported_proc: &ProcReExport,
+    ) -> Result<(), AssemblyError> {
+        let proc_label = |path: &LibraryPath, name: &ProcedureName| {
+            format!("{}{}{}", path.as_str(), LibraryPath::PATH_DELIM, name.as_str())
+        };
+
+        let mut chain = vec![proc_label(module_path, reexported_proc.name())];
+        let mut next = Some(reexported_proc.clone());
+        while let Some(reexport) = next {
+            let label = proc_label(reexport.ref_path(), reexport.ref_name());
+            let is_cycle = chain.contains(&label);
+            chain.push(label);
+            if is_cycle {
+                return Err(AssemblyError::reexport_cycle(&chain));
+            }
+
+            // if the referenced procedure is itself a re-export, continue with its reference
+            next = self.module_provider.get_module(&reexport.proc_id()).and_then(|module| {
+                module
+                    .ast
+                    .reexported_procs()
+                    .iter()
+                    .find(|proc| proc.name() == reexport.ref_name())
+                    .cloned()
+            });
+        }
+
+        Ok(())
+    }
+
     // PROCEDURE COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -262,90 +714,156 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.nodes().iter(), context, Some(wrapper))?
+            self.compile_body(&proc.body, context, Some(wrapper))?
         } else {
-            self.compile_body(proc.body.nodes().iter(), context, None)?
+            self.compile_body(&proc.body, context, None)?
         };
 
-        context.complete_proc(code_root);
+        let stack_effect = if self.check_stack_depth {
+            self.analyze_stack_effect(&proc.body, proc.is_stack_unsafe, context)?
+        } else {
+            None
+        };
+
+        context.complete_proc(code_root, stack_effect);
+
+        Ok(())
+    }
 
+    /// Adds a placeholder for a procedure which does not need to be compiled to the provided
+    /// context.
+    ///
+    /// The placeholder keeps indexes of subsequent local procedures intact. It is never invoked,
+    /// and thus, never becomes a part of the compiled MAST.
+    fn skip_procedure(
+        &self,
+        proc: &ProcedureAst,
+        context: &mut AssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        context.begin_proc(&proc.name, false, proc.num_locals)?;
+        context.complete_proc(CodeBlock::new_span(vec![Operation::Noop]), None);
         Ok(())
     }
 
     // CODE BODY COMPILER
     // --------------------------------------------------------------------------------------------
 
-    /// TODO: add comments
-    fn compile_body<A, N>(
+    /// Compiles the provided code body into a [CodeBlock], wrapping it with the operations
+    /// specified by the `wrapper`, if any.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of any of the body's nodes fails. If the body contains
+    /// source location information, the error is bound to the location of the node which caused
+    /// it.
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
+    ) -> Result<CodeBlock, AssemblyError> {
         let mut blocks: Vec<CodeBlock> = Vec::new();
-        let mut span = SpanBuilder::new(wrapper);
-
-        for node in body {
-            match node.borrow() {
-                Node::Instruction(inner) => {
-                    if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
-                        span.extract_span_into(&mut blocks);
-                        blocks.push(block);
+        let mut span = SpanBuilder::new(wrapper, self.optimize);
+        let locations = body.source_locations();
+
+        for (idx, node) in body.nodes().iter().enumerate() {
+            let location = locations.get(idx);
+            self.compile_node(node, location, &mut span, &mut blocks, context)
+                .map_err(|err| match location {
+                    Some(location) => {
+                        let module_path = context.current_module_path().map(|p| p.as_str());
+                        err.with_location(module_path, *location)
                     }
+                    None => err,
+                })?;
+        }
+
+        span.extract_final_span_into(&mut blocks);
+        Ok(if blocks.is_empty() {
+            CodeBlock::new_span(vec![Operation::Noop])
+        } else {
+            combine_blocks(blocks)
+        })
+    }
+
+    /// Compiles a single AST node, appending its operations to the provided span builder, or,
+    /// for control flow nodes, appending the resulting code blocks to `blocks`.
+    ///
+    /// The source location of the node, if known, is attached to the debug information emitted
+    /// for instructions in debug mode.
+    fn compile_node(
+        &self,
+        node: &Node,
+        location: Option<&SourceLocation>,
+        span: &mut SpanBuilder,
+        blocks: &mut Vec<CodeBlock>,
+        context: &mut AssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        match node {
+            Node::Instruction(inner) => {
+                if let Some(block) = self.compile_instruction(inner, location, span, context)? {
+                    span.extract_span_into(blocks);
+                    blocks.push(block);
                 }
+            }
 
-                Node::IfElse {
-                    true_case,
-                    false_case,
-                } => {
-                    span.extract_span_into(&mut blocks);
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                span.extract_span_into(blocks);
 
-                    let true_case = self.compile_body(true_case.nodes().iter(), context, None)?;
+                let true_case = self.compile_body(true_case, context, None)?;
 
-                    // else is an exception because it is optional; hence, will have to be replaced
-                    // by noop span
-                    let false_case = if !false_case.nodes().is_empty() {
-                        self.compile_body(false_case.nodes().iter(), context, None)?
-                    } else {
-                        CodeBlock::new_span(vec![Operation::Noop])
-                    };
+                // else is an exception because it is optional; hence, will have to be replaced
+                // by noop span
+                let false_case = if !false_case.nodes().is_empty() {
+                    self.compile_body(false_case, context, None)?
+                } else {
+                    CodeBlock::new_span(vec![Operation::Noop])
+                };
 
-                    let block = CodeBlock::new_split(true_case, false_case);
+                let block = CodeBlock::new_split(true_case, false_case);
 
-                    blocks.push(block);
+                blocks.push(block);
+            }
+
+            Node::Repeat { times, body } => {
+                span.extract_span_into(blocks);
+
+                // make sure unrolling the loop does not blow up the size of the program; all
+                // repeat blocks of a procedure count against the same limit
+                let num_unrolled = context
+                    .num_unrolled_instructions()
+                    .saturating_add(count_instructions(body).saturating_mul(*times as usize));
+                if num_unrolled > self.max_unrolled_instructions {
+                    return Err(AssemblyError::repeat_expansion_too_large(
+                        *times,
+                        num_unrolled,
+                        self.max_unrolled_instructions,
+                    ));
                 }
 
-                Node::Repeat { times, body } => {
-                    span.extract_span_into(&mut blocks);
+                let block = self.compile_body(body, context, None)?;
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                // nested repeat blocks are already accounted for in the expansion of this block
+                context.set_num_unrolled_instructions(num_unrolled);
 
-                    for _ in 0..*times {
-                        blocks.push(block.clone());
-                    }
+                for _ in 0..*times {
+                    blocks.push(block.clone());
                 }
+            }
 
-                Node::While { body } => {
-                    span.extract_span_into(&mut blocks);
+            Node::While { body } => {
+                span.extract_span_into(blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
-                    let block = CodeBlock::new_loop(block);
+                let block = self.compile_body(body, context, None)?;
+                let block = CodeBlock::new_loop(block);
 
-                    blocks.push(block);
-                }
+                blocks.push(block);
             }
         }
 
-        span.extract_final_span_into(&mut blocks);
-        Ok(if blocks.is_empty() {
-            CodeBlock::new_span(vec![Operation::Noop])
-        } else {
-            combine_blocks(blocks)
-        })
+        Ok(())
     }
 
     // PROCEDURE CACHE
@@ -370,7 +888,9 @@ impl Assembler {
                 .module_provider
                 .get_module(proc_id)
                 .ok_or_else(|| AssemblyError::imported_proc_module_not_found(proc_id))?;
-            self.compile_module(module, context)?;
+            // with dead code elimination enabled, only the requested procedure is compiled
+            let target = self.eliminate_dead_code.then_some(proc_id);
+            self.compile_module_procs(module, target, context)?;
             // if the procedure is still not in cache, then there was some error
             if !self.proc_cache.borrow().contains_id(proc_id) {
                 return Err(AssemblyError::imported_proc_not_found_in_module(
@@ -383,6 +903,55 @@ impl Assembler {
         Ok(())
     }
 
+    /// Ensures that all procedures exported from the specified module (including re-exported
+    /// procedures) are in the procedure cache, compiling the module if needed.
+    ///
+    /// Returns fully-qualified paths of the exported procedures together with their MAST roots.
+    fn ensure_exports_are_in_cache(
+        &self,
+        module: &Module,
+    ) -> Result<Vec<(LibraryPath, RpoDigest)>, AssemblyError> {
+        let reexported = module.ast.reexported_procs().iter().map(|proc| proc.name());
+        let exported = module.ast.procs().iter().filter(|proc| proc.is_export);
+
+        let mut exports = Vec::new();
+        for proc_name in reexported.chain(exported.map(|proc| &proc.name)) {
+            let proc_id = ProcedureId::from_name(proc_name, &module.path);
+            let mut context = AssemblyContext::new(AssemblyContextType::Module);
+            self.ensure_procedure_is_in_cache(&proc_id, &mut context)?;
+
+            let proc_cache = self.proc_cache.borrow();
+            let proc = proc_cache
+                .get_by_id(&proc_id)
+                .expect("exported procedure is not in the procedure cache");
+            let proc_path = module.path.append(proc_name).map_err(LibraryError::from)?;
+            exports.push((proc_path, proc.code_root().hash()));
+        }
+
+        Ok(exports)
+    }
+
+    /// Compiles all procedures exported from the specified library and checks that they match
+    /// the MAST roots recorded in the library.
+    fn verify_exported_roots<L>(&self, library: &L) -> Result<(), AssemblyError>
+    where
+        L: Library,
+    {
+        let mut compiled = BTreeMap::new();
+        for module in library.modules() {
+            compiled.extend(self.ensure_exports_are_in_cache(module)?);
+        }
+
+        for (path, expected) in library.exported_roots() {
+            let actual = compiled.get(path).copied();
+            if actual != Some(*expected) {
+                return Err(LibraryError::export_root_mismatch(path, *expected, actual).into());
+            }
+        }
+
+        Ok(())
+    }
+
     // CODE BLOCK BUILDER
     // --------------------------------------------------------------------------------------------
     /// Returns the [CodeBlockTable] associated with the [AssemblyContext].
@@ -410,6 +979,74 @@ struct BodyWrapper {
 // UTILITY FUNCTIONS
 // ================================================================================================
 
+/// Appends indexes of all local procedures invoked or referenced (via `procref`) from the
+/// specified code body to `calls`.
+fn collect_local_calls(body: &CodeBody, calls: &mut Vec<u16>) {
+    for node in body.nodes() {
+        match node {
+            Node::Instruction(Instruction::ExecLocal(proc_idx))
+            | Node::Instruction(Instruction::CallLocal(proc_idx))
+            | Node::Instruction(Instruction::ProcRefLocal(proc_idx)) => calls.push(*proc_idx),
+            Node::Instruction(_) => (),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_local_calls(true_case, calls);
+                collect_local_calls(false_case, calls);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => collect_local_calls(body, calls),
+        }
+    }
+}
+
+/// Returns indexes of the local procedures which are reachable from the procedures at the
+/// specified indexes, including the root procedures themselves.
+fn find_reachable_procs<I>(procs: &[ProcedureAst], roots: I) -> BTreeSet<u16>
+where
+    I: IntoIterator<Item = u16>,
+{
+    let mut reachable = BTreeSet::new();
+    let mut pending = roots.into_iter().collect::<Vec<_>>();
+    while let Some(proc_idx) = pending.pop() {
+        // invalid indexes are left for the compiler to report
+        if let Some(proc) = procs.get(proc_idx as usize) {
+            if reachable.insert(proc_idx) {
+                collect_local_calls(&proc.body, &mut pending);
+            }
+        }
+    }
+    reachable
+}
+
+/// Returns true if the procedure at the specified index is in the set of reachable procedures,
+/// or if the set was not computed (i.e., all procedures are considered reachable).
+fn is_reachable(reachable: &Option<BTreeSet<u16>>, proc_idx: usize) -> bool {
+    match reachable {
+        Some(procs) => procs.contains(&(proc_idx as u16)),
+        None => true,
+    }
+}
+
+/// Returns the number of instructions in the specified code body once all nested `repeat` blocks
+/// are unrolled.
+fn count_instructions(body: &CodeBody) -> usize {
+    body.nodes()
+        .iter()
+        .map(|node| match node {
+            Node::Instruction(_) => 1,
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => count_instructions(true_case).saturating_add(count_instructions(false_case)),
+            Node::Repeat { times, body } => {
+                count_instructions(body).saturating_mul(*times as usize)
+            }
+            Node::While { body } => count_instructions(body),
+        })
+        .fold(0, usize::saturating_add)
+}
+
 pub fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     debug_assert!(!blocks.is_empty(), "cannot combine empty block list");
     // merge consecutive Span blocks.
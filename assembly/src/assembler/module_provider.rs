@@ -1,4 +1,4 @@
-use super::{BTreeMap, Library, LibraryError, Module, ProcedureId, Vec};
+use super::{BTreeMap, Library, LibraryError, LibraryNamespace, Module, ProcedureId, Vec};
 
 // MODULE PROVIDER
 // ================================================================================================
@@ -22,6 +22,12 @@ impl ModuleProvider {
         self.procedures.get(id).map(|i| &self.modules[*i])
     }
 
+    /// Returns true if this module provider contains modules of the library with the specified
+    /// namespace.
+    pub fn contains_namespace(&self, namespace: &LibraryNamespace) -> bool {
+        self.modules.iter().any(|m| m.path.first() == namespace.as_str())
+    }
+
     // MODULE AND LIBRARY MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -30,7 +36,7 @@ impl ModuleProvider {
     /// # Errors
     ///
     /// Will error if there is a duplicated module path.
-    fn add_module(&mut self, module: Module) -> Result<(), LibraryError> {
+    pub fn add_module(&mut self, module: Module) -> Result<(), LibraryError> {
         if self.modules.iter().any(|m| module.path == m.path) {
             return Err(LibraryError::duplicate_module_path(&module.path));
         }
@@ -64,4 +70,26 @@ impl ModuleProvider {
             self.add_module(module.clone())
         })
     }
+
+    /// Removes all modules of the library with the specified namespace from this module
+    /// provider.
+    ///
+    /// Returns true if any modules were removed.
+    pub fn remove_library(&mut self, namespace: &LibraryNamespace) -> bool {
+        if !self.contains_namespace(namespace) {
+            return false;
+        }
+
+        // rebuild the module list and the procedure map from the remaining modules
+        let modules = core::mem::take(&mut self.modules);
+        self.procedures.clear();
+        modules
+            .into_iter()
+            .filter(|m| m.path.first() != namespace.as_str())
+            .for_each(|m| {
+                self.add_module(m).expect("failed to re-add a previously added module");
+            });
+
+        true
+    }
 }
@@ -0,0 +1,217 @@
+use super::{Decorator, DecoratorList, Felt, Operation, Vec, ONE, ZERO};
+use vm_core::FieldElement;
+
+// SPAN OPTIMIZER
+// ================================================================================================
+
+/// Applies peephole optimizations to the operations of a single SPAN block, and returns the
+/// optimized operations together with the updated list of decorators.
+///
+/// The following rewrites are performed:
+/// - Arithmetic on constants is folded: `INCR`, `NEG`, `INV`, `EQZ` applied to a constant, and
+///   `ADD`, `MUL`, `EQ` applied to two constants are replaced by the resulting constant.
+/// - Additive and multiplicative identities are removed: `PUSH(0) ADD`, `PUSH(1) MUL` and `NOOP`.
+///   A `NOOP` is kept only if it is the only remaining operation between two decorators.
+/// - Values which are pushed onto the stack and immediately dropped are removed: `DUP{n} DROP`
+///   and `PUSH(a) DROP`.
+/// - `ASSERT` is removed only when the asserted value is the constant 1.
+///
+/// Decorators refer to the state of the stack at specific operations, and thus, operations are
+/// never folded across a decorator. In debug mode, this means that only operations emitted for
+/// the same instruction can be folded together; cycle counts of the affected instructions are
+/// updated accordingly.
+pub fn optimize_span(
+    ops: Vec<Operation>,
+    mut decorators: DecoratorList,
+) -> (Vec<Operation>, DecoratorList) {
+    let mut result = Vec::with_capacity(ops.len());
+    let old_positions: Vec<usize> = decorators.iter().map(|(pos, _)| *pos).collect();
+
+    // optimize operations between consecutive decorators, and record where the boundaries
+    // between them end up in the optimized list of operations
+    let mut boundaries = vec![(0, 0)];
+    let mut start = 0;
+    for (pos, _) in decorators.iter_mut() {
+        if *pos > start {
+            optimize_ops(&ops[start..*pos], &mut result);
+            start = *pos;
+            boundaries.push((start, result.len()));
+        }
+        *pos = result.len();
+    }
+    optimize_ops(&ops[start..], &mut result);
+    boundaries.push((ops.len(), result.len()));
+
+    // update cycle counts of instructions tracked in debug mode
+    for ((pos, decorator), old_pos) in decorators.iter_mut().zip(old_positions) {
+        if let Decorator::AsmOp(assembly_op) = decorator {
+            let old_end = old_pos + assembly_op.num_cycles() as usize;
+            if let Some((_, new_end)) = boundaries.iter().find(|(old, _)| *old == old_end) {
+                assembly_op.set_num_cycles((new_end - *pos) as u8);
+            }
+        }
+    }
+
+    (result, decorators)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends the provided operations to the `result`, folding them with each other wherever
+/// possible.
+///
+/// If all operations are folded away, a single NOOP is appended so that decorators attached to
+/// the operations (e.g., breakpoints) still precede an operation.
+fn optimize_ops(ops: &[Operation], result: &mut Vec<Operation>) {
+    let segment_start = result.len();
+    for &op in ops {
+        // only the operations of the current segment can be folded
+        let mut tail = TailOps {
+            ops: &mut *result,
+            start: segment_start,
+        };
+        match op {
+            Operation::Noop => (),
+            Operation::Incr => match tail.pop_constant() {
+                Some(a) => tail.push_constant(a + ONE),
+                None => tail.ops.push(op),
+            },
+            Operation::Neg => match tail.pop_constant() {
+                Some(a) => tail.push_constant(-a),
+                None => tail.ops.push(op),
+            },
+            Operation::Inv => match tail.peek_constant() {
+                Some(a) if a != ZERO => {
+                    tail.pop_constant();
+                    tail.push_constant(a.inv());
+                }
+                _ => tail.ops.push(op),
+            },
+            Operation::Eqz => match tail.pop_constant() {
+                Some(a) => tail.push_constant(felt_from_bool(a == ZERO)),
+                None => tail.ops.push(op),
+            },
+            Operation::Add => match tail.pop_two_constants() {
+                Some((a, b)) => tail.push_constant(a + b),
+                None if tail.peek_constant() == Some(ZERO) => {
+                    tail.pop_constant();
+                }
+                None => tail.ops.push(op),
+            },
+            Operation::Mul => match tail.pop_two_constants() {
+                Some((a, b)) => tail.push_constant(a * b),
+                None if tail.peek_constant() == Some(ONE) => {
+                    tail.pop_constant();
+                }
+                None => tail.ops.push(op),
+            },
+            Operation::Eq => match tail.pop_two_constants() {
+                Some((a, b)) => tail.push_constant(felt_from_bool(a == b)),
+                None => tail.ops.push(op),
+            },
+            Operation::Assert(_) => match tail.peek_constant() {
+                Some(a) if a == ONE => {
+                    tail.pop_constant();
+                }
+                _ => tail.ops.push(op),
+            },
+            Operation::Drop => {
+                if matches!(tail.ops[tail.start..].last(), Some(last) if is_dup(last)) {
+                    tail.ops.pop();
+                } else if tail.pop_constant().is_none() {
+                    tail.ops.push(op);
+                }
+            }
+            _ => tail.ops.push(op),
+        }
+    }
+
+    if result.len() == segment_start && !ops.is_empty() {
+        result.push(Operation::Noop);
+    }
+}
+
+/// The list of optimized operations, of which only the operations starting at `start` can be
+/// modified.
+struct TailOps<'a> {
+    ops: &'a mut Vec<Operation>,
+    start: usize,
+}
+
+impl TailOps<'_> {
+    /// Returns the constant pushed onto the stack by the last operations of the list together
+    /// with the number of operations pushing it, or None if the last operations do not push a
+    /// constant.
+    fn last_constant(&self, end: usize) -> Option<(Felt, usize)> {
+        let ops = &self.ops[self.start..end];
+        match ops {
+            [.., Operation::Pad, Operation::Incr] => Some((ONE, 2)),
+            [.., Operation::Pad] => Some((ZERO, 1)),
+            [.., Operation::Push(value)] => Some((*value, 1)),
+            _ => None,
+        }
+    }
+
+    /// Returns the constant at the top of the stack, if it is pushed by the last operations.
+    fn peek_constant(&self) -> Option<Felt> {
+        self.last_constant(self.ops.len()).map(|(value, _)| value)
+    }
+
+    /// Removes the operations pushing a constant from the end of the list, and returns the
+    /// constant, if the last operations push a constant.
+    fn pop_constant(&mut self) -> Option<Felt> {
+        let (value, num_ops) = self.last_constant(self.ops.len())?;
+        self.ops.truncate(self.ops.len() - num_ops);
+        Some(value)
+    }
+
+    /// Removes the operations pushing two constants from the end of the list, and returns the
+    /// constants (with the top of the stack last), if the last operations push two constants.
+    fn pop_two_constants(&mut self) -> Option<(Felt, Felt)> {
+        let (b, b_ops) = self.last_constant(self.ops.len())?;
+        let (a, a_ops) = self.last_constant(self.ops.len() - b_ops)?;
+        self.ops.truncate(self.ops.len() - a_ops - b_ops);
+        Some((a, b))
+    }
+
+    /// Appends the operations pushing the specified constant onto the stack.
+    ///
+    /// Same as when compiling `push` instructions, 0 is pushed via PAD, and 1 via PAD INCR.
+    fn push_constant(&mut self, value: Felt) {
+        if value == ZERO {
+            self.ops.push(Operation::Pad);
+        } else if value == ONE {
+            self.ops.extend_from_slice(&[Operation::Pad, Operation::Incr]);
+        } else {
+            self.ops.push(Operation::Push(value));
+        }
+    }
+}
+
+/// Returns true if the operation pushes a copy of a stack element onto the stack.
+fn is_dup(op: &Operation) -> bool {
+    matches!(
+        op,
+        Operation::Dup0
+            | Operation::Dup1
+            | Operation::Dup2
+            | Operation::Dup3
+            | Operation::Dup4
+            | Operation::Dup5
+            | Operation::Dup6
+            | Operation::Dup7
+            | Operation::Dup9
+            | Operation::Dup11
+            | Operation::Dup13
+            | Operation::Dup15
+    )
+}
+
+fn felt_from_bool(value: bool) -> Felt {
+    if value {
+        ONE
+    } else {
+        ZERO
+    }
+}
@@ -5,7 +5,7 @@ use super::{btree_map::Entry, AssemblyError, BTreeMap, Procedure, ProcedureId, R
 
 /// The [ProcedureCache] is responsible for caching [Procedure]s. It allows [Procedure]s to be
 /// fetched using both [ProcedureId] and [RpoDigest].
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ProcedureCache {
     proc_map: BTreeMap<ProcedureId, Procedure>,
     mast_map: BTreeMap<RpoDigest, ProcedureId>,
@@ -105,6 +105,19 @@ impl ProcedureCache {
         Ok(proc.code_root().hash())
     }
 
+    /// Removes all procedures from the cache except for the procedures with the specified MAST
+    /// roots.
+    pub fn retain_roots(&mut self, roots: &[RpoDigest]) {
+        self.proc_map.retain(|_, proc| roots.contains(&proc.code_root().hash()));
+        self.proc_aliases.retain(|_, proc_id| self.proc_map.contains_key(proc_id));
+
+        // rebuild the MAST root map as some of the roots may have pointed to removed procedures
+        self.mast_map.clear();
+        for (proc_id, proc) in self.proc_map.iter() {
+            self.mast_map.entry(proc.code_root().hash()).or_insert(*proc_id);
+        }
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -1,7 +1,8 @@
 use super::{
-    AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
-    Instruction, Operation, ToString, Vec,
+    optimizer::optimize_span, AssemblyContext, AssemblyError, BodyWrapper, CodeBlock, Decorator,
+    DecoratorList, Instruction, Operation, SourceLocation, ToString, Vec,
 };
+use core::borrow::Borrow;
 use vm_core::{AdviceInjector, AssemblyOp};
 
 // SPAN BUILDER
@@ -14,12 +15,16 @@ use vm_core::{AdviceInjector, AssemblyOp};
 ///
 /// The same span builder can be used to construct many blocks. It is expected that when the last
 /// SPAN block in a procedure's body is constructed `extract_final_span_into()` will be used.
+///
+/// If optimizations are enabled, operations of each SPAN block are optimized right before the
+/// block is constructed.
 #[derive(Default)]
 pub struct SpanBuilder {
     ops: Vec<Operation>,
     decorators: DecoratorList,
     epilogue: Vec<Operation>,
     last_asmop_pos: usize,
+    optimize: bool,
 }
 
 impl SpanBuilder {
@@ -30,15 +35,21 @@ impl SpanBuilder {
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of
     /// operations upon consumption of the builder via `extract_final_span_into()` method.
-    pub(super) fn new(wrapper: Option<BodyWrapper>) -> Self {
+    ///
+    /// If `optimize` is true, SPAN blocks extracted from the builder are optimized.
+    pub(super) fn new(wrapper: Option<BodyWrapper>, optimize: bool) -> Self {
         match wrapper {
             Some(wrapper) => Self {
                 ops: wrapper.prologue,
                 decorators: Vec::new(),
                 epilogue: wrapper.epilogue,
                 last_asmop_pos: 0,
+                optimize,
+            },
+            None => Self {
+                optimize,
+                ..Self::default()
             },
-            None => Self::default(),
         }
     }
 
@@ -98,12 +109,25 @@ impl SpanBuilder {
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
     /// this instruction will be computed when the call to set_instruction_cycle_count() is made.
-    pub fn track_instruction(&mut self, instruction: &Instruction, ctx: &AssemblyContext) {
+    /// The decorator also records the path of the module being compiled and the source line of
+    /// the instruction, if known.
+    pub fn track_instruction(
+        &mut self,
+        instruction: &Instruction,
+        location: Option<&SourceLocation>,
+        ctx: &AssemblyContext,
+    ) {
         let context_name = ctx.current_context_name().to_string();
         let num_cycles = 0;
         let op = instruction.to_string();
         let should_break = instruction.should_break();
-        let op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        let mut op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        if let Some(path) = ctx.current_module_path() {
+            op = op.with_module_path(path.as_str().to_string());
+        }
+        if let Some(location) = location {
+            op = op.with_line(location.line());
+        }
         self.push_decorator(Decorator::AsmOp(op));
         self.last_asmop_pos = self.decorators.len() - 1;
     }
@@ -140,14 +164,19 @@ impl SpanBuilder {
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
     pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
+        // decorators which are not followed by any operations (e.g., a debug decorator right
+        // before a control flow block) still need to be executed; so, we attach them to a NOOP
+        if self.ops.is_empty() && !self.decorators.is_empty() {
+            self.ops.push(Operation::Noop);
+        }
+
         if !self.ops.is_empty() {
-            let ops = self.ops.drain(..).collect();
-            let decorators = self.decorators.drain(..).collect();
+            let mut ops = self.ops.drain(..).collect();
+            let mut decorators = self.decorators.drain(..).collect();
+            if self.optimize {
+                (ops, decorators) = optimize_span(ops, decorators);
+            }
             target.push(CodeBlock::new_span_with_decorators(ops, decorators));
-        } else if !self.decorators.is_empty() {
-            // this is a bug in the assembler. we shouldn't have decorators added without their
-            // associated operations
-            unreachable!()
         }
     }
 
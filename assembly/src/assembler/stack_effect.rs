@@ -0,0 +1,415 @@
+use super::{Assembler, AssemblyContext, AssemblyError, CodeBody, Instruction, Node, Procedure};
+use core::cmp::max;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of stack elements which are guaranteed to be available to a procedure invoked in a new
+/// context (i.e., via `call` or `syscall` instructions).
+const MIN_STACK_DEPTH: usize = 16;
+
+// STACK EFFECT
+// ================================================================================================
+
+/// Describes how a procedure affects the depth of the operand stack.
+///
+/// Stack effects are computed by the static stack depth analysis of the assembler (see
+/// [Assembler::with_stack_depth_checks()]).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StackEffect {
+    min_depth: usize,
+    depth_change: i64,
+}
+
+impl StackEffect {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a stack effect of code which consumes the specified number of inputs from the top
+    /// of the stack and puts the specified number of outputs onto the stack.
+    pub fn new(num_inputs: usize, num_outputs: usize) -> Self {
+        Self {
+            min_depth: num_inputs,
+            depth_change: num_outputs as i64 - num_inputs as i64,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the minimum number of elements which must be on the stack before the code is
+    /// executed.
+    pub fn min_depth(&self) -> usize {
+        self.min_depth
+    }
+
+    /// Returns the net change of the stack depth caused by the code.
+    pub fn depth_change(&self) -> i64 {
+        self.depth_change
+    }
+
+    // COMPOSITION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the stack effect of executing code with this effect followed by code with the
+    /// `next` effect.
+    pub fn then(self, next: Self) -> Self {
+        let min_depth = max(self.min_depth as i64, next.min_depth as i64 - self.depth_change);
+        Self {
+            min_depth: min_depth as usize,
+            depth_change: self.depth_change + next.depth_change,
+        }
+    }
+
+    /// Returns the stack effect of executing code with this effect the specified number of times.
+    pub fn repeat(self, times: u32) -> Self {
+        if times == 0 {
+            return Self::default();
+        }
+
+        // each iteration which shrinks the stack requires more elements to be on the stack
+        let times = times as i64;
+        let shrink = max(-self.depth_change, 0);
+        Self {
+            min_depth: (self.min_depth as i64 + shrink * (times - 1)) as usize,
+            depth_change: self.depth_change * times,
+        }
+    }
+}
+
+// STACK DEPTH ANALYSIS
+// ================================================================================================
+
+impl Assembler {
+    /// Computes the stack effect of the provided code body of the procedure currently being
+    /// compiled.
+    ///
+    /// Returns None if the stack effect cannot be determined statically, e.g., if the body
+    /// executes a dynamic code block, or if `is_stack_unsafe` is set and the body contains
+    /// control flow which changes the stack depth in a data-dependent way.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The branches of an if/else block change the stack depth by different amounts.
+    /// - An iteration of a while loop changes the stack depth.
+    /// - A procedure invoked via `call` or `syscall` requires more than 16 stack elements.
+    ///
+    /// The first two checks are skipped if `is_stack_unsafe` is set.
+    pub(super) fn analyze_stack_effect(
+        &self,
+        body: &CodeBody,
+        is_stack_unsafe: bool,
+        context: &AssemblyContext,
+    ) -> Result<Option<StackEffect>, AssemblyError> {
+        let mut effect = Some(StackEffect::default());
+        let locations = body.source_locations();
+
+        // nodes are analyzed even after the effect becomes unknown to make sure all nested
+        // blocks are checked
+        for (idx, node) in body.nodes().iter().enumerate() {
+            let node_effect = self
+                .analyze_node_stack_effect(node, is_stack_unsafe, context)
+                .map_err(|err| match locations.get(idx) {
+                    Some(location) => {
+                        let module_path = context.current_module_path().map(|p| p.as_str());
+                        err.with_location(module_path, *location)
+                    }
+                    None => err,
+                })?;
+            effect = effect.zip(node_effect).map(|(effect, next)| effect.then(next));
+        }
+
+        Ok(effect)
+    }
+
+    /// Computes the stack effect of a single AST node.
+    fn analyze_node_stack_effect(
+        &self,
+        node: &Node,
+        is_stack_unsafe: bool,
+        context: &AssemblyContext,
+    ) -> Result<Option<StackEffect>, AssemblyError> {
+        // the condition of if/else blocks and while loops is popped off the stack
+        let pop_condition = StackEffect::new(1, 0);
+
+        match node {
+            Node::Instruction(instruction) => self.instruction_stack_effect(instruction, context),
+
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                let true_effect = self.analyze_stack_effect(true_case, is_stack_unsafe, context)?;
+                let false_effect =
+                    self.analyze_stack_effect(false_case, is_stack_unsafe, context)?;
+
+                let (true_effect, false_effect) = match (true_effect, false_effect) {
+                    (Some(true_effect), Some(false_effect)) => (true_effect, false_effect),
+                    _ => return Ok(None),
+                };
+
+                if true_effect.depth_change != false_effect.depth_change {
+                    if is_stack_unsafe {
+                        return Ok(None);
+                    }
+                    return Err(AssemblyError::unbalanced_branches(
+                        context.current_context_name(),
+                        true_effect.depth_change,
+                        false_effect.depth_change,
+                    ));
+                }
+
+                let branch_effect = StackEffect {
+                    min_depth: max(true_effect.min_depth, false_effect.min_depth),
+                    depth_change: true_effect.depth_change,
+                };
+                Ok(Some(pop_condition.then(branch_effect)))
+            }
+
+            Node::Repeat { times, body } => {
+                let effect = self.analyze_stack_effect(body, is_stack_unsafe, context)?;
+                Ok(effect.map(|effect| effect.repeat(*times)))
+            }
+
+            Node::While { body } => {
+                let body_effect = match self.analyze_stack_effect(body, is_stack_unsafe, context)? {
+                    Some(body_effect) => body_effect,
+                    None => return Ok(None),
+                };
+
+                // the body must leave exactly one new condition on the stack for the loop to be
+                // net-zero across iterations
+                let iteration_effect = body_effect.then(pop_condition);
+                if iteration_effect.depth_change != 0 {
+                    if is_stack_unsafe {
+                        return Ok(None);
+                    }
+                    return Err(AssemblyError::unbalanced_loop(
+                        context.current_context_name(),
+                        iteration_effect.depth_change,
+                    ));
+                }
+
+                // since iterations are net-zero, the first iteration requires the deepest stack
+                Ok(Some(pop_condition.then(iteration_effect)))
+            }
+        }
+    }
+
+    /// Computes the stack effect of a single instruction.
+    fn instruction_stack_effect(
+        &self,
+        instruction: &Instruction,
+        context: &AssemblyContext,
+    ) -> Result<Option<StackEffect>, AssemblyError> {
+        let proc_cache = self.proc_cache.borrow();
+        let effect = match instruction {
+            // an executed procedure affects the stack of the caller directly
+            Instruction::ExecLocal(idx) => {
+                return Ok(context.get_local_proc(*idx).and_then(Procedure::stack_effect))
+            }
+            Instruction::ExecImported(id) => {
+                return Ok(proc_cache.get_by_id(id).and_then(Procedure::stack_effect))
+            }
+            Instruction::DynExec => return Ok(None),
+
+            // a called procedure is executed against a new stack of at least 16 elements, and
+            // the depth of the caller's stack is unaffected
+            Instruction::CallLocal(idx) => {
+                check_call_target(context.get_local_proc(*idx), context)?;
+                StackEffect::default()
+            }
+            Instruction::CallImported(id) | Instruction::SysCall(id) => {
+                check_call_target(proc_cache.get_by_id(id), context)?;
+                StackEffect::default()
+            }
+            Instruction::CallMastRoot(root) => {
+                check_call_target(proc_cache.get_by_hash(root), context)?;
+                StackEffect::default()
+            }
+            Instruction::DynCall => StackEffect::new(4, 4),
+
+            instruction => instruction_io(instruction),
+        };
+        Ok(Some(effect))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error if the specified procedure, invoked in a new context, requires more stack
+/// elements than are guaranteed to be available to it.
+fn check_call_target(
+    callee: Option<&Procedure>,
+    context: &AssemblyContext,
+) -> Result<(), AssemblyError> {
+    if let Some(callee) = callee {
+        if let Some(effect) = callee.stack_effect() {
+            if effect.min_depth > MIN_STACK_DEPTH {
+                return Err(AssemblyError::stack_underflow(
+                    context.current_context_name(),
+                    callee.label(),
+                    effect.min_depth,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the stack effect of an instruction which does not invoke other procedures.
+///
+/// The effect is described by the number of elements the instruction reads from the top of the
+/// stack, and the number of elements it leaves in their place.
+fn instruction_io(instruction: &Instruction) -> StackEffect {
+    use Instruction::*;
+
+    let (num_inputs, num_outputs) = match instruction {
+        Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => (1, 0),
+        AssertEq | AssertEqWithError(_) => (2, 0),
+        AssertEqw => (8, 0),
+
+        Add | Sub | Mul | Div | And | Or | Xor | Eq | Neq | Lt | Lte | Gt | Gte => (2, 1),
+        AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | EqImm(_) | NeqImm(_) => (1, 1),
+        Neg | Inv | Incr | Pow2 | ILog2 | Not | IsOdd => (1, 1),
+        Exp | ExpBitLength(_) => (2, 1),
+        ExpImm(_) => (1, 1),
+        Eqw => (8, 9),
+
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => (4, 2),
+        Ext2Neg | Ext2Inv => (2, 2),
+
+        U32Test => (1, 2),
+        U32TestW => (4, 5),
+        U32Assert | U32AssertWithError(_) | U32Cast => (1, 1),
+        U32Assert2 | U32Assert2WithError(_) => (2, 2),
+        U32AssertW => (4, 4),
+        U32Split => (1, 2),
+
+        U32CheckedAdd | U32WrappingAdd | U32CheckedSub | U32WrappingSub | U32CheckedMul
+        | U32WrappingMul | U32CheckedDiv | U32UncheckedDiv | U32CheckedMod | U32UncheckedMod
+        | U32CheckedAnd | U32CheckedOr | U32CheckedXor | U32CheckedShl | U32UncheckedShl
+        | U32CheckedShr | U32UncheckedShr | U32CheckedRotl | U32UncheckedRotl | U32CheckedRotr
+        | U32UncheckedRotr | U32CheckedEq | U32CheckedNeq | U32CheckedLt | U32UncheckedLt
+        | U32CheckedLte | U32UncheckedLte | U32CheckedGt | U32UncheckedGt | U32CheckedGte
+        | U32UncheckedGte | U32CheckedMin | U32UncheckedMin | U32CheckedMax | U32UncheckedMax => {
+            (2, 1)
+        }
+        U32CheckedAddImm(_)
+        | U32WrappingAddImm(_)
+        | U32CheckedSubImm(_)
+        | U32WrappingSubImm(_)
+        | U32CheckedMulImm(_)
+        | U32WrappingMulImm(_)
+        | U32CheckedDivImm(_)
+        | U32UncheckedDivImm(_)
+        | U32CheckedModImm(_)
+        | U32UncheckedModImm(_)
+        | U32CheckedShlImm(_)
+        | U32UncheckedShlImm(_)
+        | U32CheckedShrImm(_)
+        | U32UncheckedShrImm(_)
+        | U32CheckedRotlImm(_)
+        | U32UncheckedRotlImm(_)
+        | U32CheckedRotrImm(_)
+        | U32UncheckedRotrImm(_)
+        | U32CheckedEqImm(_)
+        | U32CheckedNeqImm(_)
+        | U32CheckedNot
+        | U32CheckedPopcnt
+        | U32UncheckedPopcnt => (1, 1),
+        U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32CheckedDivMod
+        | U32UncheckedDivMod | U32OverflowingShl | U32OverflowingShr => (2, 2),
+        U32OverflowingAddImm(_)
+        | U32OverflowingSubImm(_)
+        | U32OverflowingMulImm(_)
+        | U32CheckedDivModImm(_)
+        | U32UncheckedDivModImm(_)
+        | U32OverflowingShlImm(_)
+        | U32OverflowingShrImm(_) => (1, 2),
+        U32OverflowingAdd3 | U32OverflowingMadd => (3, 2),
+        U32WrappingAdd3 | U32WrappingMadd => (3, 1),
+
+        Drop => (1, 0),
+        DropW => (4, 0),
+        PadW => (0, 4),
+        Dup0 => (1, 2),
+        Dup1 => (2, 3),
+        Dup2 => (3, 4),
+        Dup3 => (4, 5),
+        Dup4 => (5, 6),
+        Dup5 => (6, 7),
+        Dup6 => (7, 8),
+        Dup7 => (8, 9),
+        Dup8 => (9, 10),
+        Dup9 => (10, 11),
+        Dup10 => (11, 12),
+        Dup11 => (12, 13),
+        Dup12 => (13, 14),
+        Dup13 => (14, 15),
+        Dup14 => (15, 16),
+        Dup15 => (16, 17),
+        DupW0 => (4, 8),
+        DupW1 => (8, 12),
+        DupW2 => (12, 16),
+        DupW3 => (16, 20),
+        Swap1 => (2, 2),
+        Swap2 | MovUp2 | MovDn2 => (3, 3),
+        Swap3 | MovUp3 | MovDn3 => (4, 4),
+        Swap4 | MovUp4 | MovDn4 => (5, 5),
+        Swap5 | MovUp5 | MovDn5 => (6, 6),
+        Swap6 | MovUp6 | MovDn6 => (7, 7),
+        Swap7 | MovUp7 | MovDn7 | SwapW1 => (8, 8),
+        Swap8 | MovUp8 | MovDn8 => (9, 9),
+        Swap9 | MovUp9 | MovDn9 => (10, 10),
+        Swap10 | MovUp10 | MovDn10 => (11, 11),
+        Swap11 | MovUp11 | MovDn11 | SwapW2 | MovUpW2 | MovDnW2 => (12, 12),
+        Swap12 | MovUp12 | MovDn12 => (13, 13),
+        Swap13 | MovUp13 | MovDn13 => (14, 14),
+        Swap14 | MovUp14 | MovDn14 => (15, 15),
+        Swap15 | MovUp15 | MovDn15 | SwapW3 | MovUpW3 | MovDnW3 | SwapDw => (16, 16),
+        CSwap => (3, 2),
+        CSwapW => (9, 8),
+        CDrop => (3, 1),
+        CDropW => (9, 4),
+
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => (0, 1),
+        PushWord(_) => (0, 4),
+        PushU8List(imms) => (0, imms.len()),
+        PushU16List(imms) => (0, imms.len()),
+        PushU32List(imms) => (0, imms.len()),
+        PushFeltList(imms) => (0, imms.len()),
+        Sdepth | Clk | Locaddr(_) => (0, 1),
+        Caller => (4, 4),
+        AdvPipe | MemStream => (13, 13),
+        AdvPush(n) => (0, *n as usize),
+        AdvLoadW => (4, 4),
+        AdvInject(_) => (0, 0),
+
+        MemLoad => (1, 1),
+        MemLoadImm(_) | LocLoad(_) => (0, 1),
+        MemLoadW => (5, 4),
+        MemLoadWImm(_) | LocLoadW(_) => (4, 4),
+        MemStore => (2, 0),
+        MemStoreImm(_) | LocStore(_) => (1, 0),
+        MemStoreW => (5, 4),
+        MemStoreWImm(_) | LocStoreW(_) => (4, 4),
+
+        Hash => (4, 4),
+        HPerm => (12, 12),
+        HMerge => (8, 4),
+        MTreeGet => (6, 8),
+        MTreeSet => (10, 8),
+        MTreeMerge => (8, 4),
+        MTreeVerify => (10, 10),
+        FriExt2Fold4 => (17, 16),
+
+        ProcRefLocal(_) | ProcRefImported(_) => (0, 4),
+        Breakpoint | Debug(_) | Trace(_) => (0, 0),
+
+        ExecLocal(_) | ExecImported(_) | CallLocal(_) | CallImported(_) | CallMastRoot(_)
+        | SysCall(_) | DynExec | DynCall => unreachable!("procedure invocation"),
+    };
+    StackEffect::new(num_inputs, num_outputs)
+}
@@ -1,5 +1,5 @@
 use super::{combine_blocks, Assembler, CodeBlock, Library, Module, Operation};
-use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, Version};
+use crate::{ast::ModuleAst, tests::DummyLibrary, LibraryNamespace, LibraryPath, Version};
 use core::slice::Iter;
 
 // TESTS
@@ -139,3 +139,312 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+// PROCEDURE CACHE
+// ================================================================================================
+
+#[test]
+fn procedure_cache_persists_across_compilations() {
+    let source = "
+        use.foo::bar
+        begin
+            exec.bar::baz
+            exec.bar::qux
+        end";
+
+    let library = dummy_library("foo", "export.baz push.29 end export.qux push.31 end");
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 0);
+
+    // imported procedures are compiled during the first compilation and re-used afterwards
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 2);
+    let program2 = assembler.compile(source).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 2);
+    assert_eq!(program.hash(), program2.hash());
+}
+
+#[test]
+fn procedure_cache_prewarmed_by_precompiled_library() {
+    let library = dummy_library("foo", "export.baz push.29 end export.qux push.31 end");
+    let assembler = Assembler::default().with_precompiled_library(&library).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 2);
+
+    // the result of compilation does not depend on whether the library was pre-compiled
+    let source = "
+        use.foo::bar
+        begin
+            exec.bar::qux
+        end";
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 2);
+
+    let expected = Assembler::default().with_library(&library).unwrap().compile(source).unwrap();
+    assert_eq!(expected.hash(), program.hash());
+}
+
+#[test]
+fn procedure_cache_invalidated_by_conflicting_namespace() {
+    const KERNEL: &str = "export.foo add end";
+    let source = "
+        use.foo::bar
+        begin
+            exec.bar::baz
+            syscall.foo
+        end";
+
+    let assembler = Assembler::default()
+        .with_kernel(KERNEL)
+        .unwrap()
+        .with_library(&dummy_library("foo", "export.baz push.29 end"))
+        .unwrap();
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 2);
+
+    // replacing the library removes everything but the kernel procedures from the cache
+    let assembler =
+        assembler.with_library(&dummy_library("foo", "export.baz push.30 end")).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 1);
+
+    // the program is now compiled against the new library
+    let program2 = assembler.compile(source).unwrap();
+    assert_ne!(program.hash(), program2.hash());
+
+    let syscall = CodeBlock::new_syscall(assembler.kernel().proc_hashes()[0]);
+    let exec = CodeBlock::new_span(vec![Operation::Push(30u64.into())]);
+    assert_eq!(combine_blocks(vec![exec, syscall]).hash(), program2.hash());
+}
+
+// DEAD CODE ELIMINATION
+// ================================================================================================
+
+#[test]
+fn dead_code_elimination_preserves_program_hash() {
+    let source = "
+        proc.foo push.1 end
+        begin
+            exec.foo
+        end";
+    let source_with_helper = "
+        proc.unused push.2 end
+        proc.foo push.1 end
+        proc.also_unused exec.unused end
+        begin
+            exec.foo
+        end";
+
+    let program = Assembler::default().compile(source).unwrap();
+    let program_with_helper = Assembler::default().compile(source_with_helper).unwrap();
+    assert_eq!(program.hash(), program_with_helper.hash());
+
+    // the result is the same when all procedures are compiled
+    let assembler = Assembler::default().with_dead_code_elimination(false);
+    assert!(!assembler.eliminates_dead_code());
+    assert_eq!(program.hash(), assembler.compile(source_with_helper).unwrap().hash());
+}
+
+#[test]
+fn dead_code_elimination_skips_unreachable_local_procs() {
+    // the helper invokes a procedure which does not exist, but it is never invoked itself
+    let source = "
+        use.foo::bar
+        proc.helper exec.bar::missing end
+        proc.foo exec.bar::baz end
+        begin
+            call.foo
+        end";
+    let library = dummy_library("foo", "export.baz push.29 end");
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert!(assembler.compile(source).is_ok());
+
+    let assembler = assembler.with_dead_code_elimination(false);
+    assert!(assembler.compile(source).is_err());
+}
+
+#[test]
+fn dead_code_elimination_skips_unreachable_imported_procs() {
+    let library = dummy_library(
+        "foo",
+        "
+        use.foo::missing
+        proc.helper push.31 end
+        export.baz push.29 exec.helper end
+        export.qux exec.missing::quux end",
+    );
+    let source = "
+        use.foo::bar
+        begin
+            exec.bar::baz
+        end";
+
+    // only the invoked procedure is compiled and added to the cache
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(assembler.proc_cache.borrow().len(), 1);
+
+    let expected =
+        CodeBlock::new_span(vec![Operation::Push(29u64.into()), Operation::Push(31u64.into())]);
+    assert_eq!(expected.hash(), program.hash());
+
+    // the invalid procedure is compiled only when it is invoked
+    let invalid_source = "
+        use.foo::bar
+        begin
+            exec.bar::qux
+        end";
+    assert!(assembler.compile(invalid_source).is_err());
+
+    // without dead code elimination, all procedures of the module are compiled
+    let assembler = Assembler::default()
+        .with_dead_code_elimination(false)
+        .with_library(&library)
+        .unwrap();
+    assert!(assembler.compile(source).is_err());
+}
+
+// OPTIMIZATIONS
+// ================================================================================================
+
+#[test]
+fn optimizations_fold_constants() {
+    let source = "begin push.5 push.3 add push.2 mul push.7 sub push.1 add.1 end";
+    let assembler = Assembler::default().with_optimizations(true);
+    assert!(assembler.optimizations_enabled());
+
+    let program = Assembler::default().compile(source).unwrap();
+    let expected = "\
+        begin \
+            span push(5) push(3) add push(2) mul push(7) neg add pad incr incr end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+    assert_eq!(11, count_ops(program.root()));
+
+    let optimized = assembler.compile(source).unwrap();
+    let expected = "begin span push(9) push(2) end end";
+    assert_eq!(expected, format!("{optimized}"));
+    assert_eq!(2, count_ops(optimized.root()));
+
+    // the program hash reflects the optimized code
+    let hand_optimized = Assembler::default().compile("begin push.9 push.2 end").unwrap();
+    assert_eq!(hand_optimized.hash(), optimized.hash());
+    assert_ne!(program.hash(), optimized.hash());
+}
+
+#[test]
+fn optimizations_remove_identities() {
+    let source = "begin push.0 add mul.1 add.0 swap dup drop push.4 drop dup.3 drop end";
+    let assembler = Assembler::default().with_optimizations(true);
+
+    let program = Assembler::default().compile(source).unwrap();
+    let expected = "\
+        begin \
+            span pad add noop noop swap dup0 drop push(4) drop dup3 drop end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+    assert_eq!(11, count_ops(program.root()));
+
+    let optimized = assembler.compile(source).unwrap();
+    assert_eq!("begin span swap end end", format!("{optimized}"));
+    assert_eq!(1, count_ops(optimized.root()));
+
+    // a span which is optimized away entirely is replaced by a NOOP
+    let optimized = assembler.compile("begin push.1 drop end").unwrap();
+    assert_eq!("begin span noop end end", format!("{optimized}"));
+}
+
+#[test]
+fn optimizations_keep_unprovable_asserts() {
+    let source = "begin push.1 assert push.0 assert dup.1 assert push.3 push.3 assert_eq end";
+    let assembler = Assembler::default().with_optimizations(true);
+
+    // asserts on values which are not known to be 1 are kept
+    let optimized = assembler.compile(source).unwrap();
+    let expected = "begin span pad assert dup1 assert end end";
+    assert_eq!(expected, format!("{optimized}"));
+}
+
+#[test]
+fn optimizations_respect_block_boundaries() {
+    // operations are not folded across control flow boundaries
+    let source = "\
+        begin \
+            push.3 push.1 \
+            if.true push.2 add else push.0 end \
+            push.4 add \
+            repeat.2 push.1 add end \
+            while.true push.0 end \
+        end";
+    let assembler = Assembler::default().with_optimizations(true);
+    let program = Assembler::default().compile(source).unwrap();
+    let optimized = assembler.compile(source).unwrap();
+    assert_eq!(format!("{program}"), format!("{optimized}"));
+    assert_eq!(program.hash(), optimized.hash());
+
+    // in debug mode, operations of different instructions are not folded as each instruction is
+    // tracked by a decorator
+    let source = "begin push.5 push.3 add breakpoint add.0 end";
+    let assembler = assembler.with_debug_mode(true);
+    let optimized = assembler.compile(source).unwrap();
+    let expected = "begin span push(5) push(3) add noop noop end end";
+    assert_eq!(expected, format!("{optimized}"));
+}
+
+#[test]
+fn debug_decorators_preserve_program_hash() {
+    let source = "\
+        begin \
+            debug.stack push.3 debug.stack.2 push.5 add debug.mem.0.4 \
+            if.true debug.mem push.7 end \
+            push.11 debug.stack \
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let debug_program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    assert_eq!(program.hash(), debug_program.hash());
+
+    // outside of debug mode, debug decorators are dropped
+    let before = CodeBlock::new_span(vec![
+        Operation::Push(3u64.into()),
+        Operation::Push(5u64.into()),
+        Operation::Add,
+    ]);
+    let r#true = CodeBlock::new_span(vec![Operation::Push(7u64.into())]);
+    let r#false = CodeBlock::new_span(vec![Operation::Noop]);
+    let r#if = CodeBlock::new_split(r#true.clone(), r#false.clone());
+    let after = CodeBlock::new_span(vec![Operation::Push(11u64.into())]);
+    let combined = combine_blocks(vec![before, r#if, after]);
+    assert_eq!(combined.hash(), program.hash());
+
+    // a debug decorator which is not adjacent to any operation of its block is attached to a NOOP
+    let source = "begin push.3 if.true push.7 end debug.stack end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let r#if = CodeBlock::new_split(r#true, r#false);
+    let before = CodeBlock::new_span(vec![Operation::Push(3u64.into())]);
+    let after = CodeBlock::new_span(vec![Operation::Noop]);
+    let combined = combine_blocks(vec![before, r#if, after]);
+    assert_eq!(combined.hash(), program.hash());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a library with the specified namespace containing a single `bar` module.
+fn dummy_library(namespace: &str, module_source: &str) -> DummyLibrary {
+    let namespace = LibraryNamespace::try_from(namespace.to_string()).unwrap();
+    let path = LibraryPath::try_from("bar".to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(module_source).unwrap();
+    DummyLibrary::new(namespace, vec![Module { path, ast }])
+}
+
+/// Returns the number of operations in the specified code block, including the operations of all
+/// nested blocks. Calls and dynamic code blocks are counted as a single operation as the callee is
+/// not inlined.
+fn count_ops(block: &CodeBlock) -> usize {
+    match block {
+        CodeBlock::Span(span) => span.op_batches().iter().map(|batch| batch.ops().len()).sum(),
+        CodeBlock::Join(join) => count_ops(join.first()) + count_ops(join.second()),
+        CodeBlock::Split(split) => count_ops(split.on_true()) + count_ops(split.on_false()),
+        CodeBlock::Loop(loop_block) => count_ops(loop_block.body()),
+        CodeBlock::Call(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => 1,
+    }
+}
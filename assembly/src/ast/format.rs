@@ -0,0 +1,293 @@
+use super::{
+    CodeBody, Felt, Instruction, LibraryPath, ModuleAst, Node, ProcReExport, ProcedureAst,
+    ProcedureId, StarkField, String, ToString, Vec, STACK_UNSAFE_ANNOTATION,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Indentation added for every level of block nesting.
+const INDENT: &str = "  ";
+
+/// Values pushed onto the stack which are greater than or equal to this bound are rendered in
+/// hexadecimal form.
+const HEX_THRESHOLD: u64 = 1 << 32;
+
+// FORMAT OPTIONS
+// ================================================================================================
+
+/// Options which control the layout of the source code emitted by [masm_fmt_with_options()].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Maximum number of instructions placed on a single line. Only consecutive instructions
+    /// which are not separated by control flow statements are placed on the same line.
+    pub max_ops_per_line: usize,
+}
+
+impl FormatOptions {
+    pub fn new(max_ops_per_line: usize) -> Self {
+        Self { max_ops_per_line }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+// FORMATTER
+// ================================================================================================
+
+/// Renders the provided module AST as Miden assembly source code in canonical form.
+///
+/// This is equivalent to calling [masm_fmt_with_options()] with default options, i.e., every
+/// instruction is placed on its own line.
+pub fn masm_fmt(module: &ModuleAst) -> String {
+    masm_fmt_with_options(module, FormatOptions::default())
+}
+
+/// Renders the provided module AST as Miden assembly source code in canonical form.
+///
+/// The emitted source code is laid out as follows:
+/// - Module docs, imports (sorted by module path), re-exported procedures, and local procedures
+///   (in declaration order) are separated by empty lines.
+/// - The bodies of procedures and control flow blocks are indented by two spaces per level.
+/// - Instructions are placed on separate lines, unless `options` allow more instructions per
+///   line.
+/// - Values pushed onto the stack are rendered in decimal form if they are smaller than 2^32, and
+///   in hexadecimal form otherwise. Immediate values of other instructions are always rendered in
+///   decimal form as they cannot be specified in hexadecimal form.
+/// - Doc comments of procedures are preserved; constants are inlined, and regular comments are
+///   not preserved as they are not a part of the AST.
+///
+/// Parsing the emitted source code yields a module AST identical to the provided one (ignoring
+/// source locations). This requires names of the imported procedures invoked from the module
+/// (see [ModuleAst::invoked_procs()]); invocations of procedures with unknown names are rendered
+/// by procedure ID, and such source code cannot be parsed.
+pub fn masm_fmt_with_options(module: &ModuleAst, options: FormatOptions) -> String {
+    let formatter = ModuleFormatter {
+        module,
+        max_ops_per_line: options.max_ops_per_line.max(1),
+    };
+
+    let mut sections = Vec::new();
+    if let Some(docs) = module.docs() {
+        sections.push(format_docs(docs));
+    }
+    if !module.imports().is_empty() {
+        sections.push(formatter.format_imports());
+    }
+    if !module.reexported_procs().is_empty() {
+        let reexports = module.reexported_procs().iter();
+        sections.push(reexports.map(|proc| formatter.format_reexport(proc)).collect());
+    }
+    for proc in module.procs() {
+        sections.push(formatter.format_proc(proc));
+    }
+
+    sections.join("\n")
+}
+
+/// Renders parts of a single module; all rendered lines are terminated with a line break.
+struct ModuleFormatter<'a> {
+    module: &'a ModuleAst,
+    max_ops_per_line: usize,
+}
+
+impl ModuleFormatter<'_> {
+    // MODULE ITEMS
+    // --------------------------------------------------------------------------------------------
+
+    fn format_imports(&self) -> String {
+        let mut imports = self.module.imports().iter().collect::<Vec<_>>();
+        imports.sort_by(|(_, a), (_, b)| a.as_str().cmp(b.as_str()));
+        imports
+            .into_iter()
+            .map(|(name, path)| {
+                if name == path.last() {
+                    format!("use.{}\n", path.as_str())
+                } else {
+                    format!("use.{}->{name}\n", path.as_str())
+                }
+            })
+            .collect()
+    }
+
+    fn format_reexport(&self, proc: &ProcReExport) -> String {
+        let module = self.import_name(proc.ref_path()).unwrap_or_else(|| proc.ref_path().last());
+        if proc.is_alias() {
+            format!("export.{module}::{}->{}\n", proc.ref_name().as_str(), proc.name().as_str())
+        } else {
+            format!("export.{module}::{}\n", proc.name().as_str())
+        }
+    }
+
+    fn format_proc(&self, proc: &ProcedureAst) -> String {
+        let mut result = String::new();
+
+        // doc comments are attached only to exported procedures, but the stack-unsafe annotation
+        // must be preserved for all procedures
+        match &proc.docs {
+            Some(docs) => {
+                result.push_str(&format_docs(docs));
+                let is_annotated = docs.lines().any(|line| line.trim() == STACK_UNSAFE_ANNOTATION);
+                if proc.is_stack_unsafe && !is_annotated {
+                    result.push_str(&format!("#! {STACK_UNSAFE_ANNOTATION}\n"));
+                }
+            }
+            None if proc.is_stack_unsafe => {
+                result.push_str(&format!("#! {STACK_UNSAFE_ANNOTATION}\n"));
+            }
+            None => (),
+        }
+
+        let keyword = if proc.is_export { "export" } else { "proc" };
+        if proc.num_locals == 0 {
+            result.push_str(&format!("{keyword}.{}\n", proc.name.as_str()));
+        } else {
+            result.push_str(&format!("{keyword}.{}.{}\n", proc.name.as_str(), proc.num_locals));
+        }
+        self.format_body(&proc.body, 1, &mut result);
+        result.push_str("end\n");
+
+        result
+    }
+
+    // CODE BODIES
+    // --------------------------------------------------------------------------------------------
+
+    fn format_body(&self, body: &CodeBody, depth: usize, result: &mut String) {
+        let mut line = Vec::new();
+        for node in body.nodes() {
+            match node {
+                Node::Instruction(instruction) => {
+                    line.push(self.format_instruction(instruction));
+                    if line.len() == self.max_ops_per_line {
+                        push_line(result, depth, &line.join(" "));
+                        line.clear();
+                    }
+                }
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    flush_line(result, depth, &mut line);
+                    push_line(result, depth, "if.true");
+                    self.format_body(true_case, depth + 1, result);
+                    if !false_case.nodes().is_empty() {
+                        push_line(result, depth, "else");
+                        self.format_body(false_case, depth + 1, result);
+                    }
+                    push_line(result, depth, "end");
+                }
+                Node::Repeat { times, body } => {
+                    flush_line(result, depth, &mut line);
+                    push_line(result, depth, &format!("repeat.{times}"));
+                    self.format_body(body, depth + 1, result);
+                    push_line(result, depth, "end");
+                }
+                Node::While { body } => {
+                    flush_line(result, depth, &mut line);
+                    push_line(result, depth, "while.true");
+                    self.format_body(body, depth + 1, result);
+                    push_line(result, depth, "end");
+                }
+            }
+        }
+        flush_line(result, depth, &mut line);
+    }
+
+    fn format_instruction(&self, instruction: &Instruction) -> String {
+        use Instruction::*;
+        match instruction {
+            ExecLocal(idx) => self.format_local_invocation("exec", *idx, instruction),
+            CallLocal(idx) => self.format_local_invocation("call", *idx, instruction),
+            ProcRefLocal(idx) => self.format_local_invocation("procref", *idx, instruction),
+            ExecImported(proc_id) => self.format_invocation("exec", proc_id, instruction),
+            CallImported(proc_id) => self.format_invocation("call", proc_id, instruction),
+            ProcRefImported(proc_id) => self.format_invocation("procref", proc_id, instruction),
+            SysCall(proc_id) => match self.module.invoked_procs().get(proc_id) {
+                Some((name, _)) => format!("syscall.{}", name.as_str()),
+                None => instruction.to_string(),
+            },
+            PushFelt(value) => format_push(&[*value]),
+            PushWord(values) => format_push(values),
+            PushFeltList(values) => format_push(values),
+            _ => instruction.to_string(),
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders an invocation of a local procedure by the procedure name.
+    fn format_local_invocation(&self, op: &str, idx: u16, instruction: &Instruction) -> String {
+        match self.module.procs().get(idx as usize) {
+            Some(proc) => format!("{op}.{}", proc.name.as_str()),
+            None => instruction.to_string(),
+        }
+    }
+
+    /// Renders an invocation of an imported procedure by the module and procedure names.
+    fn format_invocation(
+        &self,
+        op: &str,
+        proc_id: &ProcedureId,
+        instruction: &Instruction,
+    ) -> String {
+        self.module
+            .invoked_procs()
+            .get(proc_id)
+            .and_then(|(name, path)| {
+                let module = self.import_name(path)?;
+                Some(format!("{op}.{module}::{}", name.as_str()))
+            })
+            .unwrap_or_else(|| instruction.to_string())
+    }
+
+    /// Returns the name under which the module with the specified path is imported.
+    fn import_name(&self, path: &LibraryPath) -> Option<&str> {
+        self.module
+            .imports()
+            .iter()
+            .find_map(|(name, import_path)| (import_path == path).then_some(name.as_str()))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Renders the provided docs as a doc comment.
+fn format_docs(docs: &str) -> String {
+    docs.lines().map(|line| format!("#! {line}\n")).collect()
+}
+
+/// Renders a push instruction for the provided values.
+fn format_push(values: &[Felt]) -> String {
+    let values = values
+        .iter()
+        .map(|value| match value.as_int() {
+            value if value < HEX_THRESHOLD => value.to_string(),
+            value => format!("0x{value:016x}"),
+        })
+        .collect::<Vec<_>>();
+    format!("push.{}", values.join("."))
+}
+
+/// Appends the provided line to the result with indentation for the specified nesting depth.
+fn push_line(result: &mut String, depth: usize, line: &str) {
+    for _ in 0..depth {
+        result.push_str(INDENT);
+    }
+    result.push_str(line);
+    result.push('\n');
+}
+
+/// Appends the instructions accumulated for the current line to the result, if there are any.
+fn flush_line(result: &mut String, depth: usize, line: &mut Vec<String>) {
+    if !line.is_empty() {
+        push_line(result, depth, &line.join(" "));
+        line.clear();
+    }
+}
@@ -1,15 +1,19 @@
 //! Abstract syntax tree (AST) components of Miden programs, modules, and procedures.
 //!
 //! Structs in this module (specifically [ProgramAst] and [ModuleAst]) can be used to parse source
-//! code into relevant ASTs. This can be done via their `parse()` methods.
+//! code into relevant ASTs. This can be done via their `parse()` methods, or via [parse_program()]
+//! and [parse_module()] functions. A parsed (or programmatically constructed) program AST can be
+//! compiled via [Assembler::compile_ast()](crate::Assembler::compile_ast), and both program and
+//! module ASTs can be serialized into bytes and deserialized back. Module ASTs can also be rendered
+//! back into source code in canonical form via [masm_fmt()].
 
 use super::{
-    crypto::hash::RpoDigest, BTreeMap, ByteReader, ByteWriter, Deserializable,
+    crypto::hash::RpoDigest, BTreeMap, BTreeSet, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Felt, LabelError, LibraryPath, ParsingError, ProcedureId, ProcedureName,
     Serializable, SliceReader, StarkField, String, ToString, Token, TokenStream, Vec,
     MAX_LABEL_LEN,
 };
-use core::{iter, str::from_utf8};
+use core::{cell::RefCell, iter, str::from_utf8};
 use vm_core::utils::bound_into_included_u64;
 
 pub use super::tokens::SourceLocation;
@@ -20,6 +24,9 @@ pub use nodes::{AdviceInjectorNode, Instruction, Node};
 mod code_body;
 pub use code_body::CodeBody;
 
+mod format;
+pub use format::{masm_fmt, masm_fmt_with_options, FormatOptions};
+
 mod invocation_target;
 pub use invocation_target::InvocationTarget;
 
@@ -55,11 +62,61 @@ const MAX_IMPORTS: usize = u16::MAX as usize;
 /// Maximum stack index at which a full word can start.
 const MAX_STACK_WORD_OFFSET: u8 = 12;
 
+/// Doc comment line which marks a procedure as stack-unsafe.
+const STACK_UNSAFE_ANNOTATION: &str = "stack-unsafe";
+
+/// Serialized procedure flag which is set for exported procedures.
+const PROC_FLAG_EXPORT: u8 = 0b01;
+
+/// Serialized procedure flag which is set for stack-unsafe procedures.
+const PROC_FLAG_STACK_UNSAFE: u8 = 0b10;
+
+// PARSERS
+// ================================================================================================
+
+/// Parses the provided source code into a [ProgramAst].
+///
+/// This is equivalent to [ProgramAst::parse()].
+pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
+    ProgramAst::parse(source)
+}
+
+/// Parses the provided source code into a [ModuleAst].
+///
+/// This is equivalent to [ModuleAst::parse()].
+pub fn parse_module(source: &str) -> Result<ModuleAst, ParsingError> {
+    ModuleAst::parse(source)
+}
+
+/// Returns locations of the `use` statements at the beginning of the provided source code, keyed
+/// by the names under which the imported modules can be referenced.
+///
+/// Import locations are not a part of program and module ASTs; they are needed only to report
+/// diagnostics about imports.
+pub(crate) fn parse_import_locations(
+    source: &str,
+) -> Result<BTreeMap<String, SourceLocation>, ParsingError> {
+    let mut tokens = TokenStream::new(source)?;
+    let mut locations = BTreeMap::new();
+    while let Some(token) = tokens.read() {
+        if token.parts()[0] != Token::USE {
+            break;
+        }
+        let (module_path, alias) = token.parse_use()?;
+        let name = alias.unwrap_or_else(|| module_path.last());
+        locations.insert(name.to_string(), *token.location());
+        tokens.advance();
+    }
+    Ok(locations)
+}
+
 // TYPE ALIASES
 // ================================================================================================
 type LocalProcMap = BTreeMap<String, (u16, ProcedureAst)>;
 type LocalConstMap = BTreeMap<String, u64>;
 type ReExportedProcMap = BTreeMap<String, ProcReExport>;
+type AmbiguousImportMap = BTreeMap<String, Vec<LibraryPath>>;
+type InvokedProcMap = BTreeMap<ProcedureId, (ProcedureName, LibraryPath)>;
 
 // EXECUTABLE PROGRAM AST
 // ================================================================================================
@@ -137,6 +194,12 @@ impl ProgramAst {
         &self.body
     }
 
+    /// Returns a map of modules imported by this program, keyed by the names under which the
+    /// modules can be referenced in the program.
+    pub fn imports(&self) -> &BTreeMap<String, LibraryPath> {
+        &self.imports
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ProgramAst].
@@ -144,14 +207,16 @@ impl ProgramAst {
     /// A program consist of a body and a set of internal (i.e., not exported) procedures.
     pub fn parse(source: &str) -> Result<ProgramAst, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
-        let imports = parse_imports(&mut tokens)?;
+        let (imports, ambiguous_imports) = parse_imports(&mut tokens)?;
         let local_constants = parse_constants(&mut tokens)?;
 
         let mut context = ParserContext {
             imports: &imports,
+            ambiguous_imports: &ambiguous_imports,
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            invoked_procs: RefCell::default(),
         };
 
         context.parse_procedures(&mut tokens, false)?;
@@ -222,10 +287,7 @@ impl ProgramAst {
         if options.serialize_imports {
             assert!(self.imports.len() <= MAX_IMPORTS, "too many imports");
             target.write_u16(self.imports.len() as u16);
-            // We don't need to serialize the library names (the keys),
-            // since the libraty paths (the values) contain the library
-            // names
-            self.imports.values().for_each(|path| path.write_into(&mut target));
+            write_imports(&self.imports, &mut target);
         }
 
         assert!(self.local_procs.len() <= MAX_LOCAL_PROCS, "too many local procs");
@@ -249,14 +311,12 @@ impl ProgramAst {
         // Deserialize the serialization options used when serializing
         let options = AstSerdeOptions::read_from(&mut source)?;
 
-        let mut imports = BTreeMap::<String, LibraryPath>::new();
-        if options.serialize_imports {
-            let num_imports = source.read_u16()?;
-            for _ in 0..num_imports {
-                let path = LibraryPath::read_from(&mut source)?;
-                imports.insert(path.last().to_string(), path);
-            }
-        }
+        let imports = if options.serialize_imports {
+            let num_imports = source.read_u16()? as usize;
+            read_imports(&mut source, num_imports)?
+        } else {
+            BTreeMap::new()
+        };
 
         let num_local_procs = source.read_u16()?;
         let local_procs = Deserializable::read_batch_from(&mut source, num_local_procs as usize)?;
@@ -315,6 +375,7 @@ pub struct ModuleAst {
     local_procs: Vec<ProcedureAst>,
     reexported_procs: Vec<ProcReExport>,
     imports: BTreeMap<String, LibraryPath>,
+    invoked_procs: InvokedProcMap,
     docs: Option<String>,
 }
 
@@ -351,10 +412,21 @@ impl ModuleAst {
             local_procs,
             reexported_procs,
             imports,
+            invoked_procs: InvokedProcMap::new(),
             docs,
         })
     }
 
+    /// Binds the names of the procedures invoked from this module by their IDs (i.e., via
+    /// `exec`, `call`, `procref`, or `syscall` instructions) to this module.
+    ///
+    /// The names are not needed for compilation, but they make it possible to render the module
+    /// back into source code (see [masm_fmt()]).
+    pub fn with_invoked_procs(mut self, invoked_procs: InvokedProcMap) -> Self {
+        self.invoked_procs = invoked_procs;
+        self
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ModuleAst].
@@ -363,13 +435,15 @@ impl ModuleAst {
     pub fn parse(source: &str) -> Result<Self, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
 
-        let imports = parse_imports(&mut tokens)?;
+        let (imports, ambiguous_imports) = parse_imports(&mut tokens)?;
         let local_constants = parse_constants(&mut tokens)?;
         let mut context = ParserContext {
             imports: &imports,
+            ambiguous_imports: &ambiguous_imports,
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            invoked_procs: RefCell::default(),
         };
         context.parse_procedures(&mut tokens, true)?;
 
@@ -391,7 +465,9 @@ impl ModuleAst {
         // get module docs and make sure the size is within the limit
         let docs = tokens.take_module_comments();
 
-        Self::new(local_procs, reexported_procs, imports, docs)
+        let invoked_procs = context.invoked_procs.into_inner();
+        Ok(Self::new(local_procs, reexported_procs, imports, docs)?
+            .with_invoked_procs(invoked_procs))
     }
 
     // PUBLIC ACCESSORS
@@ -417,6 +493,16 @@ impl ModuleAst {
         &self.imports
     }
 
+    /// Returns names of the imported and kernel procedures invoked from this module, keyed by
+    /// procedure IDs.
+    ///
+    /// Each name is accompanied by the path of the module in which the procedure is defined. The
+    /// names are available only if the module was parsed from source, or if it was deserialized
+    /// with imports.
+    pub fn invoked_procs(&self) -> &BTreeMap<ProcedureId, (ProcedureName, LibraryPath)> {
+        &self.invoked_procs
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -450,10 +536,11 @@ impl ModuleAst {
         if options.serialize_imports {
             assert!(self.imports.len() <= MAX_IMPORTS, "too many imports");
             target.write_u16(self.imports.len() as u16);
-            // We don't need to serialize the library names (the keys),
-            // since the libraty paths (the values) contain the library
-            // names
-            self.imports.values().for_each(|i| i.write_into(target));
+            write_imports(&self.imports, target);
+
+            assert!(self.invoked_procs.len() <= u16::MAX as usize, "too many invoked procs");
+            target.write_u16(self.invoked_procs.len() as u16);
+            write_invoked_procs(&self.invoked_procs, target);
         }
 
         assert!(self.local_procs.len() <= u16::MAX as usize, "too many local procs");
@@ -485,15 +572,15 @@ impl ModuleAst {
             None
         };
 
-        // deserialize imports if required
-        let mut imports = BTreeMap::<String, LibraryPath>::new();
-        if options.serialize_imports {
-            let num_imports = source.read_u16()?;
-            for _ in 0..num_imports {
-                let path = LibraryPath::read_from(source)?;
-                imports.insert(path.last().to_string(), path);
-            }
-        }
+        // deserialize imports and names of invoked procedures if required
+        let (imports, invoked_procs) = if options.serialize_imports {
+            let num_imports = source.read_u16()? as usize;
+            let imports = read_imports(source, num_imports)?;
+            let num_invoked_procs = source.read_u16()? as usize;
+            (imports, read_invoked_procs(source, num_invoked_procs)?)
+        } else {
+            (BTreeMap::new(), BTreeMap::new())
+        };
 
         // deserialize re-exports
         let num_reexported_procs = source.read_u16()? as usize;
@@ -504,6 +591,7 @@ impl ModuleAst {
         let local_procs = Deserializable::read_batch_from(source, num_local_procs)?;
 
         Self::new(local_procs, reexported_procs, imports, docs)
+            .map(|module| module.with_invoked_procs(invoked_procs))
             .map_err(|err| DeserializationError::UnknownError(err.message().clone()))
     }
 
@@ -561,6 +649,10 @@ impl ModuleAst {
 /// A procedure AST consists of a list of body nodes and additional metadata about the procedure
 /// (e.g., procedure name, number of memory locals used by the procedure, and whether a procedure
 /// is exported or internal).
+///
+/// A procedure is marked as stack-unsafe if its doc comment contains a `stack-unsafe` line. Such
+/// procedures are allowed to change the stack depth in a data-dependent way, and are exempt from
+/// the stack depth checks of the assembler.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProcedureAst {
     pub name: ProcedureName,
@@ -569,6 +661,7 @@ pub struct ProcedureAst {
     pub body: CodeBody,
     pub start: SourceLocation,
     pub is_export: bool,
+    pub is_stack_unsafe: bool,
 }
 
 impl ProcedureAst {
@@ -593,10 +686,18 @@ impl ProcedureAst {
             num_locals,
             body,
             is_export,
+            is_stack_unsafe: false,
             start,
         }
     }
 
+    /// Marks this procedure as stack-unsafe, i.e., as allowed to change the stack depth in a
+    /// data-dependent way.
+    pub fn with_stack_unsafe(mut self, is_stack_unsafe: bool) -> Self {
+        self.is_stack_unsafe = is_stack_unsafe;
+        self
+    }
+
     /// Binds the provided `locations` into the ast nodes.
     ///
     /// The `start` location points to the first node of this block.
@@ -669,7 +770,14 @@ impl Serializable for ProcedureAst {
             }
         }
 
-        target.write_bool(self.is_export);
+        let mut flags = 0;
+        if self.is_export {
+            flags |= PROC_FLAG_EXPORT;
+        }
+        if self.is_stack_unsafe {
+            flags |= PROC_FLAG_STACK_UNSAFE;
+        }
+        target.write_u8(flags);
         target.write_u16(self.num_locals);
         assert!(self.body.nodes().len() <= MAX_BODY_LEN, "too many body instructions");
         target.write_u16(self.body.nodes().len() as u16);
@@ -690,7 +798,14 @@ impl Deserializable for ProcedureAst {
             None
         };
 
-        let is_export = source.read_bool()?;
+        let flags = source.read_u8()?;
+        if flags & !(PROC_FLAG_EXPORT | PROC_FLAG_STACK_UNSAFE) != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid procedure flags: {flags:#04x}"
+            )));
+        }
+        let is_export = flags & PROC_FLAG_EXPORT != 0;
+        let is_stack_unsafe = flags & PROC_FLAG_STACK_UNSAFE != 0;
         let num_locals = source.read_u16()?;
         let body_len = source.read_u16()? as usize;
         let nodes = Deserializable::read_batch_from(source, body_len)?;
@@ -702,6 +817,7 @@ impl Deserializable for ProcedureAst {
             body,
             start,
             is_export,
+            is_stack_unsafe,
             docs,
         })
     }
@@ -712,17 +828,27 @@ impl Deserializable for ProcedureAst {
 /// A re-exported procedure is a procedure that is defined in a different module in the same
 /// library or a different library and re-exported with the same or a different name. The
 /// re-exported procedure is not copied into the module, but rather a reference to it is added to
-/// the [ModuleAST].
+/// the [ModuleAST]. The reference retains the path of the module in which the procedure is
+/// defined and its original name, so that tools (e.g., documentation generators) can follow it.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct ProcReExport {
     proc_id: ProcedureId,
     name: ProcedureName,
+    ref_path: LibraryPath,
+    ref_name: ProcedureName,
 }
 
 impl ProcReExport {
-    /// Creates a new re-exported procedure.
-    pub fn new(proc_id: ProcedureId, name: ProcedureName) -> Self {
-        Self { proc_id, name }
+    /// Creates a new re-exported procedure which exports procedure `ref_name` from the module at
+    /// `ref_path` under the specified `name`.
+    pub fn new(name: ProcedureName, ref_path: LibraryPath, ref_name: ProcedureName) -> Self {
+        let proc_id = ProcedureId::from_name(&ref_name, &ref_path);
+        Self {
+            proc_id,
+            name,
+            ref_path,
+            ref_name,
+        }
     }
 
     // PUBLIC ACCESSORS
@@ -738,6 +864,22 @@ impl ProcReExport {
         &self.name
     }
 
+    /// Returns the path of the module in which the re-exported procedure is defined.
+    pub fn ref_path(&self) -> &LibraryPath {
+        &self.ref_path
+    }
+
+    /// Returns the name of the re-exported procedure in the module in which it is defined.
+    pub fn ref_name(&self) -> &ProcedureName {
+        &self.ref_name
+    }
+
+    /// Returns true if the procedure is re-exported under a name different from its original
+    /// name.
+    pub fn is_alias(&self) -> bool {
+        self.name != self.ref_name
+    }
+
     /// Returns the ID of the re-exported procedure using the specified module.
     pub fn get_alias_id(&self, module_path: &LibraryPath) -> ProcedureId {
         ProcedureId::from_name(&self.name, module_path)
@@ -746,16 +888,18 @@ impl ProcReExport {
 
 impl Serializable for ProcReExport {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        self.proc_id.write_into(target);
         self.name.write_into(target);
+        self.ref_path.write_into(target);
+        self.ref_name.write_into(target);
     }
 }
 
 impl Deserializable for ProcReExport {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let proc_id = ProcedureId::read_from(source)?;
         let name = ProcedureName::read_from(source)?;
-        Ok(Self { proc_id, name })
+        let ref_path = LibraryPath::read_from(source)?;
+        let ref_name = ProcedureName::read_from(source)?;
+        Ok(Self::new(name, ref_path, ref_name))
     }
 }
 
@@ -769,3 +913,61 @@ fn sort_procs_into_vec(proc_map: LocalProcMap) -> Vec<ProcedureAst> {
 
     procedures.into_iter().map(|(_idx, proc)| proc).collect()
 }
+
+/// Writes the imports into `target`; every import is written as the name under which the module
+/// is imported followed by the module's path.
+fn write_imports<W: ByteWriter>(imports: &BTreeMap<String, LibraryPath>, target: &mut W) {
+    imports.iter().for_each(|(name, path)| {
+        assert!(name.len() <= u8::MAX as usize, "import name too long");
+        target.write_u8(name.len() as u8);
+        target.write_bytes(name.as_bytes());
+        path.write_into(target);
+    });
+}
+
+/// Reads the specified number of imports written via [write_imports] from `source`.
+fn read_imports<R: ByteReader>(
+    source: &mut R,
+    num_imports: usize,
+) -> Result<BTreeMap<String, LibraryPath>, DeserializationError> {
+    let mut imports = BTreeMap::<String, LibraryPath>::new();
+    for _ in 0..num_imports {
+        let name_len = source.read_u8()? as usize;
+        let name = source.read_vec(name_len)?;
+        let name =
+            from_utf8(&name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+        let path = LibraryPath::read_from(source)?;
+        if imports.insert(name.to_string(), path).is_some() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "module '{name}' imported more than once"
+            )));
+        }
+    }
+    Ok(imports)
+}
+
+/// Writes the names of invoked procedures into `target`; every entry is written as the procedure
+/// ID followed by the procedure name and the path of the module which defines the procedure.
+fn write_invoked_procs<W: ByteWriter>(invoked_procs: &InvokedProcMap, target: &mut W) {
+    invoked_procs.iter().for_each(|(proc_id, (name, module_path))| {
+        proc_id.write_into(target);
+        name.write_into(target);
+        module_path.write_into(target);
+    });
+}
+
+/// Reads the specified number of invoked procedure names written via [write_invoked_procs] from
+/// `source`.
+fn read_invoked_procs<R: ByteReader>(
+    source: &mut R,
+    num_invoked_procs: usize,
+) -> Result<InvokedProcMap, DeserializationError> {
+    let mut invoked_procs = InvokedProcMap::new();
+    for _ in 0..num_invoked_procs {
+        let proc_id = ProcedureId::read_from(source)?;
+        let name = ProcedureName::read_from(source)?;
+        let module_path = LibraryPath::read_from(source)?;
+        invoked_procs.insert(proc_id, (name, module_path));
+    }
+    Ok(invoked_procs)
+}
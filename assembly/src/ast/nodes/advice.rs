@@ -16,6 +16,7 @@ use vm_core::{AdviceInjector, Felt, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64div,
+    PushU256div,
     PushExt2intt,
     PushSmtGet,
     PushMapVal,
@@ -33,6 +34,7 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64div => Self::DivU64,
+            PushU256div => Self::DivU256,
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushMapVal => Self::MapValueToStack {
@@ -66,6 +68,7 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => write!(f, "push_u64div"),
+            PushU256div => write!(f, "push_u256div"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushMapVal => write!(f, "push_mapval"),
@@ -94,12 +97,14 @@ const PUSH_MTNODE: u8 = 7;
 const INSERT_MEM: u8 = 8;
 const INSERT_HDWORD: u8 = 9;
 const INSERT_HDWORD_IMM: u8 = 10;
+const PUSH_U256DIV: u8 = 11;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => target.write_u8(PUSH_U64DIV),
+            PushU256div => target.write_u8(PUSH_U256DIV),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushMapVal => target.write_u8(PUSH_MAPVAL),
@@ -127,6 +132,7 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64div),
+            PUSH_U256DIV => Ok(AdviceInjectorNode::PushU256div),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_MAPVAL => Ok(AdviceInjectorNode::PushMapVal),
@@ -1,5 +1,6 @@
 use super::{CodeBody, Felt, ProcedureId, RpoDigest, ToString, Vec};
 use core::fmt;
+use vm_core::DebugOptions;
 
 mod advice;
 pub use advice::AdviceInjectorNode;
@@ -31,9 +32,12 @@ pub enum Node {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Instruction {
     Assert,
+    AssertWithError(Felt),
     AssertEq,
+    AssertEqWithError(Felt),
     AssertEqw,
     Assertz,
+    AssertzWithError(Felt),
     Add,
     AddImm(Felt),
     Sub,
@@ -46,6 +50,7 @@ pub enum Instruction {
     Inv,
     Incr,
     Pow2,
+    ILog2,
     Exp,
     ExpImm(Felt),
     ExpBitLength(u8),
@@ -76,7 +81,9 @@ pub enum Instruction {
     U32Test,
     U32TestW,
     U32Assert,
+    U32AssertWithError(Felt),
     U32Assert2,
+    U32Assert2WithError(Felt),
     U32AssertW,
     U32Split,
     U32Cast,
@@ -122,10 +129,14 @@ pub enum Instruction {
     U32CheckedShrImm(u8),
     U32UncheckedShr,
     U32UncheckedShrImm(u8),
+    U32OverflowingShr,
+    U32OverflowingShrImm(u8),
     U32CheckedShl,
     U32CheckedShlImm(u8),
     U32UncheckedShl,
     U32UncheckedShlImm(u8),
+    U32OverflowingShl,
+    U32OverflowingShlImm(u8),
     U32CheckedRotr,
     U32CheckedRotrImm(u8),
     U32UncheckedRotr,
@@ -289,9 +300,15 @@ pub enum Instruction {
     CallMastRoot(RpoDigest),
     CallImported(ProcedureId),
     SysCall(ProcedureId),
+    DynExec,
+    DynCall,
+    ProcRefLocal(u16),
+    ProcRefImported(ProcedureId),
 
     // ----- debug decorators ---------------------------------------------------------------------
     Breakpoint,
+    Debug(DebugOptions),
+    Trace(u32),
 }
 
 impl Instruction {
@@ -305,9 +322,12 @@ impl fmt::Display for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Assert => write!(f, "assert"),
+            Self::AssertWithError(err_code) => write!(f, "assert.err={err_code}"),
             Self::AssertEq => write!(f, "assert_eq"),
+            Self::AssertEqWithError(err_code) => write!(f, "assert_eq.err={err_code}"),
             Self::AssertEqw => write!(f, "assert_eqw"),
             Self::Assertz => write!(f, "assertz"),
+            Self::AssertzWithError(err_code) => write!(f, "assertz.err={err_code}"),
             Self::Add => write!(f, "add"),
             Self::AddImm(value) => write!(f, "add.{value}"),
             Self::Sub => write!(f, "sub"),
@@ -320,6 +340,7 @@ impl fmt::Display for Instruction {
             Self::Inv => write!(f, "inv"),
             Self::Incr => write!(f, "add.1"),
             Self::Pow2 => write!(f, "pow2"),
+            Self::ILog2 => write!(f, "ilog2"),
             Self::Exp => write!(f, "exp"),
             Self::ExpImm(value) => write!(f, "exp.{value}"),
             Self::ExpBitLength(value) => write!(f, "exp.u{value}"),
@@ -350,7 +371,9 @@ impl fmt::Display for Instruction {
             Self::U32Test => write!(f, "u32test"),
             Self::U32TestW => write!(f, "u32testw"),
             Self::U32Assert => write!(f, "u32assert.1"),
+            Self::U32AssertWithError(err_code) => write!(f, "u32assert.1.err={err_code}"),
             Self::U32Assert2 => write!(f, "u32assert.2"),
+            Self::U32Assert2WithError(err_code) => write!(f, "u32assert.2.err={err_code}"),
             Self::U32AssertW => write!(f, "u32assertw"),
             Self::U32Split => write!(f, "u32split"),
             Self::U32Cast => write!(f, "u32cast"),
@@ -396,10 +419,14 @@ impl fmt::Display for Instruction {
             Self::U32CheckedShrImm(value) => write!(f, "u32checked_shr.{value}"),
             Self::U32UncheckedShr => write!(f, "u32unchecked_shr"),
             Self::U32UncheckedShrImm(value) => write!(f, "u32unchecked_shr.{value}"),
+            Self::U32OverflowingShr => write!(f, "u32overflowing_shr"),
+            Self::U32OverflowingShrImm(value) => write!(f, "u32overflowing_shr.{value}"),
             Self::U32CheckedShl => write!(f, "u32checked_shl"),
             Self::U32CheckedShlImm(value) => write!(f, "u32checked_shl.{value}"),
             Self::U32UncheckedShl => write!(f, "u32unchecked_shl"),
             Self::U32UncheckedShlImm(value) => write!(f, "u32unchecked_shl.{value}"),
+            Self::U32OverflowingShl => write!(f, "u32overflowing_shl"),
+            Self::U32OverflowingShlImm(value) => write!(f, "u32overflowing_shl.{value}"),
             Self::U32CheckedRotr => write!(f, "u32checked_rotr"),
             Self::U32CheckedRotrImm(value) => write!(f, "u32checked_rotr.{value}"),
             Self::U32UncheckedRotr => write!(f, "u32unchecked_rotr"),
@@ -566,9 +593,15 @@ impl fmt::Display for Instruction {
             }
             Self::CallImported(proc_id) => write!(f, "call.{proc_id}"),
             Self::SysCall(proc_id) => write!(f, "syscall.{proc_id}"),
+            Self::DynExec => write!(f, "dynexec"),
+            Self::DynCall => write!(f, "dyncall"),
+            Self::ProcRefLocal(index) => write!(f, "procref.{index}"),
+            Self::ProcRefImported(proc_id) => write!(f, "procref.{proc_id}"),
 
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => write!(f, "breakpoint"),
+            Self::Debug(options) => write!(f, "debug.{options}"),
+            Self::Trace(trace_id) => write!(f, "trace.{trace_id}"),
         }
     }
 }
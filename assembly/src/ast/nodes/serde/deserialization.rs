@@ -1,6 +1,7 @@
 use super::{
-    super::AdviceInjectorNode, ByteReader, CodeBody, Deserializable, DeserializationError, Felt,
-    Instruction, Node, OpCode, ProcedureId, RpoDigest, ToString, MAX_PUSH_INPUTS,
+    super::AdviceInjectorNode, ByteReader, CodeBody, DebugOptions, Deserializable,
+    DeserializationError, Felt, Instruction, Node, OpCode, ProcedureId, RpoDigest, ToString,
+    MAX_PUSH_INPUTS,
 };
 
 // NODE DESERIALIZATION
@@ -58,10 +59,19 @@ impl Deserializable for Instruction {
         let opcode = OpCode::read_from(source)?;
 
         match opcode {
-            OpCode::Assert => Ok(Instruction::Assert),
-            OpCode::AssertEq => Ok(Instruction::AssertEq),
+            OpCode::Assert => match read_err_code(source)? {
+                Some(err_code) => Ok(Instruction::AssertWithError(err_code)),
+                None => Ok(Instruction::Assert),
+            },
+            OpCode::AssertEq => match read_err_code(source)? {
+                Some(err_code) => Ok(Instruction::AssertEqWithError(err_code)),
+                None => Ok(Instruction::AssertEq),
+            },
             OpCode::AssertEqw => Ok(Instruction::AssertEqw),
-            OpCode::Assertz => Ok(Instruction::Assertz),
+            OpCode::Assertz => match read_err_code(source)? {
+                Some(err_code) => Ok(Instruction::AssertzWithError(err_code)),
+                None => Ok(Instruction::Assertz),
+            },
             OpCode::Add => Ok(Instruction::Add),
             OpCode::AddImm => Ok(Instruction::AddImm(Felt::read_from(source)?)),
             OpCode::Sub => Ok(Instruction::Sub),
@@ -103,8 +113,14 @@ impl Deserializable for Instruction {
             // ----- u32 manipulation -------------------------------------------------------------
             OpCode::U32Test => Ok(Instruction::U32Test),
             OpCode::U32TestW => Ok(Instruction::U32TestW),
-            OpCode::U32Assert => Ok(Instruction::U32Assert),
-            OpCode::U32Assert2 => Ok(Instruction::U32Assert2),
+            OpCode::U32Assert => match read_err_code(source)? {
+                Some(err_code) => Ok(Instruction::U32AssertWithError(err_code)),
+                None => Ok(Instruction::U32Assert),
+            },
+            OpCode::U32Assert2 => match read_err_code(source)? {
+                Some(err_code) => Ok(Instruction::U32Assert2WithError(err_code)),
+                None => Ok(Instruction::U32Assert2),
+            },
             OpCode::U32AssertW => Ok(Instruction::U32AssertW),
             OpCode::U32Split => Ok(Instruction::U32Split),
             OpCode::U32Cast => Ok(Instruction::U32Cast),
@@ -354,6 +370,47 @@ impl Deserializable for Instruction {
             OpCode::CallMastRoot => Ok(Instruction::CallMastRoot(RpoDigest::read_from(source)?)),
             OpCode::CallImported => Ok(Instruction::CallImported(ProcedureId::read_from(source)?)),
             OpCode::SysCall => Ok(Instruction::SysCall(ProcedureId::read_from(source)?)),
+            OpCode::DynExec => Ok(Instruction::DynExec),
+            OpCode::DynCall => Ok(Instruction::DynCall),
+
+            // ----- integer logarithm ------------------------------------------------------------
+            OpCode::ILog2 => Ok(Instruction::ILog2),
+
+            // ----- u32 overflowing shifts -------------------------------------------------------
+            OpCode::U32OverflowingShr => Ok(Instruction::U32OverflowingShr),
+            OpCode::U32OverflowingShrImm => {
+                Ok(Instruction::U32OverflowingShrImm(source.read_u8()?))
+            }
+            OpCode::U32OverflowingShl => Ok(Instruction::U32OverflowingShl),
+            OpCode::U32OverflowingShlImm => {
+                Ok(Instruction::U32OverflowingShlImm(source.read_u8()?))
+            }
+
+            // ----- debug decorators -------------------------------------------------------------
+            OpCode::Debug => {
+                let options = match source.read_u8()? {
+                    0 => DebugOptions::StackAll,
+                    1 => DebugOptions::StackTop(source.read_u16()?),
+                    2 => DebugOptions::MemAll,
+                    3 => DebugOptions::MemInterval(source.read_u32()?, source.read_u32()?),
+                    4 => return Ok(Instruction::Trace(source.read_u32()?)),
+                    _ => {
+                        return Err(DeserializationError::InvalidValue(
+                            "invalid debug options".to_string(),
+                        ))
+                    }
+                };
+                Ok(Instruction::Debug(options))
+            }
+
+            // ----- procedure references ---------------------------------------------------------
+            OpCode::ProcRef => match source.read_u8()? {
+                0 => Ok(Instruction::ProcRefLocal(source.read_u16()?)),
+                1 => Ok(Instruction::ProcRefImported(ProcedureId::read_from(source)?)),
+                _ => Err(DeserializationError::InvalidValue(
+                    "invalid procedure reference".to_string(),
+                )),
+            },
 
             // ----- control flow -----------------------------------------------------------------
             // control flow instructions should be parsed as a part of Node::read_from() and we
@@ -376,3 +433,12 @@ fn parse_num_push_params<R: ByteReader>(source: &mut R) -> Result<u8, Deserializ
         Ok(length as u8)
     }
 }
+
+/// Reads the flag which follows an assertion opcode, and the error code if the flag is set.
+fn read_err_code<R: ByteReader>(source: &mut R) -> Result<Option<Felt>, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(None),
+        1 => Ok(Some(Felt::read_from(source)?)),
+        _ => Err(DeserializationError::InvalidValue("invalid assertion error code".to_string())),
+    }
+}
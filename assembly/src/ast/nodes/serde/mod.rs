@@ -1,4 +1,4 @@
-use super::{CodeBody, Felt, Instruction, Node, ProcedureId, RpoDigest, ToString};
+use super::{CodeBody, DebugOptions, Felt, Instruction, Node, ProcedureId, RpoDigest, ToString};
 use crate::MAX_PUSH_INPUTS;
 use num_enum::TryFromPrimitive;
 
@@ -272,6 +272,23 @@ pub enum OpCode {
     CallMastRoot = 237,
     CallImported = 238,
     SysCall = 239,
+    DynExec = 240,
+    DynCall = 241,
+
+    // ----- integer logarithm --------------------------------------------------------------------
+    ILog2 = 242,
+
+    // ----- u32 overflowing shifts ---------------------------------------------------------------
+    U32OverflowingShr = 243,
+    U32OverflowingShrImm = 244,
+    U32OverflowingShl = 245,
+    U32OverflowingShlImm = 246,
+
+    // ----- debug decorators ---------------------------------------------------------------------
+    Debug = 247,
+
+    // ----- procedure references -----------------------------------------------------------------
+    ProcRef = 248,
 
     // ----- control flow -------------------------------------------------------------------------
     IfElse = 253,
@@ -1,4 +1,4 @@
-use super::{ByteWriter, Instruction, Node, OpCode, Serializable};
+use super::{ByteWriter, DebugOptions, Felt, Instruction, Node, OpCode, Serializable};
 use crate::ast::MAX_BODY_LEN;
 
 // NODE SERIALIZATION
@@ -52,10 +52,31 @@ impl Serializable for Node {
 impl Serializable for Instruction {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
-            Self::Assert => OpCode::Assert.write_into(target),
-            Self::AssertEq => OpCode::AssertEq.write_into(target),
+            Self::Assert => {
+                OpCode::Assert.write_into(target);
+                write_err_code(target, None);
+            }
+            Self::AssertWithError(err_code) => {
+                OpCode::Assert.write_into(target);
+                write_err_code(target, Some(err_code));
+            }
+            Self::AssertEq => {
+                OpCode::AssertEq.write_into(target);
+                write_err_code(target, None);
+            }
+            Self::AssertEqWithError(err_code) => {
+                OpCode::AssertEq.write_into(target);
+                write_err_code(target, Some(err_code));
+            }
             Self::AssertEqw => OpCode::AssertEqw.write_into(target),
-            Self::Assertz => OpCode::Assertz.write_into(target),
+            Self::Assertz => {
+                OpCode::Assertz.write_into(target);
+                write_err_code(target, None);
+            }
+            Self::AssertzWithError(err_code) => {
+                OpCode::Assertz.write_into(target);
+                write_err_code(target, Some(err_code));
+            }
             Self::Add => OpCode::Add.write_into(target),
             Self::AddImm(v) => {
                 OpCode::AddImm.write_into(target);
@@ -121,8 +142,22 @@ impl Serializable for Instruction {
             // ----- u32 operations ---------------------------------------------------------------
             Self::U32Test => OpCode::U32Test.write_into(target),
             Self::U32TestW => OpCode::U32TestW.write_into(target),
-            Self::U32Assert => OpCode::U32Assert.write_into(target),
-            Self::U32Assert2 => OpCode::U32Assert2.write_into(target),
+            Self::U32Assert => {
+                OpCode::U32Assert.write_into(target);
+                write_err_code(target, None);
+            }
+            Self::U32AssertWithError(err_code) => {
+                OpCode::U32Assert.write_into(target);
+                write_err_code(target, Some(err_code));
+            }
+            Self::U32Assert2 => {
+                OpCode::U32Assert2.write_into(target);
+                write_err_code(target, None);
+            }
+            Self::U32Assert2WithError(err_code) => {
+                OpCode::U32Assert2.write_into(target);
+                write_err_code(target, Some(err_code));
+            }
             Self::U32AssertW => OpCode::U32AssertW.write_into(target),
             Self::U32Split => OpCode::U32Split.write_into(target),
             Self::U32Cast => OpCode::U32Cast.write_into(target),
@@ -491,11 +526,75 @@ impl Serializable for Instruction {
                 OpCode::SysCall.write_into(target);
                 imported.write_into(target)
             }
+            Self::DynExec => OpCode::DynExec.write_into(target),
+            Self::DynCall => OpCode::DynCall.write_into(target),
+            Self::ProcRefLocal(v) => {
+                OpCode::ProcRef.write_into(target);
+                target.write_u8(0);
+                target.write_u16(*v);
+            }
+            Self::ProcRefImported(imported) => {
+                OpCode::ProcRef.write_into(target);
+                target.write_u8(1);
+                imported.write_into(target)
+            }
+
+            // ----- integer logarithm ------------------------------------------------------------
+            Self::ILog2 => OpCode::ILog2.write_into(target),
+
+            // ----- u32 overflowing shifts -------------------------------------------------------
+            Self::U32OverflowingShr => OpCode::U32OverflowingShr.write_into(target),
+            Self::U32OverflowingShrImm(v) => {
+                OpCode::U32OverflowingShrImm.write_into(target);
+                target.write_u8(*v);
+            }
+            Self::U32OverflowingShl => OpCode::U32OverflowingShl.write_into(target),
+            Self::U32OverflowingShlImm(v) => {
+                OpCode::U32OverflowingShlImm.write_into(target);
+                target.write_u8(*v);
+            }
 
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => {
                 // this is a transparent instruction and will not be encoded into the library
             }
+            Self::Debug(options) => {
+                OpCode::Debug.write_into(target);
+                match options {
+                    DebugOptions::StackAll => target.write_u8(0),
+                    DebugOptions::StackTop(n) => {
+                        target.write_u8(1);
+                        target.write_u16(*n);
+                    }
+                    DebugOptions::MemAll => target.write_u8(2),
+                    DebugOptions::MemInterval(addr, len) => {
+                        target.write_u8(3);
+                        target.write_u32(*addr);
+                        target.write_u32(*len);
+                    }
+                }
+            }
+            Self::Trace(trace_id) => {
+                // trace decorators share the opcode with other debug decorators
+                OpCode::Debug.write_into(target);
+                target.write_u8(4);
+                target.write_u32(*trace_id);
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a flag indicating whether an assertion carries an error code, followed by the error
+/// code if it does.
+fn write_err_code<W: ByteWriter>(target: &mut W, err_code: Option<&Felt>) {
+    match err_code {
+        Some(err_code) => {
+            target.write_u8(1);
+            err_code.write_into(target);
         }
+        None => target.write_u8(0),
     }
 }
@@ -25,6 +25,10 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushU64div),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_u256div" => match op.num_parts() {
+            2 => AdvInject(PushU256div),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_ext2intt" => match op.num_parts() {
             2 => AdvInject(PushExt2intt),
             _ => return Err(ParsingError::extra_param(op)),
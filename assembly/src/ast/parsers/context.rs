@@ -1,8 +1,10 @@
 use super::{
-    super::ProcReExport, adv_ops, field_ops, io_ops, stack_ops, u32_ops, CodeBody, Instruction,
-    InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap, Node, ParsingError, ProcedureAst,
-    ProcedureId, ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    super::ProcReExport, adv_ops, debug, field_ops, io_ops, parse_const_expr, stack_ops, u32_ops,
+    AmbiguousImportMap, CodeBody, Instruction, InvocationTarget, InvokedProcMap, LibraryPath,
+    LocalConstMap, LocalProcMap, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName,
+    ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN, STACK_UNSAFE_ANNOTATION,
 };
+use core::cell::RefCell;
 use vm_core::utils::{
     collections::{BTreeMap, Vec},
     string::{String, ToString},
@@ -14,9 +16,12 @@ use vm_core::utils::{
 /// AST Parser context that holds internal state to generate correct ASTs.
 pub struct ParserContext<'a> {
     pub imports: &'a BTreeMap<String, LibraryPath>,
+    pub ambiguous_imports: &'a AmbiguousImportMap,
     pub local_procs: LocalProcMap,
     pub reexported_procs: ReExportedProcMap,
     pub local_constants: LocalConstMap,
+    /// Names of the imported and kernel procedures invoked from the parsed code.
+    pub invoked_procs: RefCell<InvokedProcMap>,
 }
 
 impl ParserContext<'_> {
@@ -134,7 +139,8 @@ impl ParserContext<'_> {
         // record start of the repeat block and consume the 'repeat' token
         let repeat_start = tokens.pos();
         let repeat_token = tokens.read().expect("no repeat token");
-        let times = repeat_token.parse_repeat()?;
+        repeat_token.validate_repeat()?;
+        let times = parse_const_expr::<u32>(repeat_token, 1, &self.local_constants)?;
         tokens.advance();
 
         // read the loop body
@@ -200,12 +206,34 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Parse a `procref` token into an instruction node.
+    fn parse_procref(&self, token: &Token) -> Result<Node, ParsingError> {
+        match token.parse_invocation(token.parts()[0])? {
+            InvocationTarget::MastRoot(_) => Err(ParsingError::invalid_param_with_reason(
+                token,
+                1,
+                "procedure reference must be a procedure name",
+            )),
+            InvocationTarget::ProcedureName(proc_name) => {
+                let index = self.get_local_proc_index(proc_name, token)?;
+                let inner = Instruction::ProcRefLocal(index);
+                Ok(Node::Instruction(inner))
+            }
+            InvocationTarget::ProcedurePath { name, module } => {
+                let proc_id = self.get_imported_proc_id(name, module, token)?;
+                let inner = Instruction::ProcRefImported(proc_id);
+                Ok(Node::Instruction(inner))
+            }
+        }
+    }
+
     /// Parse `syscall` token into an instruction node.
     fn parse_syscall(&self, token: &Token) -> Result<Node, ParsingError> {
         match token.parse_invocation(token.parts()[0])? {
             InvocationTarget::MastRoot(_) => Err(ParsingError::syscall_with_mast_root(token)),
             InvocationTarget::ProcedureName(proc_name) => {
                 let proc_id = ProcedureId::from_kernel_name(proc_name);
+                self.record_invoked_proc(proc_id, proc_name, &LibraryPath::kernel_path());
                 let inner = Instruction::SysCall(proc_id);
                 Ok(Node::Instruction(inner))
             }
@@ -277,9 +305,13 @@ impl ParserContext<'_> {
         let start = *header.location();
         tokens.advance();
 
-        // attach doc comments (if any) to exported procedures
+        // doc comments of all procedures may carry the stack-unsafe annotation, but the comments
+        // are attached only to exported procedures
+        let docs = tokens.take_doc_comment_at(proc_start);
+        let is_stack_unsafe = docs
+            .as_ref()
+            .map_or(false, |docs| docs.lines().any(|line| line.trim() == STACK_UNSAFE_ANNOTATION));
         let docs = if is_export {
-            let docs = tokens.take_doc_comment_at(proc_start);
             // make sure procedure docs don't exceed the allowed limit
             if let Some(ref docs) = docs {
                 if docs.len() > MAX_DOCS_LEN {
@@ -314,6 +346,7 @@ impl ParserContext<'_> {
         // build and return the procedure
         let (nodes, locations) = body.into_parts();
         Ok(ProcedureAst::new(name, num_locals, nodes, is_export, docs)
+            .with_stack_unsafe(is_stack_unsafe)
             .with_source_locations(locations, start))
     }
 
@@ -338,16 +371,12 @@ impl ParserContext<'_> {
         }
 
         // check if the module from which the procedure is re-exported was imported
-        let module_path = self
-            .imports
-            .get(module)
-            .ok_or(ParsingError::procedure_module_not_imported(header, module))?;
+        let module_path = self.get_imported_module(module, header)?;
 
         // consume the `export` token
         tokens.advance();
 
-        let proc_id = ProcedureId::from_name(&ref_name, module_path);
-        Ok(ProcReExport::new(proc_id, proc_name))
+        Ok(ProcReExport::new(proc_name, module_path.clone(), ref_name))
     }
 
     // BODY PARSER
@@ -428,9 +457,9 @@ impl ParserContext<'_> {
         // based on the instruction, invoke the correct parser for the operation
         match op.parts()[0] {
             // ----- field operations -------------------------------------------------------------
-            "assert" => simple_instruction(op, Assert),
-            "assertz" => simple_instruction(op, Assertz),
-            "assert_eq" => simple_instruction(op, AssertEq),
+            "assert" => field_ops::parse_assert(op, &self.local_constants),
+            "assertz" => field_ops::parse_assertz(op, &self.local_constants),
+            "assert_eq" => field_ops::parse_assert_eq(op, &self.local_constants),
             "assert_eqw" => simple_instruction(op, AssertEqw),
 
             "add" => field_ops::parse_add(op),
@@ -441,6 +470,7 @@ impl ParserContext<'_> {
             "inv" => simple_instruction(op, Inv),
 
             "pow2" => simple_instruction(op, Pow2),
+            "ilog2" => simple_instruction(op, ILog2),
             "exp" => field_ops::parse_exp(op),
 
             "not" => simple_instruction(op, Not),
@@ -468,7 +498,7 @@ impl ParserContext<'_> {
             // ----- u32 operations ---------------------------------------------------------------
             "u32test" => simple_instruction(op, U32Test),
             "u32testw" => simple_instruction(op, U32TestW),
-            "u32assert" => u32_ops::parse_u32assert(op),
+            "u32assert" => u32_ops::parse_u32assert(op, &self.local_constants),
             "u32assertw" => simple_instruction(op, U32AssertW),
             "u32cast" => simple_instruction(op, U32Cast),
             "u32split" => simple_instruction(op, U32Split),
@@ -493,12 +523,15 @@ impl ParserContext<'_> {
 
             "u32checked_div" => u32_ops::parse_u32_div(op, true),
             "u32unchecked_div" => u32_ops::parse_u32_div(op, false),
+            "u32wrapping_div" => u32_ops::parse_u32_div(op, false),
 
             "u32checked_mod" => u32_ops::parse_u32_mod(op, true),
             "u32unchecked_mod" => u32_ops::parse_u32_mod(op, false),
+            "u32wrapping_mod" => u32_ops::parse_u32_mod(op, false),
 
             "u32checked_divmod" => u32_ops::parse_u32_divmod(op, true),
             "u32unchecked_divmod" => u32_ops::parse_u32_divmod(op, false),
+            "u32wrapping_divmod" => u32_ops::parse_u32_divmod(op, false),
 
             "u32checked_and" => simple_instruction(op, U32CheckedAnd),
             "u32checked_or" => simple_instruction(op, U32CheckedOr),
@@ -507,15 +540,21 @@ impl ParserContext<'_> {
 
             "u32checked_shr" => u32_ops::parse_u32_shr(op, true),
             "u32unchecked_shr" => u32_ops::parse_u32_shr(op, false),
+            "u32wrapping_shr" => u32_ops::parse_u32_shr(op, false),
+            "u32overflowing_shr" => u32_ops::parse_u32overflowing_shr(op),
 
             "u32checked_shl" => u32_ops::parse_u32_shl(op, true),
             "u32unchecked_shl" => u32_ops::parse_u32_shl(op, false),
+            "u32wrapping_shl" => u32_ops::parse_u32_shl(op, false),
+            "u32overflowing_shl" => u32_ops::parse_u32overflowing_shl(op),
 
             "u32checked_rotr" => u32_ops::parse_u32_rotr(op, true),
             "u32unchecked_rotr" => u32_ops::parse_u32_rotr(op, false),
+            "u32wrapping_rotr" => u32_ops::parse_u32_rotr(op, false),
 
             "u32checked_rotl" => u32_ops::parse_u32_rotl(op, true),
             "u32unchecked_rotl" => u32_ops::parse_u32_rotl(op, false),
+            "u32wrapping_rotl" => u32_ops::parse_u32_rotl(op, false),
 
             "u32checked_popcnt" => simple_instruction(op, U32CheckedPopcnt),
             "u32unchecked_popcnt" => simple_instruction(op, U32UncheckedPopcnt),
@@ -604,12 +643,17 @@ impl ParserContext<'_> {
             "exec" => self.parse_exec(op),
             "call" => self.parse_call(op),
             "syscall" => self.parse_syscall(op),
+            "procref" => self.parse_procref(op),
+            "dynexec" => simple_instruction(op, DynExec),
+            "dyncall" => simple_instruction(op, DynCall),
 
             // ----- constant statements ----------------------------------------------------------
             "const" => Err(ParsingError::const_invalid_scope(op)),
 
             // ----- debug decorators -------------------------------------------------------------
             "breakpoint" => simple_instruction(op, Breakpoint),
+            "debug" => debug::parse_debug(op),
+            "trace" => debug::parse_trace(op, &self.local_constants),
 
             // ----- catch all --------------------------------------------------------------------
             _ => Err(ParsingError::invalid_op(op)),
@@ -631,21 +675,54 @@ impl ParserContext<'_> {
     ///
     /// # Errors
     /// Return an error if the module with the specified name has not been imported via the `use`
-    /// statement.
+    /// statement, or if the name refers to more than one imported module.
     fn get_imported_proc_id(
         &self,
         proc_name: &str,
         module_name: &str,
         token: &Token,
     ) -> Result<ProcedureId, ParsingError> {
-        let module_path = self
-            .imports
-            .get(module_name)
-            .ok_or_else(|| ParsingError::procedure_module_not_imported(token, module_name))?;
+        let module_path = self.get_imported_module(module_name, token)?;
         let proc_id = ProcedureId::from_name(proc_name, module_path);
+        self.record_invoked_proc(proc_id, proc_name, module_path);
         Ok(proc_id)
     }
 
+    /// Records the name of the procedure with the specified ID so that the invocation can be
+    /// rendered by name later on.
+    ///
+    /// Names which are not valid procedure names are not recorded; invocations of such procedures
+    /// are reported by the assembler.
+    fn record_invoked_proc(
+        &self,
+        proc_id: ProcedureId,
+        proc_name: &str,
+        module_path: &LibraryPath,
+    ) {
+        if let Ok(name) = ProcedureName::try_from(proc_name.to_string()) {
+            self.invoked_procs.borrow_mut().insert(proc_id, (name, module_path.clone()));
+        }
+    }
+
+    /// Returns the path of the module imported under the specified name (i.e., either the alias
+    /// of the module or the last component of its path).
+    ///
+    /// # Errors
+    /// Return an error if the module with the specified name has not been imported via the `use`
+    /// statement, or if more than one module has been imported under this name.
+    fn get_imported_module(
+        &self,
+        module_name: &str,
+        token: &Token,
+    ) -> Result<&LibraryPath, ParsingError> {
+        if let Some(candidates) = self.ambiguous_imports.get(module_name) {
+            return Err(ParsingError::ambiguous_module_reference(token, module_name, candidates));
+        }
+        self.imports
+            .get(module_name)
+            .ok_or_else(|| ParsingError::procedure_module_not_imported(token, module_name))
+    }
+
     /// Returns true if a procedure with the specified name is present in the set of local or
     /// re-exported procedures.
     fn contains_proc_name(&self, proc_name: &str) -> bool {
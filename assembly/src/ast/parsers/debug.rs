@@ -0,0 +1,67 @@
+use super::{
+    parse_checked_param, parse_param_with_constant_lookup, Instruction, LocalConstMap, Node,
+    ParsingError, Token,
+};
+use vm_core::DebugOptions;
+
+// INSTRUCTION PARSERS
+// ================================================================================================
+
+/// Returns `Debug` instruction node with the debug options specified by the token.
+///
+/// The following forms are supported:
+/// - `debug.stack` reports the entire operand stack.
+/// - `debug.stack.<n>` reports the top `n` items of the operand stack.
+/// - `debug.mem` reports all initialized memory of the current context.
+/// - `debug.mem.<addr>.<len>` reports `len` memory addresses starting at `addr`.
+///
+/// # Errors
+/// Returns an error if the token specifies an unknown debug option, or if the number of
+/// parameters or the parameter values are invalid.
+pub fn parse_debug(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "debug");
+    if op.num_parts() < 2 {
+        return Err(ParsingError::missing_param(op));
+    }
+
+    let options = match op.parts()[1] {
+        "stack" => match op.num_parts() {
+            2 => DebugOptions::StackAll,
+            3 => DebugOptions::StackTop(parse_checked_param(op, 2, 1..=u16::MAX)?),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "mem" => match op.num_parts() {
+            2 => DebugOptions::MemAll,
+            3 => return Err(ParsingError::missing_param(op)),
+            4 => {
+                let addr = parse_checked_param(op, 2, 0..=u32::MAX)?;
+                let len = parse_checked_param(op, 3, 1..=u32::MAX)?;
+                DebugOptions::MemInterval(addr, len)
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        _ => return Err(ParsingError::invalid_op(op)),
+    };
+
+    Ok(Node::Instruction(Instruction::Debug(options)))
+}
+
+/// Returns `Trace` instruction node with the trace event identifier specified by the token.
+///
+/// The identifier can be either a u32 value or a name of a constant.
+///
+/// # Errors
+/// Returns an error if the token contains a wrong number of parameters, or if the identifier is
+/// not a valid u32 value.
+pub fn parse_trace(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "trace");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op)),
+        2 => {
+            let trace_id = parse_param_with_constant_lookup::<u32>(op, 1, constants)?;
+            Ok(Node::Instruction(Instruction::Trace(trace_id)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
-    check_div_by_zero, parse_checked_param,
+    check_div_by_zero, parse_checked_param, parse_error_code,
     Instruction::*,
+    LocalConstMap,
     Node::{self, Instruction},
     ParsingError, Token,
 };
@@ -9,6 +10,60 @@ use vm_core::{Felt, StarkField, ONE};
 // INSTRUCTION PARSERS
 // ================================================================================================
 
+/// Returns `Assert` instruction node if no error code is provided or `AssertWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has an invalid error code or more than one param.
+pub fn parse_assert(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assert");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Assert)),
+        2 => {
+            let err_code = parse_error_code(op, 1, constants)?;
+            Ok(Instruction(AssertWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Assertz` instruction node if no error code is provided or `AssertzWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has an invalid error code or more than one param.
+pub fn parse_assertz(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assertz");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Assertz)),
+        2 => {
+            let err_code = parse_error_code(op, 1, constants)?;
+            Ok(Instruction(AssertzWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `AssertEq` instruction node if no error code is provided or `AssertEqWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has an invalid error code or more than one param.
+pub fn parse_assert_eq(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assert_eq");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(AssertEq)),
+        2 => {
+            let err_code = parse_error_code(op, 1, constants)?;
+            Ok(Instruction(AssertEqWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `Add` instruction node if no immediate value is provided or `AddImm` instruction
 /// node otherwise.
 ///
@@ -286,12 +286,23 @@ fn parse_non_hex_param_with_constants_lookup<R: RangeBounds<u64>>(
 ///
 /// # Errors
 /// Returns an error if:
+/// - The hex string contains non-hexadecimal characters.
 /// - The length of hex string is not even.
 /// - The length of hex string is not divisible by 16.
 /// - If the string does not contain a valid hexadecimal value.
-/// - If the parsed value is greater than or equal to the field modulus.
+/// - If any of the parsed values is greater than or equal to the field modulus; the error
+///   specifies the index of the offending value (limb).
 fn parse_long_hex_param(op: &Token, param_str: &str) -> Result<Node, ParsingError> {
-    // handle error cases where the hex string is poorly formed
+    // handle error cases where the hex string is poorly formed; non-hex characters are rejected
+    // first, as a multi-byte character would not fall on a chunk boundary
+    if !param_str.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParsingError::invalid_param_with_reason(
+            op,
+            1,
+            &format!("hex string '{param_str}' contains non-hexadecimal characters"),
+        ));
+    }
+
     if param_str.len() % HEX_CHUNK_SIZE != 0 {
         // hex string doesn't contain a valid number of bytes
         return Err(ParsingError::invalid_param_with_reason(
@@ -303,10 +314,22 @@ fn parse_long_hex_param(op: &Token, param_str: &str) -> Result<Node, ParsingErro
         ));
     }
 
-    // iterate over the multi-value hex string and parse each 8-byte chunk into a valid u64
-    let values = (0..param_str.len())
-        .step_by(HEX_CHUNK_SIZE)
-        .map(|i| parse_hex_value(op, &param_str[i..i + HEX_CHUNK_SIZE], 1));
+    // iterate over the multi-value hex string and parse each 8-byte chunk into a valid u64; the
+    // chunks are pushed in order, and thus, the first chunk ends up deepest in the stack (i.e.,
+    // for a 4-chunk string it becomes the first element of the word saved by `mem_storew`)
+    let values = (0..param_str.len()).step_by(HEX_CHUNK_SIZE).enumerate().map(|(limb_idx, i)| {
+        let limb_str = &param_str[i..i + HEX_CHUNK_SIZE];
+        match u64::from_str_radix(limb_str, 16) {
+            Ok(value) if value >= Felt::MODULUS => Err(ParsingError::invalid_param_with_reason(
+                op,
+                1,
+                &format!(
+                    "limb {limb_idx} of hex string ('{limb_str}') contains value greater than field modulus"
+                ),
+            )),
+            _ => parse_hex_value(op, limb_str, 1),
+        }
+    });
 
     build_push_many_instruction(values)
 }
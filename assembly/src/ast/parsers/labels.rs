@@ -78,6 +78,10 @@ pub fn decode_hex_rpo_digest_label(s: &str) -> Result<RpoDigest, LabelError> {
     debug_assert!(s.starts_with("0x"), "hex label must start with 0x");
     if s.len() != 66 {
         Err(LabelError::rpo_digest_hex_label_incorrect_length(s.len()))
+    } else if !s.bytes().skip(2).all(|b| b.is_ascii_hexdigit()) {
+        // checked before splitting the string into pairs of bytes, as a multi-byte character
+        // would not fall on a pair boundary
+        Err(LabelError::InvalidHexCharacters(s.to_string()))
     } else {
         let data: Vec<u8> = (2..s.len())
             .step_by(2)
@@ -1,13 +1,15 @@
 use super::{
-    bound_into_included_u64, AdviceInjectorNode, BTreeMap, CodeBody, Deserializable, Felt,
-    Instruction, InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, Node,
-    ParsingError, ProcedureAst, ProcedureId, ReExportedProcMap, RpoDigest, SliceReader, StarkField,
-    String, ToString, Token, TokenStream, Vec, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_IMPORTS,
-    MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
+    bound_into_included_u64, AdviceInjectorNode, AmbiguousImportMap, BTreeMap, BTreeSet, CodeBody,
+    Deserializable, Felt, Instruction, InvocationTarget, InvokedProcMap, LabelError, LibraryPath,
+    LocalConstMap, LocalProcMap, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName,
+    ReExportedProcMap, RpoDigest, SliceReader, StarkField, String, ToString, Token, TokenStream,
+    Vec, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_IMPORTS, MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
+    STACK_UNSAFE_ANNOTATION,
 };
 use core::{fmt::Display, ops::RangeBounds};
 
 pub mod adv_ops;
+pub mod debug;
 pub mod field_ops;
 pub mod io_ops;
 pub mod stack_ops;
@@ -27,21 +29,60 @@ pub use labels::{
 
 /// Parses all `use` statements into a map of imports which maps a module name (e.g., "u64") to
 /// its fully-qualified path (e.g., "std::math::u64").
+///
+/// A module is imported under its alias if one is specified (e.g., `use.std::math::u64->stdu64`),
+/// and under the last component of its path otherwise. Modules imported without an alias whose
+/// names collide are not added to the map of imports; instead, they are returned in a separate
+/// map, so that references to such modules can be reported as ambiguous.
+///
+/// # Errors
+/// Returns an error if:
+/// - The same module is imported more than once.
+/// - The same alias is specified for more than one module.
+/// - An alias is the same as the name of a module imported without an alias.
 pub fn parse_imports(
     tokens: &mut TokenStream,
-) -> Result<BTreeMap<String, LibraryPath>, ParsingError> {
+) -> Result<(BTreeMap<String, LibraryPath>, AmbiguousImportMap), ParsingError> {
     let mut imports = BTreeMap::<String, LibraryPath>::new();
+    let mut ambiguous_imports = AmbiguousImportMap::new();
+    let mut aliases = BTreeSet::<String>::new();
     // read tokens from the token stream until all `use` tokens are consumed
     while let Some(token) = tokens.read() {
         match token.parts()[0] {
             Token::USE => {
-                let module_path = token.parse_use()?;
-                let module_name = module_path.last();
-                if imports.contains_key(module_name) {
+                let (module_path, alias) = token.parse_use()?;
+                let is_imported = imports.values().any(|path| path == &module_path)
+                    || ambiguous_imports.values().flatten().any(|path| path == &module_path);
+                if is_imported {
                     return Err(ParsingError::duplicate_module_import(token, &module_path));
                 }
 
-                imports.insert(module_name.to_string(), module_path);
+                match alias {
+                    Some(alias) => {
+                        if aliases.contains(alias) {
+                            return Err(ParsingError::duplicate_module_alias(token, alias));
+                        }
+                        if imports.contains_key(alias) || ambiguous_imports.contains_key(alias) {
+                            return Err(ParsingError::conflicting_module_alias(token, alias));
+                        }
+                        aliases.insert(alias.to_string());
+                        imports.insert(alias.to_string(), module_path);
+                    }
+                    None => {
+                        let module_name = module_path.last();
+                        if aliases.contains(module_name) {
+                            return Err(ParsingError::conflicting_module_alias(token, module_name));
+                        }
+                        if let Some(candidates) = ambiguous_imports.get_mut(module_name) {
+                            candidates.push(module_path);
+                        } else if let Some(prev_path) = imports.remove(module_name) {
+                            let module_name = module_name.to_string();
+                            ambiguous_imports.insert(module_name, vec![prev_path, module_path]);
+                        } else {
+                            imports.insert(module_name.to_string(), module_path);
+                        }
+                    }
+                }
 
                 // consume the `use` token
                 tokens.advance();
@@ -50,10 +91,11 @@ pub fn parse_imports(
         }
     }
 
-    if imports.len() > MAX_IMPORTS {
-        return Err(ParsingError::too_many_imports(imports.len(), MAX_IMPORTS));
+    let num_imports = imports.len() + ambiguous_imports.values().map(Vec::len).sum::<usize>();
+    if num_imports > MAX_IMPORTS {
+        return Err(ParsingError::too_many_imports(num_imports, MAX_IMPORTS));
     }
-    Ok(imports)
+    Ok((imports, ambiguous_imports))
 }
 
 /// Parses all `const` statements into a map which maps a const name to a value
@@ -147,6 +189,72 @@ where
     }
 }
 
+/// Parses a param from the op token which may be a constant expression of the form
+/// `TERM*TERM*...`, where each term is either an integer literal or a constant label which will
+/// be looked up in the provided constant map. The result must fit into the specified type.
+fn parse_const_expr<R>(
+    op: &Token,
+    param_idx: usize,
+    constants: &LocalConstMap,
+) -> Result<R, ParsingError>
+where
+    R: TryFrom<u64>,
+{
+    let mut result = 1_u64;
+    for term in op.parts()[param_idx].split('*') {
+        let value = match CONSTANT_LABEL_PARSER.parse_label(term) {
+            Ok(_) => {
+                constants.get(term).cloned().ok_or_else(|| ParsingError::const_not_found(op))?
+            }
+            Err(_) => {
+                term.parse::<u64>().map_err(|_| ParsingError::invalid_param(op, param_idx))?
+            }
+        };
+        result = result.checked_mul(value).ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(op, param_idx, "constant expression overflow")
+        })?;
+    }
+
+    result
+        .try_into()
+        .map_err(|_| ParsingError::const_conversion_failed(op, core::any::type_name::<R>()))
+}
+
+/// Parses an error code from the op token param of the form `err=CODE`, where `CODE` is either
+/// an integer literal or a constant label which will be looked up in the provided constant map.
+/// The error code must be a valid field element.
+fn parse_error_code(
+    op: &Token,
+    param_idx: usize,
+    constants: &LocalConstMap,
+) -> Result<Felt, ParsingError> {
+    let err_code = op.parts()[param_idx]
+        .strip_prefix("err=")
+        .ok_or_else(|| ParsingError::invalid_param(op, param_idx))?;
+
+    let value = match CONSTANT_LABEL_PARSER.parse_label(err_code) {
+        Ok(_) => constants
+            .get(err_code)
+            .cloned()
+            .ok_or_else(|| ParsingError::const_not_found(op))?,
+        Err(_) => {
+            let value = err_code
+                .parse::<u64>()
+                .map_err(|_| ParsingError::invalid_param(op, param_idx))?;
+            if value >= Felt::MODULUS {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    param_idx,
+                    "error code must be a valid field element",
+                ));
+            }
+            value
+        }
+    };
+
+    Ok(Felt::new(value))
+}
+
 /// Parses a param from the op token with the specified type.
 fn parse_param<I: core::str::FromStr>(op: &Token, param_idx: usize) -> Result<I, ParsingError> {
     let param_value = op.parts()[param_idx];
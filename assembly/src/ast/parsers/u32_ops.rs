@@ -1,6 +1,7 @@
 use super::{
-    check_div_by_zero, parse_checked_param, parse_param,
+    check_div_by_zero, parse_checked_param, parse_error_code, parse_param,
     Instruction::*,
+    LocalConstMap,
     Node::{self, Instruction},
     ParsingError, Token,
 };
@@ -12,10 +13,13 @@ use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
 /// Returns `U32Assert` instruction node if no immediate value is provided or the immediate value
 /// is 1. Returns instruction `U32Assert2` if immediate value is equal 2.
 ///
+/// If an error code is specified as the last parameter (e.g., `u32assert.2.err=123`),
+/// `U32AssertWithError` or `U32Assert2WithError` instruction node is returned instead.
+///
 /// # Errors
-/// Returns an error if the instruction token contains wrong number of parameters, or if the
-/// provided parameter is not 1 or 2.
-pub fn parse_u32assert(op: &Token) -> Result<Node, ParsingError> {
+/// Returns an error if the instruction token contains wrong number of parameters, if the
+/// provided parameter is not 1 or 2, or if the error code is invalid.
+pub fn parse_u32assert(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "u32assert");
     match op.num_parts() {
         0 => unreachable!(),
@@ -23,8 +27,19 @@ pub fn parse_u32assert(op: &Token) -> Result<Node, ParsingError> {
         2 => match op.parts()[1] {
             "1" => Ok(Instruction(U32Assert)),
             "2" => Ok(Instruction(U32Assert2)),
-            _ => Err(ParsingError::invalid_param(op, 1)),
+            _ => {
+                let err_code = parse_error_code(op, 1, constants)?;
+                Ok(Instruction(U32AssertWithError(err_code)))
+            }
         },
+        3 => {
+            let err_code = parse_error_code(op, 2, constants)?;
+            match op.parts()[1] {
+                "1" => Ok(Instruction(U32AssertWithError(err_code))),
+                "2" => Ok(Instruction(U32Assert2WithError(err_code))),
+                _ => Err(ParsingError::invalid_param(op, 1)),
+            }
+        }
         _ => Err(ParsingError::extra_param(op)),
     }
 }
@@ -327,6 +342,25 @@ pub fn parse_u32_shr(op: &Token, checked: bool) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32OverflowingShr` instruction node if no immediate value is provided or
+/// `U32OverflowingShrImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is greater than 31.
+pub fn parse_u32overflowing_shr(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32overflowing_shr");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32OverflowingShr)),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32OverflowingShrImm(n)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of four possible instructions:
 /// - checked without parameter: `U32CheckedShl`
 /// - unchecked without parameter: `U32UncheckedShl`
@@ -358,6 +392,25 @@ pub fn parse_u32_shl(op: &Token, checked: bool) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32OverflowingShl` instruction node if no immediate value is provided or
+/// `U32OverflowingShlImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is greater than 31.
+pub fn parse_u32overflowing_shl(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32overflowing_shl");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32OverflowingShl)),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32OverflowingShlImm(n)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of four possible instructions:
 /// - checked without parameter: `U32CheckedRotr`
 /// - unchecked without parameter: `U32UncheckedRotr`
@@ -1,6 +1,7 @@
 use super::{
-    AstSerdeOptions, BTreeMap, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node,
-    ParsingError, ProcedureAst, ProcedureId, ProgramAst, SourceLocation, Token,
+    masm_fmt, masm_fmt_with_options, AstSerdeOptions, BTreeMap, CodeBody, Felt, FormatOptions,
+    Instruction, LibraryPath, LocalProcMap, ModuleAst, Node, ParsingError, ProcedureAst,
+    ProcedureId, ProgramAst, SourceLocation, Token,
 };
 use vm_core::utils::SliceReader;
 
@@ -85,6 +86,47 @@ fn test_ast_parsing_program_u32() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_program_assert_with_error() {
+    let source = "\
+    const.ERR1=77
+
+    begin
+        assert
+        assert.err=4096
+        assertz.err=ERR1
+        assert_eq.err=3
+        u32assert.err=ERR1
+        u32assert.1.err=5
+        u32assert.2.err=6
+        u32assert.2
+    end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::Assert),
+        Node::Instruction(Instruction::AssertWithError(Felt::new(4096))),
+        Node::Instruction(Instruction::AssertzWithError(Felt::new(77))),
+        Node::Instruction(Instruction::AssertEqWithError(Felt::new(3))),
+        Node::Instruction(Instruction::U32AssertWithError(Felt::new(77))),
+        Node::Instruction(Instruction::U32AssertWithError(Felt::new(5))),
+        Node::Instruction(Instruction::U32Assert2WithError(Felt::new(6))),
+        Node::Instruction(Instruction::U32Assert2),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // error codes must be valid field elements
+    let source = "begin assert.err=18446744069414584321 end";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // error codes must be specified via `err=`
+    let source = "begin assert.4096 end";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // constants used as error codes must be defined
+    let source = "begin u32assert.2.err=ERR1 end";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
 #[test]
 fn test_ast_parsing_program_proc() {
     let source = "\
@@ -196,9 +238,11 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source =
+        "begin adv.push_u64div adv.push_u256div adv.push_mapval adv.push_smtget adv.insert_mem end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64div)),
+        Node::Instruction(AdvInject(PushU256div)),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
         Node::Instruction(AdvInject(InsertMem)),
@@ -207,6 +251,58 @@ fn test_ast_parsing_adv_injection() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_debug() {
+    use vm_core::DebugOptions::*;
+    use Instruction::Debug;
+
+    let source = "begin debug.stack debug.stack.4 debug.mem debug.mem.100.3 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Debug(StackAll)),
+        Node::Instruction(Debug(StackTop(4))),
+        Node::Instruction(Debug(MemAll)),
+        Node::Instruction(Debug(MemInterval(100, 3))),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    for source in [
+        "begin debug end",
+        "begin debug.stack.0 end",
+        "begin debug.stack.1.2 end",
+        "begin debug.mem.100 end",
+        "begin debug.mem.100.0 end",
+        "begin debug.locals end",
+    ] {
+        ProgramAst::parse(source).expect_err(source);
+    }
+}
+
+#[test]
+fn test_ast_parsing_trace() {
+    let source = "\
+    const.VERIFY_START=4294967295
+    begin
+        trace.0 trace.VERIFY_START
+    end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::Trace(0)),
+        Node::Instruction(Instruction::Trace(u32::MAX)),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    for source in [
+        "begin trace end",
+        "begin trace.4294967296 end",
+        "begin trace.1.2 end",
+        "begin trace.UNKNOWN end",
+        "const.TOO_BIG=4294967296 begin trace.TOO_BIG end",
+    ] {
+        ProgramAst::parse(source).expect_err(source);
+    }
+}
+
 #[test]
 fn test_ast_parsing_use() {
     let source = "\
@@ -221,6 +317,53 @@ fn test_ast_parsing_use() {
     assert_program_output(source, procedures, nodes);
 }
 
+#[test]
+fn test_ast_parsing_procref() {
+    let source = "\
+    use.std::abc::foo
+    proc.bar
+        push.1
+    end
+    begin
+        procref.bar
+        procref.foo::baz
+    end";
+    let mut procedures: LocalProcMap = BTreeMap::new();
+    procedures.insert(
+        String::from("bar"),
+        (
+            0,
+            ProcedureAst::new(
+                String::from("bar").try_into().unwrap(),
+                0,
+                vec![Node::Instruction(Instruction::PushU8(1))],
+                false,
+                None,
+            )
+            .with_source_locations(
+                [SourceLocation::new(3, 9), SourceLocation::new(4, 5)],
+                SourceLocation::new(2, 5),
+            ),
+        ),
+    );
+    let proc_id = ProcedureId::new("std::abc::foo::baz");
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::ProcRefLocal(0)),
+        Node::Instruction(Instruction::ProcRefImported(proc_id)),
+    ];
+    assert_program_output(source, procedures, nodes);
+
+    for source in [
+        "begin procref end",
+        "begin procref.foo end",
+        "begin procref.foo::bar end",
+        "begin procref.0x0000000000000000000000000000000000000000000000000000000000000000 end",
+        "proc.foo push.1 end begin procref.foo.1 end",
+    ] {
+        ProgramAst::parse(source).expect_err(source);
+    }
+}
+
 #[test]
 fn test_ast_parsing_module_nested_if() {
     let source = "\
@@ -456,6 +599,111 @@ fn test_missing_import() {
     }
 }
 
+#[test]
+fn test_ast_parsing_use_alias() {
+    let source = "\
+    use.std::math::u64->stdu64
+    use.mylib::math::u64
+    begin
+        exec.stdu64::checked_add
+        exec.u64::checked_add
+    end";
+
+    let program = ProgramAst::parse(source).unwrap();
+    let imports = program.imports();
+    assert_eq!(imports.len(), 2);
+    assert_eq!(imports["stdu64"].as_str(), "std::math::u64");
+    assert_eq!(imports["u64"].as_str(), "mylib::math::u64");
+
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::ExecImported(ProcedureId::new(
+            "std::math::u64::checked_add",
+        ))),
+        Node::Instruction(Instruction::ExecImported(ProcedureId::new(
+            "mylib::math::u64::checked_add",
+        ))),
+    ];
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // aliases are preserved when imports are serialized
+    assert_correct_program_serialization(source, true);
+}
+
+#[test]
+fn test_ast_parsing_use_alias_reexport() {
+    let source = "\
+    use.std::math::u64->stdu64
+
+    export.stdu64::checked_add";
+    let module = ModuleAst::parse(source).unwrap();
+    let reexports = module.reexported_procs();
+    assert_eq!(reexports.len(), 1);
+    assert_eq!(reexports[0].ref_path().as_str(), "std::math::u64");
+
+    assert_correct_module_serialization(source, true);
+}
+
+#[test]
+fn test_ast_parsing_use_alias_fail() {
+    // invalid alias
+    let source = "use.std::math::u64->std::u64\nbegin\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("use.std::math::u64->std::u64", SourceLocation::new(1, 1));
+    assert_eq!(err, ParsingError::invalid_module_alias(&token, "std::u64"));
+
+    // duplicate alias
+    let source = "use.std::math::u64->u64\nuse.mylib::math::u64->u64\nbegin\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("use.mylib::math::u64->u64", SourceLocation::new(2, 1));
+    assert_eq!(err, ParsingError::duplicate_module_alias(&token, "u64"));
+
+    // alias colliding with the name of a module imported without an alias
+    let source = "use.std::math::u64\nuse.mylib::math::u128->u64\nbegin\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("use.mylib::math::u128->u64", SourceLocation::new(2, 1));
+    assert_eq!(err, ParsingError::conflicting_module_alias(&token, "u64"));
+
+    // module imported without an alias colliding with an alias
+    let source = "use.mylib::math::u128->u64\nuse.std::math::u64\nbegin\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("use.std::math::u64", SourceLocation::new(2, 1));
+    assert_eq!(err, ParsingError::conflicting_module_alias(&token, "u64"));
+
+    // the same module imported twice
+    let source = "use.std::math::u64\nuse.std::math::u64->stdu64\nbegin\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("use.std::math::u64->stdu64", SourceLocation::new(2, 1));
+    let path = LibraryPath::new("std::math::u64").unwrap();
+    assert_eq!(err, ParsingError::duplicate_module_import(&token, &path));
+}
+
+#[test]
+fn test_ast_parsing_ambiguous_import() {
+    // colliding modules can be imported as long as they are not referenced by name
+    let source = "\
+    use.std::math::u64
+    use.mylib::math::u64
+    begin
+        push.1
+    end";
+    assert!(ProgramAst::parse(source).is_ok());
+
+    let source = "\
+    use.std::math::u64
+    use.mylib::math::u64
+    begin
+        exec.u64::checked_add
+    end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let token = Token::new("exec.u64::checked_add", SourceLocation::new(4, 9));
+    let candidates = [
+        LibraryPath::new("std::math::u64").unwrap(),
+        LibraryPath::new("mylib::math::u64").unwrap(),
+    ];
+    assert_eq!(err, ParsingError::ambiguous_module_reference(&token, "u64", &candidates));
+    assert!(err.message().contains("std::math::u64, mylib::math::u64"));
+}
+
 // INVALID BODY TESTS
 // ================================================================================================
 
@@ -730,6 +978,48 @@ fn test_ast_program_serde_simple() {
     assert_correct_program_serialization(source, false);
 }
 
+#[test]
+fn test_ast_program_serde_assert_with_error() {
+    let source = "\
+    begin
+        assert.err=1 assertz.err=2 assert_eq.err=3 u32assert.err=4 u32assert.2.err=5
+        assert assertz assert_eq u32assert u32assert.2
+    end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_pow2_ilog2() {
+    let source = "begin push.5 pow2 ilog2 pow2 end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_debug() {
+    let source = "begin debug.stack push.1 debug.stack.4 debug.mem debug.mem.100.3 end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_trace() {
+    let source = "begin trace.0 push.1 trace.4294967295 end";
+    assert_correct_program_serialization(source, false);
+}
+
+#[test]
+fn test_ast_program_serde_procref() {
+    let source = "\
+    use.std::math::u64
+    proc.foo
+        push.1
+    end
+    begin
+        procref.foo
+        procref.u64::checked_add
+    end";
+    assert_correct_program_serialization(source, true);
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
@@ -758,6 +1048,26 @@ fn test_ast_program_serde_exported_procs() {
     assert_correct_module_serialization(source, false);
 }
 
+#[test]
+fn test_ast_module_serde_stack_unsafe_procs() {
+    let source = "\
+    #! stack-unsafe
+    proc.foo
+        if.true
+            drop
+        end
+    end
+    #! Drops a value if the top of the stack is 1.
+    #!
+    #! stack-unsafe
+    export.bar
+        exec.foo
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    assert!(module.local_procs.iter().all(|proc| proc.is_stack_unsafe));
+    assert_correct_module_serialization(source, false);
+}
+
 #[test]
 fn test_ast_program_serde_control_flow() {
     let source = "\
@@ -917,6 +1227,143 @@ fn test_ast_module_serde_imports_not_serialized() {
     assert_correct_module_serialization(source, false);
 }
 
+#[test]
+fn test_ast_module_reexports() {
+    let source = "\
+    use.std::math::u64
+
+    export.u64::checked_add
+    export.u64::wrapping_mul->mul";
+    let module = ModuleAst::parse(source).unwrap();
+    let reexports = module.reexported_procs();
+    assert_eq!(reexports.len(), 2);
+
+    let path = LibraryPath::try_from("std::math::u64".to_string()).unwrap();
+    assert_eq!(reexports[0].name().as_str(), "checked_add");
+    assert_eq!(reexports[0].ref_path(), &path);
+    assert_eq!(reexports[0].ref_name().as_str(), "checked_add");
+    assert!(!reexports[0].is_alias());
+
+    assert_eq!(reexports[1].name().as_str(), "mul");
+    assert_eq!(reexports[1].ref_path(), &path);
+    assert_eq!(reexports[1].ref_name().as_str(), "wrapping_mul");
+    assert!(reexports[1].is_alias());
+
+    assert_correct_module_serialization(source, true);
+}
+
+// FORMATTING TESTS
+// ================================================================================================
+
+#[test]
+fn test_ast_formatting_module() {
+    let source = "\
+    #! Module docs
+
+    use.std::math::u64
+    use.std::crypto::hashes::rpo->hash
+
+    export.u64::checked_add
+    export.hash::hash_2to1->merge
+
+    # comments are not a part of the AST
+    #! stack-unsafe
+    proc.helper.2 push.4294967296 push.1 add
+        if.true push.0x10 else drop end
+    end
+
+    #! Adds two numbers.
+    #! stack-unsafe
+    export.foo
+        repeat.2 exec.helper end
+        while.true call.u64::wrapping_mul procref.helper end
+        exec.hash::hash
+    end";
+    let expected = "\
+#! Module docs
+
+use.std::crypto::hashes::rpo->hash
+use.std::math::u64
+
+export.u64::checked_add
+export.hash::hash_2to1->merge
+
+#! stack-unsafe
+proc.helper.2
+  push.0x0000000100000000
+  push.1
+  add
+  if.true
+    push.16
+  else
+    drop
+  end
+end
+
+#! Adds two numbers.
+#! stack-unsafe
+export.foo
+  repeat.2
+    exec.helper
+  end
+  while.true
+    call.u64::wrapping_mul
+    procref.helper
+  end
+  exec.hash::hash
+end
+";
+    let module = ModuleAst::parse(source).unwrap();
+    assert_eq!(masm_fmt(&module), expected);
+    assert_correct_formatting(source, FormatOptions::default());
+}
+
+#[test]
+fn test_ast_formatting_ops_per_line() {
+    let source = "\
+    proc.foo push.1 push.2 add push.3 mul if.true drop end swap end
+    export.bar exec.foo end";
+    let expected = "\
+proc.foo
+  push.1 push.2 add
+  push.3 mul
+  if.true
+    drop
+  end
+  swap.1
+end
+
+export.bar
+  exec.foo
+end
+";
+    let module = ModuleAst::parse(source).unwrap();
+    assert_eq!(masm_fmt_with_options(&module, FormatOptions::new(3)), expected);
+    assert_correct_formatting(source, FormatOptions::new(3));
+
+    // zero instructions per line is treated as one instruction per line
+    assert_eq!(masm_fmt_with_options(&module, FormatOptions::new(0)), masm_fmt(&module));
+}
+
+#[test]
+fn test_ast_formatting_immediates() {
+    let source = "\
+    const.A=4294967295
+    const.B=4294967296
+
+    export.foo
+        push.A push.B push.0x0000000000000005 push.1.B.3.4 push.1.2.3 push.B.1
+        u32checked_add.5 loc_store.1 mem_storew adv_push.2 exp.u64
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let formatted = masm_fmt_with_options(&module, FormatOptions::new(6));
+    assert!(formatted.contains(
+        "push.4294967295 push.0x0000000100000000 push.5 push.1.0x0000000100000000.3.4 push.1.2.3"
+    ));
+    assert!(formatted.contains("push.0x0000000100000000.1\n"));
+    assert_correct_formatting(source, FormatOptions::default());
+}
+
 fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>) {
     let program = ProgramAst::parse(source).unwrap();
     assert_eq!(program.body.nodes(), body);
@@ -935,6 +1382,22 @@ fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Asserts that formatting the module parsed from the provided source and parsing the result
+/// yields the same module AST, and that formatting is idempotent.
+fn assert_correct_formatting(source: &str, options: FormatOptions) {
+    // nested code bodies retain their source locations, so the modules are compared after a
+    // serialization round-trip which drops all locations
+    let without_locations = |module: &ModuleAst| {
+        ModuleAst::from_bytes(&module.to_bytes(AstSerdeOptions::new(true))).unwrap()
+    };
+
+    let module = ModuleAst::parse(source).unwrap();
+    let formatted = masm_fmt_with_options(&module, options);
+    let reparsed = ModuleAst::parse(&formatted).unwrap();
+    assert_eq!(without_locations(&module), without_locations(&reparsed));
+    assert_eq!(formatted, masm_fmt_with_options(&reparsed, options));
+}
+
 /// Clears the proc locations.
 ///
 /// Currently, the locations are not part of the serialized libraries; thus, they have to be
@@ -961,12 +1424,13 @@ fn clear_procs_loc_program(mut program: ProgramAst) -> ProgramAst {
     program
 }
 
-/// Clears the module's imports.
+/// Clears the module's imports and the names of the procedures invoked from the module.
 ///
 /// Serialization of imports is optional, so if they are not serialized, then they have to be
 /// cleared before testing for equality
 fn clear_imports_module(module: &mut ModuleAst) {
     module.imports.clear();
+    module.invoked_procs.clear();
 }
 
 /// Clears the program's imports.
@@ -1037,6 +1501,7 @@ fn assert_correct_module_serialization(source: &str, serialize_imports: bool) {
         .unwrap();
     if !serialize_imports {
         module_deserialized.imports = module.imports.clone();
+        module_deserialized.invoked_procs = module.invoked_procs.clone();
     }
     assert_eq!(module, module_deserialized);
 }
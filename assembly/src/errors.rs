@@ -1,6 +1,6 @@
 use super::{
-    crypto::hash::RpoDigest, tokens::SourceLocation, LibraryNamespace, ProcedureId, String,
-    ToString, Token, Vec,
+    crypto::hash::RpoDigest, tokens::SourceLocation, Box, LibraryNamespace, LibraryPath,
+    ProcedureId, String, ToString, Token, Vec,
 };
 use core::fmt;
 use vm_core::utils::write_hex_bytes;
@@ -26,11 +26,25 @@ pub enum AssemblyError {
     InvalidCacheLock,
     KernelProcNotFound(ProcedureId),
     LocalProcNotFound(u16, String),
-    ParsingError(String),
+    LocalsNotAllocated(u16),
+    Located {
+        error: Box<AssemblyError>,
+        module_path: Option<String>,
+        location: SourceLocation,
+    },
+    ParsingError(ParsingError),
     ParamOutOfBounds(u64, u64, u64),
     ProcedureNameError(String),
+    RepeatExpansionTooLarge(u32, usize, usize),
+    ReExportCycle(Vec<String>),
+    SelfImport(String),
+    StackUnderflow(String, String, usize),
     SysCallInKernel(String),
+    UnbalancedBranches(String, i64, i64),
+    UnbalancedLoop(String, i64),
+    Warning(AssemblyWarning),
     LibraryError(String),
+    LibraryConflict(String, String),
     Io(String),
 }
 
@@ -58,6 +72,10 @@ impl AssemblyError {
         Self::DuplicateProcName(proc_name.to_string(), module_path.to_string())
     }
 
+    pub fn library_conflict(namespace: &str, module_path: &str) -> Self {
+        Self::LibraryConflict(namespace.to_string(), module_path.to_string())
+    }
+
     pub fn duplicate_proc_id(proc_id: &ProcedureId) -> Self {
         Self::DuplicateProcId(*proc_id)
     }
@@ -86,22 +104,72 @@ impl AssemblyError {
         Self::LocalProcNotFound(proc_idx, module_path.to_string())
     }
 
+    pub fn locals_not_allocated(index: u16) -> Self {
+        Self::LocalsNotAllocated(index)
+    }
+
     pub fn param_out_of_bounds(value: u64, min: u64, max: u64) -> Self {
         Self::ParamOutOfBounds(value, min, max)
     }
 
+    pub fn repeat_expansion_too_large(
+        times: u32,
+        num_instructions: usize,
+        max_instructions: usize,
+    ) -> Self {
+        Self::RepeatExpansionTooLarge(times, num_instructions, max_instructions)
+    }
+
+    pub fn reexport_cycle(reexport_chain: &[String]) -> Self {
+        Self::ReExportCycle(reexport_chain.to_vec())
+    }
+
+    pub fn self_import(module_path: &str) -> Self {
+        Self::SelfImport(module_path.to_string())
+    }
+
+    pub fn stack_underflow(proc_name: &str, callee: &str, min_depth: usize) -> Self {
+        Self::StackUnderflow(proc_name.to_string(), callee.to_string(), min_depth)
+    }
+
     pub fn syscall_in_kernel(kernel_proc_name: &str) -> Self {
         Self::SysCallInKernel(kernel_proc_name.to_string())
     }
 
+    pub fn unbalanced_branches(proc_name: &str, true_change: i64, false_change: i64) -> Self {
+        Self::UnbalancedBranches(proc_name.to_string(), true_change, false_change)
+    }
+
+    pub fn unbalanced_loop(proc_name: &str, depth_change: i64) -> Self {
+        Self::UnbalancedLoop(proc_name.to_string(), depth_change)
+    }
+
     pub fn invalid_cache_lock() -> Self {
         Self::InvalidCacheLock
     }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Binds this error to the specified location in the source code of the module at the
+    /// specified path; `None` path refers to the executable module of a program.
+    ///
+    /// Errors which are already bound to a location are returned unchanged.
+    pub fn with_location(self, module_path: Option<&str>, location: SourceLocation) -> Self {
+        match self {
+            Self::Located { .. } | Self::ParsingError(_) => self,
+            error => Self::Located {
+                error: Box::new(error),
+                module_path: module_path.map(|path| path.to_string()),
+                location,
+            },
+        }
+    }
 }
 
 impl From<ParsingError> for AssemblyError {
     fn from(err: ParsingError) -> Self {
-        Self::ParsingError(err.message)
+        Self::ParsingError(err)
     }
 }
 
@@ -124,7 +192,7 @@ impl fmt::Display for AssemblyError {
             CallInKernel(proc_name) => write!(f, "call instruction used kernel procedure '{proc_name}'"),
             CallerOutOKernel => write!(f, "caller instruction used outside of kernel"),
             CallSetProcedureNotFound(proc_id) => write!(f, "callset procedure not found in assembler cache for procedure  '{proc_id}'"),
-            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency in the following chain: {dep_chain:?}"),
+            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency: {}", dep_chain.join(" -> ")),
             DivisionByZero => write!(f, "division by zero"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
             DuplicateProcId(proc_id) => write!(f, "duplicate proc id {proc_id}"),
@@ -135,14 +203,28 @@ impl fmt::Display for AssemblyError {
             InvalidCacheLock => write!(f, "an attempt was made to lock a borrowed procedures cache"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
-            LibraryError(err) | ParsingError(err) | ProcedureNameError(err) => write!(f, "{err}"),
+            LibraryError(err) | ProcedureNameError(err) => write!(f, "{err}"),
+            LibraryConflict(namespace, module_path) => write!(f, "failed to load library '{namespace}': module {module_path} is already provided by a previously loaded library"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
+            LocalsNotAllocated(index) => write!(f, "local memory at index {index} accessed in a procedure with no locals"),
+            Located { error, module_path, location } => {
+                write!(f, "error: {error} at ")?;
+                write_location(f, module_path.as_deref(), location)
+            },
+            ParsingError(err) => write!(f, "{err}"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
             ProcMastRootNotFound(digest) => {
                 write!(f, "procedure mast root not found for digest - ")?;
                 write_hex_bytes(f, &digest.as_bytes())
             },
+            RepeatExpansionTooLarge(times, num_instructions, max_instructions) => write!(f, "unrolling 'repeat.{times}' would bring the number of unrolled instructions to {num_instructions}, but at most {max_instructions} are allowed"),
+            ReExportCycle(reexport_chain) => write!(f, "circular procedure re-export: {}", reexport_chain.join(" -> ")),
+            SelfImport(module_path) => write!(f, "module {module_path} imports itself"),
+            StackUnderflow(proc_name, callee, min_depth) => write!(f, "procedure '{proc_name}' invokes '{callee}' in a new context, but '{callee}' requires {min_depth} stack elements while only 16 are available"),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
+            UnbalancedBranches(proc_name, true_change, false_change) => write!(f, "branches of an if/else in procedure '{proc_name}' change the stack depth by {true_change} and {false_change} respectively"),
+            UnbalancedLoop(proc_name, depth_change) => write!(f, "each iteration of a while loop in procedure '{proc_name}' changes the stack depth by {depth_change}"),
+            Warning(warning) => write!(f, "{warning} (warnings are treated as errors in strict mode)"),
         }
     }
 }
@@ -157,6 +239,84 @@ impl From<std::io::Error> for AssemblyError {
 #[cfg(feature = "std")]
 impl std::error::Error for AssemblyError {}
 
+// ASSEMBLY WARNING
+// ================================================================================================
+
+/// A non-fatal issue detected while compiling a Miden assembly program or library.
+///
+/// Each warning is bound to a location in the source code of the program or of a library module.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AssemblyWarning {
+    ReducedImmediate(String, SourceLocation),
+    ShadowedProcedure(String, String, SourceLocation),
+    StackEffectMismatch(String, i64, i64, SourceLocation),
+    UnusedImport(String, SourceLocation),
+    UnusedProcedure(String, SourceLocation),
+}
+
+impl AssemblyWarning {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn reduced_immediate(instructions: &str, location: SourceLocation) -> Self {
+        Self::ReducedImmediate(instructions.to_string(), location)
+    }
+
+    pub fn shadowed_procedure(
+        proc_name: &str,
+        module_path: &str,
+        location: SourceLocation,
+    ) -> Self {
+        Self::ShadowedProcedure(proc_name.to_string(), module_path.to_string(), location)
+    }
+
+    pub fn stack_effect_mismatch(
+        proc_path: &str,
+        documented_change: i64,
+        actual_change: i64,
+        location: SourceLocation,
+    ) -> Self {
+        Self::StackEffectMismatch(proc_path.to_string(), documented_change, actual_change, location)
+    }
+
+    pub fn unused_import(module_path: &str, location: SourceLocation) -> Self {
+        Self::UnusedImport(module_path.to_string(), location)
+    }
+
+    pub fn unused_procedure(proc_name: &str, location: SourceLocation) -> Self {
+        Self::UnusedProcedure(proc_name.to_string(), location)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the location in the source code to which this warning is bound.
+    pub fn location(&self) -> &SourceLocation {
+        match self {
+            Self::ReducedImmediate(_, location)
+            | Self::ShadowedProcedure(_, _, location)
+            | Self::StackEffectMismatch(_, _, _, location)
+            | Self::UnusedImport(_, location)
+            | Self::UnusedProcedure(_, location) => location,
+        }
+    }
+}
+
+impl fmt::Display for AssemblyWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AssemblyWarning::*;
+        match self {
+            ReducedImmediate(instructions, _) => write!(f, "warning: result of '{instructions}' is reduced modulo the field")?,
+            ShadowedProcedure(proc_name, module_path, _) => write!(f, "warning: procedure '{proc_name}' shadows a procedure exported from imported module {module_path}")?,
+            StackEffectMismatch(proc_path, documented, actual, _) => write!(f, "warning: procedure {proc_path} is documented to change the stack depth by {documented}, but changes it by {actual}")?,
+            UnusedImport(module_path, _) => write!(f, "warning: module {module_path} is imported but never used")?,
+            UnusedProcedure(proc_name, _) => write!(f, "warning: procedure '{proc_name}' is never invoked")?,
+        }
+        write!(f, " at ")?;
+        write_location(f, None, self.location())
+    }
+}
+
 // PARSING ERROR
 // ================================================================================================
 
@@ -166,6 +326,7 @@ pub struct ParsingError {
     message: String,
     location: SourceLocation,
     op: String,
+    module_path: Option<String>,
 }
 
 impl ParsingError {
@@ -177,6 +338,7 @@ impl ParsingError {
             message: "source code cannot be an empty string".to_string(),
             location: SourceLocation::default(),
             op: "".to_string(),
+            module_path: None,
         }
     }
 
@@ -185,6 +347,7 @@ impl ParsingError {
             message: "unexpected EOF".to_string(),
             location,
             op: "".to_string(),
+            module_path: None,
         }
     }
 
@@ -193,6 +356,7 @@ impl ParsingError {
             message: format!("unexpected token: expected '{expected}' but was '{token}'"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -203,6 +367,7 @@ impl ParsingError {
             message: format!("duplicate constant name: '{label}'"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -211,6 +376,7 @@ impl ParsingError {
             message: format!("invalid constant name: {err}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -221,6 +387,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -229,6 +396,7 @@ impl ParsingError {
             message: format!("invalid constant declaration: `{token}` - constants can only be defined below imports and above procedure / program bodies"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -237,6 +405,7 @@ impl ParsingError {
             message: format!("constant used in operation `{token}` not found"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -247,6 +416,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -258,6 +428,7 @@ impl ParsingError {
             message: format!("instruction '{token}' is invalid"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -266,6 +437,7 @@ impl ParsingError {
             message: format!("malformed instruction '{token}': missing required parameter"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -274,6 +446,7 @@ impl ParsingError {
             message: format!("malformed instruction '{token}': too many parameters provided"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -285,6 +458,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -296,6 +470,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -307,6 +482,7 @@ impl ParsingError {
             message: "else without matching if".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -315,6 +491,7 @@ impl ParsingError {
             message: "if without matching else/end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -323,6 +500,7 @@ impl ParsingError {
             message: "while without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -331,6 +509,7 @@ impl ParsingError {
             message: "repeat without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -339,6 +518,7 @@ impl ParsingError {
             message: "else without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -347,6 +527,7 @@ impl ParsingError {
             message: "begin without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -355,6 +536,7 @@ impl ParsingError {
             message: "dangling instructions after program end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -363,6 +545,7 @@ impl ParsingError {
             message: "dangling instructions after module end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -372,6 +555,7 @@ impl ParsingError {
                 .to_string(),
             location,
             op: "".to_string(),
+            module_path: None,
         }
     }
 
@@ -380,6 +564,7 @@ impl ParsingError {
             message: "not a module: `begin` instruction found".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -390,6 +575,7 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            module_path: None,
         }
     }
 
@@ -400,6 +586,7 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            module_path: None,
         }
     }
 
@@ -408,6 +595,7 @@ impl ParsingError {
             message: format!("body block size cannot contain more than {max_body_size} instructions, but had {body_size}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -419,6 +607,7 @@ impl ParsingError {
             message: format!("duplicate procedure name: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -427,6 +616,7 @@ impl ParsingError {
             message: format!("invalid procedure name: {err}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -435,6 +625,7 @@ impl ParsingError {
             message: format!("invalid re-exported procedure: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -446,6 +637,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -454,6 +646,7 @@ impl ParsingError {
             message: format!("invalid procedure locals: {locals}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -462,6 +655,7 @@ impl ParsingError {
             message: format!("number of procedure locals cannot be greater than {max_locals} characters, but was {num_locals}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -470,6 +664,7 @@ impl ParsingError {
             message: format!("procedure '{proc_name}' has no matching end"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -478,6 +673,7 @@ impl ParsingError {
             message: format!("exported procedures not allowed in this context: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -488,6 +684,7 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -499,6 +696,7 @@ impl ParsingError {
             message: format!("invalid procedure root invocation: {label} - {err}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -507,6 +705,7 @@ impl ParsingError {
             message: format!("invalid procedure invocation: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -515,6 +714,7 @@ impl ParsingError {
             message: "invalid exec: cannot invoke a procedure on a mast root".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -523,6 +723,7 @@ impl ParsingError {
             message: "invalid syscall: cannot invoke a syscall on a named module".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -531,6 +732,7 @@ impl ParsingError {
             message: "invalid syscall: cannot invoke a syscall on a mast root".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -539,6 +741,7 @@ impl ParsingError {
             message: format!("undefined local procedure: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -547,6 +750,7 @@ impl ParsingError {
             message: format!("module '{module_name}' was not imported"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -558,6 +762,54 @@ impl ParsingError {
             message: format!("duplicate module import found: {module}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
+        }
+    }
+
+    pub fn duplicate_module_alias(token: &Token, alias: &str) -> Self {
+        ParsingError {
+            message: format!("duplicate module alias found: {alias}"),
+            location: *token.location(),
+            op: token.to_string(),
+            module_path: None,
+        }
+    }
+
+    pub fn conflicting_module_alias(token: &Token, name: &str) -> Self {
+        ParsingError {
+            message: format!(
+                "module name '{name}' is used both as an alias and as the name of an imported module"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+            module_path: None,
+        }
+    }
+
+    pub fn ambiguous_module_reference(
+        token: &Token,
+        module_name: &str,
+        candidates: &[LibraryPath],
+    ) -> Self {
+        let candidates = candidates.iter().map(|path| path.as_str()).collect::<Vec<_>>();
+        ParsingError {
+            message: format!(
+                "module name '{module_name}' is ambiguous as it may refer to any of: {}; use \
+                `use.<path>-><alias>` to import the modules under distinct names",
+                candidates.join(", ")
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+            module_path: None,
+        }
+    }
+
+    pub fn invalid_module_alias(token: &Token, alias: &str) -> Self {
+        ParsingError {
+            message: format!("invalid module import alias: {alias}"),
+            location: *token.location(),
+            op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -566,6 +818,7 @@ impl ParsingError {
             message: format!("invalid module import path: {module_path}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -574,6 +827,7 @@ impl ParsingError {
             message: "import in procedure body".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -582,6 +836,7 @@ impl ParsingError {
             message: format!("invalid path resolution: {error}"),
             location: *token.location(),
             op: token.to_string(),
+            module_path: None,
         }
     }
 
@@ -592,9 +847,19 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            module_path: None,
         }
     }
 
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Binds this error to the module at the specified path.
+    pub fn with_module_path(mut self, module_path: &str) -> Self {
+        self.module_path = Some(module_path.to_string());
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
     pub fn message(&self) -> &String {
@@ -608,17 +873,23 @@ impl ParsingError {
     pub const fn location(&self) -> &SourceLocation {
         &self.location
     }
+
+    /// Returns the path of the module in which this error occurred, if known.
+    pub fn module_path(&self) -> Option<&str> {
+        self.module_path.as_deref()
+    }
 }
 
 impl fmt::Debug for ParsingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "parsing error at {}: {}", self.location, self.message)
+        write!(f, "{self}")
     }
 }
 
 impl fmt::Display for ParsingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "parsing error at {}: {}", self.location, self.message)
+        write!(f, "error: {} at ", self.message)?;
+        write_location(f, self.module_path.as_deref(), &self.location)
     }
 }
 
@@ -707,13 +978,26 @@ impl fmt::Display for LabelError {
 #[derive(Clone, Debug)]
 pub enum LibraryError {
     DeserializationFailed(String, String),
+    DuplicateModuleFile {
+        first: String,
+        second: String,
+    },
     DuplicateModulePath(String),
     DuplicateNamespace(String),
+    ExportRootMismatch {
+        path: String,
+        expected: RpoDigest,
+        actual: Option<RpoDigest>,
+    },
     FileIO(String, String),
     InconsistentNamespace {
         expected: String,
         actual: String,
     },
+    InvalidModuleFile {
+        file: String,
+        reason: String,
+    },
     InvalidNamespace(LabelError),
     InvalidPath(PathError),
     InvalidVersionNumber {
@@ -748,6 +1032,13 @@ impl LibraryError {
         Self::DeserializationFailed(path.into(), message.into())
     }
 
+    pub fn duplicate_module_file(first: &str, second: &str) -> Self {
+        Self::DuplicateModuleFile {
+            first: first.into(),
+            second: second.into(),
+        }
+    }
+
     pub fn duplicate_module_path(path: &str) -> Self {
         Self::DuplicateModulePath(path.into())
     }
@@ -756,6 +1047,18 @@ impl LibraryError {
         Self::DuplicateNamespace(namespace.into())
     }
 
+    pub fn export_root_mismatch(
+        path: &LibraryPath,
+        expected: RpoDigest,
+        actual: Option<RpoDigest>,
+    ) -> Self {
+        Self::ExportRootMismatch {
+            path: path.as_str().into(),
+            expected,
+            actual,
+        }
+    }
+
     pub fn file_error(path: &str, message: &str) -> Self {
         Self::FileIO(path.into(), message.into())
     }
@@ -767,6 +1070,13 @@ impl LibraryError {
         }
     }
 
+    pub fn invalid_module_file(file: &str, reason: &str) -> Self {
+        Self::InvalidModuleFile {
+            file: file.into(),
+            reason: reason.into(),
+        }
+    }
+
     pub fn invalid_namespace(err: LabelError) -> Self {
         Self::InvalidNamespace(err)
     }
@@ -827,14 +1137,36 @@ impl fmt::Display for LibraryError {
             DeserializationFailed(path, message) => {
                 write!(f, "library deserialization failed - '{path}': {message}")
             }
+            DuplicateModuleFile { first, second } => write!(
+                f,
+                "module files '{first}' and '{second}' map to module paths which differ only in case"
+            ),
             DuplicateModulePath(path) => write!(f, "duplciate module path '{path}'"),
             DuplicateNamespace(namespace) => write!(f, "duplicate namespace '{namespace}'"),
+            ExportRootMismatch {
+                path,
+                expected,
+                actual,
+            } => {
+                write!(f, "procedure '{path}' recorded with MAST root ")?;
+                write_hex_bytes(f, &expected.as_bytes())?;
+                match actual {
+                    Some(actual) => {
+                        write!(f, " compiles to MAST root ")?;
+                        write_hex_bytes(f, &actual.as_bytes())
+                    }
+                    None => write!(f, " is not exported from the modules of the library"),
+                }
+            }
             FileIO(path, message) => {
                 write!(f, "file error - '{path}': {message}")
             }
             InconsistentNamespace { expected, actual } => {
                 write!(f, "inconsistent module namespace: expected '{expected}', but was {actual}")
             }
+            InvalidModuleFile { file, reason } => {
+                write!(f, "file '{file}' does not map to a valid module path: {reason}")
+            }
             InvalidNamespace(err) => {
                 write!(f, "invalid namespace: {err}")
             }
@@ -979,3 +1311,19 @@ impl fmt::Display for PathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for PathError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the specified location as `module_path:line:column`, omitting the module path if it is
+/// not known.
+fn write_location(
+    f: &mut fmt::Formatter<'_>,
+    module_path: Option<&str>,
+    location: &SourceLocation,
+) -> fmt::Result {
+    if let Some(module_path) = module_path {
+        write!(f, "{module_path}:")?;
+    }
+    write!(f, "{}:{}", location.line(), location.column())
+}
@@ -0,0 +1,65 @@
+//! Entry points for fuzzing the assembler.
+//!
+//! The functions in this module are intended to be invoked by a fuzzing engine (e.g., from a
+//! `cargo fuzz` target) with arbitrary inputs. Malformed inputs must be rejected with an error; a
+//! panic in any of these functions indicates a bug in the assembler.
+//!
+//! A seed corpus of malformed programs is located in the `fuzz/corpus` directory of this crate.
+
+use super::{Assembler, String, ToString};
+
+// FUZZ TARGETS
+// ================================================================================================
+
+/// Compiles the provided bytes as a Miden assembly program using the default assembler.
+///
+/// Invalid UTF-8 sequences in the input are replaced with the replacement character, so that the
+/// input reaches the tokenizer rather than being discarded. Compilation is expected to either
+/// succeed or return an error; the outcome is also rendered to a string to make sure that the
+/// compiled program and the returned errors can always be displayed.
+///
+/// The assembler does not depend on any external state, and thus, the outcome is fully
+/// determined by the input.
+pub fn compile_fuzz(input: &[u8]) {
+    let source = String::from_utf8_lossy(input);
+    match Assembler::default().compile(source) {
+        Ok(program) => {
+            let _ = program.to_string();
+        }
+        Err(err) => {
+            let _ = err.to_string();
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{compile_fuzz, Assembler, String};
+
+    /// Seed corpus for [compile_fuzz]; all programs in the corpus are malformed.
+    const COMPILE_CORPUS: [&[u8]; 9] = [
+        include_bytes!("../fuzz/corpus/compile/bad_reexport.masm"),
+        include_bytes!("../fuzz/corpus/compile/invalid_utf8.masm"),
+        include_bytes!("../fuzz/corpus/compile/missing_proc_end.masm"),
+        include_bytes!("../fuzz/corpus/compile/multibyte_hex_word.masm"),
+        include_bytes!("../fuzz/corpus/compile/multibyte_mast_root.masm"),
+        include_bytes!("../fuzz/corpus/compile/param_out_of_bounds.masm"),
+        include_bytes!("../fuzz/corpus/compile/repeat_too_large.masm"),
+        include_bytes!("../fuzz/corpus/compile/unknown_instruction.masm"),
+        include_bytes!("../fuzz/corpus/compile/unterminated_block.masm"),
+    ];
+
+    #[test]
+    fn compile_corpus() {
+        for (i, input) in COMPILE_CORPUS.into_iter().enumerate() {
+            compile_fuzz(input);
+
+            let source = String::from_utf8_lossy(input);
+            let result = Assembler::default().compile(source);
+            assert!(result.is_err(), "malformed program {i} of the corpus compiled successfully");
+        }
+    }
+}
@@ -10,29 +10,38 @@ use vm_core::{
     utils::{
         collections::{btree_map, BTreeMap, BTreeSet, Vec},
         string::{String, ToString},
-        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+        Box, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+        SliceReader,
     },
     CodeBlockTable, Felt, Kernel, Operation, Program, StarkField, ONE, ZERO,
 };
 
 mod library;
-pub use library::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version};
+pub use library::{
+    CompiledLibrary, Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version,
+};
 
 mod procedures;
 use procedures::{CallSet, Procedure};
 pub use procedures::{ProcedureId, ProcedureName};
 
 pub mod ast;
+pub use ast::{masm_fmt, parse_module, parse_program};
 use ast::{NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER};
 
 mod tokens;
 use tokens::{Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{
+    AssemblyError, AssemblyWarning, LabelError, LibraryError, ParsingError, PathError,
+};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext, AssemblyContextType};
+pub use assembler::{Assembler, AssemblyContext, AssemblyContextType, StackEffect};
+
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
 
 #[cfg(test)]
 mod tests;
@@ -67,3 +76,7 @@ const MAX_LABEL_LEN: usize = 255;
 /// The required length of the hexadecimal representation for an input value when more than one hex
 /// input is provided to `push` masm operation without period separators.
 const HEX_CHUNK_SIZE: usize = 16;
+
+/// The default maximum number of instructions the `repeat` blocks of a single procedure are
+/// allowed to expand into when they are unrolled by the assembler.
+const MAX_UNROLLED_INSTRUCTIONS: usize = 1 << 16;
@@ -0,0 +1,316 @@
+use super::{
+    super::{crypto::hash::RpoDigest, BTreeSet},
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Library, LibraryError,
+    LibraryNamespace, LibraryPath, MaslLibrary, Module, Serializable, Vec, Version,
+};
+use core::slice::Iter;
+
+// COMPILED LIBRARY
+// ================================================================================================
+
+/// A library produced by [Assembler::compile_library](crate::Assembler::compile_library).
+///
+/// In addition to the modules of the library (including their doc strings), a compiled library
+/// records the MAST root of every procedure exported from it. The library can be serialized into
+/// a versioned binary format and, once deserialized, provided to an assembler exactly like any
+/// other [Library].
+///
+/// Procedure bodies are stored as ASTs and compiled by the assembler which uses the library. When
+/// the library is added to an assembler via [Assembler::with_library](crate::Assembler::with_library),
+/// its exported procedures are compiled right away and checked against the recorded MAST roots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledLibrary {
+    /// Modules of the library.
+    library: MaslLibrary,
+    /// Fully-qualified paths of the exported procedures together with their MAST roots.
+    exports: Vec<(LibraryPath, RpoDigest)>,
+}
+
+impl Library for CompiledLibrary {
+    type ModuleIterator<'a> = Iter<'a, Module>;
+
+    fn root_ns(&self) -> &LibraryNamespace {
+        self.library.root_ns()
+    }
+
+    fn version(&self) -> &Version {
+        self.library.version()
+    }
+
+    fn modules(&self) -> Self::ModuleIterator<'_> {
+        self.library.modules()
+    }
+
+    fn dependencies(&self) -> &[LibraryNamespace] {
+        self.library.dependencies()
+    }
+
+    fn exported_roots(&self) -> &[(LibraryPath, RpoDigest)] {
+        &self.exports
+    }
+}
+
+impl CompiledLibrary {
+    /// Version of the binary format produced by [Serializable::write_into].
+    ///
+    /// Version 2 embeds the modules of the library in the versioned format of [MaslLibrary].
+    pub const FORMAT_VERSION: u8 = 2;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CompiledLibrary] instantiated from the specified modules and the MAST roots
+    /// of the procedures exported from them.
+    ///
+    /// # Errors
+    /// Returns an error if the provided `modules` vector is empty or contains more than
+    /// [u16::MAX] elements.
+    pub(crate) fn new(
+        namespace: LibraryNamespace,
+        modules: Vec<Module>,
+        exports: Vec<(LibraryPath, RpoDigest)>,
+    ) -> Result<Self, LibraryError> {
+        // every namespace imported by the modules, except for the library itself, is a dependency
+        let dependencies = modules
+            .iter()
+            .flat_map(|module| module.ast.imports().values())
+            .map(|path| LibraryNamespace::new(path.first()))
+            .collect::<Result<BTreeSet<_>, _>>()?
+            .into_iter()
+            .filter(|dep| dep != &namespace)
+            .collect();
+
+        let library = MaslLibrary::new(namespace, Version::MIN, false, modules, dependencies)?;
+        Ok(Self { library, exports })
+    }
+
+    /// Sets the version of this library.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.library.version = version;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fully-qualified paths of the procedures exported from this library together
+    /// with their MAST roots.
+    pub fn exports(&self) -> &[(LibraryPath, RpoDigest)] {
+        &self.exports
+    }
+
+    /// Returns the MAST root of the exported procedure with the specified fully-qualified path,
+    /// or None if the library does not export such a procedure.
+    pub fn get_export(&self, path: &LibraryPath) -> Option<&RpoDigest> {
+        self.exports.iter().find(|(export, _)| export == path).map(|(_, root)| root)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Clears the source locations from the modules of this library.
+    pub fn clear_locations(&mut self) {
+        self.library.clear_locations()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CompiledLibrary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(Self::FORMAT_VERSION);
+        self.library.write_into(target);
+
+        // the number of exports is bounded by the number of procedures in the library, which is
+        // limited to u16::MAX per module
+        debug_assert!(self.exports.len() <= u32::MAX as usize, "too many exports");
+        target.write_u32(self.exports.len() as u32);
+        self.exports.iter().for_each(|(path, root)| {
+            path.write_into(target);
+            root.write_into(target);
+        });
+    }
+}
+
+impl Deserializable for CompiledLibrary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let format_version = source.read_u8()?;
+        if format_version != Self::FORMAT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported compiled library format version {format_version}; expected {}",
+                Self::FORMAT_VERSION
+            )));
+        }
+
+        let library = MaslLibrary::read_from(source)?;
+
+        let num_exports = source.read_u32()? as usize;
+        let mut exports = Vec::with_capacity(num_exports);
+        for _ in 0..num_exports {
+            let path = LibraryPath::read_from(source)?;
+            if path.first() != library.root_ns().as_str() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "exported procedure '{}' does not belong to library '{}'",
+                    path.as_str(),
+                    library.root_ns().as_str()
+                )));
+            }
+            let root = RpoDigest::read_from(source)?;
+            exports.push((path, root));
+        }
+
+        Ok(Self { library, exports })
+    }
+}
+
+// LIBRARY BUNDLING
+// ================================================================================================
+
+#[cfg(feature = "std")]
+mod use_std {
+    use super::*;
+    use crate::{ast::ModuleAst, Assembler, AssemblyError, AssemblyWarning, BTreeMap, ToString};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    impl CompiledLibrary {
+        /// Compiles all `masm` files in the specified directory (including its subdirectories)
+        /// into a library with the specified namespace, and returns the library together with
+        /// the warnings detected in its modules.
+        ///
+        /// Module paths are derived from file paths relative to the directory. For example,
+        /// with namespace `foo`, file `./bar/baz.masm` becomes module `foo::bar::baz`. Files with
+        /// other extensions are ignored. Modules of the library may import each other, but not
+        /// modules of other libraries; to bundle a library with external dependencies, use
+        /// [Assembler::compile_library_from_dir()] on an assembler which has access to them.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// - The specified path is not a directory or could not be read.
+        /// - A directory or file name does not map to a valid module path component (e.g., it
+        ///   does not start with a letter or contains characters other than ASCII letters,
+        ///   numbers, and underscores).
+        /// - Two files map to module paths which differ only in case, and thus could not be
+        ///   checked out on a case-insensitive filesystem.
+        /// - Parsing or compilation of any of the modules fails.
+        pub fn from_dir(
+            namespace: &str,
+            path: &Path,
+        ) -> Result<(Self, Vec<AssemblyWarning>), AssemblyError> {
+            Assembler::default().compile_library_from_dir(namespace, path)
+        }
+    }
+
+    impl Assembler {
+        /// Compiles all `masm` files in the specified directory into a [CompiledLibrary] and
+        /// returns it together with the warnings detected in the modules.
+        ///
+        /// In addition to each other, modules of the library may import modules of the libraries
+        /// which are available to this assembler.
+        ///
+        /// # Errors
+        /// Returns an error under the same conditions as [CompiledLibrary::from_dir()], or if the
+        /// assembler is in strict mode and a warning is detected in the modules.
+        pub fn compile_library_from_dir(
+            &self,
+            namespace: &str,
+            path: &Path,
+        ) -> Result<(CompiledLibrary, Vec<AssemblyWarning>), AssemblyError> {
+            let namespace = LibraryNamespace::new(namespace)?;
+            let modules = read_modules_from_dir(&namespace, path)?;
+            self.compile_library_with_diagnostics(namespace, modules)
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads and parses all `masm` files in the specified directory, in the order of their paths.
+    fn read_modules_from_dir(
+        namespace: &LibraryNamespace,
+        dir: &Path,
+    ) -> Result<Vec<Module>, AssemblyError> {
+        if !dir.is_dir() {
+            let dir = dir.display().to_string();
+            return Err(LibraryError::file_error(&dir, "not a directory").into());
+        }
+
+        let mut files = Vec::new();
+        collect_module_files(dir, &mut files)?;
+
+        // module paths which differ only in case are rejected as the files they are read from
+        // would overwrite each other on a case-insensitive filesystem
+        let mut module_files = BTreeMap::<String, PathBuf>::new();
+        let mut modules = Vec::with_capacity(files.len());
+        for file in files {
+            let path = module_path(namespace, dir, &file)?;
+            if let Some(other) = module_files.insert(path.as_str().to_lowercase(), file.clone()) {
+                return Err(LibraryError::duplicate_module_file(
+                    &other.display().to_string(),
+                    &file.display().to_string(),
+                )
+                .into());
+            }
+
+            let source = fs::read_to_string(&file).map_err(|err| {
+                LibraryError::file_error(&file.display().to_string(), &err.to_string())
+            })?;
+            let ast = ModuleAst::parse(&source).map_err(|err| err.with_module_path(&path))?;
+            modules.push(Module::new(path, ast));
+        }
+
+        Ok(modules)
+    }
+
+    /// Appends the paths of all `masm` files in the specified directory and its subdirectories to
+    /// `files`. Directory entries are visited in the order of their names.
+    fn collect_module_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), LibraryError> {
+        let file_error = |err: std::io::Error| {
+            LibraryError::file_error(&dir.display().to_string(), &err.to_string())
+        };
+
+        let mut entries = fs::read_dir(dir)
+            .map_err(file_error)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(file_error)?;
+        entries.sort();
+
+        for path in entries {
+            if path.is_dir() {
+                collect_module_files(&path, files)?;
+            } else if path.extension().map_or(false, |ext| ext == MaslLibrary::MODULE_EXTENSION) {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the module read from the specified file, e.g., `foo::bar::baz` for
+    /// file `<dir>/bar/baz.masm` and namespace `foo`.
+    fn module_path(
+        namespace: &LibraryNamespace,
+        dir: &Path,
+        file: &Path,
+    ) -> Result<LibraryPath, LibraryError> {
+        let invalid_file =
+            |reason: &str| LibraryError::invalid_module_file(&file.display().to_string(), reason);
+
+        let relative_path = file
+            .strip_prefix(dir)
+            .expect("module file is not located in the library directory")
+            .with_extension("");
+
+        let mut path = LibraryPath::new(namespace)?;
+        for component in relative_path.iter() {
+            let component = component
+                .to_str()
+                .ok_or_else(|| invalid_file("file path is not valid unicode"))?;
+            path = path.append(component).map_err(|err| invalid_file(&err.to_string()))?;
+        }
+        Ok(path)
+    }
+}
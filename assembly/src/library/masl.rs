@@ -27,7 +27,7 @@ pub struct MaslLibrary {
     /// Root namespace of the library.
     namespace: LibraryNamespace,
     /// Version of the library.
-    version: Version,
+    pub(super) version: Version,
     /// Flag defining if locations are serialized with the library.
     has_source_locations: bool,
     /// Available modules.
@@ -61,6 +61,14 @@ impl MaslLibrary {
     pub const LIBRARY_EXTENSION: &str = "masl";
     /// File extension for the Assembly Module.
     pub const MODULE_EXTENSION: &str = "masm";
+    /// Magic bytes at the start of a serialized library.
+    pub const MAGIC: &[u8; 4] = b"MASL";
+    /// Version of the binary format produced by [Serializable::write_into], written right after
+    /// [MaslLibrary::MAGIC].
+    ///
+    /// Version 1 is the first versioned format, in which re-exported procedures are stored with
+    /// the paths and names of the procedures they refer to.
+    pub const FORMAT_VERSION: u8 = 1;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -245,9 +253,16 @@ mod use_std {
                         io::Error::new(io::ErrorKind::Other, "invalid directory entry!")
                     })?;
 
-                    // read & parse file
+                    // build module path
+                    let module = module_path
+                        .append(name)
+                        .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{err}")))?;
+
+                    // read & parse file; parsing errors are bound to the module path so that
+                    // they can be traced back to the file
                     let contents = fs::read_to_string(&path)?;
-                    let ast = ModuleAst::parse(&contents)?;
+                    let ast =
+                        ModuleAst::parse(&contents).map_err(|err| err.with_module_path(&module))?;
 
                     // add dependencies of this module to the dependencies of this library
                     for path in ast.imports().values() {
@@ -255,10 +270,7 @@ mod use_std {
                         deps.insert(ns);
                     }
 
-                    // build module path and add it to the map of modules
-                    let module = module_path
-                        .append(name)
-                        .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{err}")))?;
+                    // add the module to the map of modules
                     if state.insert(module, ast).is_some() {
                         unreachable!(
                             "the filesystem is inconsistent as it produced duplicated module paths"
@@ -273,6 +285,8 @@ mod use_std {
 
 impl Serializable for MaslLibrary {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(Self::MAGIC);
+        target.write_u8(Self::FORMAT_VERSION);
         self.namespace.write_into(target);
         self.version.write_into(target);
 
@@ -304,6 +318,21 @@ impl Serializable for MaslLibrary {
 
 impl Deserializable for MaslLibrary {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // libraries serialized before the format was versioned do not start with the magic bytes
+        let magic = source.read_array::<4>()?;
+        if &magic != Self::MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "not a masl library, or a library in an unsupported legacy format".into(),
+            ));
+        }
+        let format_version = source.read_u8()?;
+        if format_version != Self::FORMAT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported masl format version {format_version}; expected {}",
+                Self::FORMAT_VERSION
+            )));
+        }
+
         let namespace = LibraryNamespace::read_from(source)?;
         let version = Version::read_from(source)?;
 
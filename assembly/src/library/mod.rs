@@ -1,10 +1,14 @@
 use super::{
     ast::{AstSerdeOptions, ModuleAst},
+    crypto::hash::RpoDigest,
     ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryError, PathError,
     Serializable, String, ToString, Vec, MAX_LABEL_LEN, NAMESPACE_LABEL_PARSER,
 };
 use core::{cmp::Ordering, fmt, ops::Deref, str::from_utf8};
 
+mod compiled;
+pub use compiled::CompiledLibrary;
+
 mod masl;
 pub use masl::MaslLibrary;
 
@@ -41,13 +45,45 @@ pub trait Library {
 
     /// Returns the dependency libraries of this library.
     fn dependencies(&self) -> &[LibraryNamespace];
+
+    /// Returns the fully-qualified paths of the procedures exported from this library together
+    /// with their MAST roots, as recorded when the library was compiled.
+    ///
+    /// An empty slice is returned if the library does not record MAST roots of its procedures.
+    fn exported_roots(&self) -> &[(LibraryPath, RpoDigest)] {
+        &[]
+    }
+
+    /// Returns the doc comment of the procedure with the specified fully-qualified path (e.g.,
+    /// `std::math::u64::checked_add`).
+    ///
+    /// Returns None if no such procedure is exported from this library, or if the procedure is
+    /// not documented. For a re-exported procedure, the doc comment of the referenced procedure
+    /// is returned if that procedure is defined in this library.
+    fn procedure_docs(&self, path: &str) -> Option<&str> {
+        let (module_path, proc_name) = path.rsplit_once(LibraryPath::PATH_DELIM)?;
+        let module = self.modules().find(|module| module.path.as_str() == module_path)?;
+
+        let proc = module.ast.procs().iter().find(|proc| proc.name.as_str() == proc_name);
+        if let Some(proc) = proc {
+            return proc.docs.as_deref().filter(|_| proc.is_export);
+        }
+
+        let reexport = module
+            .ast
+            .reexported_procs()
+            .iter()
+            .find(|proc| proc.name().as_str() == proc_name)?;
+        self.procedure_docs(&reexport.ref_path().append_unchecked(reexport.ref_name()))
+    }
 }
 
 impl<T> Library for &T
 where
     T: Library,
 {
-    type ModuleIterator<'a> = T::ModuleIterator<'a>
+    type ModuleIterator<'a>
+        = T::ModuleIterator<'a>
     where
         Self: 'a;
 
@@ -66,6 +102,14 @@ where
     fn dependencies(&self) -> &[LibraryNamespace] {
         T::dependencies(self)
     }
+
+    fn exported_roots(&self) -> &[(LibraryPath, RpoDigest)] {
+        T::exported_roots(self)
+    }
+
+    fn procedure_docs(&self, path: &str) -> Option<&str> {
+        T::procedure_docs(self, path)
+    }
 }
 
 // MODULE
@@ -56,3 +56,26 @@ fn masl_locations_serialization() {
     bundle.clear_locations();
     assert_eq!(bundle, deserialized);
 }
+
+#[test]
+fn masl_format_version() {
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let path = LibraryPath::new("test::foo").unwrap();
+    let ast = ModuleAst::parse("export.foo add end").unwrap();
+    let modules = [Module::new(path, ast)].to_vec();
+    let bundle = MaslLibrary::new(namespace, Version::MIN, false, modules, Vec::new()).unwrap();
+
+    let mut bytes = Vec::new();
+    bundle.write_into(&mut bytes);
+    assert_eq!(&bytes[..4], MaslLibrary::MAGIC);
+    assert_eq!(bytes[4], MaslLibrary::FORMAT_VERSION);
+
+    // libraries without the magic bytes are rejected
+    let result = MaslLibrary::read_from(&mut SliceReader::new(&bytes[5..]));
+    assert!(result.is_err());
+
+    // libraries in another version of the format are rejected
+    bytes[4] = MaslLibrary::FORMAT_VERSION + 1;
+    let result = MaslLibrary::read_from(&mut SliceReader::new(&bytes));
+    assert!(result.is_err());
+}
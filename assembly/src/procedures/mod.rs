@@ -1,6 +1,6 @@
 use super::{
     crypto::hash::Blake3_160, BTreeSet, ByteReader, ByteWriter, CodeBlock, Deserializable,
-    DeserializationError, LabelError, LibraryPath, Serializable, String, ToString,
+    DeserializationError, LabelError, LibraryPath, Serializable, StackEffect, String, ToString,
     PROCEDURE_LABEL_PARSER,
 };
 use core::{
@@ -21,6 +21,7 @@ pub struct Procedure {
     num_locals: u32,
     code_root: CodeBlock,
     callset: CallSet,
+    stack_effect: Option<StackEffect>,
 }
 
 impl Procedure {
@@ -42,9 +43,17 @@ impl Procedure {
             num_locals,
             code_root,
             callset,
+            stack_effect: None,
         }
     }
 
+    /// Sets the stack effect of this procedure, as determined by the stack depth analysis of the
+    /// assembler.
+    pub fn with_stack_effect(mut self, stack_effect: Option<StackEffect>) -> Self {
+        self.stack_effect = stack_effect;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -79,6 +88,12 @@ impl Procedure {
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns the stack effect of this procedure, or None if the stack effect was not computed
+    /// or could not be determined statically.
+    pub fn stack_effect(&self) -> Option<StackEffect> {
+        self.stack_effect
+    }
 }
 
 // PROCEDURE NAME
@@ -1,8 +1,11 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyContextType, Library, LibraryNamespace, LibraryPath, Module, Version,
+    ast::{ModuleAst, ProgramAst, SourceLocation},
+    utils::{Deserializable, Serializable},
+    Assembler, AssemblyContextType, AssemblyError, AssemblyWarning, CompiledLibrary, Library,
+    LibraryNamespace, LibraryPath, Module, Version,
 };
 use core::slice::Iter;
+use vm_core::code_blocks::CodeBlock;
 
 // SIMPLE PROGRAMS
 // ================================================================================================
@@ -35,6 +38,32 @@ fn simple_instructions() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn assertions_with_error_codes() {
+    let assembler = super::Assembler::default();
+    let source = "\
+    const.ERR1=4096 \
+    begin \
+        assert.err=ERR1 assert_eq.err=7 u32assert.2.err=5 assert.err=0 \
+    end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span assert(4096) eq assert(7) u32assert2(5) assert end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // error codes are bound to the program hash
+    let source = "begin assert.err=4097 assert_eq.err=7 u32assert.2.err=5 assert end";
+    let other = assembler.compile(source).unwrap();
+    assert_ne!(program.hash(), other.hash());
+
+    // a zero error code is equivalent to not specifying an error code
+    let with_zero = assembler.compile("begin assert.err=0 u32assert.err=0 end").unwrap();
+    let without = assembler.compile("begin assert u32assert end").unwrap();
+    assert_eq!(with_zero.hash(), without.hash());
+}
+
 #[test]
 fn empty_program() {
     let assembler = super::Assembler::default();
@@ -71,6 +100,51 @@ fn empty_repeat() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn repeat_with_constant_expression() {
+    let assembler = super::Assembler::default();
+    let source = "const.WORDS_PER_BLOCK=2 begin repeat.WORDS_PER_BLOCK*2 add end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span add add add add end end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn nested_repeat() {
+    let assembler = super::Assembler::default();
+    let source = "const.N=2 begin repeat.N repeat.3 add end mul end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span add add add mul add add add mul end end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn repeat_expansion_too_large() {
+    let assembler = super::Assembler::default().with_max_unrolled_instructions(10);
+    let source = "begin repeat.4 repeat.3 add end end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: unrolling 'repeat.4' would bring the number of unrolled instructions to 12, but at most 10 are allowed at 1:7"
+    );
+
+    // nested blocks are counted once, in their expanded form
+    let source = "begin repeat.3 repeat.3 add end end end";
+    assert!(assembler.compile(source).is_ok());
+
+    // all repeat blocks of a procedure count against the same limit
+    let source = "begin repeat.3 repeat.3 add end end repeat.2 mul end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: unrolling 'repeat.2' would bring the number of unrolled instructions to 11, but at most 10 are allowed at 1:37"
+    );
+
+    // each procedure has a limit of its own
+    let source = "proc.foo repeat.9 add end end begin repeat.9 mul end exec.foo end";
+    assert!(assembler.compile(source).is_ok());
+}
+
 #[test]
 fn single_span() {
     let assembler = super::Assembler::default();
@@ -203,7 +277,8 @@ fn constants_must_be_uppercase() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "invalid constant name: 'constant_1' cannot contain lower-case characters";
+    let expected_error =
+        "error: invalid constant name: 'constant_1' cannot contain lower-case characters at 1:1";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -218,7 +293,7 @@ fn duplicate_constant_name() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "duplicate constant name: 'CONSTANT'";
+    let expected_error = "error: duplicate constant name: 'CONSTANT' at 1:19";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -232,8 +307,8 @@ fn constant_must_be_valid_felt() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "malformed constant `const.CONSTANT=1122INVALID` - invalid value: \
-     `1122INVALID` - reason: invalid digit found in string";
+    let expected_error = "error: malformed constant `const.CONSTANT=1122INVALID` - invalid value: \
+     `1122INVALID` - reason: invalid digit found in string at 1:1";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -247,9 +322,9 @@ fn constant_must_be_within_valid_felt_range() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "malformed constant `const.CONSTANT=18446744073709551615` - invalid value: \
+    let expected_error = "error: malformed constant `const.CONSTANT=18446744073709551615` - invalid value: \
      `18446744073709551615` - reason: constant value must be greater than or equal to 0 and less than or \
-      equal to 18446744069414584320";
+      equal to 18446744069414584320 at 1:1";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -264,7 +339,7 @@ fn constants_defined_in_global_scope() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "invalid constant declaration: `const.CONSTANT=12` - constants can only be defined below imports and above procedure / program bodies";
+    let expected_error = "error: invalid constant declaration: `const.CONSTANT=12` - constants can only be defined below imports and above procedure / program bodies at 2:11";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -278,7 +353,7 @@ fn constant_not_found() {
     let result = assembler.compile(source);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    let expected_error = "constant used in operation `push.CONSTANT` not found";
+    let expected_error = "error: constant used in operation `push.CONSTANT` not found at 2:11";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -409,7 +484,7 @@ fn const_conversion_failed_to_u16() {
     assert!(result.is_err());
     let err = result.err().unwrap();
     let expected_error =
-        "failed to convert u64 constant used in `loc_load.CONSTANT` to required type u16";
+        "error: failed to convert u64 constant used in `loc_load.CONSTANT` to required type u16 at 4:9";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -432,7 +507,7 @@ fn const_conversion_failed_to_u32() {
     assert!(result.is_err());
     let err = result.err().unwrap();
     let expected_error =
-        "failed to convert u64 constant used in `mem_load.CONSTANT` to required type u32";
+        "error: failed to convert u64 constant used in `mem_load.CONSTANT` to required type u32 at 4:9";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -550,6 +625,21 @@ fn program_with_exported_procedure() {
     assert!(assembler.compile(source).is_err());
 }
 
+#[test]
+fn program_with_procref_only_procedure() {
+    // foo is only referenced via procref, and thus, must not be eliminated as dead code
+    let assembler = super::Assembler::default();
+    let foo_root = assembler.compile("begin push.3 add end").unwrap().hash();
+    let program = assembler.compile("proc.foo push.3 add end begin procref.foo end").unwrap();
+    assert!(program.cb_table().has(foo_root));
+
+    let [e0, e1, e2, e3] = foo_root.as_elements() else {
+        unreachable!()
+    };
+    let expected = assembler.compile(format!("begin push.{e0}.{e1}.{e2}.{e3} end")).unwrap();
+    assert_eq!(expected.hash(), program.hash());
+}
+
 // MAST ROOT CALLS
 // ================================================================================================
 
@@ -559,7 +649,7 @@ fn program_with_incorrect_mast_root_length() {
     let source = "begin call.0x1234 end";
     let result = assembler.compile(source);
     let err = result.err().unwrap();
-    let expected_error = "invalid procedure root invocation: 0x1234 - rpo digest hex label must have 66 characters, but was 6";
+    let expected_error = "error: invalid procedure root invocation: 0x1234 - rpo digest hex label must have 66 characters, but was 6 at 1:7";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -570,8 +660,8 @@ fn program_with_invalid_mast_root_chars() {
         "begin call.0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a21xyzb end";
     let result = assembler.compile(source);
     let err = result.err().unwrap();
-    let expected_error = "invalid procedure root invocation: 0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a21xyzb - \
-    '0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a21xyzb' contains invalid hex characters";
+    let expected_error = "error: invalid procedure root invocation: 0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a21xyzb - \
+    '0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a21xyzb' contains invalid hex characters at 1:7";
     assert_eq!(expected_error, err.to_string());
 }
 
@@ -582,20 +672,31 @@ fn program_with_invalid_rpo_digest_call() {
         "begin call.0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff end";
     let result = assembler.compile(source);
     let err = result.err().unwrap();
-    let expected_error = "invalid procedure root invocation: 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff - \
-    '0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff' is not a valid Rpo Digest hex label";
+    let expected_error = "error: invalid procedure root invocation: 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff - \
+    '0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff' is not a valid Rpo Digest hex label at 1:7";
     assert_eq!(expected_error, err.to_string());
 }
 
 #[test]
-fn program_with_mast_root_call_that_does_not_exist() {
+fn program_with_phantom_mast_root_call() {
     let assembler = super::Assembler::default();
     let source =
         "begin call.0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a213dae end";
-    let result = assembler.compile(source);
-    let err = result.err().unwrap();
-    let expected_error = "procedure mast root not found for digest - 0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a213dae";
-    assert_eq!(expected_error, err.to_string());
+    let program = assembler.compile(source).unwrap();
+    let expected =
+        "begin call.0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a213dae end";
+    assert_eq!(expected, format!("{program}"));
+
+    // the procedure is not known to the assembler, and thus, it is not in the code block table
+    let unresolved_calls = program.unresolved_calls();
+    assert_eq!(1, unresolved_calls.len());
+    assert!(!program.cb_table().has(unresolved_calls[0]));
+    assert_eq!(CodeBlock::new_call(unresolved_calls[0]).hash(), program.hash());
+
+    // phantom calls cannot be used in a kernel
+    let kernel =
+        "export.foo call.0xc2545da99d3a1f3f38d957c7893c44d78998d8ea8b11aba7e22c8c2b2a213dae end";
+    assert!(super::Assembler::default().with_kernel(kernel).is_err());
 }
 
 // IMPORTS
@@ -804,6 +905,409 @@ fn program_with_reexported_proc_in_another_library() {
     assert!(assembler.compile(source).is_err());
 }
 
+#[test]
+fn libraries_with_overlapping_namespace() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE_BODY: &str = r#"
+        export.foo
+            push.1
+        end
+    "#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let module = |path: &str| Module {
+        path: LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap(),
+        ast: ModuleAst::parse(MODULE_BODY).unwrap(),
+    };
+
+    // both libraries provide the dummy::math::u64 module
+    let library_1 = DummyLibrary::new(namespace.clone(), vec![module("math::u64")]);
+    let library_2 =
+        DummyLibrary::new(namespace.clone(), vec![module("math::u32"), module("math::u64")]);
+    let result = super::Assembler::default().with_libraries([&library_1, &library_2].into_iter());
+    let err = result.err().expect("loading libraries with the same namespace should fail");
+    assert_eq!(
+        "failed to load library 'dummy': module dummy::math::u64 is already provided by a \
+        previously loaded library",
+        err.to_string()
+    );
+
+    // libraries with the same namespace conflict even if they provide disjoint sets of modules
+    let library_3 = DummyLibrary::new(namespace.clone(), vec![module("math::u128")]);
+    let result = super::Assembler::default().with_libraries([&library_1, &library_3].into_iter());
+    assert!(matches!(result, Err(AssemblyError::LibraryConflict(ns, path))
+        if ns == NAMESPACE && path == NAMESPACE));
+}
+
+#[test]
+fn program_with_reexport_cycle() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE_A_BODY: &str = "
+        use.dummy::b
+        export.b::foo";
+    const MODULE_B_BODY: &str = "
+        use.dummy::a
+        export.a::foo";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let modules = [("a", MODULE_A_BODY), ("b", MODULE_B_BODY)]
+        .into_iter()
+        .map(|(path, body)| Module {
+            path: LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap(),
+            ast: ModuleAst::parse(body).unwrap(),
+        })
+        .collect();
+    let assembler = super::Assembler::default()
+        .with_library(&DummyLibrary::new(namespace, modules))
+        .unwrap();
+
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::a
+        begin
+            exec.a::foo
+        end"#
+    );
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error =
+        "circular procedure re-export: dummy::a::foo -> dummy::b::foo -> dummy::a::foo";
+    assert!(err.to_string().contains(expected_error), "unexpected error: {err}");
+}
+
+#[test]
+fn program_with_import_cycle() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE_A_BODY: &str = "
+        use.dummy::b
+        export.foo
+            exec.b::bar
+        end";
+    const MODULE_B_BODY: &str = "
+        use.dummy::c
+        export.bar
+            exec.c::baz
+        end";
+    const MODULE_C_BODY: &str = "
+        use.dummy::a
+        export.baz
+            exec.a::foo
+        end";
+    const MODULE_D_BODY: &str = "
+        use.dummy::d
+        export.qux
+            push.1
+        end
+        export.quux
+            exec.d::qux
+        end";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let modules = [
+        ("a", MODULE_A_BODY),
+        ("b", MODULE_B_BODY),
+        ("c", MODULE_C_BODY),
+        ("d", MODULE_D_BODY),
+    ]
+    .into_iter()
+    .map(|(path, body)| Module {
+        path: LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap(),
+        ast: ModuleAst::parse(body).unwrap(),
+    })
+    .collect();
+    let assembler = super::Assembler::default()
+        .with_library(&DummyLibrary::new(namespace, modules))
+        .unwrap();
+
+    // the error lists the full chain of imports, starting from the module which closes the cycle
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::a
+        begin
+            exec.a::foo
+        end"#
+    );
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error = "circular module dependency: dummy::a -> dummy::b -> dummy::c -> dummy::a";
+    assert!(err.to_string().contains(expected_error), "unexpected error: {err}");
+
+    // the same cycle is reported when entering it from another module
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::b
+        begin
+            exec.b::bar
+        end"#
+    );
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error = "circular module dependency: dummy::b -> dummy::c -> dummy::a -> dummy::b";
+    assert!(err.to_string().contains(expected_error), "unexpected error: {err}");
+
+    // a module importing itself is reported separately
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::d
+        begin
+            exec.d::quux
+        end"#
+    );
+    let err = assembler.compile(source).unwrap_err();
+    assert!(
+        err.to_string().contains("module dummy::d imports itself"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn program_with_aliased_imports() {
+    const MODULE: &str = "math::u64";
+    const STD_MODULE_BODY: &str = "
+        export.foo
+            push.1
+        end";
+    const MYLIB_MODULE_BODY: &str = "
+        export.foo
+            push.2
+        end";
+
+    let mut assembler = super::Assembler::default();
+    for (namespace, body) in [("std", STD_MODULE_BODY), ("mylib", MYLIB_MODULE_BODY)] {
+        let namespace = LibraryNamespace::try_from(namespace.to_string()).unwrap();
+        let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+        let modules = vec![Module {
+            path,
+            ast: ModuleAst::parse(body).unwrap(),
+        }];
+        assembler = assembler.with_library(&DummyLibrary::new(namespace, modules)).unwrap();
+    }
+
+    // both modules can be referenced when at least one of them is aliased
+    let source = "
+        use.std::math::u64->stdu64
+        use.mylib::math::u64
+        begin
+            exec.stdu64::foo
+            exec.u64::foo
+        end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span pad incr push(2) end end";
+    assert_eq!(expected, format!("{program}"));
+
+    // aliases are resolved per module
+    let source = "
+        use.mylib::math::u64->mylib_u64
+        use.std::math::u64->std_u64
+        begin
+            exec.mylib_u64::foo
+            exec.std_u64::foo
+        end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span push(2) pad incr end end";
+    assert_eq!(expected, format!("{program}"));
+
+    // an unaliased reference to colliding modules is ambiguous
+    let source = "
+        use.std::math::u64
+        use.mylib::math::u64
+        begin
+            exec.u64::foo
+        end";
+    let err = assembler.compile(source).unwrap_err().to_string();
+    assert!(err.contains("module name 'u64' is ambiguous"), "unexpected error: {err}");
+    assert!(err.contains("std::math::u64, mylib::math::u64"), "unexpected error: {err}");
+}
+
+#[test]
+fn module_with_duplicate_reexported_proc_names() {
+    // the same name is re-exported twice
+    let source = "
+        use.dummy::math::u64
+        export.u64::checked_eqz->eqz
+        export.u64::unchecked_eqz->eqz";
+    assert!(ModuleAst::parse(source).is_err());
+
+    // a re-exported name clashes with a local procedure
+    let source = "
+        use.dummy::math::u64
+        export.u64::checked_eqz
+        export.checked_eqz
+            eq.0
+        end";
+    assert!(ModuleAst::parse(source).is_err());
+}
+
+#[test]
+fn compiled_library_round_trip() {
+    const NAMESPACE: &str = "dummy";
+    const U64_MODULE_BODY: &str = r#"
+        #! Dummy u64 operations.
+
+        #! Returns 1 if the u64 value on the top of the stack is zero.
+        export.checked_eqz
+            u32assert.2
+            eq.0
+            swap
+            eq.0
+            and
+        end
+    "#;
+    const U256_MODULE_BODY: &str = r#"
+        use.dummy::math::u64
+        export.u64::checked_eqz->eqz
+        export.add_one
+            add.1
+        end
+    "#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let modules = [("math::u64", U64_MODULE_BODY), ("math::u256", U256_MODULE_BODY)]
+        .into_iter()
+        .map(|(path, body)| Module {
+            path: LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap(),
+            ast: ModuleAst::parse(body).unwrap(),
+        })
+        .collect();
+    let library = Assembler::default().compile_library(namespace, modules).unwrap();
+
+    // all exported procedures, including re-exports, are recorded in the library
+    let exported_paths =
+        library.exports().iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        exported_paths,
+        [
+            "dummy::math::u64::checked_eqz",
+            "dummy::math::u256::eqz",
+            "dummy::math::u256::add_one"
+        ]
+    );
+    let eqz_path = LibraryPath::new("dummy::math::u256::eqz").unwrap();
+    let checked_eqz_path = LibraryPath::new("dummy::math::u64::checked_eqz").unwrap();
+    assert_eq!(library.get_export(&eqz_path), library.get_export(&checked_eqz_path));
+
+    // the library survives a serialization round trip, including the doc strings
+    let mut library = library;
+    let bytes = library.to_bytes();
+    let deserialized = CompiledLibrary::read_from_bytes(&bytes).unwrap();
+    assert_ne!(library, deserialized, "sanity check");
+    // source locations are not serialized
+    library.clear_locations();
+    assert_eq!(library, deserialized);
+    let u64_module =
+        deserialized.modules().find(|m| m.path.as_str() == "dummy::math::u64").unwrap();
+    assert!(u64_module.ast.docs().is_some());
+
+    // docs of exported procedures can be looked up by procedure paths, including re-exports
+    let eqz_docs = Some("Returns 1 if the u64 value on the top of the stack is zero.");
+    assert_eq!(deserialized.procedure_docs("dummy::math::u64::checked_eqz"), eqz_docs);
+    assert_eq!(deserialized.procedure_docs("dummy::math::u256::eqz"), eqz_docs);
+    assert_eq!(deserialized.procedure_docs("dummy::math::u256::add_one"), None);
+    assert_eq!(deserialized.procedure_docs("dummy::math::u256::sub_one"), None);
+    assert_eq!(deserialized.procedure_docs("dummy::math::u128::checked_eqz"), None);
+
+    // programs compiled against both versions of the library are identical
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::math::u256
+        begin
+            push.4 push.3
+            exec.u256::eqz
+            call.u256::add_one
+        end"#
+    );
+    let program = Assembler::default().with_library(&library).unwrap().compile(&source).unwrap();
+    let deserialized_program = Assembler::default()
+        .with_library(&deserialized)
+        .unwrap()
+        .compile(&source)
+        .unwrap();
+    assert_eq!(program.hash(), deserialized_program.hash());
+
+    // the recorded MAST root of the called procedure matches the one used by the program
+    let add_one_path = LibraryPath::new("dummy::math::u256::add_one").unwrap();
+    let add_one_root = deserialized.get_export(&add_one_path).unwrap();
+    assert!(program.cb_table().get(*add_one_root).is_some());
+
+    // the format version is checked upon deserialization
+    let mut bytes = bytes;
+    bytes[0] = CompiledLibrary::FORMAT_VERSION + 1;
+    assert!(CompiledLibrary::read_from_bytes(&bytes).is_err());
+
+    // libraries whose procedures do not compile to the recorded MAST roots are rejected
+    let tamper = |exports: Vec<(LibraryPath, _)>| {
+        let modules = deserialized.modules().cloned().collect();
+        CompiledLibrary::new(deserialized.root_ns().clone(), modules, exports).unwrap()
+    };
+    let mut exports = deserialized.exports().to_vec();
+    exports[2].1 = exports[0].1;
+    let error = Assembler::default().with_library(&tamper(exports)).err().unwrap();
+    assert!(error
+        .to_string()
+        .contains("'dummy::math::u256::add_one' recorded with MAST root"));
+
+    let mut exports = deserialized.exports().to_vec();
+    exports.push((LibraryPath::new("dummy::math::u256::sub_one").unwrap(), exports[0].1));
+    let error = Assembler::default().with_library(&tamper(exports)).err().unwrap();
+    assert!(error.to_string().contains("is not exported from the modules of the library"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compiled_library_from_dir() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/library");
+    let (library, warnings) = CompiledLibrary::from_dir("fixture", &dir).unwrap();
+    assert!(warnings.is_empty());
+
+    // module paths are derived from file paths; files without the masm extension are ignored
+    let module_paths = library.modules().map(|m| m.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(module_paths, ["fixture::consts", "fixture::math::ops"]);
+    assert_eq!(
+        library.procedure_docs("fixture::math::ops::double"),
+        Some("Doubles the element on the top of the stack.")
+    );
+
+    // procedures of the library can invoke each other across modules
+    let source = "use.fixture::math::ops begin push.3 exec.ops::quadruple end";
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let expected = "begin span push(3) push(2) mul push(2) mul end end";
+    assert_eq!(expected, format!("{}", assembler.compile(source).unwrap()));
+
+    // the namespace must be valid, and the path must point to a directory
+    assert!(CompiledLibrary::from_dir("1fixture", &dir).is_err());
+    let error = CompiledLibrary::from_dir("fixture", &dir.join("consts.masm")).unwrap_err();
+    assert!(error.to_string().contains("not a directory"), "{error}");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compiled_library_from_dir_errors() {
+    use std::fs;
+
+    let dir = std::env::temp_dir()
+        .join(format!("miden_assembly_{}_library_from_dir_errors", std::process::id()));
+    fs::create_dir_all(dir.join("math")).unwrap();
+    fs::write(dir.join("math/u64.masm"), "export.foo push.1 end").unwrap();
+
+    // file and directory names must be valid module path components
+    fs::write(dir.join("math/u64-ext.masm"), "export.bar push.2 end").unwrap();
+    let error = CompiledLibrary::from_dir("dummy", &dir).unwrap_err();
+    assert!(error.to_string().contains("u64-ext.masm"), "{error}");
+    assert!(error.to_string().contains("does not map to a valid module path"), "{error}");
+    fs::remove_file(dir.join("math/u64-ext.masm")).unwrap();
+
+    // module paths which differ only in case are rejected; on a case-insensitive filesystem the
+    // second file overwrites the first one, and thus, the library compiles
+    fs::write(dir.join("math/U64.masm"), "export.bar push.2 end").unwrap();
+    let num_files = fs::read_dir(dir.join("math")).unwrap().count();
+    let result = CompiledLibrary::from_dir("dummy", &dir);
+    if num_files == 2 {
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("differ only in case"), "{error}");
+    } else {
+        assert!(result.is_ok());
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn program_with_import_errors() {
     // --- non-existent import ------------------------------------------------
@@ -907,35 +1411,38 @@ fn invalid_program() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "source code cannot be an empty string");
+        assert_eq!(error.to_string(), "error: source code cannot be an empty string at 1:1");
     }
 
     let source = " ";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "source code cannot be an empty string");
+        assert_eq!(error.to_string(), "error: source code cannot be an empty string at 1:1");
     }
 
     let source = "none";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "unexpected token: expected 'begin' but was 'none'");
+        assert_eq!(
+            error.to_string(),
+            "error: unexpected token: expected 'begin' but was 'none' at 1:1"
+        );
     }
 
     let source = "begin add";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "begin without matching end");
+        assert_eq!(error.to_string(), "error: begin without matching end at 1:1");
     }
 
     let source = "begin add end mul";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "dangling instructions after program end");
+        assert_eq!(error.to_string(), "error: dangling instructions after program end at 1:15");
     }
 }
 
@@ -947,35 +1454,38 @@ fn invalid_proc() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "procedure 'foo' has no matching end");
+        assert_eq!(error.to_string(), "error: procedure 'foo' has no matching end at 1:1");
     }
 
     let source = "proc.foo add mul proc.bar push.3 end begin push.1 end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "procedure 'foo' has no matching end");
+        assert_eq!(error.to_string(), "error: procedure 'foo' has no matching end at 1:1");
     }
 
     let source = "proc.foo add mul end begin push.1 exec.bar end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "undefined local procedure: bar");
+        assert_eq!(error.to_string(), "error: undefined local procedure: bar at 1:35");
     }
 
     let source = "proc.123 add mul end begin push.1 exec.123 end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "invalid procedure name: '123' does not start with a letter");
+        assert_eq!(
+            error.to_string(),
+            "error: invalid procedure name: '123' does not start with a letter at 1:1"
+        );
     }
 
     let source = "proc.foo add mul end proc.foo push.3 end begin push.1 end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "duplicate procedure name: foo");
+        assert_eq!(error.to_string(), "error: duplicate procedure name: foo at 1:22");
     }
 }
 
@@ -988,7 +1498,7 @@ fn invalid_if_else() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "if without matching else/end");
+        assert_eq!(error.to_string(), "error: if without matching else/end at 1:18");
     }
 
     // --- unmatched else -------------------------------------------------------------------------
@@ -996,28 +1506,28 @@ fn invalid_if_else() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(error.to_string(), "error: else without matching if at 1:18");
     }
 
     let source = "begin push.1 while.true add else mul end end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(error.to_string(), "error: else without matching if at 1:29");
     }
 
     let source = "begin push.1 if.true add else mul else push.1 end end end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(error.to_string(), "error: else without matching if at 1:35");
     }
 
     let source = "begin push.1 add if.true mul else add";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching end");
+        assert_eq!(error.to_string(), "error: else without matching end at 1:30");
     }
 }
 
@@ -1030,7 +1540,7 @@ fn invalid_repeat() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "repeat without matching end");
+        assert_eq!(error.to_string(), "error: repeat without matching end at 1:18");
     }
 
     // invalid iter count
@@ -1040,7 +1550,29 @@ fn invalid_repeat() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "malformed instruction `repeat.23x3`: parameter '23x3' is invalid"
+            "error: malformed instruction `repeat.23x3`: parameter '23x3' is invalid at 1:18"
+        );
+    }
+
+    // undefined constant in iter count
+    let source = "const.A=2 begin push.1 add repeat.A*B mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "error: constant used in operation `repeat.A*B` not found at 1:28"
+        );
+    }
+
+    // iter count does not fit into u32
+    let source = "const.A=65536 begin push.1 add repeat.A*A mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "error: failed to convert u64 constant used in `repeat.A*A` to required type u32 at 1:32"
         );
     }
 }
@@ -1053,7 +1585,10 @@ fn invalid_while() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "malformed instruction 'while': missing required parameter");
+        assert_eq!(
+            error.to_string(),
+            "error: malformed instruction 'while': missing required parameter at 1:18"
+        );
     }
 
     let source = "begin push.1 add while.abc mul end end";
@@ -1062,7 +1597,7 @@ fn invalid_while() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "malformed instruction `while.abc`: parameter 'abc' is invalid"
+            "error: malformed instruction `while.abc`: parameter 'abc' is invalid at 1:18"
         );
     }
 
@@ -1070,21 +1605,356 @@ fn invalid_while() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "while without matching end");
+        assert_eq!(error.to_string(), "error: while without matching end at 1:18");
     }
 }
 
+#[test]
+fn error_locations() {
+    let assembler = super::Assembler::default();
+
+    // parsing error
+    let source = "\
+begin
+    push.1
+    foo
+end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), "error: instruction 'foo' is invalid at 3:5");
+
+    // semantic error
+    let source = "\
+begin
+    push.1
+    caller
+end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), "error: caller instruction used outside of kernel at 3:5");
+}
+
+#[test]
+fn error_locations_in_imported_module() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "math::u64";
+    const MODULE_BODY: &str = "\
+export.foo
+    push.1
+    caller
+end";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(MODULE_BODY).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let assembler = super::Assembler::default().with_library(&library).unwrap();
+    let source = format!(
+        "\
+use.{NAMESPACE}::{MODULE}
+begin
+    exec.u64::foo
+end"
+    );
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: caller instruction used outside of kernel at dummy::math::u64:3:5"
+    );
+}
+
+// STACK DEPTH ANALYSIS
+// ================================================================================================
+
+#[test]
+fn stack_depth_unbalanced_branches() {
+    let source = "proc.foo if.true push.1 else push.1 push.2 end end begin exec.foo end";
+
+    // without stack depth checks, unbalanced branches are allowed
+    assert!(super::Assembler::default().compile(source).is_ok());
+
+    let assembler = super::Assembler::default().with_stack_depth_checks(true);
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: branches of an if/else in procedure 'foo' change the stack depth by 1 and 2 \
+        respectively at 1:10"
+    );
+
+    // an if without an else branch must not change the stack depth
+    let source = "begin push.1 push.1 if.true drop end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: branches of an if/else in procedure '#main' change the stack depth by -1 and 0 \
+        respectively at 1:21"
+    );
+
+    let source = "begin push.1 push.1 if.true drop else swap drop end end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+#[test]
+fn stack_depth_unbalanced_loop() {
+    let assembler = super::Assembler::default().with_stack_depth_checks(true);
+
+    let source = "begin push.1 while.true push.1 push.1 end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: each iteration of a while loop in procedure '#main' changes the stack depth by 1 \
+        at 1:14"
+    );
+
+    let source = "begin push.1 while.true push.0 end end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+#[test]
+fn stack_depth_stack_unsafe_procs() {
+    let assembler = super::Assembler::default().with_stack_depth_checks(true);
+
+    let source = "\
+#! Drops elements until the top of the stack is 0.
+#!
+#! stack-unsafe
+proc.foo
+    dup neq.0
+    while.true
+        drop dup neq.0
+    end
+end
+
+begin
+    exec.foo
+end";
+    assert!(assembler.compile(source).is_ok());
+
+    // without the annotation, the loop is rejected
+    let source = source.replace("#! stack-unsafe", "#!");
+    assert!(assembler.compile(source).is_err());
+}
+
+#[test]
+fn stack_depth_call_underflow() {
+    let assembler = super::Assembler::default().with_stack_depth_checks(true);
+
+    // the procedure requires 17 elements, which is fine when it is executed in the same context
+    let source = "proc.foo dropw dropw dropw dropw drop end begin exec.foo end";
+    assert!(assembler.compile(source).is_ok());
+
+    // but only 16 elements are available to the procedure when it is called in a new context
+    let source = "proc.foo dropw dropw dropw dropw drop end begin call.foo end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: procedure '#main' invokes 'foo' in a new context, but 'foo' requires 17 stack \
+        elements while only 16 are available at 1:49"
+    );
+}
+
+// WARNINGS
+// ================================================================================================
+
+#[test]
+fn warnings_unused_import_and_proc() {
+    let assembler = warnings_assembler();
+    let source = "\
+use.dummy::math::u64
+proc.foo
+    push.1
+end
+begin
+    push.2
+end";
+    let (_, warnings) = assembler.compile_with_diagnostics(source).unwrap();
+    let warnings = warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        [
+            "warning: module dummy::math::u64 is imported but never used at 1:1",
+            "warning: procedure 'foo' is never invoked at 2:1",
+        ]
+    );
+
+    // a module referenced only via procref is used, and so is a procedure referenced via procref
+    let source = "\
+use.dummy::math::u64
+proc.foo
+    push.1
+end
+begin
+    procref.u64::bar
+    procref.foo
+    dropw dropw
+end";
+    let (_, warnings) = assembler.compile_with_diagnostics(source).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn warnings_shadowed_proc() {
+    let assembler = warnings_assembler();
+    let source = "\
+use.dummy::math::u64
+proc.bar
+    push.1
+end
+begin
+    exec.bar
+    exec.u64::bar
+end";
+    let (_, warnings) = assembler.compile_with_diagnostics(source).unwrap();
+    assert_eq!(
+        warnings,
+        [AssemblyWarning::shadowed_procedure(
+            "bar",
+            "dummy::math::u64",
+            SourceLocation::new(2, 1)
+        )]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "warning: procedure 'bar' shadows a procedure exported from imported module \
+        dummy::math::u64 at 2:1"
+    );
+
+    // a procedure with the same name as a non-exported procedure of the module is not reported
+    let source = source.replace("bar", "baz").replace("u64::baz", "u64::bar");
+    let (_, warnings) = assembler.compile_with_diagnostics(source).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn warnings_reduced_immediates() {
+    let assembler = super::Assembler::default();
+    let source = "\
+begin
+    push.1 sub.2
+    push.3 mul.6148914691236517205
+    push.1 add.1
+    push.18446744069414584320 add.1
+    push.2 sub.2
+end";
+    let (_, warnings) = assembler.compile_with_diagnostics(source).unwrap();
+    let warnings = warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        [
+            "warning: result of 'push.1 sub.2' is reduced modulo the field at 2:12",
+            "warning: result of 'push.3 mul.6148914691236517205' is reduced modulo the field \
+            at 3:12",
+            "warning: result of 'push.18446744069414584320 add.1' is reduced modulo the field \
+            at 5:31",
+        ]
+    );
+}
+
+#[test]
+fn warnings_strict_mode() {
+    let source = "proc.foo push.1 end begin push.2 end";
+
+    // by default, warnings do not affect compilation
+    assert!(super::Assembler::default().compile(source).is_ok());
+
+    let assembler = super::Assembler::default().with_strict_mode(true);
+    let expected = "warning: procedure 'foo' is never invoked at 1:1 \
+        (warnings are treated as errors in strict mode)";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = assembler.compile_with_diagnostics(source).unwrap_err();
+    assert_eq!(error.to_string(), expected);
+
+    let source = "proc.foo push.1 end begin exec.foo end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+#[test]
+fn warnings_documented_stack_effect() {
+    let source = "\
+#! Input: [a, b, ...]
+#! Output: [c, ...]
+export.add
+    add
+end
+
+#! Input: [A, ...]
+#! Output: [b, ...]
+export.take_first
+    drop drop drop
+end
+
+#! Input: [a, ...]
+#! Output: [B, ...]
+export.mismatch
+    push.1
+end
+
+#! Input: [a, ...]
+#! Output: [b, c ...]
+export.unrecognized
+    drop
+end";
+    let namespace = LibraryNamespace::try_from("dummy".to_string()).unwrap();
+    let path = LibraryPath::new("dummy::math::ops").unwrap();
+    let modules = vec![Module::new(path, ModuleAst::parse(source).unwrap())];
+
+    let assembler = super::Assembler::default().with_stack_depth_checks(true);
+    let (_, warnings) = assembler
+        .compile_library_with_diagnostics(namespace.clone(), modules.clone())
+        .unwrap();
+    assert_eq!(
+        warnings,
+        [AssemblyWarning::stack_effect_mismatch(
+            "dummy::math::ops::mismatch",
+            3,
+            1,
+            SourceLocation::new(15, 1)
+        )]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "warning: procedure dummy::math::ops::mismatch is documented to change the stack depth \
+        by 3, but changes it by 1 at 15:1"
+    );
+
+    // without stack depth analysis, stack effects of procedures are unknown
+    let (_, warnings) = super::Assembler::default()
+        .compile_library_with_diagnostics(namespace.clone(), modules.clone())
+        .unwrap();
+    assert!(warnings.is_empty());
+
+    // in strict mode, the mismatch fails compilation of the library
+    let assembler = assembler.with_strict_mode(true);
+    assert!(assembler.compile_library(namespace, modules).is_err());
+}
+
+/// Returns an assembler with access to the `dummy::math::u64` module which exports procedure
+/// `bar` and defines a local procedure `baz`.
+fn warnings_assembler() -> Assembler {
+    let namespace = LibraryNamespace::try_from("dummy".to_string()).unwrap();
+    let path = LibraryPath::try_from("math::u64".to_string())
+        .unwrap()
+        .prepend(&namespace)
+        .unwrap();
+    let ast = ModuleAst::parse("proc.baz push.1 end export.bar exec.baz end").unwrap();
+    let modules = vec![Module { path, ast }];
+    super::Assembler::default()
+        .with_library(&DummyLibrary::new(namespace, modules))
+        .unwrap()
+}
+
 // DUMMY LIBRARY
 // ================================================================================================
 
-struct DummyLibrary {
+pub struct DummyLibrary {
     namespace: LibraryNamespace,
     modules: Vec<Module>,
     dependencies: Vec<LibraryNamespace>,
 }
 
 impl DummyLibrary {
-    fn new(namespace: LibraryNamespace, modules: Vec<Module>) -> Self {
+    pub fn new(namespace: LibraryNamespace, modules: Vec<Module>) -> Self {
         Self {
             namespace,
             modules,
@@ -104,12 +104,19 @@ impl<'a> Token<'a> {
     // CONTROL TOKEN PARSERS / VALIDATORS
     // --------------------------------------------------------------------------------------------
 
-    pub fn parse_use(&self) -> Result<LibraryPath, ParsingError> {
+    pub fn parse_use(&self) -> Result<(LibraryPath, Option<&str>), ParsingError> {
         assert_eq!(Self::USE, self.parts[0], "not a use");
         match self.num_parts() {
             0 => unreachable!(),
             1 => Err(ParsingError::missing_param(self)),
-            2 => validate_import_path(self.parts[1], self),
+            2 => {
+                // get the alias of the module if it is specified
+                let (path, alias) = match self.parts[1].split_once(Self::EXPORT_ALIAS_DELIM) {
+                    Some((path, alias)) => (path, Some(validate_import_alias(alias, self)?)),
+                    None => (self.parts[1], None),
+                };
+                Ok((validate_import_path(path, self)?, alias))
+            }
             _ => Err(ParsingError::extra_param(self)),
         }
     }
@@ -219,12 +226,12 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn parse_repeat(&self) -> Result<u32, ParsingError> {
+    pub fn validate_repeat(&self) -> Result<(), ParsingError> {
         assert_eq!(Self::REPEAT, self.parts[0], "not a repeat");
         match self.num_parts() {
             0 => unreachable!(),
             1 => Err(ParsingError::missing_param(self)),
-            2 => self.parts[1].parse::<u32>().map_err(|_| ParsingError::invalid_param(self, 1)),
+            2 => Ok(()),
             _ => Err(ParsingError::extra_param(self)),
         }
     }
@@ -269,6 +276,16 @@ fn validate_import_path(path: &str, token: &Token) -> Result<LibraryPath, Parsin
     LibraryPath::try_from(path).map_err(|_| ParsingError::invalid_module_path(token, path))
 }
 
+/// An import alias must be a valid single-component module path, i.e.:
+/// - It must start with an ASCII letter.
+/// - It can contain only ASCII letters, numbers, or underscores.
+fn validate_import_alias<'a>(alias: &'a str, token: &Token) -> Result<&'a str, ParsingError> {
+    match LibraryPath::new(alias) {
+        Ok(path) if path.num_components() == 1 => Ok(alias),
+        _ => Err(ParsingError::invalid_module_alias(token, alias)),
+    }
+}
+
 /// Procedure locals must be a 16-bit integer.
 fn validate_proc_locals(locals: &str, token: &Token) -> Result<u16, ParsingError> {
     match locals.parse::<u64>() {
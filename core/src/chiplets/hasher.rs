@@ -1,5 +1,6 @@
-//! TODO: add docs
-use super::Felt;
+//! Rescue Prime Optimized (RPO) hash function instantiated over the 64-bit field used by the VM.
+use super::{Felt, ZERO};
+use core::ops::Range;
 
 pub use crate::crypto::hash::{Rpo256 as Hasher, RpoDigest as Digest};
 
@@ -13,6 +14,37 @@ pub const STATE_WIDTH: usize = Hasher::STATE_WIDTH;
 /// Number of field elements in the rate portion of the hasher's state.
 pub const RATE_LEN: usize = 8;
 
+/// Number of field elements in the capacity portion of the hasher's state.
+pub const CAPACITY_LEN: usize = STATE_WIDTH - RATE_LEN;
+
+/// Index of the capacity element which holds the number of elements in the last block of hashed
+/// elements (modulo the rate), or [RATE_LEN] if no elements are hashed.
+pub const CAPACITY_LEN_IDX: usize = 0;
+
+/// Index of the capacity element which holds the domain tag.
+pub const CAPACITY_DOMAIN_IDX: usize = 1;
+
+/// The rate portion of the hasher's state; the rate follows the capacity.
+pub const RATE_RANGE: Range<usize> = CAPACITY_LEN..STATE_WIDTH;
+
+/// Number of field elements in a digest.
+pub const DIGEST_SIZE: usize = 4;
+
+/// The portion of the hasher's state which holds the digest after a permutation is applied.
+pub const DIGEST_RANGE: Range<usize> = Hasher::DIGEST_RANGE;
+
+// DOMAIN SEPARATION
+// ================================================================================================
+
+/// Domain tag for hashing a sequence of field elements via [hash_in_domain()].
+///
+/// Domain tags are placed into the capacity portion of the state. The tags defined here are
+/// outside of the range of opcodes, which are used as domains when hashing code blocks.
+pub const HASH_DOMAIN: Felt = Felt::new(1 << 8);
+
+/// Domain tag for compressing two digests into one via [hash_in_domain()].
+pub const COMPRESSION_DOMAIN: Felt = Felt::new((1 << 8) + 1);
+
 // PASS-THROUGH FUNCTIONS
 // ================================================================================================
 
@@ -49,3 +81,135 @@ pub fn apply_round(state: &mut [Felt; STATE_WIDTH], round: usize) {
 pub fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
     Hasher::apply_permutation(state)
 }
+
+/// Applies Rescue-XLIX permutation to the provided sponge state, and returns the digest portion
+/// of the permuted state.
+///
+/// # Panics
+/// Panics if the length of the state is not equal to [STATE_WIDTH].
+pub fn digest(state: &[Felt]) -> [Felt; DIGEST_SIZE] {
+    let mut state: [Felt; STATE_WIDTH] = state.try_into().expect("invalid sponge state length");
+    apply_permutation(&mut state);
+    state[DIGEST_RANGE].try_into().expect("invalid digest range")
+}
+
+/// Returns a hash of the provided list of field elements in the specified domain.
+///
+/// The elements are absorbed into the rate portion of the state in blocks of [RATE_LEN] elements,
+/// and a permutation is applied after each block. The last block is padded with zeros. Prior to
+/// absorbing the elements, the number of elements in the last block (modulo [RATE_LEN]) and the
+/// domain tag (e.g., [HASH_DOMAIN] or [COMPRESSION_DOMAIN]) are placed into the capacity portion
+/// of the state.
+///
+/// Empty input is treated as a single block of zeros. To distinguish it from a block of
+/// [RATE_LEN] zeros, its length is recorded as [RATE_LEN], which no other input produces.
+pub fn hash_in_domain(elements: &[Felt], domain: Felt) -> [Felt; DIGEST_SIZE] {
+    let len_tag = if elements.is_empty() {
+        RATE_LEN
+    } else {
+        elements.len() % RATE_LEN
+    };
+    let mut state = [ZERO; STATE_WIDTH];
+    state[CAPACITY_LEN_IDX] = Felt::from(len_tag as u8);
+    state[CAPACITY_DOMAIN_IDX] = domain;
+
+    // absorb all blocks except for the last one
+    let last_block_start = elements.len().saturating_sub(1) / RATE_LEN * RATE_LEN;
+    for block in elements[..last_block_start].chunks(RATE_LEN) {
+        state[RATE_RANGE].copy_from_slice(block);
+        apply_permutation(&mut state);
+    }
+
+    // pad the last block with zeros and compute the digest
+    let last_block = &elements[last_block_start..];
+    state[RATE_RANGE].fill(ZERO);
+    state[RATE_RANGE.start..RATE_RANGE.start + last_block.len()].copy_from_slice(last_block);
+    digest(&state)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_permutation, digest, hash_in_domain, merge_in_domain, Digest, Felt,
+        COMPRESSION_DOMAIN, DIGEST_RANGE, HASH_DOMAIN, RATE_LEN, RATE_RANGE, STATE_WIDTH, ZERO,
+    };
+
+    #[test]
+    fn hash_in_domain_empty() {
+        let mut state = [ZERO; STATE_WIDTH];
+        state[0] = Felt::new(RATE_LEN as u64);
+        state[1] = HASH_DOMAIN;
+        apply_permutation(&mut state);
+        assert_eq!(state[DIGEST_RANGE], hash_in_domain(&[], HASH_DOMAIN));
+
+        // the digest of empty input depends on the domain
+        assert_ne!(hash_in_domain(&[], HASH_DOMAIN), hash_in_domain(&[], COMPRESSION_DOMAIN));
+
+        // empty input does not collide with a block of zeros
+        for domain in [HASH_DOMAIN, COMPRESSION_DOMAIN] {
+            assert_ne!(hash_in_domain(&[], domain), hash_in_domain(&[ZERO; RATE_LEN], domain));
+        }
+    }
+
+    #[test]
+    fn hash_in_domain_single_block() {
+        let elements = build_elements(5);
+        let mut state = [ZERO; STATE_WIDTH];
+        state[0] = Felt::new(5);
+        state[1] = HASH_DOMAIN;
+        state[RATE_RANGE.start..RATE_RANGE.start + 5].copy_from_slice(&elements);
+        assert_eq!(digest(&state), hash_in_domain(&elements, HASH_DOMAIN));
+
+        // padding with zeros changes the digest as the number of elements is in the capacity
+        let mut padded = elements.clone();
+        padded.push(ZERO);
+        assert_ne!(hash_in_domain(&elements, HASH_DOMAIN), hash_in_domain(&padded, HASH_DOMAIN));
+
+        // compression of two digests is the same as merging them in the compression domain
+        let elements = build_elements(8);
+        let digests = [
+            Digest::new(elements[..4].try_into().unwrap()),
+            Digest::new(elements[4..].try_into().unwrap()),
+        ];
+        let expected = merge_in_domain(&digests, COMPRESSION_DOMAIN);
+        assert_eq!(expected.as_elements(), hash_in_domain(&elements, COMPRESSION_DOMAIN));
+        assert_ne!(expected.as_elements(), hash_in_domain(&elements, HASH_DOMAIN));
+    }
+
+    #[test]
+    fn hash_in_domain_multiple_blocks() {
+        let elements = build_elements(19);
+        let mut state = [ZERO; STATE_WIDTH];
+        state[0] = Felt::new(3);
+        state[1] = HASH_DOMAIN;
+        state[RATE_RANGE].copy_from_slice(&elements[..8]);
+        apply_permutation(&mut state);
+        state[RATE_RANGE].copy_from_slice(&elements[8..16]);
+        apply_permutation(&mut state);
+        state[RATE_RANGE].fill(ZERO);
+        state[RATE_RANGE.start..RATE_RANGE.start + 3].copy_from_slice(&elements[16..]);
+        assert_eq!(digest(&state), hash_in_domain(&elements, HASH_DOMAIN));
+
+        // a full last block is not followed by an extra block of padding
+        let elements = build_elements(16);
+        let mut state = [ZERO; STATE_WIDTH];
+        state[1] = HASH_DOMAIN;
+        state[RATE_RANGE].copy_from_slice(&elements[..8]);
+        apply_permutation(&mut state);
+        state[RATE_RANGE].copy_from_slice(&elements[8..]);
+        assert_eq!(digest(&state), hash_in_domain(&elements, HASH_DOMAIN));
+    }
+
+    #[test]
+    #[should_panic]
+    fn digest_invalid_state() {
+        digest(&[ZERO; STATE_WIDTH - 1]);
+    }
+
+    fn build_elements(n: u64) -> Vec<Felt> {
+        (0..n).map(|i| Felt::new(i * 7 + 3)).collect()
+    }
+}
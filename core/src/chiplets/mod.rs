@@ -1,2 +1,2 @@
-use super::Felt;
+use super::{Felt, ZERO};
 pub mod hasher;
@@ -25,3 +25,29 @@ impl fmt::Display for InputError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for InputError {}
+
+// HEX PARSE ERROR
+// ================================================================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HexParseError {
+    InvalidLength(usize, usize),
+    InvalidChar(char),
+    NotFieldElement(u64),
+}
+
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use HexParseError::*;
+        match self {
+            InvalidLength(expected, actual) => {
+                write!(f, "expected {expected} hex characters, but was {actual}")
+            }
+            InvalidChar(c) => write!(f, "'{c}' is not a valid hex character"),
+            NotFieldElement(value) => write!(f, "{value} is not a valid field element"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexParseError {}
@@ -10,8 +10,8 @@ use core::fmt;
 /// These actions can affect all 3 components of the advice provider: Merkle store, advice stack,
 /// and advice map.
 ///
-/// All actions, except for `MerkleNodeMerge` and `Ext2Inv`, can be invoked directly from Miden
-/// assembly via dedicated instructions.
+/// All actions, except for `MerkleNodeMerge`, `Ext2Inv`, and `ILog2`, can be invoked directly
+/// from Miden assembly via dedicated instructions.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum AdviceInjector {
     // MERKLE STORE INJECTORS
@@ -89,6 +89,23 @@ pub enum AdviceInjector {
     /// the remainder respectively.
     DivU64,
 
+    /// Pushes the result of `u256` division (both the quotient and the remainder) onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+    /// respectively (with a0 representing the 32 least significant bits and a7 representing the
+    /// 32 most significant bits). Similarly, (q0, ..., q7) and (r0, ..., r7) represent the
+    /// quotient and the remainder respectively.
+    DivU256,
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -104,6 +121,20 @@ pub enum AdviceInjector {
     /// top of the stack.
     Ext2Inv,
 
+    /// Pushes the base 2 logarithm (rounded down) of the element at the top of the operand stack
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [floor(log2(a)), ...]
+    ///
+    /// If `a` is ZERO, ZERO is pushed onto the advice stack.
+    ILog2,
+
     /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
     ///  into a polynomial in coefficient form and pushes the result into the advice stack.
     ///
@@ -199,7 +230,9 @@ impl fmt::Display for AdviceInjector {
                 }
             }
             Self::DivU64 => write!(f, "div_u64"),
+            Self::DivU256 => write!(f, "div_u256"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
+            Self::ILog2 => write!(f, "ilog2"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
             Self::MemToMap => write!(f, "mem_to_map"),
@@ -5,9 +5,15 @@ use core::fmt;
 // ================================================================================================
 
 /// Contains information corresponding to an assembly instruction (only applicable in debug mode).
+///
+/// In addition to the instruction itself, this includes the name of the procedure the instruction
+/// belongs to and, when available, the path of the module and the source line the instruction was
+/// parsed from.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AssemblyOp {
     context_name: String,
+    module_path: Option<String>,
+    line: Option<u32>,
     num_cycles: u8,
     op: String,
     should_break: bool,
@@ -19,17 +25,47 @@ impl AssemblyOp {
     pub fn new(context_name: String, num_cycles: u8, op: String, should_break: bool) -> Self {
         Self {
             context_name,
+            module_path: None,
+            line: None,
             num_cycles,
             op,
             should_break,
         }
     }
 
+    /// Returns [AssemblyOp] with the path of the module containing the assembly instruction set
+    /// to the specified value.
+    pub fn with_module_path(mut self, module_path: String) -> Self {
+        self.module_path = Some(module_path);
+        self
+    }
+
+    /// Returns [AssemblyOp] with the source line of the assembly instruction set to the specified
+    /// value.
+    pub fn with_line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
     /// Returns the context name for this operation.
     pub fn context_name(&self) -> &str {
         &self.context_name
     }
 
+    /// Returns the path of the module containing the assembly instruction of this decorator, or
+    /// None if the instruction belongs to the executable module of a program.
+    pub fn module_path(&self) -> Option<&str> {
+        self.module_path.as_deref()
+    }
+
+    /// Returns the source line of the assembly instruction of this decorator, if known.
+    pub const fn line(&self) -> Option<u32> {
+        self.line
+    }
+
     /// Returns the number of VM cycles taken to execute the assembly instruction of this decorator.
     pub const fn num_cycles(&self) -> u8 {
         self.num_cycles
@@ -60,6 +96,12 @@ impl fmt::Display for AssemblyOp {
             f,
             "context={}, operation={}, cost={}",
             self.context_name, self.op, self.num_cycles,
-        )
+        )?;
+        match (&self.module_path, self.line) {
+            (Some(path), Some(line)) => write!(f, ", location={path}:{line}"),
+            (None, Some(line)) => write!(f, ", location={line}"),
+            (Some(path), None) => write!(f, ", location={path}"),
+            (None, None) => Ok(()),
+        }
     }
 }
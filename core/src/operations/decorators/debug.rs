@@ -0,0 +1,32 @@
+use core::fmt;
+
+// DEBUG OPTIONS
+// ================================================================================================
+
+/// Defines which part of the VM state should be reported when a debug decorator is executed.
+///
+/// Debug decorators do not affect the state of the VM and are executed only when the VM runs in
+/// debug mode.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DebugOptions {
+    /// Reports the entire contents of the operand stack.
+    StackAll,
+    /// Reports the specified number of items from the top of the operand stack.
+    StackTop(u16),
+    /// Reports the contents of all initialized memory addresses of the current context.
+    MemAll,
+    /// Reports the contents of memory in the current context for the specified number of
+    /// addresses, starting at the specified address.
+    MemInterval(u32, u32),
+}
+
+impl fmt::Display for DebugOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StackAll => write!(f, "stack"),
+            Self::StackTop(n) => write!(f, "stack.{n}"),
+            Self::MemAll => write!(f, "mem"),
+            Self::MemInterval(addr, len) => write!(f, "mem.{addr}.{len}"),
+        }
+    }
+}
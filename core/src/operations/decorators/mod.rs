@@ -1,9 +1,11 @@
 mod advice;
 mod assembly_op;
+mod debug;
 use crate::utils::collections::Vec;
 pub use advice::AdviceInjector;
 pub use assembly_op::AssemblyOp;
 use core::fmt;
+pub use debug::DebugOptions;
 
 // DECORATORS
 // ================================================================================================
@@ -17,6 +19,13 @@ pub enum Decorator {
     /// Adds information about the assembly instruction at a particular index
     /// (only applicable in debug mode)
     AsmOp(AssemblyOp),
+    /// Reports the part of the VM state specified by the options (only applicable in debug mode).
+    /// This does not affect the state of the VM and does not advance the VM clock.
+    Debug(DebugOptions),
+    /// Emits a trace event with the specified identifier to the host (only applicable when
+    /// tracing is enabled). This does not affect the state of the VM and does not advance the VM
+    /// clock.
+    Trace(u32),
 }
 
 impl fmt::Display for Decorator {
@@ -26,6 +35,8 @@ impl fmt::Display for Decorator {
             Self::AsmOp(assembly_op) => {
                 write!(f, "asmOp({}, {})", assembly_op.op(), assembly_op.num_cycles())
             }
+            Self::Debug(options) => write!(f, "debug({options})"),
+            Self::Trace(trace_id) => write!(f, "trace({trace_id})"),
         }
     }
 }
@@ -1,7 +1,9 @@
-use super::Felt;
+use super::{Felt, ZERO};
 use core::fmt;
 mod decorators;
-pub use decorators::{AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList};
+pub use decorators::{
+    AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList,
+};
 
 // OPERATIONS
 // ================================================================================================
@@ -16,7 +18,11 @@ pub enum Operation {
     Noop,
 
     /// Pops the stack; if the popped value is not 1, execution fails.
-    Assert,
+    ///
+    /// The internal value specifies an error code associated with the error in case when the
+    /// execution fails. A non-zero error code is carried as an immediate value of the operation,
+    /// and thus, it affects the hash of the program.
+    Assert(Felt),
 
     /// Pops an element off the stack, adds the current value of the `fmp` register to it, and
     /// pushes the result back onto the stack.
@@ -52,6 +58,9 @@ pub enum Operation {
     /// Marks the beginning of a kernel call.
     SysCall,
 
+    /// Marks the beginning of a dynamic code block, where the target is specified by the stack.
+    Dyn,
+
     /// Marks the beginning of a span code block.
     Span,
 
@@ -147,7 +156,11 @@ pub enum Operation {
 
     /// Pops two elements off the stack and checks if each of them represents a 32-bit value.
     /// If both of them are, they are pushed back onto the stack, otherwise an error is returned.
-    U32assert2,
+    ///
+    /// The internal value specifies an error code associated with the error in case when the
+    /// execution fails. A non-zero error code is carried as an immediate value of the operation,
+    /// and thus, it affects the hash of the program.
+    U32assert2(Felt),
 
     /// Pops three elements off the stack, adds them together, and splits the result into upper
     /// and lower 32-bit values. Then pushes the result back onto the stack.
@@ -464,7 +477,7 @@ impl Operation {
             Self::SwapDW    => 0b0001_1110,
             // <empty>      => 0b0001_1111,
 
-            Self::Assert    => 0b0010_0000,
+            Self::Assert(_) => 0b0010_0000,
             Self::Eq        => 0b0010_0001,
             Self::Add       => 0b0010_0010,
             Self::Mul       => 0b0010_0011,
@@ -503,7 +516,7 @@ impl Operation {
             Self::U32mul    => 0b0100_0100,
             Self::U32div    => 0b0100_0110,
             Self::U32split  => 0b0100_1000,
-            Self::U32assert2 => 0b0100_1010,
+            Self::U32assert2(_) => 0b0100_1010,
             Self::U32add3   => 0b0100_1100,
             Self::U32madd   => 0b0100_1110,
 
@@ -515,7 +528,7 @@ impl Operation {
             Self::Loop      => 0b0101_0101,
             Self::Span      => 0b0101_0110,
             Self::Join      => 0b0101_0111,
-            // <empty>      => 0b0101_1000,
+            Self::Dyn       => 0b0101_1000,
             // <empty>      => 0b0101_1001,
             // <empty>      => 0b0101_1010,
             // <empty>      => 0b0101_1011,
@@ -536,9 +549,14 @@ impl Operation {
     }
 
     /// Returns an immediate value carried by this operation.
+    ///
+    /// Assertions carry their error codes as immediate values, unless the error code is ZERO.
     pub fn imm_value(&self) -> Option<Felt> {
         match self {
             Self::Push(imm) => Some(*imm),
+            Self::Assert(err_code) | Self::U32assert2(err_code) if *err_code != ZERO => {
+                Some(*err_code)
+            }
             _ => None,
         }
     }
@@ -557,8 +575,131 @@ impl Operation {
                 | Self::Halt
                 | Self::Call
                 | Self::SysCall
+                | Self::Dyn
         )
     }
+
+    /// Returns the category of this operation.
+    pub fn category(&self) -> OpCategory {
+        match self {
+            Self::Noop
+            | Self::Assert(_)
+            | Self::FmpAdd
+            | Self::FmpUpdate
+            | Self::SDepth
+            | Self::Caller
+            | Self::Clk => OpCategory::System,
+
+            Self::Join
+            | Self::Split
+            | Self::Loop
+            | Self::Call
+            | Self::SysCall
+            | Self::Dyn
+            | Self::Span
+            | Self::End
+            | Self::Repeat
+            | Self::Respan
+            | Self::Halt => OpCategory::FlowControl,
+
+            Self::Add
+            | Self::Neg
+            | Self::Mul
+            | Self::Inv
+            | Self::Incr
+            | Self::And
+            | Self::Or
+            | Self::Not
+            | Self::Eq
+            | Self::Eqz
+            | Self::Expacc => OpCategory::Field,
+
+            Self::Ext2Mul => OpCategory::Ext2,
+
+            Self::U32split
+            | Self::U32add
+            | Self::U32assert2(_)
+            | Self::U32add3
+            | Self::U32sub
+            | Self::U32mul
+            | Self::U32madd
+            | Self::U32div
+            | Self::U32and
+            | Self::U32xor => OpCategory::U32,
+
+            Self::Pad
+            | Self::Drop
+            | Self::Dup0
+            | Self::Dup1
+            | Self::Dup2
+            | Self::Dup3
+            | Self::Dup4
+            | Self::Dup5
+            | Self::Dup6
+            | Self::Dup7
+            | Self::Dup9
+            | Self::Dup11
+            | Self::Dup13
+            | Self::Dup15
+            | Self::Swap
+            | Self::SwapW
+            | Self::SwapW2
+            | Self::SwapW3
+            | Self::SwapDW
+            | Self::MovUp2
+            | Self::MovUp3
+            | Self::MovUp4
+            | Self::MovUp5
+            | Self::MovUp6
+            | Self::MovUp7
+            | Self::MovUp8
+            | Self::MovDn2
+            | Self::MovDn3
+            | Self::MovDn4
+            | Self::MovDn5
+            | Self::MovDn6
+            | Self::MovDn7
+            | Self::MovDn8
+            | Self::CSwap
+            | Self::CSwapW => OpCategory::StackManipulation,
+
+            Self::Push(_)
+            | Self::AdvPop
+            | Self::AdvPopW
+            | Self::MLoadW
+            | Self::MStoreW
+            | Self::MLoad
+            | Self::MStore
+            | Self::MStream
+            | Self::Pipe => OpCategory::InputOutput,
+
+            Self::HPerm | Self::MpVerify | Self::MrUpdate | Self::FriE2F4 => OpCategory::Crypto,
+        }
+    }
+}
+
+// OPERATION CATEGORY
+// ================================================================================================
+
+/// Groups of operations, as returned by [Operation::category()].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OpCategory {
+    /// System operations (e.g., NOOP, ASSERT, FMPUPDATE).
+    System,
+    /// Flow control operations (e.g., JOIN, SPLIT, END).
+    FlowControl,
+    /// Field operations (e.g., ADD, MUL, EQ).
+    Field,
+    /// Operations in the quadratic extension field (e.g., EXT2MUL).
+    Ext2,
+    /// u32 operations (e.g., U32ADD, U32SPLIT).
+    U32,
+    /// Stack manipulation operations (e.g., PAD, DUP, SWAP).
+    StackManipulation,
+    /// Input / output operations (e.g., PUSH, MLOAD, ADVPOP).
+    InputOutput,
+    /// Cryptographic operations (e.g., HPERM, MPVERIFY).
+    Crypto,
 }
 
 impl fmt::Display for Operation {
@@ -566,7 +707,13 @@ impl fmt::Display for Operation {
         match self {
             // ----- system operations ------------------------------------------------------------
             Self::Noop => write!(f, "noop"),
-            Self::Assert => write!(f, "assert"),
+            Self::Assert(err_code) => {
+                if *err_code == ZERO {
+                    write!(f, "assert")
+                } else {
+                    write!(f, "assert({err_code})")
+                }
+            }
 
             Self::FmpAdd => write!(f, "fmpadd"),
             Self::FmpUpdate => write!(f, "fmpupdate"),
@@ -582,6 +729,7 @@ impl fmt::Display for Operation {
             Self::Loop => write!(f, "loop"),
             Self::Call => writeln!(f, "call"),
             Self::SysCall => writeln!(f, "syscall"),
+            Self::Dyn => write!(f, "dyn"),
             Self::Span => write!(f, "span"),
             Self::End => write!(f, "end"),
             Self::Repeat => write!(f, "repeat"),
@@ -608,7 +756,13 @@ impl fmt::Display for Operation {
             Self::Ext2Mul => write!(f, "ext2mul"),
 
             // ----- u32 operations ---------------------------------------------------------------
-            Self::U32assert2 => write!(f, "u32assert2"),
+            Self::U32assert2(err_code) => {
+                if *err_code == ZERO {
+                    write!(f, "u32assert2")
+                } else {
+                    write!(f, "u32assert2({err_code})")
+                }
+            }
             Self::U32split => write!(f, "u32split"),
             Self::U32add => write!(f, "u32add"),
             Self::U32add3 => write!(f, "u32add3"),
@@ -0,0 +1,59 @@
+use super::{fmt, hasher, Digest, Felt, Operation};
+
+// DYN BLOCK
+// ================================================================================================
+/// Block for dynamic code where the target is specified by the stack.
+///
+/// Executes the code block referenced by the hash on top of the stack. Fails if the body is
+/// unavailable to the VM, or if the execution of the dynamically-specified code block fails.
+///
+/// The child of a Dyn block (the target specified by the stack) is not a part of the block, and
+/// thus, the hash of a Dyn block is a constant computed as:
+///
+/// > hash(padding || padding, domain=DYN_DOMAIN)
+///
+/// Where `padding` is 4 ZERO elements (256 bits).
+#[derive(Clone, Debug)]
+pub struct Dyn {}
+
+impl Dyn {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+    /// The domain of the Dyn block (used for control block hashing).
+    pub const DOMAIN: Felt = Felt::new(Operation::Dyn.op_code() as u64);
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [Dyn] block.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a hash of this code block.
+    pub fn hash(&self) -> Digest {
+        Self::dyn_hash()
+    }
+
+    /// Returns a hash of this code block.
+    ///
+    /// Since the hash of a Dyn block does not depend on its target, it can be computed without
+    /// instantiating the block.
+    pub fn dyn_hash() -> Digest {
+        hasher::merge_in_domain(&[Digest::default(), Digest::default()], Self::DOMAIN)
+    }
+}
+
+impl Default for Dyn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Dyn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dyn")
+    }
+}
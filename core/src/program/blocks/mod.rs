@@ -3,6 +3,7 @@ use crate::DecoratorList;
 use core::fmt;
 
 mod call_block;
+mod dyn_block;
 mod join_block;
 mod loop_block;
 mod proxy_block;
@@ -10,6 +11,7 @@ mod span_block;
 mod split_block;
 
 pub use call_block::Call;
+pub use dyn_block::Dyn;
 pub use join_block::Join;
 pub use loop_block::Loop;
 pub use proxy_block::Proxy;
@@ -29,6 +31,7 @@ pub enum CodeBlock {
     Split(Split),
     Loop(Loop),
     Call(Call),
+    Dyn(Dyn),
     Proxy(Proxy),
 }
 
@@ -71,6 +74,18 @@ impl CodeBlock {
         Self::Call(Call::new_syscall(fn_hash))
     }
 
+    /// Returns a new Dyn block which executes the code block specified by the hash on top of the
+    /// stack.
+    pub fn new_dyn() -> Self {
+        Self::Dyn(Dyn::new())
+    }
+
+    /// Returns a new Call block which calls a Dyn block, i.e., executes the code block specified
+    /// by the hash on top of the stack in a new execution context.
+    pub fn new_dyncall() -> Self {
+        Self::Call(Call::new(Dyn::dyn_hash()))
+    }
+
     /// TODO: add comments
     pub fn new_proxy(code_hash: Digest) -> Self {
         Self::Proxy(Proxy::new(code_hash))
@@ -92,6 +107,7 @@ impl CodeBlock {
             CodeBlock::Split(block) => block.hash(),
             CodeBlock::Loop(block) => block.hash(),
             CodeBlock::Call(block) => block.hash(),
+            CodeBlock::Dyn(block) => block.hash(),
             CodeBlock::Proxy(block) => block.hash(),
         }
     }
@@ -100,6 +116,7 @@ impl CodeBlock {
     pub fn domain(&self) -> Felt {
         match self {
             CodeBlock::Call(block) => block.domain(),
+            CodeBlock::Dyn(_) => Dyn::DOMAIN,
             CodeBlock::Join(_) => Join::DOMAIN,
             CodeBlock::Loop(_) => Loop::DOMAIN,
             CodeBlock::Span(_) => Span::DOMAIN,
@@ -117,6 +134,7 @@ impl fmt::Display for CodeBlock {
             CodeBlock::Split(block) => write!(f, "{block}"),
             CodeBlock::Loop(block) => write!(f, "{block}"),
             CodeBlock::Call(block) => write!(f, "{block}"),
+            CodeBlock::Dyn(block) => write!(f, "{block}"),
             CodeBlock::Proxy(block) => write!(f, "{block}"),
         }
     }
@@ -52,6 +52,14 @@ impl ProgramInfo {
     }
 }
 
+impl From<Digest> for ProgramInfo {
+    /// Returns the program info of a program with the specified hash compiled against an empty
+    /// kernel.
+    fn from(program_hash: Digest) -> Self {
+        Self::new(program_hash, Kernel::default())
+    }
+}
+
 impl From<Program> for ProgramInfo {
     fn from(program: Program) -> Self {
         let Program { root, kernel, .. } = program;
@@ -4,13 +4,13 @@ use super::{
         collections::{BTreeMap, Vec},
         Box,
     },
-    Felt, FieldElement, Operation,
+    Felt, FieldElement, OpCategory, Operation,
 };
 use core::fmt;
 use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 pub mod blocks;
-use blocks::CodeBlock;
+use blocks::{CodeBlock, Dyn};
 
 mod info;
 pub use info::ProgramInfo;
@@ -71,6 +71,60 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns MAST roots of procedures which are invoked via `call` from this program, but whose
+    /// code blocks are not in the code block table of this program.
+    ///
+    /// Such calls (also called phantom calls) are created when a procedure is invoked by a MAST
+    /// root which was not known to the assembler. The code blocks of these procedures must be
+    /// supplied via [Program::insert_code_block()] before the program can be executed. The roots
+    /// are returned in a consistent order, and each root is returned once.
+    pub fn unresolved_calls(&self) -> Vec<Digest> {
+        let mut roots = BTreeMap::new();
+        collect_unresolved_calls(&self.root, &self.cb_table, &mut roots);
+        self.cb_table
+            .0
+            .values()
+            .for_each(|block| collect_unresolved_calls(block, &self.cb_table, &mut roots));
+        roots.into_values().collect()
+    }
+
+    // PUBLIC MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided code block into the code block table of this program.
+    ///
+    /// This can be used to supply code blocks of procedures invoked via phantom calls (see
+    /// [Program::unresolved_calls()]). The hash of the program is not affected.
+    pub fn insert_code_block(&mut self, block: CodeBlock) {
+        self.cb_table.insert(block);
+    }
+
+    // INTROSPECTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of operations in this program.
+    ///
+    /// The count includes operations of all SPAN blocks in the program MAST as well as in the
+    /// code blocks of the code block table (i.e., procedures invoked via `call` or `syscall`
+    /// instructions), with every code block counted once. Thus, the count describes the size of
+    /// the program rather than the number of cycles needed to execute it: loop bodies are counted
+    /// once, both branches of conditional blocks are counted, and neither flow control operations
+    /// (e.g., JOIN, END) nor NOOPs executed to align operation groups are included.
+    pub fn num_ops(&self) -> usize {
+        self.op_counts().values().sum()
+    }
+
+    /// Returns the number of operations in this program grouped by [OpCategory].
+    ///
+    /// Operations are counted in the same way as by [Program::num_ops()]. Categories which do not
+    /// have any operations in this program are not included in the returned map.
+    pub fn op_counts(&self) -> BTreeMap<OpCategory, usize> {
+        let mut counts = BTreeMap::new();
+        count_ops(&self.root, &mut counts);
+        self.cb_table.0.values().for_each(|block| count_ops(block, &mut counts));
+        counts
+    }
 }
 
 impl fmt::Display for Program {
@@ -79,6 +133,59 @@ impl fmt::Display for Program {
     }
 }
 
+/// Adds the operations of the specified code block and all of its nested blocks to the counts.
+///
+/// Call, dyn and proxy blocks do not contain operations, as the code they refer to is not a part
+/// of the block.
+fn count_ops(block: &CodeBlock, counts: &mut BTreeMap<OpCategory, usize>) {
+    match block {
+        CodeBlock::Span(span) => {
+            let ops = span.op_batches().iter().flat_map(|batch| batch.ops());
+            ops.for_each(|op| *counts.entry(op.category()).or_insert(0) += 1);
+        }
+        CodeBlock::Join(join) => {
+            count_ops(join.first(), counts);
+            count_ops(join.second(), counts);
+        }
+        CodeBlock::Split(split) => {
+            count_ops(split.on_true(), counts);
+            count_ops(split.on_false(), counts);
+        }
+        CodeBlock::Loop(loop_block) => count_ops(loop_block.body(), counts),
+        CodeBlock::Call(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+    }
+}
+
+/// Adds MAST roots of procedures called from the specified code block (and all of its nested
+/// blocks) which are not present in the provided code block table to `roots`.
+///
+/// Syscall targets are not included as they are resolved via the kernel, and neither are dynamic
+/// calls as their targets are not known until runtime.
+fn collect_unresolved_calls(
+    block: &CodeBlock,
+    cb_table: &CodeBlockTable,
+    roots: &mut BTreeMap<[u8; 32], Digest>,
+) {
+    match block {
+        CodeBlock::Join(join) => {
+            collect_unresolved_calls(join.first(), cb_table, roots);
+            collect_unresolved_calls(join.second(), cb_table, roots);
+        }
+        CodeBlock::Split(split) => {
+            collect_unresolved_calls(split.on_true(), cb_table, roots);
+            collect_unresolved_calls(split.on_false(), cb_table, roots);
+        }
+        CodeBlock::Loop(loop_block) => collect_unresolved_calls(loop_block.body(), cb_table, roots),
+        CodeBlock::Call(call) => {
+            let fn_hash = call.fn_hash();
+            if !call.is_syscall() && fn_hash != Dyn::dyn_hash() && !cb_table.has(fn_hash) {
+                roots.insert(fn_hash.into(), fn_hash);
+            }
+        }
+        CodeBlock::Span(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
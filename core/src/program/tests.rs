@@ -1,4 +1,7 @@
-use super::{Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
+use super::{
+    CodeBlock, CodeBlockTable, Deserializable, Digest, Felt, Kernel, OpCategory, Operation,
+    Program, ProgramInfo, Serializable,
+};
 use crate::Word;
 use proptest::prelude::*;
 use rand_utils::prng_array;
@@ -24,6 +27,43 @@ proptest! {
     }
 }
 
+#[test]
+fn program_op_counts() {
+    // a procedure invoked via `call` is stored in the code block table
+    let callee = CodeBlock::new_span(vec![Operation::HPerm, Operation::Drop]);
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+
+    let branches = CodeBlock::new_split(
+        CodeBlock::new_span(vec![Operation::U32add, Operation::Drop]),
+        CodeBlock::new_call(callee.hash()),
+    );
+    let loop_block = CodeBlock::new_loop(CodeBlock::new_span(vec![
+        Operation::Push(Felt::new(3)),
+        Operation::Mul,
+        Operation::Dup0,
+        Operation::Noop,
+    ]));
+    let root = CodeBlock::new_join([
+        CodeBlock::new_span(vec![Operation::Push(Felt::new(1)), Operation::Pad, Operation::Add]),
+        CodeBlock::new_join([branches, loop_block]),
+    ]);
+    let program = Program::with_kernel(root, Kernel::default(), cb_table);
+
+    // flow control operations are not counted, and every code block is counted once
+    assert_eq!(11, program.num_ops());
+
+    let counts = program.op_counts();
+    assert_eq!(Some(&1), counts.get(&OpCategory::System));
+    assert_eq!(Some(&2), counts.get(&OpCategory::Field));
+    assert_eq!(Some(&1), counts.get(&OpCategory::U32));
+    assert_eq!(Some(&4), counts.get(&OpCategory::StackManipulation));
+    assert_eq!(Some(&2), counts.get(&OpCategory::InputOutput));
+    assert_eq!(Some(&1), counts.get(&OpCategory::Crypto));
+    assert_eq!(None, counts.get(&OpCategory::FlowControl));
+    assert_eq!(program.num_ops(), counts.values().sum::<usize>());
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
@@ -8,6 +8,12 @@ use core::slice;
 ///
 /// The program execution expects the inputs to be a stack on the VM, and it will be stored in
 /// reversed order on this struct.
+///
+/// When stack inputs are created from a list of values (e.g., via [StackInputs::new()] or
+/// [StackInputs::try_from_values()]), the values are pushed onto the stack one by one. Thus, the
+/// last value of the list ends up at the top of the stack (position 0), and the first value ends
+/// up at the deepest position. For example, inputs created from `[1, 2, 3]` result in the stack
+/// `[3, 2, 1]` (top first). To use the opposite ordering, apply [StackInputs::reversed()].
 #[derive(Clone, Debug, Default)]
 pub struct StackInputs {
     values: Vec<Felt>,
@@ -18,6 +24,8 @@ impl StackInputs {
     // --------------------------------------------------------------------------------------------
 
     /// Returns `[StackInputs]` from a list of values, reversing them into a stack.
+    ///
+    /// The last value of the list will be at the top of the stack.
     pub fn new(mut values: Vec<Felt>) -> Self {
         values.reverse();
         Self { values }
@@ -39,10 +47,37 @@ impl StackInputs {
             .map(Self::new)
     }
 
+    /// Returns these stack inputs with the specified value pushed onto the top of the stack.
+    ///
+    /// The value will be at the top of the stack (position 0) when the program starts executing.
+    pub fn push_top(mut self, value: Felt) -> Self {
+        self.values.insert(0, value);
+        self
+    }
+
+    /// Returns these stack inputs with the specified value placed at the bottom of the stack.
+    ///
+    /// The value will be deeper in the stack than any of the values already in these inputs.
+    pub fn push_bottom(mut self, value: Felt) -> Self {
+        self.values.push(value);
+        self
+    }
+
+    /// Returns these stack inputs with the order of the values reversed.
+    ///
+    /// This can be used to create stack inputs from a list of values in which the first value is
+    /// the top of the stack, e.g., `StackInputs::new(values).reversed()`.
+    pub fn reversed(mut self) -> Self {
+        self.values.reverse();
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
     /// Returns the initial stack values in stack/reversed order.
+    ///
+    /// The first returned value is the top of the stack.
     pub fn values(&self) -> &[Felt] {
         &self.values
     }
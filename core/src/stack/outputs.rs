@@ -83,6 +83,20 @@ impl StackOutputs {
             .expect("failed to convert vector to array")
     }
 
+    /// Returns the values which were on the stack beyond the top 16 elements at the end of
+    /// execution (i.e., the values in the overflow table), ordered as on the stack. Thus, the
+    /// deepest stack item is in the last position.
+    pub fn stack_overflow_values(&self) -> &[u64] {
+        self.stack.get(STACK_TOP_SIZE..).unwrap_or_default()
+    }
+
+    /// Returns the full state of the stack at the end of execution converted to integers, including
+    /// the values in the overflow table. The value at the top of the stack is in the first
+    /// position.
+    pub fn to_ints(&self) -> Vec<u64> {
+        self.stack.clone()
+    }
+
     /// Returns the overflow address outputs, which are the addresses required to reconstruct the
     /// overflow table (when combined with the stack overflow values) converted to integers.
     pub fn overflow_addrs(&self) -> &[u64] {
@@ -1,4 +1,4 @@
-use super::{Felt, StarkField};
+use super::{chiplets::hasher::Digest, errors::HexParseError, Felt, StarkField};
 use core::fmt::{self, Write};
 use core::{
     fmt::Debug,
@@ -149,3 +149,101 @@ pub fn write_hex_bytes(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result
     }
     Ok(())
 }
+
+// DIGEST HEX
+// ================================================================================================
+
+/// Conversion of digests to and from hex strings.
+///
+/// The hex representation of a digest consists of 64 lowercase hex characters encoding the 4
+/// elements of the digest as little-endian 8-byte integers. This is the same representation as
+/// the one produced by the [Display](core::fmt::Display) implementation of the digest.
+pub trait DigestHex: Sized {
+    /// Returns the hex representation of this digest prefixed with `0x`.
+    fn to_hex(&self) -> String;
+
+    /// Parses a digest from the specified hex string. The `0x` prefix is optional.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string does not consist of exactly 64 hex characters (after the optional prefix).
+    /// - Any of the encoded elements is not smaller than the field modulus.
+    fn from_hex(hex: &str) -> Result<Self, HexParseError>;
+}
+
+impl DigestHex for Digest {
+    fn to_hex(&self) -> String {
+        format!("0x{self}")
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, HexParseError> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        if hex.len() != 64 {
+            return Err(HexParseError::InvalidLength(64, hex.len()));
+        }
+
+        let mut bytes = [0u8; 32];
+        for (byte, chunk) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = (hex_value(chunk[0])? << 4) | hex_value(chunk[1])?;
+        }
+
+        let mut elements = [Felt::new(0); 4];
+        for (element, chunk) in elements.iter_mut().zip(bytes.chunks(8)) {
+            let value = u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+            if value >= Felt::MODULUS {
+                return Err(HexParseError::NotFieldElement(value));
+            }
+            *element = Felt::new(value);
+        }
+
+        Ok(elements.into())
+    }
+}
+
+/// Returns the value of the specified ASCII hex character.
+fn hex_value(c: u8) -> Result<u8, HexParseError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(HexParseError::InvalidChar(c as char)),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Digest, DigestHex, Felt, HexParseError, StarkField};
+
+    #[test]
+    fn digest_hex_roundtrip() {
+        let digest: Digest =
+            [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(u64::MAX >> 1)].into();
+        let hex = "0x\
+            0100000000000000\
+            0200000000000000\
+            0300000000000000\
+            ffffffffffffff7f";
+
+        assert_eq!(hex, digest.to_hex());
+        assert_eq!(&hex[2..], format!("{digest}"));
+        assert_eq!(Ok(digest), Digest::from_hex(hex));
+        assert_eq!(Ok(digest), Digest::from_hex(&hex[2..]));
+        assert_eq!(Ok(digest), Digest::from_hex(&hex.to_uppercase().replace("0X", "0x")));
+    }
+
+    #[test]
+    fn digest_hex_invalid() {
+        let hex = "01000000000000000200000000000000030000000000000004000000000000";
+        assert_eq!(Err(HexParseError::InvalidLength(64, 62)), Digest::from_hex(hex));
+
+        let hex = "0100000000000000020000000000000003000000000000000400000000000g00";
+        assert_eq!(Err(HexParseError::InvalidChar('g')), Digest::from_hex(hex));
+
+        // the last element is equal to the field modulus
+        let hex = "01000000000000000200000000000000030000000000000001000000ffffffff";
+        assert_eq!(Err(HexParseError::NotFieldElement(Felt::MODULUS)), Digest::from_hex(hex));
+    }
+}
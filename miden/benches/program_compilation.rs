@@ -7,13 +7,14 @@ fn program_compilation(c: &mut Criterion) {
     let mut group = c.benchmark_group("program_compilation");
     group.measurement_time(Duration::from_secs(10));
 
-    group.bench_function("sha256", |bench| {
-        let source = "
-            use.std::crypto::hashes::sha256
+    let source = "
+        use.std::crypto::hashes::sha256
+
+        begin
+            exec.sha256::hash_2to1
+        end";
 
-            begin
-                exec.sha256::hash_2to1
-            end";
+    group.bench_function("sha256", |bench| {
         bench.iter(|| {
             let assembler = Assembler::default()
                 .with_library(&StdLibrary::default())
@@ -22,6 +23,16 @@ fn program_compilation(c: &mut Criterion) {
         });
     });
 
+    // the same assembler is used for all compilations; thus, starting with the second
+    // compilation, all stdlib procedures are taken from the assembler's procedure cache
+    group.bench_function("sha256_cached", |bench| {
+        let assembler = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .expect("failed to load stdlib");
+        assembler.compile(source).expect("Failed to compile test source.");
+        bench.iter(|| assembler.compile(source).expect("Failed to compile test source."));
+    });
+
     group.finish();
 }
 
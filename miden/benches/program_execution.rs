@@ -24,5 +24,59 @@ fn program_execution(c: &mut Criterion) {
     group.finish();
 }
 
+fn memory_hashing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("memory_hashing");
+    group.measurement_time(Duration::from_secs(10));
+
+    // 1 KiB of memory is 128 field elements (i.e., 32 words) starting at address 1000; the memory
+    // is not initialized, and thus, all hashed words are zeros.
+    let assembler = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .expect("failed to load stdlib");
+
+    // hashing memory via mem_stream takes 6 cycles per pair of words
+    group.bench_function("hash_1kib_mem_stream", |bench| {
+        let source = "
+            use.std::crypto::hashes::native
+
+            begin
+                push.1032 push.1000
+                exec.native::hash_memory
+            end";
+        let program = assembler.compile(source).expect("Failed to compile test source.");
+        bench.iter(|| execute(&program, StackInputs::default(), MemAdviceProvider::default()));
+    });
+
+    // hashing memory via mem_loadw takes 16 cycles per pair of words
+    group.bench_function("hash_1kib_mem_loadw", |bench| {
+        let source = "
+            begin
+                # prepare hasher state: [C, B, A, start_addr, end_addr]
+                push.1032 push.1000 push.0.0.0.0 padw padw
+
+                dup.13 dup.13 neq
+                while.true
+                    # load the next two words into the rate portion of the hasher state
+                    dup.12 add.1 mem_loadw
+                    swapw dup.12 mem_loadw swapw
+
+                    # advance the address and absorb the words
+                    movup.12 add.2 movdn.12
+                    hperm
+
+                    dup.13 dup.13 neq
+                end
+
+                # keep only the hash result
+                dropw swapw dropw movup.4 drop movup.4 drop
+            end";
+        let program = assembler.compile(source).expect("Failed to compile test source.");
+        bench.iter(|| execute(&program, StackInputs::default(), MemAdviceProvider::default()));
+    });
+
+    group.finish();
+}
+
 criterion_group!(sha256_group, program_execution);
-criterion_main!(sha256_group);
+criterion_group!(memory_hashing_group, memory_hashing);
+criterion_main!(sha256_group, memory_hashing_group);
@@ -0,0 +1,304 @@
+use core::{cmp, fmt};
+use processor::{
+    utils::{
+        collections::{BTreeMap, Vec},
+        string::{String, ToString},
+    },
+    AdviceProvider, AsmOpInfo, ExecutionError, Operation, Program, StackInputs, TraceLenSummary,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Separator between the path of a module and the name of a procedure defined in it.
+const PATH_DELIM: &str = "::";
+
+// ANALYSIS
+// ================================================================================================
+
+/// Executes the specified program against the specified inputs and returns details of the
+/// execution, such as the number of executed cycles and the length of the resulting execution
+/// trace.
+///
+/// Cycles are attributed to assembly instructions and procedures via the AsmOp decorators of the
+/// program; thus, the program is expected to have been compiled in debug mode. For programs
+/// compiled without debug mode, only the total numbers of cycles and the trace lengths are
+/// reported.
+pub fn analyze<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+) -> Result<ExecutionDetails, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut vm_state_iterator = processor::execute_iter(program, stack_inputs, advice_provider);
+    let mut execution_details = ExecutionDetails::default();
+    let mut procedure_cycles = BTreeMap::<String, usize>::new();
+
+    for state in vm_state_iterator.by_ref() {
+        let vm_state = state?;
+        if matches!(vm_state.op, Some(Operation::Noop)) {
+            execution_details.total_noops += 1;
+        }
+        if let Some(asmop_info) = vm_state.asmop {
+            *procedure_cycles.entry(procedure_path(&asmop_info)).or_default() += 1;
+            execution_details.record_asmop(asmop_info);
+        }
+        execution_details.total_vm_cycles = vm_state.clk;
+    }
+    execution_details.trace_len_summary = *vm_state_iterator.trace_len_summary();
+
+    // procedures which take the most cycles are listed first
+    let mut procedure_stats = procedure_cycles
+        .into_iter()
+        .map(|(name, cycles)| ProcedureStats::new(name, cycles))
+        .collect::<Vec<_>>();
+    procedure_stats.sort_by_key(|stats| cmp::Reverse(stats.total_vm_cycles()));
+    execution_details.procedure_stats = procedure_stats;
+
+    Ok(execution_details)
+}
+
+// EXECUTION DETAILS
+// ================================================================================================
+
+/// Contains details of executing a program, used for program analysis.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ExecutionDetails {
+    /// Number of VM cycles it took to execute the entire program.
+    total_vm_cycles: u32,
+    /// Number of noops executed as part of a program.
+    total_noops: usize,
+    /// Statistics about individual assembly operations executed by the VM, see [AsmOpStats].
+    asm_op_stats: Vec<AsmOpStats>,
+    /// Statistics about procedures executed by the VM, see [ProcedureStats].
+    procedure_stats: Vec<ProcedureStats>,
+    /// Lengths of the execution trace segments before padding.
+    trace_len_summary: TraceLenSummary,
+}
+
+impl ExecutionDetails {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns total vm cycles to execute a program
+    pub fn total_vm_cycles(&self) -> u32 {
+        self.total_vm_cycles
+    }
+
+    /// Returns total noops executed as part of a program
+    pub fn total_noops(&self) -> usize {
+        self.total_noops
+    }
+
+    /// Returns [AsmOpStats] that contains assembly instructions and the number of vm cycles
+    /// it takes to execute them and the number of times they are run as part of the given program.
+    pub fn asm_op_stats(&self) -> &[AsmOpStats] {
+        &self.asm_op_stats
+    }
+
+    /// Returns [ProcedureStats] of all procedures executed by the program, sorted by the number of
+    /// cycles attributed to them in descending order.
+    pub fn procedure_stats(&self) -> &[ProcedureStats] {
+        &self.procedure_stats
+    }
+
+    /// Returns the number of cycles attributed to the procedure with the specified path (e.g.,
+    /// `std::crypto::hashes::sha256::hash_2to1`, or `#main` for the program body).
+    pub fn procedure_cycles(&self, path: &str) -> usize {
+        self.procedure_stats
+            .iter()
+            .find(|proc_info| proc_info.name() == path)
+            .map_or(0, |proc_info| proc_info.total_vm_cycles())
+    }
+
+    /// Returns the lengths of the execution trace segments before padding.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the length of the execution trace after padding.
+    pub fn padded_trace_len(&self) -> usize {
+        self.trace_len_summary.padded_trace_len()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records a new occurrence of asmop in the sorted asmop stats vector of this program info.
+    /// If the asmop is already in the list, increments its frequency by one.
+    /// If the asmop is not already in the list, add it at the appropriate index to keep the
+    /// list sorted alphabetically.
+    fn record_asmop(&mut self, asmop_info: AsmOpInfo) {
+        match &mut self
+            .asm_op_stats
+            .binary_search_by_key(&(asmop_info.op_generalized()), |asmop: &AsmOpStats| {
+                asmop.op().to_string()
+            }) {
+            Ok(pos) => {
+                if asmop_info.cycle_idx() == 1 {
+                    self.asm_op_stats[*pos].incr_frequency();
+                    self.asm_op_stats[*pos].add_vm_cycles(asmop_info.num_cycles());
+                }
+            }
+            Err(pos) => {
+                self.asm_op_stats.insert(
+                    *pos,
+                    AsmOpStats::new(
+                        asmop_info.op_generalized(),
+                        1,
+                        asmop_info.num_cycles() as usize,
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl fmt::Display for ExecutionDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total_vm_cycles = self.total_vm_cycles();
+        let total_noops = self.total_noops();
+        let asm_op_stats = self.asm_op_stats();
+        let trace_len_summary = self.trace_len_summary();
+        let chiplets = trace_len_summary.chiplets_trace_len();
+        writeln!(f, "Total Number of VM Cycles: {}\n", total_vm_cycles)?;
+        writeln!(f, "Total Number of NOOPs executed: {}\n", total_noops)?;
+        writeln!(
+            f,
+            "Trace length: {} (padded from {}, {}% padding)\n",
+            trace_len_summary.padded_trace_len(),
+            trace_len_summary.trace_len(),
+            trace_len_summary.padding_percentage()
+        )?;
+        writeln!(f, "{0: <20} | {1: <20}", "Trace Segment", "Rows")?;
+        writeln!(f, "{0: <20} | {1: <20}", "main", trace_len_summary.main_trace_len())?;
+        writeln!(f, "{0: <20} | {1: <20}", "range checker", trace_len_summary.range_trace_len())?;
+        writeln!(f, "{0: <20} | {1: <20}", "hasher", chiplets.hash_chiplet_len())?;
+        writeln!(f, "{0: <20} | {1: <20}", "bitwise", chiplets.bitwise_chiplet_len())?;
+        writeln!(f, "{0: <20} | {1: <20}", "memory", chiplets.memory_chiplet_len())?;
+        writeln!(f, "{0: <20} | {1: <20}\n", "kernel rom", chiplets.kernel_rom_len())?;
+        writeln!(f, "{0: <60} | {1: <20}", "Procedure", "Total Cycles")?;
+        for proc_info in self.procedure_stats() {
+            writeln!(f, "{0: <60} | {1: <20}", proc_info.name(), proc_info.total_vm_cycles())?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{0: <20} | {1: <20} | {2: <20} | {3: <20}",
+            "AsmOp", "Frequency", "Total Cycles", "Avg Instruction Cycles"
+        )?;
+        for op_info in asm_op_stats {
+            writeln!(
+                f,
+                "{0: <20} | {1: <20} | {2: <20} | {3: <20.2}",
+                op_info.op(),
+                op_info.frequency(),
+                op_info.total_vm_cycles(),
+                op_info.total_vm_cycles() as f64 / op_info.frequency() as f64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ASMOP STATS
+// ================================================================================================
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AsmOpStats {
+    op: String,
+    frequency: usize,
+    total_vm_cycles: usize,
+}
+
+impl AsmOpStats {
+    /// Returns [AsmOpStats] instantiated with the specified assembly instruction string,
+    /// number of cycles it takes to execute the assembly instruction and the number of times
+    /// the assembly instruction is executed.
+    pub fn new(op: String, frequency: usize, total_vm_cycles: usize) -> Self {
+        Self {
+            op,
+            frequency,
+            total_vm_cycles,
+        }
+    }
+
+    /// Returns the assembly instruction corresponding to this decorator.
+    pub fn op(&self) -> &String {
+        &self.op
+    }
+
+    /// Returns the number of times this AsmOp is executed as part of a program.
+    pub fn frequency(&self) -> usize {
+        self.frequency
+    }
+
+    /// Returns the combined vm cycles all occurrences of this AsmOp take.
+    pub fn total_vm_cycles(&self) -> usize {
+        self.total_vm_cycles
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Increments the frequency of this AsmOp.
+    fn incr_frequency(&mut self) {
+        self.frequency += 1;
+    }
+
+    /// Increments the total vm cycles of this AsmOp by the specified number of vm cycles.
+    fn add_vm_cycles(&mut self, num_cycles: u8) {
+        self.total_vm_cycles += num_cycles as usize;
+    }
+}
+
+// PROCEDURE STATS
+// ================================================================================================
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ProcedureStats {
+    name: String,
+    total_vm_cycles: usize,
+}
+
+impl ProcedureStats {
+    /// Returns [ProcedureStats] instantiated with the specified procedure path and the number of
+    /// cycles attributed to the procedure.
+    pub fn new(name: String, total_vm_cycles: usize) -> Self {
+        Self {
+            name,
+            total_vm_cycles,
+        }
+    }
+
+    /// Returns the full path of this procedure (e.g., `std::math::u64::checked_add`), or the
+    /// procedure name for procedures defined in the executable module of a program.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of cycles spent executing instructions of this procedure, excluding the
+    /// cycles spent in the procedures it invokes.
+    pub fn total_vm_cycles(&self) -> usize {
+        self.total_vm_cycles
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the full path of the procedure containing the specified assembly instruction.
+///
+/// Cycles are attributed to the procedure which contains the executed instruction, and thus,
+/// cycles spent in the procedures it invokes are attributed to the invoked procedures.
+fn procedure_path(asmop_info: &AsmOpInfo) -> String {
+    let mut path = String::new();
+    if let Some(module_path) = asmop_info.module_path() {
+        path.push_str(module_path);
+        path.push_str(PATH_DELIM);
+    }
+    path.push_str(asmop_info.context_name());
+    path
+}
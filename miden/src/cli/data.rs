@@ -1,14 +1,9 @@
 use assembly::{Library, MaslLibrary};
 use miden::{
-    crypto::{MerkleStore, MerkleTree, SimpleSmt},
-    math::Felt,
     utils::{Deserializable, SliceReader},
-    AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, Program, StackInputs,
-    StackOutputs, Word,
+    Assembler, Digest, ExecutionProof, InputFile, OutputFile, Program,
 };
-use serde_derive::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
     fs,
     io::Write,
     path::{Path, PathBuf},
@@ -32,301 +27,59 @@ impl Debug {
     }
 }
 
-// MERKLE DATA
-// ================================================================================================
-
-/// Struct used to deserialize merkle data from input file. Merkle data can be represented as a
-/// merkle tree or a Sparse Merkle Tree.
-#[derive(Deserialize, Debug)]
-pub enum MerkleData {
-    /// String representation of a merkle tree.  The merkle tree is represented as a vector of
-    /// 32 byte hex strings where each string represents a leaf in the tree.
-    #[serde(rename = "merkle_tree")]
-    MerkleTree(Vec<String>),
-    /// String representation of a Sparse Merkle Tree. The Sparse Merkle Tree is represented as a
-    /// vector of tuples where each tuple consists of a u64 node index and a 32 byte hex string
-    /// representing the value of the node.
-    #[serde(rename = "sparse_merkle_tree")]
-    SparseMerkleTree(Vec<(u64, String)>),
-}
-
 // INPUT FILE
 // ================================================================================================
 
-// TODO consider using final types instead of string representations.
-/// Input file struct that is used to deserialize input data from file. It consists of four
-/// components:
-/// - operand_stack
-/// - advice_stack
-/// - advice_map
-/// - merkle_store
-#[derive(Deserialize, Debug)]
-pub struct InputFile {
-    /// String representation of the initial operand stack, composed of chained field elements.
-    pub operand_stack: Vec<String>,
-    /// Opitonal string representation of the initial advice stack, composed of chained field
-    /// elements.
-    pub advice_stack: Option<Vec<String>>,
-    /// Optional map of 32 byte hex strings to vectors of u64s representing the initial advice map.
-    pub advice_map: Option<HashMap<String, Vec<u64>>>,
-    /// Optional vector of merkle data which will be loaded into the initial merkle store. Merkle
-    /// data is represented as 32 byte hex strings and node indexes are represented as u64s.
-    pub merkle_store: Option<Vec<MerkleData>>,
-}
-
-/// Helper methods to interact with the input file
-impl InputFile {
-    pub fn read(inputs_path: &Option<PathBuf>, program_path: &Path) -> Result<Self, String> {
-        // if file not specified explicitly and corresponding file with same name as program_path
-        // with '.inputs' extension does't exist, set operand_stack to empty vector
-        if !inputs_path.is_some() && !program_path.with_extension("inputs").exists() {
-            return Ok(Self {
-                operand_stack: Vec::new(),
-                advice_stack: Some(Vec::new()),
-                advice_map: Some(HashMap::new()),
-                merkle_store: None,
-            });
-        }
-
-        // If inputs_path has been provided then use this as path. Alternatively we will
-        // replace the program_path extension with `.inputs` and use this as a default.
-        let path = match inputs_path {
-            Some(path) => path.clone(),
-            None => program_path.with_extension("inputs"),
-        };
-
-        println!("Reading input file `{}`", path.display());
-
-        // read input file to string
-        let inputs_file = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to open input file `{}` - {}", path.display(), err))?;
-
-        // deserialize input data
-        let inputs: InputFile = serde_json::from_str(&inputs_file)
-            .map_err(|err| format!("Failed to deserialize input data - {}", err))?;
-
-        Ok(inputs)
-    }
-
-    /// Parse advice provider data from the input file.
-    pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
-        let mut advice_inputs = AdviceInputs::default();
-
-        let stack = self
-            .parse_advice_stack()
-            .map_err(|e| format!("failed to parse advice provider: {e}"))?;
-        advice_inputs = advice_inputs.with_stack_values(stack).map_err(|e| e.to_string())?;
-
-        if let Some(map) = self
-            .parse_advice_map()
-            .map_err(|e| format!("failed to parse advice provider: {e}"))?
-        {
-            advice_inputs = advice_inputs.with_map(map);
-        }
-
-        if let Some(merkle_store) = self
-            .parse_merkle_store()
-            .map_err(|e| format!("failed to parse advice provider: {e}"))?
-        {
-            advice_inputs = advice_inputs.with_merkle_store(merkle_store);
-        }
-
-        Ok(MemAdviceProvider::from(advice_inputs))
-    }
-
-    /// Parse advice stack data from the input file.
-    fn parse_advice_stack(&self) -> Result<Vec<u64>, String> {
-        self.advice_stack
-            .as_ref()
-            .map(Vec::as_slice)
-            .unwrap_or(&[])
-            .iter()
-            .map(|v| {
-                v.parse::<u64>()
-                    .map_err(|e| format!("failed to parse advice stack value `{v}` - {e}"))
-            })
-            .collect::<Result<Vec<_>, _>>()
-    }
-
-    /// Parse advice map data from the input file.
-    fn parse_advice_map(&self) -> Result<Option<HashMap<[u8; 32], Vec<Felt>>>, String> {
-        let advice_map = match &self.advice_map {
-            Some(advice_map) => advice_map,
-            None => return Ok(None),
-        };
-
-        let map = advice_map
-            .iter()
-            .map(|(k, v)| {
-                // decode hex key
-                let mut key = [0u8; 32];
-                hex::decode_to_slice(k, &mut key)
-                    .map_err(|e| format!("failed to decode advice map key `{k}` - {e}"))?;
-
-                // convert values to Felt
-                let values = v
-                    .iter()
-                    .map(|v| {
-                        Felt::try_from(*v).map_err(|e| {
-                            format!("failed to convert advice map value `{v}` to Felt - {e}")
-                        })
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
-                Ok((key, values))
-            })
-            .collect::<Result<HashMap<[u8; 32], Vec<Felt>>, String>>()?;
-
-        Ok(Some(map))
-    }
-
-    /// Parse merkle store data from the input file.
-    fn parse_merkle_store(&self) -> Result<Option<MerkleStore>, String> {
-        let merkle_data = match &self.merkle_store {
-            Some(merkle_data) => merkle_data,
-            None => return Ok(None),
-        };
-
-        let mut merkle_store = MerkleStore::default();
-        for data in merkle_data {
-            match data {
-                MerkleData::MerkleTree(data) => {
-                    let leaves = Self::parse_merkle_tree(data)?;
-                    let tree = MerkleTree::new(leaves)
-                        .map_err(|e| format!("failed to parse a Merkle tree: {e}"))?;
-                    merkle_store.extend(tree.inner_nodes());
-                }
-                MerkleData::SparseMerkleTree(data) => {
-                    let entries = Self::parse_sparse_merkle_tree(data)?;
-                    let tree = SimpleSmt::with_leaves(u64::BITS as u8, entries)
-                        .map_err(|e| format!("failed to parse a Sparse Merkle Tree: {e}"))?;
-                    merkle_store.extend(tree.inner_nodes());
-                }
-            }
+/// Reads the input file of the program at the specified path.
+///
+/// If the path of the input file is not specified explicitly, the file with the same name as the
+/// program and the `.inputs` extension is used; if such a file does not exist, empty inputs are
+/// returned.
+pub fn read_input_file(
+    inputs_path: &Option<PathBuf>,
+    program_path: &Path,
+) -> Result<InputFile, String> {
+    let path = match inputs_path {
+        Some(path) => path.clone(),
+        None if program_path.with_extension("inputs").exists() => {
+            program_path.with_extension("inputs")
         }
+        None => return Ok(InputFile::default()),
+    };
 
-        Ok(Some(merkle_store))
-    }
-
-    /// Parse and return merkle tree leaves.
-    fn parse_merkle_tree(tree: &[String]) -> Result<Vec<Word>, String> {
-        tree.iter()
-            .map(|v| {
-                let leaf = Self::parse_word(v)?;
-                Ok(leaf)
-            })
-            .collect()
-    }
-
-    /// Parse and return Sparse Merkle Tree entries.
-    fn parse_sparse_merkle_tree(tree: &[(u64, String)]) -> Result<Vec<(u64, Word)>, String> {
-        tree.iter()
-            .map(|(index, v)| {
-                let leaf = Self::parse_word(v)?;
-                Ok((*index, leaf))
-            })
-            .collect()
-    }
+    println!("Reading input file `{}`", path.display());
 
-    /// Parse a `Word` from a hex string.
-    pub fn parse_word(word_hex: &str) -> Result<Word, String> {
-        let mut word_data = [0u8; 32];
-        hex::decode_to_slice(word_hex, &mut word_data)
-            .map_err(|e| format!("failed to decode `Word` from hex {word_hex} - {e}"))?;
-        let mut word = Word::default();
-        for (i, value) in word_data.chunks(8).enumerate() {
-            word[i] = Felt::try_from(value).map_err(|e| {
-                format!("failed to convert `Word` data {word_hex} (element {i}) to Felt - {e}")
-            })?;
-        }
-        Ok(word)
-    }
-
-    /// Parse and return the stack inputs for the program.
-    pub fn parse_stack_inputs(&self) -> Result<StackInputs, String> {
-        let stack_inputs = self
-            .operand_stack
-            .iter()
-            .map(|v| v.parse::<u64>().map_err(|e| e.to_string()))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        StackInputs::try_from_values(stack_inputs).map_err(|e| e.to_string())
-    }
+    InputFile::from_path(&path).map_err(|err| format!("Failed to read input file - {err}"))
 }
 
 // OUTPUT FILE
 // ================================================================================================
 
-/// Output file struct
-#[derive(Deserialize, Serialize, Debug)]
-pub struct OutputFile {
-    pub stack: Vec<String>,
-    pub overflow_addrs: Vec<String>,
+/// Reads the output file of the program at the specified path.
+///
+/// If the path of the output file is not specified explicitly, the file with the same name as the
+/// program and the `.outputs` extension is used.
+pub fn read_output_file(
+    outputs_path: &Option<PathBuf>,
+    program_path: &Path,
+) -> Result<OutputFile, String> {
+    let path = match outputs_path {
+        Some(path) => path.clone(),
+        None => program_path.with_extension("outputs"),
+    };
+
+    println!("Reading output file `{}`", path.display());
+
+    OutputFile::from_path(&path).map_err(|err| format!("Failed to read output file - {err}"))
 }
 
-/// Helper methods to interact with the output file
-impl OutputFile {
-    /// Returns a new [OutputFile] from the specified outputs vectors
-    pub fn new(stack_outputs: &StackOutputs) -> Self {
-        Self {
-            stack: stack_outputs.stack().iter().map(|&v| v.to_string()).collect::<Vec<String>>(),
-            overflow_addrs: stack_outputs
-                .overflow_addrs()
-                .iter()
-                .map(|&v| v.to_string())
-                .collect::<Vec<String>>(),
-        }
-    }
-
-    /// Read the output file
-    pub fn read(outputs_path: &Option<PathBuf>, program_path: &Path) -> Result<Self, String> {
-        // If outputs_path has been provided then use this as path.  Alternatively we will
-        // replace the program_path extension with `.outputs` and use this as a default.
-        let path = match outputs_path {
-            Some(path) => path.clone(),
-            None => program_path.with_extension("outputs"),
-        };
-
-        println!("Reading output file `{}`", path.display());
-
-        // read outputs file to string
-        let outputs_file = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to open outputs file `{}` - {}", path.display(), err))?;
-
-        // deserialize outputs data
-        let outputs: OutputFile = serde_json::from_str(&outputs_file)
-            .map_err(|err| format!("Failed to deserialize outputs data - {}", err))?;
-
-        Ok(outputs)
-    }
-
-    /// Write the output file
-    pub fn write(stack_outputs: &StackOutputs, path: &PathBuf) -> Result<(), String> {
-        // if path provided, create output file
-        println!("Creating output file `{}`", path.display());
+/// Writes the specified outputs into the output file at the specified path.
+pub fn write_output_file(outputs: &OutputFile, path: &Path) -> Result<(), String> {
+    println!("Writing data to output file `{}`", path.display());
 
-        let file = fs::File::create(&path).map_err(|err| {
-            format!("Failed to create output file `{}` - {}", path.display(), err)
-        })?;
-
-        println!("Writing data to output file");
-
-        // write outputs to output file
-        serde_json::to_writer_pretty(file, &Self::new(stack_outputs))
-            .map_err(|err| format!("Failed to write output data - {}", err))
-    }
-
-    /// Converts outputs vectors for stack and overflow addresses to [StackOutputs].
-    pub fn stack_outputs(&self) -> StackOutputs {
-        let stack = self.stack.iter().map(|v| v.parse::<u64>().unwrap()).collect::<Vec<u64>>();
-
-        let overflow_addrs = self
-            .overflow_addrs
-            .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
-
-        StackOutputs::new(stack, overflow_addrs)
-    }
+    outputs
+        .to_path(path)
+        .map_err(|err| format!("Failed to write output file - {err}"))
 }
 
 // PROGRAM FILE
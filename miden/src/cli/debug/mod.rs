@@ -1,4 +1,4 @@
-use super::data::{Debug, InputFile, Libraries, ProgramFile};
+use super::data::{read_input_file, Debug, Libraries, ProgramFile};
 use rustyline::{error::ReadlineError, Config, EditMode, Editor};
 use std::path::PathBuf;
 use structopt::StructOpt;
@@ -42,11 +42,11 @@ impl DebugCmd {
         println!("Debugging program with hash {}... ", hex::encode(program_hash));
 
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let input_data = read_input_file(&self.input_file, &self.assembly_file)?;
 
         // fetch the stack and program inputs from the arguments
-        let stack_inputs = input_data.parse_stack_inputs()?;
-        let advice_provider = input_data.parse_advice_provider()?;
+        let stack_inputs = input_data.stack_inputs().map_err(|err| err.to_string())?;
+        let advice_provider = input_data.advice_provider().map_err(|err| err.to_string())?;
 
         // Instantiate DebugExecutor
         let mut debug_executor = DebugExecutor::new(program, stack_inputs, advice_provider)?;
@@ -0,0 +1,144 @@
+use miden::{
+    examples::{self, Example},
+    ExecutionProof, ProgramInfo, ProofOptions,
+};
+use std::io::Write;
+use std::time::Instant;
+use structopt::StructOpt;
+
+// EXAMPLE OPTIONS
+// ================================================================================================
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "Examples", about = "Run an example miden program")]
+pub struct ExampleOptions {
+    #[structopt(subcommand)]
+    pub example: ExampleType,
+
+    /// Security level for execution proofs generated by the VM
+    #[structopt(short = "s", long = "security", default_value = "96bits")]
+    security: String,
+
+    /// Enable generation of proofs suitable for recursive verification
+    #[structopt(short = "r", long = "recursive")]
+    recursive: bool,
+}
+
+#[derive(StructOpt, Debug)]
+//#[structopt(about = "available examples")]
+pub enum ExampleType {
+    /// Compute a Fibonacci sequence of the specified length
+    Fib {
+        /// Length of Fibonacci sequence
+        #[structopt(short = "n", default_value = "1024")]
+        sequence_length: usize,
+    },
+    /// Prove membership of a leaf in a Merkle tree of the specified depth
+    Merkle {
+        /// Depth of the Merkle tree
+        #[structopt(short = "d", default_value = "16")]
+        depth: u8,
+    },
+    /// Count the steps of the Collatz sequence starting at the specified number
+    Collatz {
+        /// First term of the Collatz sequence
+        #[structopt(short = "n", default_value = "27")]
+        start: u32,
+    },
+}
+
+impl ExampleOptions {
+    pub fn get_proof_options(&self) -> ProofOptions {
+        match self.security.as_str() {
+            "96bits" => ProofOptions::with_96_bit_security(self.recursive),
+            "128bits" => ProofOptions::with_128_bit_security(self.recursive),
+            other => panic!("{} is not a valid security level", other),
+        }
+    }
+
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+
+        // configure logging
+        env_logger::Builder::new()
+            .format(|buf, record| writeln!(buf, "{}", record.args()))
+            .filter_level(log::LevelFilter::Debug)
+            .init();
+
+        let proof_options = self.get_proof_options();
+
+        // instantiate and prepare the example
+        let example = match self.example {
+            ExampleType::Fib { sequence_length } => {
+                let example = examples::fibonacci(sequence_length);
+                println!(
+                    "Generated a program to compute {}-th Fibonacci term; expected result: {}",
+                    sequence_length, example.expected_result[0]
+                );
+                example
+            }
+            ExampleType::Merkle { depth } => {
+                println!(
+                    "Generated a program to prove membership in a Merkle tree of depth {depth}"
+                );
+                examples::merkle_membership(depth)
+            }
+            ExampleType::Collatz { start } => {
+                let example = examples::collatz(start);
+                println!(
+                    "Generated a program to count the steps of the Collatz sequence starting at \
+                    {}; expected result: {}",
+                    start, example.expected_result[0]
+                );
+                example
+            }
+        };
+
+        let Example {
+            program,
+            stack_inputs,
+            advice_provider,
+            num_outputs,
+            expected_result,
+            ..
+        } = example;
+        println!("--------------------------------");
+
+        // execute the program and generate the proof of execution
+        let now = Instant::now();
+        let (stack_outputs, proof) =
+            miden::prove(&program, stack_inputs.clone(), advice_provider, proof_options).unwrap();
+        println!("--------------------------------");
+
+        println!(
+            "Executed program in {} ms",
+            //hex::encode(program.hash()), // TODO: include into message
+            now.elapsed().as_millis()
+        );
+        println!("Stack outputs: {:?}", stack_outputs.stack_truncated(num_outputs));
+        assert_eq!(
+            expected_result,
+            stack_outputs.stack_truncated(num_outputs),
+            "Program result was computed incorrectly"
+        );
+
+        // serialize the proof to see how big it is
+        let proof_bytes = proof.to_bytes();
+        println!("Execution proof size: {} KB", proof_bytes.len() / 1024);
+        println!("Execution proof security: {} bits", proof.security_level());
+        println!("--------------------------------");
+
+        // verify that executing a program with a given hash and given inputs
+        // results in the expected output
+        let proof = ExecutionProof::from_bytes(&proof_bytes).unwrap();
+        let now = Instant::now();
+        let program_info = ProgramInfo::from(program);
+
+        match miden::verify(program_info, stack_inputs, stack_outputs, proof) {
+            Ok(_) => println!("Execution verified in {} ms", now.elapsed().as_millis()),
+            Err(err) => println!("Failed to verify execution: {}", err),
+        }
+
+        Ok(())
+    }
+}
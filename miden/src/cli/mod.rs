@@ -1,14 +1,16 @@
 mod compile;
 mod data;
 mod debug;
+mod example;
 mod prove;
 mod repl;
 mod run;
 mod verify;
 
 pub use compile::CompileCmd;
-pub use data::InputFile;
+pub use data::read_input_file;
 pub use debug::DebugCmd;
+pub use example::ExampleOptions;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
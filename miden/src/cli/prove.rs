@@ -1,5 +1,5 @@
-use super::data::{Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
-use miden::ProofOptions;
+use super::data::{read_input_file, write_output_file, Debug, Libraries, ProgramFile, ProofFile};
+use miden::{OutputFile, ProofOptions};
 use std::{io::Write, path::PathBuf, time::Instant};
 use structopt::StructOpt;
 
@@ -67,20 +67,27 @@ impl ProveCmd {
         let program = ProgramFile::read(&self.assembly_file, &Debug::Off, libraries.libraries)?;
 
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let input_data = read_input_file(&self.input_file, &self.assembly_file)?;
 
         let program_hash: [u8; 32] = program.hash().into();
         println!("Proving program with hash {}...", hex::encode(program_hash));
         let now = Instant::now();
 
         // fetch the stack and program inputs from the arguments
-        let stack_inputs = input_data.parse_stack_inputs()?;
-        let advice_provider = input_data.parse_advice_provider()?;
+        let stack_inputs = input_data.stack_inputs().map_err(|err| err.to_string())?;
+        let advice_provider = input_data.advice_provider().map_err(|err| err.to_string())?;
 
         // execute program and generate proof
-        let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, advice_provider, self.get_proof_options())
-                .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let trace = processor::execute(&program, stack_inputs, advice_provider)
+            .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let outputs = OutputFile::new(
+            program.hash(),
+            trace.stack_outputs(),
+            trace.trace_len_summary().main_trace_len(),
+        );
+        let stack_outputs = trace.stack_outputs().clone();
+        let proof = prover::prove_trace(trace, self.get_proof_options())
+            .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
         println!(
             "Program with hash {} proved in {} ms",
@@ -94,13 +101,13 @@ impl ProveCmd {
         // provide outputs
         if let Some(output_path) = &self.output_file {
             // write all outputs to specified file.
-            OutputFile::write(&stack_outputs, output_path)?;
+            write_output_file(&outputs, output_path)?;
         } else {
             // if no output path was provided, get the stack outputs for printing to the screen.
             let stack = stack_outputs.stack_truncated(self.num_outputs).to_vec();
 
             // write all outputs to default location if none was provided
-            OutputFile::write(&stack_outputs, &self.assembly_file.with_extension("outputs"))?;
+            write_output_file(&outputs, &self.assembly_file.with_extension("outputs"))?;
 
             // print stack outputs to screen.
             println!("Output: {:?}", stack);
@@ -1,4 +1,5 @@
-use super::data::{Debug, InputFile, Libraries, OutputFile, ProgramFile};
+use super::data::{read_input_file, write_output_file, Debug, Libraries, ProgramFile};
+use miden::OutputFile;
 use std::{path::PathBuf, time::Instant};
 use structopt::StructOpt;
 
@@ -35,11 +36,11 @@ impl RunCmd {
         let program = ProgramFile::read(&self.assembly_file, &Debug::Off, libraries.libraries)?;
 
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let input_data = read_input_file(&self.input_file, &self.assembly_file)?;
 
         // fetch the stack and program inputs from the arguments
-        let stack_inputs = input_data.parse_stack_inputs()?;
-        let advice_provider = input_data.parse_advice_provider()?;
+        let stack_inputs = input_data.stack_inputs().map_err(|err| err.to_string())?;
+        let advice_provider = input_data.advice_provider().map_err(|err| err.to_string())?;
 
         let program_hash: [u8; 32] = program.hash().into();
         print!("Executing program with hash {}... ", hex::encode(program_hash));
@@ -53,7 +54,9 @@ impl RunCmd {
 
         if let Some(output_path) = &self.output_file {
             // write outputs to file if one was specified
-            OutputFile::write(trace.stack_outputs(), output_path)?;
+            let cycle_count = trace.trace_len_summary().main_trace_len();
+            let outputs = OutputFile::new(program.hash(), trace.stack_outputs(), cycle_count);
+            write_output_file(&outputs, output_path)?;
         } else {
             // write the stack outputs to the screen.
             println!("Output: {:?}", trace.stack_outputs().stack_truncated(self.num_outputs));
@@ -1,4 +1,4 @@
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
+use super::data::{read_input_file, read_output_file, ProgramHash, ProofFile};
 use miden::{Kernel, ProgramInfo};
 use std::{path::PathBuf, time::Instant};
 use structopt::StructOpt;
@@ -15,9 +15,9 @@ pub struct VerifyCmd {
     /// Path to proof file
     #[structopt(short = "p", long = "proof", parse(from_os_str))]
     proof_file: PathBuf,
-    /// Program hash (hex)
+    /// Program hash (hex); if not specified, the program hash is read from the output file
     #[structopt(short = "h", long = "program-hash")]
-    program_hash: String,
+    program_hash: Option<String>,
 }
 
 impl VerifyCmd {
@@ -26,17 +26,23 @@ impl VerifyCmd {
         println!("Verify program");
         println!("============================================================");
 
-        // read program hash from input
-        let program_hash = ProgramHash::read(&self.program_hash)?;
-
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.proof_file)?;
+        let input_data = read_input_file(&self.input_file, &self.proof_file)?;
 
         // fetch the stack inputs from the arguments
-        let stack_inputs = input_data.parse_stack_inputs()?;
+        let stack_inputs = input_data.stack_inputs().map_err(|err| err.to_string())?;
 
         // load outputs data from file
-        let outputs_data = OutputFile::read(&self.output_file, &self.proof_file)?;
+        let outputs_data = read_output_file(&self.output_file, &self.proof_file)?;
+        let stack_outputs = outputs_data.stack_outputs().map_err(|err| err.to_string())?;
+
+        // read program hash from input, or from the output file if it was not provided
+        let program_hash = match &self.program_hash {
+            Some(program_hash) => ProgramHash::read(program_hash)?,
+            None => outputs_data.program_hash().map_err(|err| err.to_string())?.ok_or(
+                "Program hash must be provided either as an argument or in the output file",
+            )?,
+        };
 
         // load proof from file
         let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
@@ -49,7 +55,7 @@ impl VerifyCmd {
         let program_info = ProgramInfo::new(program_hash, kernel);
 
         // verify proof
-        verifier::verify(program_info, stack_inputs, outputs_data.stack_outputs(), proof)
+        verifier::verify(program_info, stack_inputs, stack_outputs, proof)
             .map_err(|err| format!("Program failed verification! - {}", err))?;
 
         println!("Verification complete in {} ms", now.elapsed().as_millis());
@@ -0,0 +1,386 @@
+//! Interactive debugger for Miden programs.
+//!
+//! [Debugger] steps through an execution of a program forward and backward, and exposes the state
+//! of the VM at the current clock cycle. It is meant to be wrapped by user interfaces; when the
+//! `std` feature is enabled, [run_repl()] provides a line-based interface to it which can be
+//! attached to stdin and stdout.
+
+use core::ops::Range;
+use processor::{
+    math::Felt,
+    utils::{collections::Vec, string::String},
+    AdviceProvider, ExecutionError, Program, StackInputs, VmState, VmStateIterator, Word,
+};
+
+#[cfg(feature = "std")]
+use processor::math::StarkField;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, Write};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Separator between the path of a module and the name of a procedure defined in it.
+const PATH_DELIM: &str = "::";
+
+// DEBUGGER
+// ================================================================================================
+
+/// Steps through an execution of a program and provides access to the state of the VM at the
+/// current clock cycle.
+///
+/// The program is executed once, when the debugger is instantiated; the state of the VM at any
+/// cycle of this execution is reconstructed on demand from the execution history recorded by the
+/// processor (see [VmStateIterator::seek()]). Thus, stepping backward (or jumping to an arbitrary
+/// cycle) neither requires re-executing the program nor buffering VM states of the visited cycles,
+/// and takes the same time regardless of the number of cycles stepped over.
+///
+/// The price for this is memory: the debugger keeps the complete execution history, i.e., the
+/// trace columns of the system, decoder, stack, and chiplets, as well as the memory history, for
+/// as long as it is alive. Memory usage therefore grows linearly with the number of executed
+/// cycles, at a rate similar to that of building an execution trace via
+/// [execute()](crate::execute) (on the order of hundreds of bytes per cycle), and programs which
+/// run for tens of millions of cycles may not fit in memory.
+///
+/// The debugger does not take periodic snapshots of the VM and re-execute the program from them.
+/// The processor cannot resume an execution from an intermediate state, so re-execution would
+/// always start at the first cycle, and the processor would record the same history up to the
+/// target cycle while re-executing. Such a scheme would therefore not reduce peak memory usage;
+/// it would only trade the retained history for repeated executions.
+///
+/// To attribute cycles to assembly instructions and procedures, the program is expected to have
+/// been compiled in debug mode.
+pub struct Debugger {
+    states: VmStateIterator,
+    state: VmState,
+    last_clk: u32,
+    error: Option<ExecutionError>,
+}
+
+impl Debugger {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified program against the specified inputs and returns a debugger which
+    /// is positioned at the first clock cycle of the execution.
+    ///
+    /// If the execution fails, the debugger can step up to the cycle at which the error occurred,
+    /// and the error can be retrieved via [Debugger::error()].
+    pub fn new<A>(program: &Program, stack_inputs: StackInputs, advice_provider: A) -> Self
+    where
+        A: AdviceProvider,
+    {
+        let mut states = processor::execute_iter(program, stack_inputs, advice_provider);
+        let last_clk = states.trace_len_summary().main_trace_len() as u32;
+
+        // the execution error (if any) is yielded by the iterator after the last state
+        states.seek(last_clk + 1);
+        let error = match states.next() {
+            Some(Err(err)) => Some(err),
+            _ => None,
+        };
+
+        states.seek(0);
+        let state = next_state(&mut states);
+
+        Self {
+            states,
+            state,
+            last_clk,
+            error,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the VM at the current clock cycle.
+    pub fn state(&self) -> &VmState {
+        &self.state
+    }
+
+    /// Returns the current clock cycle.
+    pub fn clk(&self) -> u32 {
+        self.state.clk
+    }
+
+    /// Returns the last clock cycle of the execution.
+    pub fn last_clk(&self) -> u32 {
+        self.last_clk
+    }
+
+    /// Returns true if the debugger is positioned at the last clock cycle of the execution.
+    pub fn is_finished(&self) -> bool {
+        self.state.clk == self.last_clk
+    }
+
+    /// Returns the error with which the execution failed, or None if the execution succeeded.
+    ///
+    /// The error occurred while executing the operation following the last clock cycle.
+    pub fn error(&self) -> Option<&ExecutionError> {
+        self.error.as_ref()
+    }
+
+    /// Returns the state of the operand stack at the current clock cycle, starting with the top
+    /// of the stack.
+    pub fn stack(&self) -> &[Felt] {
+        &self.state.stack
+    }
+
+    /// Returns the initialized memory words of the current context whose addresses fall within
+    /// the specified range at the current clock cycle, sorted by address.
+    pub fn memory(&self, addrs: Range<u64>) -> Vec<(u64, Word)> {
+        let mut memory = self
+            .state
+            .memory
+            .iter()
+            .filter(|(addr, _)| addrs.contains(addr))
+            .copied()
+            .collect::<Vec<_>>();
+        memory.sort_by_key(|(addr, _)| *addr);
+        memory
+    }
+
+    /// Returns the full path of the procedure executed at the current clock cycle (e.g.,
+    /// `std::math::u64::checked_add`, or `#main` for the program body).
+    ///
+    /// None is returned for cycles which are not attributed to any assembly instruction, such as
+    /// cycles executing control flow operations.
+    pub fn current_procedure(&self) -> Option<String> {
+        let asmop = self.state.asmop.as_ref()?;
+        let mut path = String::new();
+        if let Some(module_path) = asmop.module_path() {
+            path.push_str(module_path);
+            path.push_str(PATH_DELIM);
+        }
+        path.push_str(asmop.context_name());
+        Some(path)
+    }
+
+    // NAVIGATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Moves the specified number of cycles forward, stopping at the last cycle of the execution.
+    /// Returns the number of cycles moved.
+    pub fn step(&mut self, num_cycles: u32) -> u32 {
+        let clk = self.clk();
+        self.run_to_clk(clk.saturating_add(num_cycles));
+        self.clk() - clk
+    }
+
+    /// Moves the specified number of cycles backward, stopping at the first cycle of the
+    /// execution. Returns the number of cycles moved.
+    pub fn step_back(&mut self, num_cycles: u32) -> u32 {
+        let clk = self.clk();
+        self.run_to_clk(clk.saturating_sub(num_cycles));
+        clk - self.clk()
+    }
+
+    /// Moves to the specified clock cycle, or to the last cycle of the execution if the
+    /// specified cycle is past it.
+    pub fn run_to_clk(&mut self, clk: u32) {
+        self.states.seek(clk.min(self.last_clk));
+        self.state = next_state(&mut self.states);
+    }
+
+    /// Moves forward to the first cycle of the next assembly instruction which contains the
+    /// specified pattern (e.g., `u32checked_add`, or `mem_store`).
+    ///
+    /// Returns false if no such instruction is executed after the current cycle; in this case,
+    /// the debugger is moved to the last cycle of the execution.
+    pub fn run_to_asmop(&mut self, pattern: &str) -> bool {
+        while !self.is_finished() {
+            self.state = next_state(&mut self.states);
+            if let Some(asmop) = &self.state.asmop {
+                if asmop.cycle_idx() == 1 && asmop.op().contains(pattern) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+// DEBUG COMMAND
+// ================================================================================================
+
+/// Commands accepted by [run_repl()].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugCommand {
+    /// Moves the specified number of cycles forward (`step [n]`).
+    Step(u32),
+    /// Moves the specified number of cycles backward (`back [n]`).
+    Back(u32),
+    /// Moves to the specified clock cycle (`goto <clk>`).
+    RunToClk(u32),
+    /// Moves to the next assembly instruction containing the specified pattern (`until <pat>`).
+    RunToAsmOp(String),
+    /// Prints the state of the operand stack (`stack`).
+    PrintStack,
+    /// Prints the memory words in the specified address range (`mem <start> [end]`).
+    PrintMem(Range<u64>),
+    /// Prints the procedure executed at the current clock cycle (`proc`).
+    PrintProc,
+    /// Prints the state of the VM at the current clock cycle (`state`).
+    PrintState,
+    /// Prints the list of available commands (`help`).
+    Help,
+    /// Quits the debugger (`quit`).
+    Quit,
+}
+
+#[cfg(feature = "std")]
+impl DebugCommand {
+    /// Parses a debug command from the specified string. Returns None for an empty string.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid command.
+    pub fn parse(command: &str) -> Result<Option<Self>, String> {
+        let tokens = command.split_whitespace().collect::<Vec<_>>();
+        let (identifier, args) = match tokens.split_first() {
+            Some((identifier, args)) => (*identifier, args),
+            None => return Ok(None),
+        };
+
+        let command = match (identifier, args) {
+            ("s" | "step", []) => Self::Step(1),
+            ("s" | "step", [n]) => Self::Step(parse_arg(n)?),
+            ("b" | "back", []) => Self::Back(1),
+            ("b" | "back", [n]) => Self::Back(parse_arg(n)?),
+            ("g" | "goto", [clk]) => Self::RunToClk(parse_arg(clk)?),
+            ("u" | "until", [pattern]) => Self::RunToAsmOp(pattern.to_string()),
+            ("stack", []) => Self::PrintStack,
+            ("m" | "mem", [addr]) => {
+                let addr = parse_arg(addr)?;
+                Self::PrintMem(addr..addr.saturating_add(1))
+            }
+            ("m" | "mem", [start, end]) => Self::PrintMem(parse_arg(start)?..parse_arg(end)?),
+            ("p" | "proc", []) => Self::PrintProc,
+            ("state", []) => Self::PrintState,
+            ("h" | "?" | "help", []) => Self::Help,
+            ("q" | "quit", []) => Self::Quit,
+            _ => return Err(format!("malformed command `{command}`; enter `help` for help")),
+        };
+
+        Ok(Some(command))
+    }
+}
+
+// REPL
+// ================================================================================================
+
+/// Reads debug commands from the specified input line by line, applies them to the specified
+/// debugger, and writes their results into the specified output. Returns when the `quit`
+/// command is read or when the input is exhausted.
+///
+/// To debug a program from a terminal, the debugger can be attached to stdin and stdout:
+/// `run_repl(&mut debugger, io::stdin().lock(), io::stdout())`.
+#[cfg(feature = "std")]
+pub fn run_repl<R, W>(debugger: &mut Debugger, input: R, mut output: W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    writeln!(output, "{}", debugger.state())?;
+    for line in input.lines() {
+        let command = match DebugCommand::parse(&line?) {
+            Ok(Some(command)) => command,
+            Ok(None) => continue,
+            Err(err) => {
+                writeln!(output, "{err}")?;
+                continue;
+            }
+        };
+
+        match command {
+            DebugCommand::Step(num_cycles) => {
+                debugger.step(num_cycles);
+                print_position(debugger, &mut output)?;
+            }
+            DebugCommand::Back(num_cycles) => {
+                debugger.step_back(num_cycles);
+                print_position(debugger, &mut output)?;
+            }
+            DebugCommand::RunToClk(clk) => {
+                debugger.run_to_clk(clk);
+                print_position(debugger, &mut output)?;
+            }
+            DebugCommand::RunToAsmOp(pattern) => {
+                if !debugger.run_to_asmop(&pattern) {
+                    writeln!(output, "no instruction matching `{pattern}` was executed")?;
+                }
+                print_position(debugger, &mut output)?;
+            }
+            DebugCommand::PrintStack => {
+                for (i, value) in debugger.stack().iter().enumerate() {
+                    writeln!(output, "[{i}] {}", value.as_int())?;
+                }
+            }
+            DebugCommand::PrintMem(addrs) => {
+                for (addr, word) in debugger.memory(addrs) {
+                    let word = word.map(|value| value.as_int());
+                    writeln!(output, "{addr}: {word:?}")?;
+                }
+            }
+            DebugCommand::PrintProc => match debugger.current_procedure() {
+                Some(path) => writeln!(output, "{path}")?,
+                None => writeln!(output, "no procedure is executed at clk={}", debugger.clk())?,
+            },
+            DebugCommand::PrintState => writeln!(output, "{}", debugger.state())?,
+            DebugCommand::Help => writeln!(output, "{HELP_MESSAGE}")?,
+            DebugCommand::Quit => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// List of the commands accepted by [run_repl()].
+#[cfg(feature = "std")]
+const HELP_MESSAGE: &str = "\
+step [n]           moves 1 (or `n`) clock cycles forward
+back [n]           moves 1 (or `n`) clock cycles backward
+goto <clk>         moves to clock cycle `clk`
+until <pattern>    moves to the next assembly instruction containing `pattern`
+stack              prints the operand stack
+mem <a> [b]        prints memory at address `a` (or at addresses in range `a..b`)
+proc               prints the procedure executed at the current clock cycle
+state              prints the complete state of the VM
+help               prints this message
+quit               quits the debugger";
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the next state yielded by the specified iterator.
+///
+/// # Panics
+/// Panics if the iterator is not positioned at an executed cycle.
+fn next_state(states: &mut VmStateIterator) -> VmState {
+    states
+        .next()
+        .expect("debugger moved past the last cycle")
+        .expect("debugger moved past the last cycle")
+}
+
+/// Parses a numeric argument of a debug command.
+#[cfg(feature = "std")]
+fn parse_arg<T: core::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse()
+        .map_err(|_| format!("malformed command - `{arg}` is not a valid number"))
+}
+
+/// Writes the current clock cycle and the error the execution failed with (if the debugger is
+/// positioned at the last cycle) into the specified output.
+#[cfg(feature = "std")]
+fn print_position<W: Write>(debugger: &Debugger, output: &mut W) -> io::Result<()> {
+    writeln!(output, "{}", debugger.state())?;
+    if debugger.is_finished() {
+        match debugger.error() {
+            Some(err) => writeln!(output, "execution failed: {err}")?,
+            None => writeln!(output, "execution complete")?,
+        }
+    }
+    Ok(())
+}
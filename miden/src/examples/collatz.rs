@@ -0,0 +1,79 @@
+use super::Example;
+use crate::{Assembler, MemAdviceProvider, StackInputs};
+
+// EXAMPLE BUILDER
+// ================================================================================================
+
+/// Returns an example which computes the number of steps the Collatz sequence starting at `start`
+/// takes to reach 1.
+///
+/// Unlike the other examples, the number of cycles executed by the program depends on its inputs
+/// rather than on the size of the program, as the sequence is computed in a `while` loop.
+///
+/// # Panics
+/// Panics if `start` is zero, or if a term of the sequence does not fit into 32 bits.
+pub fn collatz(start: u32) -> Example<MemAdviceProvider> {
+    let program = Assembler::default()
+        .compile(
+            "begin
+                # keep the number of steps below the current term: [n, steps, ...]
+                push.0 swap
+                dup neq.1
+                while.true
+                    # compute the next term of the sequence
+                    dup u32checked_mod.2
+                    if.true
+                        u32checked_mul.3 u32checked_add.1
+                    else
+                        u32checked_div.2
+                    end
+
+                    # increment the number of steps
+                    swap add.1 swap
+                    dup neq.1
+                end
+                drop
+            end",
+        )
+        .unwrap();
+
+    Example {
+        program,
+        stack_inputs: StackInputs::try_from_values([start as u64]).unwrap(),
+        advice_provider: MemAdviceProvider::default(),
+        expected_result: vec![compute_collatz_steps(start)],
+        num_outputs: 1,
+    }
+}
+
+/// Computes the number of steps the Collatz sequence starting at `start` takes to reach 1.
+fn compute_collatz_steps(start: u32) -> u64 {
+    assert!(start > 0, "the Collatz sequence must start at a positive integer");
+
+    let mut n = start;
+    let mut steps = 0;
+    while n != 1 {
+        n = if n % 2 == 1 {
+            n.checked_mul(3).and_then(|n| n.checked_add(1)).expect("term exceeds 32 bits")
+        } else {
+            n / 2
+        };
+        steps += 1;
+    }
+    steps
+}
+
+// EXAMPLE TESTER
+// ================================================================================================
+
+#[test]
+fn test_collatz_example() {
+    let example = collatz(27);
+    super::test_example(example, false);
+}
+
+#[test]
+fn test_collatz_example_fail() {
+    let example = collatz(27);
+    super::test_example(example, true);
+}
@@ -1,5 +1,5 @@
 use super::Example;
-use miden::{
+use crate::{
     math::{Felt, FieldElement, StarkField},
     Assembler, MemAdviceProvider, Program, StackInputs,
 };
@@ -7,20 +7,18 @@ use miden::{
 // EXAMPLE BUILDER
 // ================================================================================================
 
-pub fn get_example(n: usize) -> Example<MemAdviceProvider> {
-    // generate the program and expected results
-    let program = generate_fibonacci_program(n);
-    let expected_result = vec![compute_fibonacci(n).as_int()];
-    println!(
-        "Generated a program to compute {}-th Fibonacci term; expected result: {}",
-        n, expected_result[0]
-    );
+/// Returns an example which computes the `n`-th term of the Fibonacci sequence.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn fibonacci(n: usize) -> Example<MemAdviceProvider> {
+    assert!(n > 0, "n must be greater than 0");
 
     Example {
-        program,
+        program: generate_fibonacci_program(n),
         stack_inputs: StackInputs::try_from_values([0, 1]).unwrap(),
         advice_provider: MemAdviceProvider::default(),
-        expected_result,
+        expected_result: vec![compute_fibonacci(n).as_int()],
         num_outputs: 1,
     }
 }
@@ -42,7 +40,12 @@ fn generate_fibonacci_program(n: usize) -> Program {
         n - 1
     );
 
-    Assembler::default().compile(&program).unwrap()
+    // the loop is fully unrolled into 3 instructions per term, so the limit on unrolled
+    // instructions is raised to exactly what long sequences require
+    Assembler::default()
+        .with_max_unrolled_instructions(3 * (n - 1))
+        .compile(&program)
+        .unwrap()
 }
 
 /// Computes the `n`-th term of Fibonacci sequence
@@ -62,12 +65,12 @@ fn compute_fibonacci(n: usize) -> Felt {
 
 #[test]
 fn test_fib_example() {
-    let example = get_example(16);
+    let example = fibonacci(16);
     super::test_example(example, false);
 }
 
 #[test]
 fn test_fib_example_fail() {
-    let example = get_example(16);
+    let example = fibonacci(16);
     super::test_example(example, true);
 }
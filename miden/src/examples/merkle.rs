@@ -0,0 +1,68 @@
+use super::Example;
+use crate::{
+    crypto::{MerkleStore, MerkleTree},
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, MemAdviceProvider, StackInputs, Word,
+};
+
+// EXAMPLE BUILDER
+// ================================================================================================
+
+/// Returns an example which proves that a leaf is a member of a Merkle tree of the specified
+/// depth.
+///
+/// The tree has 2^depth leaves, and is provided to the VM via the advice provider. The program
+/// receives the root of the tree and the index of the leaf via the stack, and outputs the leaf
+/// together with the root.
+///
+/// # Panics
+/// Panics if `depth` is zero or greater than 20.
+pub fn merkle_membership(depth: u8) -> Example<MemAdviceProvider> {
+    assert!((1..=20).contains(&depth), "depth must be between 1 and 20");
+
+    let leaves = (0..1_u64 << depth).map(init_leaf).collect::<Vec<_>>();
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let root = tree.root();
+    let index = (1_u64 << depth) / 3;
+
+    // the program fetches the leaf at the specified index from the advice provider, and verifies
+    // that it opens to the specified root
+    let program = Assembler::default().compile("begin mtree_get end").unwrap();
+
+    // stack inputs: [d, i, R, ...]; stack outputs: [V, R, ...]
+    let stack_inputs = [root[0], root[1], root[2], root[3], Felt::new(index), depth.into()];
+    let leaf = leaves[index as usize];
+    let expected_result = leaf.iter().rev().chain(root.iter().rev()).map(|e| e.as_int()).collect();
+
+    let store = MerkleStore::from(&tree);
+    let advice_inputs = AdviceInputs::default().with_merkle_store(store);
+
+    Example {
+        program,
+        stack_inputs: StackInputs::try_from_values(stack_inputs.iter().map(|e| e.as_int()))
+            .unwrap(),
+        advice_provider: MemAdviceProvider::from(advice_inputs),
+        expected_result,
+        num_outputs: 8,
+    }
+}
+
+/// Returns the leaf of the tree at the specified index.
+fn init_leaf(index: u64) -> Word {
+    [Felt::new(index), Felt::new(index * index), Felt::new(index + 1), Felt::new(42)]
+}
+
+// EXAMPLE TESTER
+// ================================================================================================
+
+#[test]
+fn test_merkle_membership_example() {
+    let example = merkle_membership(10);
+    super::test_example(example, false);
+}
+
+#[test]
+fn test_merkle_membership_example_fail() {
+    let example = merkle_membership(10);
+    super::test_example(example, true);
+}
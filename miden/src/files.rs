@@ -0,0 +1,367 @@
+use crate::{
+    crypto::{MerkleStore, MerkleTree, SimpleSmt},
+    math::{Felt, StarkField},
+    utils::{Deserializable, SliceReader},
+    AdviceInputs, Digest, MemAdviceProvider, StackInputs, StackOutputs, Word,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Advice map entries keyed by the bytes of their words.
+type AdviceMap = HashMap<[u8; 32], Vec<Felt>>;
+
+// FILE ERROR
+// ================================================================================================
+
+/// An error which can occur while reading, writing, or parsing input and output files.
+#[derive(Debug)]
+pub enum FileError {
+    /// The file at the specified path could not be read or written.
+    Io(PathBuf, io::Error),
+    /// The file at the specified path does not contain valid JSON data of the expected structure.
+    Json(PathBuf, serde_json::Error),
+    /// The value of the specified field (e.g., `operand_stack[2]`) could not be parsed.
+    InvalidField { field: String, reason: String },
+}
+
+impl FileError {
+    fn invalid_field(field: impl Into<String>, reason: impl fmt::Display) -> Self {
+        Self::InvalidField {
+            field: field.into(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to access file `{}`: {err}", path.display()),
+            Self::Json(path, err) => {
+                write!(f, "failed to parse JSON data of file `{}`: {err}", path.display())
+            }
+            Self::InvalidField { field, reason } => {
+                write!(f, "failed to parse field `{field}`: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FileError {}
+
+// MERKLE DATA
+// ================================================================================================
+
+/// Merkle data which can be loaded into the Merkle store of the advice provider. Merkle data can
+/// be represented as a Merkle tree or a Sparse Merkle Tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MerkleData {
+    /// String representation of a Merkle tree. The Merkle tree is represented as a vector of
+    /// 32 byte hex strings where each string represents a leaf in the tree.
+    #[serde(rename = "merkle_tree")]
+    MerkleTree(Vec<String>),
+    /// String representation of a Sparse Merkle Tree. The Sparse Merkle Tree is represented as a
+    /// vector of tuples where each tuple consists of a u64 node index and a 32 byte hex string
+    /// representing the value of the node.
+    #[serde(rename = "sparse_merkle_tree")]
+    SparseMerkleTree(Vec<(u64, String)>),
+}
+
+// INPUT FILE
+// ================================================================================================
+
+/// Inputs of a Miden program, stored as a JSON file.
+///
+/// The file consists of the following components:
+/// - `operand_stack`: the initial operand stack, with the last value ending up at the top of the
+///   stack.
+/// - `advice_stack` (optional): the initial advice stack, with the first value at the top of the
+///   stack.
+/// - `advice_map` (optional): the initial advice map, which maps 32 byte hex keys to vectors of
+///   field elements.
+/// - `merkle_store` (optional): Merkle data which is loaded into the initial Merkle store.
+///
+/// Stack values are strings containing either decimal numbers or hex numbers prefixed with `0x`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputFile {
+    /// String representation of the initial operand stack, composed of chained field elements.
+    pub operand_stack: Vec<String>,
+    /// Optional string representation of the initial advice stack, composed of chained field
+    /// elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice_stack: Option<Vec<String>>,
+    /// Optional map of 32 byte hex strings to vectors of u64s representing the initial advice map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice_map: Option<HashMap<String, Vec<u64>>>,
+    /// Optional vector of Merkle data which will be loaded into the initial Merkle store. Merkle
+    /// data is represented as 32 byte hex strings and node indexes are represented as u64s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merkle_store: Option<Vec<MerkleData>>,
+}
+
+impl InputFile {
+    // FILE I/O
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the input file at the specified path.
+    ///
+    /// Only the structure of the file is validated; the values are validated when they are
+    /// parsed into inputs of a program (e.g., via [InputFile::stack_inputs()]).
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        read_json(path.as_ref())
+    }
+
+    /// Writes this input file to the specified path.
+    pub fn to_path(&self, path: impl AsRef<Path>) -> Result<(), FileError> {
+        write_json(self, path.as_ref())
+    }
+
+    // PARSERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the stack inputs described by this file.
+    pub fn stack_inputs(&self) -> Result<StackInputs, FileError> {
+        let values = parse_elements("operand_stack", &self.operand_stack)?;
+        StackInputs::try_from_values(values)
+            .map_err(|err| FileError::invalid_field("operand_stack", err))
+    }
+
+    /// Returns the advice inputs (i.e., the advice stack, the advice map, and the Merkle store)
+    /// described by this file.
+    pub fn advice_inputs(&self) -> Result<AdviceInputs, FileError> {
+        let stack = parse_elements("advice_stack", self.advice_stack.as_deref().unwrap_or(&[]))?;
+        let mut advice_inputs = AdviceInputs::default()
+            .with_stack_values(stack)
+            .map_err(|err| FileError::invalid_field("advice_stack", err))?;
+
+        if let Some(map) = self.parse_advice_map()? {
+            advice_inputs = advice_inputs.with_map(map);
+        }
+        if let Some(merkle_store) = self.parse_merkle_store()? {
+            advice_inputs = advice_inputs.with_merkle_store(merkle_store);
+        }
+
+        Ok(advice_inputs)
+    }
+
+    /// Returns an advice provider instantiated with the advice inputs described by this file.
+    pub fn advice_provider(&self) -> Result<MemAdviceProvider, FileError> {
+        self.advice_inputs().map(MemAdviceProvider::from)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses the advice map described by this file.
+    fn parse_advice_map(&self) -> Result<Option<AdviceMap>, FileError> {
+        let advice_map = match &self.advice_map {
+            Some(advice_map) => advice_map,
+            None => return Ok(None),
+        };
+
+        let map = advice_map
+            .iter()
+            .map(|(key_hex, values)| {
+                let mut key = [0u8; 32];
+                hex::decode_to_slice(key_hex, &mut key).map_err(|err| {
+                    FileError::invalid_field(format!("advice_map.{key_hex}"), err)
+                })?;
+
+                let values = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &value)| {
+                        if value < Felt::MODULUS {
+                            Ok(Felt::new(value))
+                        } else {
+                            let field = format!("advice_map.{key_hex}[{i}]");
+                            Err(FileError::invalid_field(
+                                field,
+                                format!("`{value}` is not a field element"),
+                            ))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((key, values))
+            })
+            .collect::<Result<HashMap<_, _>, FileError>>()?;
+
+        Ok(Some(map))
+    }
+
+    /// Parses the Merkle store described by this file.
+    fn parse_merkle_store(&self) -> Result<Option<MerkleStore>, FileError> {
+        let merkle_data = match &self.merkle_store {
+            Some(merkle_data) => merkle_data,
+            None => return Ok(None),
+        };
+
+        let mut merkle_store = MerkleStore::default();
+        for (i, data) in merkle_data.iter().enumerate() {
+            match data {
+                MerkleData::MerkleTree(leaves) => {
+                    let field = format!("merkle_store[{i}].merkle_tree");
+                    let leaves = leaves
+                        .iter()
+                        .enumerate()
+                        .map(|(j, leaf)| parse_word(&format!("{field}[{j}]"), leaf))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let tree = MerkleTree::new(leaves)
+                        .map_err(|err| FileError::invalid_field(field, err))?;
+                    merkle_store.extend(tree.inner_nodes());
+                }
+                MerkleData::SparseMerkleTree(entries) => {
+                    let field = format!("merkle_store[{i}].sparse_merkle_tree");
+                    let entries = entries
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (index, leaf))| {
+                            Ok((*index, parse_word(&format!("{field}[{j}]"), leaf)?))
+                        })
+                        .collect::<Result<Vec<_>, FileError>>()?;
+                    let tree = SimpleSmt::with_leaves(u64::BITS as u8, entries)
+                        .map_err(|err| FileError::invalid_field(field, err))?;
+                    merkle_store.extend(tree.inner_nodes());
+                }
+            }
+        }
+
+        Ok(Some(merkle_store))
+    }
+}
+
+// OUTPUT FILE
+// ================================================================================================
+
+/// Outputs of a Miden program execution, stored as a JSON file.
+///
+/// The file contains the hash of the executed program, the stack outputs (i.e., the final state
+/// of the stack and the overflow table addresses), and the number of cycles the execution took.
+/// Together with the inputs of the program, the stack outputs and the program hash are all the
+/// public data needed to verify a proof of the execution.
+///
+/// The program hash and the cycle count are optional, so that files which contain only the stack
+/// outputs can be loaded as well.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputFile {
+    /// Hex representation of the hash of the executed program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub program_hash: Option<String>,
+    /// String representation of the final state of the stack, starting with the top of the stack.
+    pub stack: Vec<String>,
+    /// String representation of the overflow table addresses.
+    pub overflow_addrs: Vec<String>,
+    /// Number of cycles it took to execute the program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_count: Option<usize>,
+}
+
+impl OutputFile {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [OutputFile] describing an execution of the program with the specified hash
+    /// which resulted in the specified stack outputs and took the specified number of cycles.
+    pub fn new(program_hash: Digest, stack_outputs: &StackOutputs, cycle_count: usize) -> Self {
+        let program_hash: [u8; 32] = program_hash.into();
+        Self {
+            program_hash: Some(hex::encode(program_hash)),
+            stack: stack_outputs.stack().iter().map(|v| v.to_string()).collect(),
+            overflow_addrs: stack_outputs.overflow_addrs().iter().map(|v| v.to_string()).collect(),
+            cycle_count: Some(cycle_count),
+        }
+    }
+
+    // FILE I/O
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the output file at the specified path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        read_json(path.as_ref())
+    }
+
+    /// Writes this output file to the specified path.
+    pub fn to_path(&self, path: impl AsRef<Path>) -> Result<(), FileError> {
+        write_json(self, path.as_ref())
+    }
+
+    // PARSERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the executed program, or None if the file does not contain it.
+    pub fn program_hash(&self) -> Result<Option<Digest>, FileError> {
+        let hash_hex = match &self.program_hash {
+            Some(hash_hex) => hash_hex,
+            None => return Ok(None),
+        };
+
+        let bytes =
+            hex::decode(hash_hex).map_err(|err| FileError::invalid_field("program_hash", err))?;
+        Digest::read_from(&mut SliceReader::new(&bytes))
+            .map(Some)
+            .map_err(|err| FileError::invalid_field("program_hash", err))
+    }
+
+    /// Returns the stack outputs described by this file.
+    pub fn stack_outputs(&self) -> Result<StackOutputs, FileError> {
+        let stack = parse_elements("stack", &self.stack)?;
+        let overflow_addrs = parse_elements("overflow_addrs", &self.overflow_addrs)?;
+        Ok(StackOutputs::new(stack, overflow_addrs))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads and deserializes the JSON file at the specified path.
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, FileError> {
+    let contents = fs::read_to_string(path).map_err(|err| FileError::Io(path.into(), err))?;
+    serde_json::from_str(&contents).map_err(|err| FileError::Json(path.into(), err))
+}
+
+/// Serializes the provided value as JSON and writes it into a file at the specified path.
+fn write_json<T: serde::Serialize>(value: &T, path: &Path) -> Result<(), FileError> {
+    let file = fs::File::create(path).map_err(|err| FileError::Io(path.into(), err))?;
+    serde_json::to_writer_pretty(file, value).map_err(|err| FileError::Json(path.into(), err))
+}
+
+/// Parses the provided values of the specified field into field elements.
+fn parse_elements(field: &str, values: &[String]) -> Result<Vec<u64>, FileError> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| parse_element(&format!("{field}[{i}]"), value))
+        .collect()
+}
+
+/// Parses a field element from a decimal string, or from a hex string prefixed with `0x`.
+fn parse_element(field: &str, value: &str) -> Result<u64, FileError> {
+    let result = match value.strip_prefix("0x") {
+        Some(hex_value) => u64::from_str_radix(hex_value, 16),
+        None => value.parse::<u64>(),
+    };
+    match result {
+        Ok(element) if element < Felt::MODULUS => Ok(element),
+        Ok(_) => Err(FileError::invalid_field(field, format!("`{value}` is not a field element"))),
+        Err(err) => {
+            Err(FileError::invalid_field(field, format!("`{value}` is not a number: {err}")))
+        }
+    }
+}
+
+/// Parses a word from a 32 byte hex string.
+fn parse_word(field: &str, word_hex: &str) -> Result<Word, FileError> {
+    let mut word_data = [0u8; 32];
+    hex::decode_to_slice(word_hex, &mut word_data)
+        .map_err(|err| FileError::invalid_field(field, err))?;
+    let mut word = Word::default();
+    for (i, value) in word_data.chunks(8).enumerate() {
+        word[i] = Felt::try_from(value)
+            .map_err(|err| FileError::invalid_field(field, format!("element {i}: {err}")))?;
+    }
+    Ok(word)
+}
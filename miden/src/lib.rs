@@ -6,12 +6,46 @@
 
 pub use assembly::{Assembler, AssemblyError, ParsingError};
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, ExecutionError,
-    ExecutionTrace, Kernel, MemAdviceProvider, Operation, ProgramInfo, StackInputs, VmState,
-    VmStateIterator,
+    crypto, execute, execute_iter, execute_main_only, execute_with_options, utils, AdviceError,
+    AdviceInputs, AdviceProvider, AdviceRequest, AdviceSnapshot, AdviceStream, AsmOpInfo,
+    CallbackAdviceProvider, ChipletsLengths, ExecutionError, ExecutionOptions, ExecutionTrace,
+    Kernel, MemAdviceProvider, OpCategory, Operation, ProgramInfo, RangeCheckMode, SegmentRanges,
+    StackInputs, TraceLenSummary, VmState, VmStateIterator,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Program,
-    ProofOptions, StackOutputs, StarkProof, Word,
+    math, prove, prove_trace, prove_with_early_cancel, CancellationToken, Digest, ExecutionProof,
+    FieldExtension, HashFunction, InputError, Program, ProofMetadata, ProofOptions, ProveError,
+    StackOutputs, StarkProof, Word,
 };
 pub use verifier::{verify, VerificationError};
+
+// PROFILING
+// ================================================================================================
+
+mod profiling;
+pub use profiling::{region_cycles, RegionCycles, TraceEvent, TraceRecorder};
+
+// ANALYSIS
+// ================================================================================================
+
+mod analysis;
+pub use analysis::{analyze, AsmOpStats, ExecutionDetails, ProcedureStats};
+
+// DEBUGGING
+// ================================================================================================
+
+pub mod debug;
+
+// EXAMPLES
+// ================================================================================================
+
+#[cfg(feature = "std")]
+pub mod examples;
+
+// INPUT AND OUTPUT FILES
+// ================================================================================================
+
+#[cfg(feature = "files")]
+mod files;
+#[cfg(feature = "files")]
+pub use files::{FileError, InputFile, MerkleData, OutputFile};
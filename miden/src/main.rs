@@ -3,7 +3,6 @@ use miden::{AssemblyError, ExecutionError};
 use structopt::StructOpt;
 
 mod cli;
-mod examples;
 mod repl;
 mod tools;
 
@@ -21,7 +20,7 @@ pub enum Actions {
     Analyze(tools::Analyze),
     Compile(cli::CompileCmd),
     Debug(cli::DebugCmd),
-    Example(examples::ExampleOptions),
+    Example(cli::ExampleOptions),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
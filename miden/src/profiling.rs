@@ -0,0 +1,170 @@
+use processor::{
+    crypto::MerklePath,
+    math::Felt,
+    utils::collections::{BTreeMap, Vec},
+    AdviceProvider, AdviceSource, DebugOptions, ExecutionError, StackTopState, VmState, Word,
+};
+
+// TRACE EVENT
+// ================================================================================================
+
+/// An event emitted by a `trace` instruction during program execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Identifier specified by the `trace` instruction.
+    pub trace_id: u32,
+    /// Clock cycle at which the event was emitted.
+    pub clk: u32,
+    /// State of the top 16 stack items at the time the event was emitted.
+    pub stack_top: StackTopState,
+}
+
+// TRACE RECORDER
+// ================================================================================================
+
+/// An advice provider which records all trace events emitted by a program.
+///
+/// All other requests are forwarded to the wrapped advice provider. Trace events are delivered to
+/// the recorder only if the program is executed with tracing enabled (see
+/// [ExecutionOptions::with_tracing()](processor::ExecutionOptions::with_tracing)).
+pub struct TraceRecorder<A: AdviceProvider> {
+    provider: A,
+    events: Vec<TraceEvent>,
+}
+
+impl<A: AdviceProvider> TraceRecorder<A> {
+    /// Returns a new recorder wrapping the specified advice provider.
+    pub fn new(provider: A) -> Self {
+        Self {
+            provider,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the trace events recorded so far, in the order in which they were emitted.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Returns cycle counts of the regions delimited by the trace events recorded so far.
+    ///
+    /// See [region_cycles()] for details.
+    pub fn region_cycles(&self) -> BTreeMap<(u32, u32), RegionCycles> {
+        region_cycles(&self.events)
+    }
+
+    /// Consumes this recorder and returns the wrapped advice provider and the recorded events.
+    pub fn into_parts(self) -> (A, Vec<TraceEvent>) {
+        (self.provider, self.events)
+    }
+}
+
+impl<A: AdviceProvider> AdviceProvider for TraceRecorder<A> {
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        self.provider.pop_stack()
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        self.provider.pop_stack_word()
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        self.provider.pop_stack_dword()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.provider.push_stack(source)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.provider.insert_into_map(key, values)
+    }
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.provider.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.get_merkle_path(root, depth, index)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        self.provider.get_leaf_depth(root, tree_depth, index)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.update_merkle_node(root, depth, index, value)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.provider.merge_roots(lhs, rhs)
+    }
+
+    fn advance_clock(&mut self) {
+        self.provider.advance_clock()
+    }
+
+    fn on_debug(&mut self, state: &VmState, options: &DebugOptions) {
+        self.provider.on_debug(state, options)
+    }
+
+    fn on_trace(&mut self, trace_id: u32, clk: u32, stack_top: &StackTopState) {
+        self.events.push(TraceEvent {
+            trace_id,
+            clk,
+            stack_top: *stack_top,
+        });
+        self.provider.on_trace(trace_id, clk, stack_top)
+    }
+}
+
+// REGION CYCLES
+// ================================================================================================
+
+/// Number of times a region of a program has been executed, and the total number of cycles spent
+/// executing it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RegionCycles {
+    pub count: usize,
+    pub total_cycles: u64,
+}
+
+/// Aggregates cycle counts of the program regions delimited by the specified trace events.
+///
+/// A region is the part of the execution between two consecutive trace events, and is identified
+/// by the pair of trace identifiers `(start, end)` of these events. For example, if a program
+/// marks the start and the end of signature verification with `trace.1` and `trace.2`, the cycles
+/// spent on all signature verifications are aggregated under the `(1, 2)` key.
+///
+/// Events are expected to be in the order in which they were emitted.
+pub fn region_cycles(events: &[TraceEvent]) -> BTreeMap<(u32, u32), RegionCycles> {
+    let mut result = BTreeMap::<(u32, u32), RegionCycles>::new();
+    for pair in events.windows(2) {
+        let (start, end) = (&pair[0], &pair[1]);
+        let region = result.entry((start.trace_id, end.trace_id)).or_default();
+        region.count += 1;
+        region.total_cycles += (end.clk - start.clk) as u64;
+    }
+    result
+}
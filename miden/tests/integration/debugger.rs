@@ -0,0 +1,138 @@
+use miden::debug::{run_repl, DebugCommand, Debugger};
+use test_utils::{build_debug_test, stack_to_ints, ExecutionError, MemAdviceProvider, Test};
+
+// DEBUGGER TESTS
+// ================================================================================================
+
+const SOURCE: &str = "
+    proc.foo.1
+        loc_store.0
+    end
+
+    begin
+        mem_storew.1
+        dropw
+        push.17
+        exec.foo
+        push.1 push.2 u32checked_add
+    end";
+
+#[test]
+fn debugger_navigation() {
+    let test = build_debug_test!(SOURCE, &[1, 2, 3, 4]);
+    let expected_states = test.execute_iter().map(Result::unwrap).collect::<Vec<_>>();
+    let mut debugger = new_debugger(&test);
+
+    assert_eq!(0, debugger.clk());
+    assert_eq!(expected_states.len() as u32 - 1, debugger.last_clk());
+    assert!(debugger.error().is_none());
+
+    // stepping forward and backward visits the same states as the iterator
+    assert_eq!(3, debugger.step(3));
+    assert_eq!(&expected_states[3], debugger.state());
+    assert_eq!(2, debugger.step_back(2));
+    assert_eq!(&expected_states[1], debugger.state());
+    assert_eq!(1, debugger.step_back(5));
+    assert_eq!(&expected_states[0], debugger.state());
+
+    // stepping stops at the last cycle
+    let num_cycles = debugger.last_clk();
+    assert_eq!(num_cycles, debugger.step(u32::MAX));
+    assert!(debugger.is_finished());
+    assert_eq!(expected_states.last().unwrap(), debugger.state());
+    assert_eq!(&[3, 0], &stack_to_ints(debugger.stack())[..2]);
+
+    debugger.run_to_clk(4);
+    assert_eq!(&expected_states[4], debugger.state());
+}
+
+#[test]
+fn debugger_run_to_asmop() {
+    let test = build_debug_test!(SOURCE, &[1, 2, 3, 4]);
+    let mut debugger = new_debugger(&test);
+
+    // the debugger stops at the first cycle of the matching instruction
+    assert!(debugger.run_to_asmop("loc_store"));
+    let asmop = debugger.state().asmop.clone().unwrap();
+    assert_eq!("loc_store.0", asmop.op());
+    assert_eq!(1, asmop.cycle_idx());
+    assert_eq!(Some("foo".to_string()), debugger.current_procedure());
+
+    // only the word written by `mem_storew.1` is stored in the first memory addresses
+    assert_eq!(
+        vec![1],
+        debugger.memory(0..10).iter().map(|(addr, _)| *addr).collect::<Vec<_>>()
+    );
+
+    assert!(debugger.run_to_asmop("push"));
+    assert_eq!("push.1", debugger.state().asmop.as_ref().unwrap().op());
+    assert_eq!(Some("#main".to_string()), debugger.current_procedure());
+
+    assert!(debugger.run_to_asmop("u32checked_add"));
+    assert!(!debugger.run_to_asmop("mem_storew"));
+    assert!(debugger.is_finished());
+}
+
+#[test]
+fn debugger_failed_execution() {
+    let source = "begin push.1 push.2 add assert end";
+    let test = build_debug_test!(source);
+    let mut debugger = new_debugger(&test);
+
+    assert!(matches!(debugger.error(), Some(ExecutionError::FailedAssertion { .. })));
+    debugger.step(u32::MAX);
+    assert_eq!(&[3], &stack_to_ints(debugger.stack())[..1]);
+
+    // the last cycle is the one at which the last successfully executed instruction started
+    debugger.step_back(u32::MAX);
+    assert_eq!(0, debugger.clk());
+    assert!(debugger.run_to_asmop("add"));
+    assert!(debugger.is_finished());
+}
+
+#[test]
+fn debugger_commands() {
+    assert_eq!(Ok(None), DebugCommand::parse("  "));
+    assert_eq!(Ok(Some(DebugCommand::Step(1))), DebugCommand::parse("step"));
+    assert_eq!(Ok(Some(DebugCommand::Back(10))), DebugCommand::parse("b 10"));
+    assert_eq!(Ok(Some(DebugCommand::RunToClk(7))), DebugCommand::parse("goto 7"));
+    assert_eq!(
+        Ok(Some(DebugCommand::RunToAsmOp("u32checked".to_string()))),
+        DebugCommand::parse("until u32checked")
+    );
+    assert_eq!(Ok(Some(DebugCommand::PrintMem(2..3))), DebugCommand::parse("mem 2"));
+    assert_eq!(Ok(Some(DebugCommand::PrintMem(2..8))), DebugCommand::parse("mem 2 8"));
+    assert!(DebugCommand::parse("step x").is_err());
+    assert!(DebugCommand::parse("stack 1").is_err());
+    assert!(DebugCommand::parse("jump").is_err());
+}
+
+#[test]
+fn debugger_repl() {
+    let test = build_debug_test!(SOURCE, &[1, 2, 3, 4]);
+    let mut debugger = new_debugger(&test);
+
+    let input = "until loc_store\nproc\nstep 5\nmem 1\nfoo\nquit\nstep\n";
+    let mut output = Vec::new();
+    run_repl(&mut debugger, input.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.contains("operation=loc_store.0"));
+    assert!(output.contains("\nfoo\n"));
+    assert!(output.contains("1: [1, 2, 3, 4]"));
+    assert!(output.contains("malformed command `foo`"));
+
+    // commands following `quit` are not executed
+    let mut expected = new_debugger(&test);
+    expected.run_to_asmop("loc_store");
+    expected.step(5);
+    assert_eq!(expected.clk(), debugger.clk());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn new_debugger(test: &Test) -> Debugger {
+    let advice_provider = MemAdviceProvider::from(test.advice_inputs.clone());
+    Debugger::new(&test.compile(), test.stack_inputs.clone(), advice_provider)
+}
@@ -31,7 +31,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Span),
             asmop: None,
-            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1].to_elements(),
+            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
         },
@@ -40,7 +40,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_line(1),
                 1,
             )),
             stack: [0, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -52,10 +53,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Incr),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_line(1),
                 2,
             )),
-            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2].to_elements(),
+            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
         },
@@ -64,7 +66,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::MStoreW),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_line(1),
                 3,
             )),
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -76,7 +79,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false).with_line(1),
                 1,
             )),
             stack: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].to_elements(),
@@ -88,7 +91,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false).with_line(1),
                 2,
             )),
             stack: [14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0].to_elements(),
@@ -100,7 +103,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false).with_line(1),
                 3,
             )),
             stack: [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
@@ -112,10 +115,10 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false).with_line(1),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -124,7 +127,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Push(Felt::new(17))),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false).with_line(1),
                 1,
             )),
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -136,7 +139,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Noop),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -145,7 +148,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Push(Felt::new(1))),
             asmop: None,
-            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
         },
@@ -154,7 +157,7 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::FmpUpdate),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: mem.clone(),
         },
@@ -163,7 +166,8 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_line(1),
                 1,
             )),
             stack: [0, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -175,10 +179,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::FmpAdd),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_line(1),
                 2,
             )),
-            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0]
+            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0]
                 .to_elements(),
             fmp: next_fmp,
             memory: mem,
@@ -188,10 +193,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::MStore),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_line(1),
                 3,
             )),
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -203,10 +209,11 @@ fn test_exec_iter() {
             ctx: 0,
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_line(1),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -220,6 +227,28 @@ fn test_exec_iter() {
     }
 }
 
+#[test]
+fn test_exec_iter_seek() {
+    let source = "proc.foo.1 loc_store.0 end begin mem_storew.1 dropw push.17 exec.foo end";
+    let test = build_debug_test!(source, &[1, 2, 3, 4]);
+    let expected_states = test.execute_iter().map(Result::unwrap).collect::<Vec<_>>();
+
+    // seeking to a cycle yields the same states as iterating forward from the first cycle, in
+    // any order of the visited cycles
+    let mut traces = test.execute_iter();
+    for clk in [5, 2, 0, expected_states.len() - 1, 3, 3, 1] {
+        traces.seek(clk as u32);
+        for expected in &expected_states[clk..] {
+            assert_eq!(*expected, traces.next().unwrap().unwrap());
+        }
+        assert!(traces.next().is_none());
+    }
+
+    // seeking past the last cycle ends the iteration
+    traces.seek(u32::MAX);
+    assert!(traces.next().is_none());
+}
+
 // HELPER FUNCTIONS
 // =================================================================
 fn slice_to_word(values: &[i32]) -> [Felt; 4] {
@@ -0,0 +1,122 @@
+use miden::{FileError, InputFile, Kernel, OutputFile, ProgramInfo, ProofOptions};
+use std::{env, fs, path::PathBuf};
+use test_utils::{build_test, stack_to_ints};
+
+// INPUT FILE
+// ================================================================================================
+
+#[test]
+fn input_file_values() {
+    let inputs = InputFile {
+        operand_stack: vec!["1".into(), "0x10".into(), "18446744069414584320".into()],
+        advice_stack: Some(vec!["0xffffffff".into(), "7".into()]),
+        ..Default::default()
+    };
+
+    let stack_inputs = inputs.stack_inputs().unwrap();
+    assert_eq!(vec![18446744069414584320, 16, 1], stack_to_ints(stack_inputs.values()));
+    assert!(inputs.advice_provider().is_ok());
+}
+
+#[test]
+fn input_file_invalid_fields() {
+    let inputs = InputFile {
+        operand_stack: vec!["1".into(), "2".into(), "0xg".into()],
+        ..Default::default()
+    };
+    assert_invalid_field(inputs.stack_inputs().unwrap_err(), "operand_stack[2]");
+
+    // values which are not field elements are rejected
+    let inputs = InputFile {
+        operand_stack: vec!["18446744069414584321".into()],
+        ..Default::default()
+    };
+    assert_invalid_field(inputs.stack_inputs().unwrap_err(), "operand_stack[0]");
+
+    let inputs = InputFile {
+        advice_stack: Some(vec!["abc".into()]),
+        ..Default::default()
+    };
+    assert_invalid_field(inputs.advice_inputs().unwrap_err(), "advice_stack[0]");
+
+    let inputs = InputFile {
+        merkle_store: Some(vec![miden::MerkleData::MerkleTree(vec!["00".into()])]),
+        ..Default::default()
+    };
+    assert_invalid_field(inputs.advice_inputs().unwrap_err(), "merkle_store[0].merkle_tree[0]");
+}
+
+#[test]
+fn input_file_round_trip() {
+    let inputs = InputFile {
+        operand_stack: vec!["1".into(), "2".into()],
+        advice_stack: Some(vec!["3".into()]),
+        advice_map: Some([("00".repeat(32), vec![4, 5])].into_iter().collect()),
+        merkle_store: None,
+    };
+
+    let path = temp_path("input_file_round_trip.inputs");
+    inputs.to_path(&path).unwrap();
+    assert_eq!(inputs, InputFile::from_path(&path).unwrap());
+    fs::remove_file(&path).unwrap();
+
+    // files which do not exist or do not contain valid JSON data report the path of the file
+    assert!(
+        matches!(InputFile::from_path(&path), Err(FileError::Io(err_path, _)) if err_path == path)
+    );
+}
+
+// OUTPUT FILE
+// ================================================================================================
+
+#[test]
+fn output_file_verify() {
+    let test = build_test!("begin push.3 push.5 add end");
+    let program = test.compile();
+    let trace = test.execute().unwrap();
+    let cycle_count = trace.trace_len_summary().main_trace_len();
+    let outputs = OutputFile::new(program.hash(), trace.stack_outputs(), cycle_count);
+
+    let path = temp_path("output_file_verify.outputs");
+    outputs.to_path(&path).unwrap();
+    let outputs = OutputFile::from_path(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(Some(cycle_count), outputs.cycle_count);
+
+    // the program hash and the stack outputs read from the file are sufficient to verify a proof
+    let proof = prover::prove_trace(trace, ProofOptions::default()).unwrap();
+    let program_hash = outputs.program_hash().unwrap().unwrap();
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    let stack_outputs = outputs.stack_outputs().unwrap();
+    assert!(miden::verify(program_info, test.stack_inputs, stack_outputs, proof).is_ok());
+}
+
+#[test]
+fn output_file_without_metadata() {
+    // output files written before the program hash and cycle count were added can still be read
+    let outputs: OutputFile =
+        serde_json::from_str(r#"{ "stack": ["8", "0"], "overflow_addrs": ["0", "1"] }"#).unwrap();
+    assert_eq!(None, outputs.program_hash().unwrap());
+    assert_eq!(None, outputs.cycle_count);
+    assert_eq!(&[8, 0], outputs.stack_outputs().unwrap().stack());
+
+    let outputs = OutputFile {
+        stack: vec!["8".into(), "-1".into()],
+        ..outputs
+    };
+    assert_invalid_field(outputs.stack_outputs().unwrap_err(), "stack[1]");
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn assert_invalid_field(err: FileError, expected_field: &str) {
+    match err {
+        FileError::InvalidField { field, .. } => assert_eq!(expected_field, field),
+        err => panic!("expected an invalid field error, but got: {err}"),
+    }
+}
+
+fn temp_path(file_name: &str) -> PathBuf {
+    env::temp_dir().join(format!("miden_{}_{file_name}", std::process::id()))
+}
@@ -1,4 +1,13 @@
-use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
+use assembly::{
+    ast::ModuleAst, Assembler, Library, LibraryNamespace, LibraryPath, Module, Version,
+};
+use core::slice::Iter;
+use stdlib::StdLibrary;
+use test_utils::{
+    build_test, AdviceInputs, ExecutionError, ExecutionErrorMatcher, MemAdviceProvider,
+    ProgramInfo, ProofOptions, StackInputs, StarkField, Test, TestError, Word,
+};
+use vm_core::{code_blocks::CodeBlock, Operation, StackOutputs};
 
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
@@ -209,3 +218,287 @@ fn simple_syscall() {
 
     test.prove_and_verify(vec![1, 2], false);
 }
+
+#[test]
+fn syscall_to_non_kernel_proc() {
+    let kernel_source = "
+        export.foo
+            add
+        end
+    ";
+
+    // a local procedure cannot be invoked via syscall, even if it has the same name as a kernel
+    // procedure
+    let program_source = "
+        proc.bar
+            mul
+        end
+        begin
+            syscall.bar
+        end";
+
+    let test = Test {
+        source: program_source.to_string(),
+        kernel: Some(kernel_source.to_string()),
+        stack_inputs: StackInputs::try_from_values([1, 2]).unwrap(),
+        advice_inputs: AdviceInputs::default(),
+        in_debug_mode: false,
+        libraries: Vec::default(),
+    };
+    test.expect_error(TestError::AssemblyError("KernelProcNotFound"));
+
+    // without a kernel, no syscall targets are available
+    let test = build_test!("begin syscall.foo end", &[1, 2]);
+    test.expect_error(TestError::AssemblyError("KernelProcNotFound"));
+}
+
+#[test]
+fn reexported_stdlib_proc_call() {
+    // a facade library which re-exports a procedure from the standard library under a new name
+    let namespace = LibraryNamespace::try_from("facade".to_string()).unwrap();
+    let path = LibraryPath::try_from("facade::math".to_string()).unwrap();
+    let ast = ModuleAst::parse(
+        "
+        use.std::math::u64
+        export.u64::wrapping_add->add64",
+    )
+    .unwrap();
+    let facade = FacadeLibrary {
+        namespace,
+        modules: vec![Module { path, ast }],
+    };
+
+    let assembler = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .and_then(|assembler| assembler.with_library(&facade))
+        .unwrap();
+
+    // calling the procedure through the alias resolves to the original procedure
+    let program = assembler.compile("use.facade::math begin exec.math::add64 end").unwrap();
+    let expected = assembler
+        .compile("use.std::math::u64 begin exec.u64::wrapping_add end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // a = 2^32 + 5, b = 2^32 - 1; the inputs are provided as [a_lo, a_hi, b_lo, b_hi]
+    let stack_inputs = StackInputs::try_from_values([5, 1, u32::MAX as u64, 0]).unwrap();
+    let trace = processor::execute(&program, stack_inputs, MemAdviceProvider::default()).unwrap();
+    assert_eq!(&[2, 4], trace.stack_outputs().stack_truncated(2));
+}
+
+#[test]
+fn phantom_mast_root_call() {
+    // the body of foo is not available to the assembler; only its MAST root is known
+    let foo_root = CodeBlock::new_span(vec![Operation::Add]);
+    let foo_hex: String =
+        foo_root.hash().as_bytes().iter().map(|byte| format!("{byte:02x}")).collect();
+
+    let assembler = Assembler::default();
+    let mut program = assembler.compile(format!("begin call.0x{foo_hex} end")).unwrap();
+    assert_eq!(vec![foo_root.hash()], program.unresolved_calls());
+
+    // the program hash is the same as the hash of the program compiled with the body of foo
+    let expected = assembler.compile("proc.foo add end begin call.foo end").unwrap();
+    assert_eq!(expected.hash(), program.hash());
+    assert!(expected.unresolved_calls().is_empty());
+
+    // the program cannot be executed until the body of foo is supplied
+    let stack_inputs = StackInputs::try_from_values([1, 2]).unwrap();
+    let result = processor::execute(&program, stack_inputs.clone(), MemAdviceProvider::default());
+    assert!(matches!(result, Err(ExecutionError::CodeBlockNotFound(_))));
+
+    program.insert_code_block(foo_root);
+    assert!(program.unresolved_calls().is_empty());
+    let trace = processor::execute(&program, stack_inputs, MemAdviceProvider::default()).unwrap();
+    assert_eq!(&[3], trace.stack_outputs().stack_truncated(1));
+}
+
+// DYNAMIC CODE EXECUTION
+// ================================================================================================
+
+#[test]
+fn simple_dynexec() {
+    // the hash of foo is computed in Rust and provided via the stack; foo is not known to the
+    // assembler, and its body is supplied alongside the program. the hash is left on the stack by
+    // dynexec, and thus, foo needs to drop it
+    let foo_hash = build_foo_hash();
+    let stack_init = [5, 3, foo_hash[0], foo_hash[1], foo_hash[2], foo_hash[3]];
+
+    let stack_outputs = prove_with_foo("begin dynexec end", &stack_init);
+    assert_eq!(&[8], stack_outputs.stack_truncated(1));
+}
+
+#[test]
+fn simple_dyncall() {
+    // same as above, but foo is invoked dynamically in a new context
+    let foo_hash = build_foo_hash();
+    let stack_init = [5, 3, foo_hash[0], foo_hash[1], foo_hash[2], foo_hash[3]];
+
+    let stack_outputs = prove_with_foo("begin dyncall end", &stack_init);
+    assert_eq!(&[8], stack_outputs.stack_truncated(1));
+}
+
+#[test]
+fn procref_dyncall() {
+    // procref pushes the MAST root of foo onto the stack, and also adds foo to the code block
+    // table of the program, so it does not need to be invoked via a regular call
+    let source = "
+        proc.foo
+            dropw
+            add
+        end
+        begin
+            procref.foo
+            dyncall
+        end";
+
+    let test = build_test!(source, &[5, 3]);
+    test.expect_stack(&[8]);
+
+    let foo_hash = build_foo_hash();
+    let test = build_test!("proc.foo dropw add end begin procref.foo end");
+    test.expect_stack(&[foo_hash[3], foo_hash[2], foo_hash[1], foo_hash[0]]);
+}
+
+#[test]
+fn procref_dyncall_stdlib() {
+    // state_to_digest drops the word at the top of the stack; when it is invoked dynamically, this
+    // word is the MAST root of the procedure itself
+    let direct = "
+        use.std::crypto::hashes::native
+        begin
+            padw
+            exec.native::state_to_digest
+        end";
+    let dynamic = "
+        use.std::crypto::hashes::native
+        begin
+            procref.native::state_to_digest
+            dyncall
+        end";
+
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let stack_inputs = StackInputs::try_from_values(1..=8).unwrap();
+    let mut outputs = Vec::new();
+    for source in [direct, dynamic] {
+        let program = assembler.compile(source).unwrap();
+        let trace =
+            processor::execute(&program, stack_inputs.clone(), MemAdviceProvider::default())
+                .unwrap();
+        outputs.push(trace.stack_outputs().clone());
+    }
+    // the dynamically called procedure drops the word in its own context, and thus, the stack
+    // depth of the caller is restored upon return; only the top of the stack is the same
+    assert_eq!(outputs[0].stack_truncated(16), outputs[1].stack_truncated(16));
+    assert_eq!(&[8, 7, 6, 5, 0, 0, 0, 0], outputs[1].stack_truncated(8));
+
+    // a procedure cannot reference the program's entrypoint or itself
+    assert!(assembler.compile("begin procref.main end").is_err());
+    assert!(assembler.compile("proc.foo procref.foo end begin exec.foo end").is_err());
+}
+
+#[test]
+fn dynexec_target_not_found() {
+    // the target of dynexec must be in the code block table of the program
+    let foo_hash = build_foo_hash();
+    let source = "
+        proc.foo
+            dropw
+            add
+        end
+        begin
+            dynexec
+        end";
+
+    let test = build_test!(source, &[5, foo_hash[0], foo_hash[1], foo_hash[2], foo_hash[3]]);
+    let error = ExecutionError::DynamicNodeNotFound(Default::default());
+    test.expect_error(ExecutionErrorMatcher::variant_of(&error));
+}
+
+#[test]
+fn dyncall_in_kernel() {
+    // same as call, dyncall cannot be used in a kernel
+    let test = Test {
+        source: "begin syscall.foo end".to_string(),
+        kernel: Some("export.foo dyncall end".to_string()),
+        stack_inputs: StackInputs::default(),
+        advice_inputs: AdviceInputs::default(),
+        in_debug_mode: false,
+        libraries: Vec::default(),
+    };
+    test.expect_error(TestError::AssemblyError("CallInKernel"));
+}
+
+// HELPERS
+// ================================================================================================
+
+struct FacadeLibrary {
+    namespace: LibraryNamespace,
+    modules: Vec<Module>,
+}
+
+impl Library for FacadeLibrary {
+    type ModuleIterator<'a> = Iter<'a, Module>;
+
+    fn root_ns(&self) -> &LibraryNamespace {
+        &self.namespace
+    }
+
+    fn version(&self) -> &Version {
+        &Version::MIN
+    }
+
+    fn modules(&self) -> Self::ModuleIterator<'_> {
+        self.modules.iter()
+    }
+
+    fn dependencies(&self) -> &[LibraryNamespace] {
+        &[]
+    }
+}
+
+/// Returns the code block of the body of `proc.foo dropw add end`.
+fn build_foo_block() -> CodeBlock {
+    CodeBlock::new_span(vec![
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Drop,
+        Operation::Add,
+    ])
+}
+
+fn build_foo_hash() -> [u64; 4] {
+    let foo_hash: Word = build_foo_block().hash().into();
+    [
+        foo_hash[0].as_int(),
+        foo_hash[1].as_int(),
+        foo_hash[2].as_int(),
+        foo_hash[3].as_int(),
+    ]
+}
+
+/// Compiles the specified source, supplies the body of foo to the resulting program, and proves
+/// and verifies its execution against the specified stack inputs. Returns the stack outputs.
+///
+/// The program must not be executable without foo being supplied.
+fn prove_with_foo(source: &str, stack_init: &[u64]) -> StackOutputs {
+    let mut program = Assembler::default().compile(source).unwrap();
+    let stack_inputs = StackInputs::try_from_values(stack_init.iter().copied()).unwrap();
+    let result = processor::execute(&program, stack_inputs.clone(), MemAdviceProvider::default());
+    assert!(matches!(result, Err(ExecutionError::DynamicNodeNotFound(_))));
+
+    program.insert_code_block(build_foo_block());
+    let (stack_outputs, proof) = prover::prove(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    let result =
+        verifier::verify(ProgramInfo::from(program), stack_inputs, stack_outputs.clone(), proof);
+    assert!(result.is_ok(), "error: {result:?}");
+    stack_outputs
+}
@@ -1,8 +1,19 @@
-use test_utils::build_test;
+use assembly::Assembler;
+use miden::{ExecutionProof, HashFunction, StackInputs, StackOutputs, VerificationError};
+use stdlib::StdLibrary;
+use test_utils::{
+    build_test,
+    crypto::{init_merkle_store, MerkleTree},
+    MemAdviceProvider, ProgramInfo, ProofOptions, StarkField,
+};
+use vm_core::OpCategory;
 
 mod air;
 mod cli;
+mod debugger;
 mod exec_iters;
+#[cfg(feature = "files")]
+mod files;
 mod flow_control;
 mod operations;
 
@@ -19,3 +30,321 @@ fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
+
+#[test]
+fn prove_executed_trace() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+
+    // execute the program once, and then prove the resulting trace without re-executing it
+    let trace =
+        processor::execute(&program, test.stack_inputs.clone(), MemAdviceProvider::default())
+            .unwrap();
+    let stack_inputs = trace.stack_inputs().clone();
+    let stack_outputs = trace.stack_outputs().clone();
+    assert_eq!(test.stack_inputs.values(), stack_inputs.values());
+
+    let proof = prover::prove_trace(trace, ProofOptions::default()).unwrap();
+    let result = verifier::verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+}
+
+#[test]
+fn deterministic_execution() {
+    // exercise the advice stack, the advice map, the Merkle store, and all chiplets
+    let source = "
+        begin
+            # stack: [d, i, R, ...]
+            mtree_get
+            # load the values stored under the leaf V onto the advice stack
+            adv.push_mapval
+            adv_push.4
+            hperm
+            adv_push.2
+            u32checked_add
+            mem_store.0
+            push.0 mem_loadw
+        end";
+
+    let index = 3;
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let root = MerkleTree::new(leaves.clone()).unwrap().root();
+    let stack_inputs =
+        [root[0].as_int(), root[1].as_int(), root[2].as_int(), root[3].as_int(), index, 3];
+
+    build_test!(source, &stack_inputs)
+        .with_advice_stack(&[10, 20])
+        .with_advice_map(leaves[index as usize], &[1, 2, 3, 4])
+        .with_merkle_store(&store)
+        .expect_deterministic();
+}
+
+#[test]
+fn prove_with_grinding() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+
+    // setting the grinding factor to its current value does not change the options
+    let options = ProofOptions::default();
+    assert_eq!(options.clone().with_grinding(options.grinding_factor()), options);
+
+    for grinding_factor in [0, 20] {
+        let options = ProofOptions::default().with_grinding(grinding_factor);
+        let program = test.compile();
+        let (stack_outputs, proof) = prover::prove(
+            &program,
+            test.stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(proof.stark_proof().options().grinding_factor(), grinding_factor);
+
+        let program_info = ProgramInfo::from(program);
+        let result =
+            verifier::verify(program_info, test.stack_inputs.clone(), stack_outputs, proof);
+        assert!(result.is_ok(), "error: {result:?}");
+    }
+}
+
+#[test]
+fn prove_with_cancellation() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+
+    // a token which is never cancelled does not affect proof generation
+    let token = prover::CancellationToken::new();
+    let (stack_outputs, proof) = prover::prove_with_early_cancel(
+        &program,
+        test.stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+        token.clone(),
+    )
+    .unwrap();
+    assert!(!token.is_cancelled());
+    let program_info = ProgramInfo::from(test.compile());
+    let result = verifier::verify(program_info, test.stack_inputs.clone(), stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // cancelling a clone of the token cancels proof generation
+    let token = prover::CancellationToken::new();
+    token.clone().cancel();
+    let result = prover::prove_with_early_cancel(
+        &program,
+        test.stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+        token,
+    );
+    assert!(matches!(result, Err(prover::ProveError::Cancelled)));
+}
+
+#[test]
+fn verify_sha256_program() {
+    // the program executed by the sha256 benchmarks
+    let source = "
+        use.std::crypto::hashes::sha256
+
+        begin
+            exec.sha256::hash_2to1
+        end";
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let program = assembler.compile(source).unwrap();
+    let (stack_outputs, proof) = miden::prove(
+        &program,
+        StackInputs::default(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    // programs compiled against an empty kernel can be verified given only their hash
+    let result =
+        miden::verify(program.hash(), StackInputs::default(), stack_outputs.clone(), proof.clone());
+    assert_eq!(Ok(proof.security_level()), result);
+
+    // the proof does not verify against other outputs or another program
+    let mut outputs = stack_outputs.stack().to_vec();
+    outputs[0] += 1;
+    let wrong_outputs = StackOutputs::new(outputs, stack_outputs.overflow_addrs().to_vec());
+    let result =
+        miden::verify(program.hash(), StackInputs::default(), wrong_outputs, proof.clone());
+    assert_eq!(Err(VerificationError::ProgramMismatch(program.hash())), result);
+
+    let other_hash = build_test!("begin push.1 end").compile().hash();
+    let result = miden::verify(other_hash, StackInputs::default(), stack_outputs, proof);
+    assert_eq!(Err(VerificationError::ProgramMismatch(other_hash)), result);
+}
+
+#[test]
+fn verify_tampered_proof() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (stack_outputs, proof) = miden::prove(
+        &program,
+        test.stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let bytes = proof.to_bytes();
+
+    // truncated proofs cannot be read
+    let truncated = ExecutionProof::from_bytes(&bytes[..bytes.len() - 1]);
+    assert!(truncated.is_err());
+
+    // the proof ends with the FRI remainder, the number of FRI partitions (1 byte), and the
+    // proof-of-work nonce (8 bytes); tampering with the remainder fails the low-degree test
+    let mut tampered = bytes;
+    let remainder_end = tampered.len() - 9;
+    tampered[remainder_end - 8] ^= 1;
+    let proof = ExecutionProof::from_bytes(&tampered).unwrap();
+    let result = miden::verify(program.hash(), test.stack_inputs, stack_outputs, proof);
+    assert!(matches!(result, Err(VerificationError::FriVerificationFailed(_))), "{result:?}");
+}
+
+#[test]
+fn proof_metadata() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let options = ProofOptions::with_128_bit_security(false).with_grinding(8);
+    let (_, proof) = miden::prove(
+        &program,
+        test.stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        options.clone(),
+    )
+    .unwrap();
+    let trace = test.execute().unwrap();
+
+    // metadata is read from a deserialized proof, without verifying it
+    let proof = ExecutionProof::from_bytes(&proof.to_bytes()).unwrap();
+    let metadata = proof.metadata();
+
+    assert_eq!(metadata.trace_length_log2(), trace.get_trace_len().ilog2());
+    // trace width includes the auxiliary columns
+    assert!(metadata.trace_width() > trace.main_matrix().num_cols());
+    assert_eq!(metadata.num_queries(), options.options.num_queries());
+    assert_eq!(metadata.blowup_factor(), options.options.blowup_factor());
+    assert_eq!(metadata.grinding_factor(), 8);
+    assert_eq!(metadata.field_extension_degree(), 3);
+    assert_eq!(metadata.hash_fn(), HashFunction::Blake3_256);
+    assert_eq!(metadata.security_level(), proof.security_level());
+
+    let summary = metadata.to_string();
+    assert!(summary.contains("grinding factor:  8 bits"), "{summary}");
+    assert!(summary.contains("hash function:    BLAKE3-256"), "{summary}");
+}
+
+#[test]
+#[should_panic(expected = "grinding factor cannot be greater than 32")]
+fn grinding_factor_out_of_range() {
+    let _ = ProofOptions::default().with_grinding(ProofOptions::MAX_GRINDING_FACTOR + 1);
+}
+
+#[test]
+fn proof_options_from_preset() {
+    assert_eq!(
+        ProofOptions::from_preset("standard").unwrap(),
+        ProofOptions::with_96_bit_security(false)
+    );
+    assert_eq!(
+        ProofOptions::from_preset("high").unwrap(),
+        ProofOptions::with_128_bit_security(false)
+    );
+
+    // the paranoid preset differs from the high one only in the number of queries
+    let high = ProofOptions::from_preset("high").unwrap();
+    let paranoid = ProofOptions::from_preset("paranoid").unwrap();
+    assert_eq!(paranoid.options.num_queries(), 2 * high.options.num_queries());
+    assert_eq!(paranoid.options.blowup_factor(), high.options.blowup_factor());
+    assert_eq!(paranoid.grinding_factor(), high.grinding_factor());
+    assert_eq!(paranoid.options.field_extension(), high.options.field_extension());
+    assert_eq!(paranoid.hash_fn(), high.hash_fn());
+
+    for name in ProofOptions::PRESETS {
+        assert!(ProofOptions::from_preset(name).is_ok(), "preset {name} is not recognized");
+    }
+}
+
+#[test]
+fn proof_options_from_unknown_preset() {
+    let err = ProofOptions::from_preset("96bits").unwrap_err();
+    assert_eq!(
+        err,
+        "unknown proof options preset '96bits'; valid presets are: standard, high, paranoid"
+    );
+    assert!(ProofOptions::from_preset("").is_err());
+    assert!(ProofOptions::from_preset("High").is_err());
+}
+
+#[test]
+fn program_op_counts() {
+    // the program executed by the sha256 benchmarks
+    let source = "
+        use.std::crypto::hashes::sha256
+
+        begin
+            exec.sha256::hash_2to1
+        end";
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let program = assembler.compile(source).unwrap();
+
+    let num_ops = program.num_ops();
+    let op_counts = program.op_counts();
+    assert_eq!(num_ops, op_counts.values().sum::<usize>());
+
+    // sha256 is implemented using u32 operations and does not use the hasher chiplet
+    assert!(op_counts[&OpCategory::U32] > 0);
+    assert!(!op_counts.contains_key(&OpCategory::Crypto));
+
+    // the counts do not depend on whether the procedures were compiled by the same assembler
+    let recompiled = assembler.compile(source).unwrap();
+    assert_eq!(num_ops, recompiled.num_ops());
+    assert_eq!(op_counts, recompiled.op_counts());
+
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let recompiled = assembler.compile(source).unwrap();
+    assert_eq!(num_ops, recompiled.num_ops());
+    assert_eq!(op_counts, recompiled.op_counts());
+}
+
+#[test]
+fn program_execution_details() {
+    // the program executed by the sha256 benchmarks
+    let source = "
+        use.std::crypto::hashes::sha256
+
+        begin
+            exec.sha256::hash_2to1
+        end";
+    let assembler = Assembler::default()
+        .with_debug_mode(true)
+        .with_library(&StdLibrary::default())
+        .unwrap();
+    let program = assembler.compile(source).unwrap();
+
+    let details =
+        miden::analyze(&program, Default::default(), MemAdviceProvider::default()).unwrap();
+    let trace_len_summary = details.trace_len_summary();
+    assert_eq!(details.total_vm_cycles() as usize, trace_len_summary.main_trace_len());
+    assert!(details.padded_trace_len().is_power_of_two());
+    assert!(details.padded_trace_len() > trace_len_summary.trace_len());
+
+    // sha256 is implemented using u32 operations, and thus, uses the bitwise chiplet
+    assert!(trace_len_summary.chiplets_trace_len().bitwise_chiplet_len() > 0);
+
+    // most cycles are spent in the procedures of the sha256 module
+    let sha256_cycles = details
+        .procedure_stats()
+        .iter()
+        .filter(|proc| proc.name().starts_with("std::crypto::hashes::sha256::"))
+        .map(|proc| proc.total_vm_cycles())
+        .sum::<usize>();
+    assert!(sha256_cycles * 2 > details.total_vm_cycles() as usize);
+    assert!(details.procedure_stats()[0].name().starts_with("std::crypto::hashes::sha256::"));
+
+    // the report is rendered as a table
+    let report = details.to_string();
+    assert!(report.contains("std::crypto::hashes::sha256::hash_2to1"));
+}
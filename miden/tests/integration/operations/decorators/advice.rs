@@ -1,4 +1,11 @@
-use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, Felt};
+use num_bigint::BigUint;
+use test_utils::{
+    bigint::u256_limbs,
+    build_test,
+    crypto::MerkleStore,
+    rand::{rand_value, rand_vector},
+    Felt, TestError,
+};
 
 // ADVICE INJECTION
 // ================================================================================================
@@ -32,6 +39,40 @@ fn advice_push_u64div() {
     test.expect_stack(&expected);
 }
 
+#[test]
+fn advice_push_u256div() {
+    // push a/b onto the advice stack and then move these values onto the operand stack.
+    let source = "begin adv.push_u256div adv_push.16 end";
+
+    // get two random 256-bit integers, with the divisor being smaller than the dividend
+    let a = BigUint::new(rand_vector::<u64>(8).iter().map(|&v| v as u32).collect());
+    let b = BigUint::new(rand_vector::<u64>(5).iter().map(|&v| v as u32).collect());
+
+    // operands are arranged with the most significant limb of b at the top of the stack
+    let mut inputs = u256_limbs(&a);
+    inputs.extend(u256_limbs(&b));
+
+    // the advice stack holds [q0, ..., q7, r0, ..., r7], and thus, after moving these values onto
+    // the operand stack, the top of the stack is [r7, ..., r0, q7, ..., q0]
+    let mut expected = u256_limbs(&(&a / &b));
+    expected.extend(u256_limbs(&(&a % &b)));
+    expected.reverse();
+
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn advice_push_u256div_by_zero() {
+    let source = "begin adv.push_u256div end";
+
+    let mut inputs = rand_vector::<u64>(8).iter().map(|&v| v as u32 as u64).collect::<Vec<_>>();
+    inputs.extend([0; 8]);
+
+    let test = build_test!(source, &inputs);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
 #[test]
 fn advice_push_u64div_repeat() {
     // This procedure repeats the following steps 7 times:
@@ -190,12 +231,9 @@ fn advice_push_mapval() {
     end";
 
     let stack_inputs = [1, 2, 3, 4];
-    let adv_map = [(
-        key_to_bytes(stack_inputs),
-        vec![Felt::new(8), Felt::new(7), Felt::new(6), Felt::new(5)],
-    )];
+    let key = stack_inputs.map(Felt::new);
 
-    let test = build_test!(source, &stack_inputs, vec![], MerkleStore::default(), adv_map);
+    let test = build_test!(source, &stack_inputs).with_advice_map(key, &[8, 7, 6, 5]);
     test.expect_stack(&[5, 6, 7, 8]);
 
     // --- test adv.mapval with offset ----------------------------------------
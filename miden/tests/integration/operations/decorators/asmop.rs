@@ -1,4 +1,4 @@
-use processor::{AsmOpInfo, VmStateIterator};
+use processor::{AsmOpInfo, ExecutionError, VmStateIterator};
 use test_utils::build_debug_test;
 use vm_core::{AssemblyOp, Felt, Operation};
 
@@ -21,7 +21,7 @@ fn asmop_one_span_block_test() {
         VmStatePartial {
             clk: 2,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -29,7 +29,7 @@ fn asmop_one_span_block_test() {
         VmStatePartial {
             clk: 3,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(1),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -37,7 +37,7 @@ fn asmop_one_span_block_test() {
         VmStatePartial {
             clk: 4,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -45,7 +45,7 @@ fn asmop_one_span_block_test() {
         VmStatePartial {
             clk: 5,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Add),
@@ -79,7 +79,7 @@ fn asmop_with_one_procedure() {
         VmStatePartial {
             clk: 2,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 2, "push.1".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -87,7 +87,7 @@ fn asmop_with_one_procedure() {
         VmStatePartial {
             clk: 3,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 2, "push.1".to_string(), false).with_line(1),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -95,7 +95,7 @@ fn asmop_with_one_procedure() {
         VmStatePartial {
             clk: 4,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 1, "push.2".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -103,7 +103,7 @@ fn asmop_with_one_procedure() {
         VmStatePartial {
             clk: 5,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 1, "add".to_string(), false).with_line(1),
                 1,
             )),
             op: Some(Operation::Add),
@@ -141,7 +141,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 2,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -149,7 +149,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 3,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -157,7 +157,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 4,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -165,7 +165,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 5,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Add),
@@ -173,7 +173,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 6,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -181,7 +181,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 7,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -189,7 +189,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 8,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -197,7 +197,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 9,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Add),
@@ -205,7 +205,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 10,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -213,7 +213,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 11,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(3),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -221,7 +221,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 12,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -229,7 +229,7 @@ fn asmop_repeat_test() {
         VmStatePartial {
             clk: 13,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(3),
                 1,
             )),
             op: Some(Operation::Add),
@@ -292,7 +292,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 3,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "eq".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "eq".to_string(), false).with_line(2),
                 1,
             )),
             op: Some(Operation::Eq),
@@ -315,7 +315,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 7,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(4),
                 1,
             )),
             op: Some(Operation::Pad),
@@ -323,7 +323,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 8,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false).with_line(4),
                 2,
             )),
             op: Some(Operation::Incr),
@@ -331,7 +331,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 9,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.2".to_string(), false).with_line(4),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(2))),
@@ -339,7 +339,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 10,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(4),
                 1,
             )),
             op: Some(Operation::Add),
@@ -385,7 +385,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 3,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "eq".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "eq".to_string(), false).with_line(2),
                 1,
             )),
             op: Some(Operation::Eq),
@@ -408,7 +408,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 7,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.3".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.3".to_string(), false).with_line(6),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(3))),
@@ -416,7 +416,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 8,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.4".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.4".to_string(), false).with_line(6),
                 1,
             )),
             op: Some(Operation::Push(Felt::new(4))),
@@ -424,7 +424,7 @@ fn asmop_conditional_execution_test() {
         VmStatePartial {
             clk: 9,
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "add".to_string(), false).with_line(6),
                 1,
             )),
             op: Some(Operation::Add),
@@ -454,6 +454,44 @@ fn asmop_conditional_execution_test() {
     assert_eq!(expected_vm_state, vm_state);
 }
 
+#[test]
+fn asmop_maps_failed_assertion_to_library_source() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_add
+        end";
+
+    // a = 2^64 - 1 and b = 1, so the overflow check at the end of checked_add fails
+    let mut test = build_debug_test!(source, &[u32::MAX as u64, u32::MAX as u64, 1, 0]);
+    test.libraries = vec![stdlib::StdLibrary::default().into()];
+
+    let mut vm_state_iterator = test.execute_iter();
+    let error = vm_state_iterator.by_ref().find_map(Result::err);
+    assert!(matches!(error, Some(ExecutionError::FailedAssertion { .. })));
+
+    let asmop = vm_state_iterator.last_asmop().expect("no asmop for the failed operation");
+    assert_eq!(asmop.module_path(), Some("std::math::u64"));
+    assert_eq!(asmop.context_name(), "checked_add");
+    assert_eq!(asmop.op(), "assert");
+    assert_eq!(asmop.line(), Some(checked_add_assert_line()));
+}
+
+/// Returns the 1-based line of the final `assert` of `checked_add` in the source of std::math::u64.
+fn checked_add_assert_line() -> u32 {
+    let source = include_str!("../../../../../stdlib/asm/math/u64.masm");
+    let mut lines = source.lines().enumerate();
+    lines
+        .find(|(_, line)| line.trim() == "export.checked_add")
+        .expect("checked_add not found");
+    let (idx, _) = lines
+        .take_while(|(_, line)| line.trim() != "end")
+        .filter(|(_, line)| line.trim() == "assert")
+        .last()
+        .expect("checked_add has no assert");
+    idx as u32 + 1
+}
+
 /// This is a helper function to build a vector of [VmStatePartial] from a specified [VmStateIterator].
 fn build_vm_state(vm_state_iterator: VmStateIterator) -> Vec<VmStatePartial> {
     let mut vm_state = Vec::new();
@@ -1,2 +1,3 @@
 mod advice;
 mod asmop;
+mod trace;
@@ -0,0 +1,69 @@
+use assembly::Assembler;
+use miden::{region_cycles, RegionCycles, TraceRecorder};
+use processor::{ExecutionOptions, MemAdviceProvider, StackInputs};
+use vm_core::Felt;
+
+#[test]
+fn trace_events() {
+    let source = "
+        const.START=1
+        begin
+            trace.START push.3 push.4 add trace.2
+            repeat.2
+                trace.START push.5 mul trace.2
+            end
+            trace.3 drop
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+
+    // when tracing is enabled, events are delivered in the order in which they were emitted
+    let options = ExecutionOptions::default().with_tracing();
+    let mut recorder = TraceRecorder::new(MemAdviceProvider::default());
+    let traced =
+        processor::execute_with_options(&program, StackInputs::default(), &mut recorder, options)
+            .unwrap();
+
+    let events = recorder.events();
+    let ids = events.iter().map(|event| event.trace_id).collect::<Vec<_>>();
+    let clks = events.iter().map(|event| event.clk).collect::<Vec<_>>();
+    assert_eq!(vec![1, 2, 1, 2, 1, 2, 3], ids);
+    assert_eq!(vec![1, 4, 4, 6, 6, 8, 8], clks);
+
+    // each event carries the state of the stack at the time it was emitted
+    assert_eq!(Felt::new(7), events[1].stack_top[0]);
+    assert_eq!(Felt::new(35), events[3].stack_top[0]);
+    assert_eq!(Felt::new(175), events[5].stack_top[0]);
+
+    // cycles are aggregated per region between consecutive events
+    let regions = recorder.region_cycles();
+    assert_eq!(3, regions.len());
+    assert_eq!(
+        RegionCycles {
+            count: 3,
+            total_cycles: 7
+        },
+        regions[&(1, 2)]
+    );
+    assert_eq!(
+        RegionCycles {
+            count: 2,
+            total_cycles: 0
+        },
+        regions[&(2, 1)]
+    );
+    assert_eq!(
+        RegionCycles {
+            count: 1,
+            total_cycles: 0
+        },
+        regions[&(2, 3)]
+    );
+    assert_eq!(regions, region_cycles(events));
+
+    // when tracing is disabled, no events are delivered, and the execution trace is the same
+    let mut recorder = TraceRecorder::new(MemAdviceProvider::default());
+    let untraced = processor::execute(&program, StackInputs::default(), &mut recorder).unwrap();
+    assert!(recorder.events().is_empty());
+    assert_eq!(traced.column_checksums(), untraced.column_checksums());
+    assert_eq!(traced.stack_outputs(), untraced.stack_outputs());
+}
@@ -1,6 +1,7 @@
 use test_utils::{
-    build_op_test, prop_randw, proptest::prelude::*, rand::rand_value, Felt, FieldElement,
-    StarkField, TestError, WORD_SIZE,
+    build_op_test, build_test, prop_randw, proptest::prelude::*, prove, rand::rand_value,
+    ExecutionError, Felt, FieldElement, MemAdviceProvider, ProofOptions, StarkField, TestError,
+    WORD_SIZE,
 };
 
 // FIELD OPS ASSERTIONS - MANUAL TESTS
@@ -44,6 +45,92 @@ fn assert_eq_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn assert_eqw() {
+    let asm_op = "assert_eqw";
+
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 1, 2, 3, 4]);
+    test.expect_stack(&[]);
+
+    let test = build_op_test!(asm_op, &[5, 6, 7, 8, 1, 2, 3, 4, 1, 2, 3, 4]);
+    test.expect_stack(&[8, 7, 6, 5]);
+}
+
+#[test]
+fn assert_eqw_fail() {
+    let asm_op = "assert_eqw";
+
+    // limbs are compared starting from the top of the words; the clock cycle at which the
+    // assertion fails identifies the limb which differed
+    let expected_clk = [3, 6, 9, 11];
+    for (limb, &clk) in expected_clk.iter().enumerate() {
+        let mut inputs = vec![1, 2, 3, 4, 1, 2, 3, 4];
+        inputs[7 - limb] += 1;
+
+        let test = build_op_test!(asm_op, &inputs);
+        match test.execute() {
+            Err(ExecutionError::FailedAssertion {
+                clk: actual_clk, ..
+            }) => {
+                assert_eq!(clk, actual_clk, "unexpected clock cycle for limb {limb}")
+            }
+            result => panic!("expected FailedAssertion error, but got {:?}", result.err()),
+        }
+    }
+}
+
+#[test]
+fn assert_with_error_code() {
+    let test = build_op_test!("assert.err=4096", &[1]);
+    test.expect_stack(&[]);
+
+    let test = build_op_test!("assert_eq.err=4096", &[3, 3]);
+    test.expect_stack(&[]);
+
+    let test = build_op_test!("assertz.err=4096", &[0]);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn assert_with_error_code_fail() {
+    let source = "
+        const.ERR_NOT_ONE=4096
+        begin
+            assert.err=ERR_NOT_ONE
+        end";
+
+    // the error code of the failed assertion is surfaced by the prover
+    let test = build_test!(source, &[2]);
+    let result = prove(
+        &test.compile(),
+        test.stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    );
+    match result {
+        Err(ExecutionError::FailedAssertion { err_code, .. }) => {
+            assert_eq!(Felt::new(4096), err_code)
+        }
+        _ => panic!("expected FailedAssertion error, but got {result:?}"),
+    }
+
+    for (asm_op, inputs) in [("assert_eq.err=7", vec![2, 1]), ("assertz.err=7", vec![1])] {
+        let test = build_op_test!(asm_op, &inputs);
+        let result = prove(
+            &test.compile(),
+            test.stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            ProofOptions::default(),
+        );
+        match result {
+            Err(ExecutionError::FailedAssertion { err_code, .. }) => {
+                assert_eq!(Felt::new(7), err_code)
+            }
+            _ => panic!("expected FailedAssertion error, but got {result:?}"),
+        }
+    }
+}
+
 // FIELD OPS ARITHMETIC - MANUAL TESTS
 // ================================================================================================
 
@@ -312,6 +399,7 @@ fn pow2() {
 
     build_op_test!(asm_op, &[0]).expect_stack(&[1]);
     build_op_test!(asm_op, &[31]).expect_stack(&[1 << 31]);
+    build_op_test!(asm_op, &[32]).expect_stack(&[1 << 32]);
     build_op_test!(asm_op, &[63]).expect_stack(&[1 << 63]);
 }
 
@@ -325,6 +413,45 @@ fn pow2_fail() {
     value += (u32::MAX as u64) + 1;
 
     build_op_test!(asm_op, &[value]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // --- smallest value > 63 --------------------------------------------------------------------
+    build_op_test!(asm_op, &[64]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn ilog2() {
+    let asm_op = "ilog2";
+
+    // --- powers of two --------------------------------------------------------------------------
+    for n in [0, 31, 32, 62] {
+        build_op_test!(asm_op, &[1 << n]).expect_stack(&[n]);
+    }
+
+    // --- values which are not powers of two -----------------------------------------------------
+    build_op_test!(asm_op, &[3]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[u32::MAX as u64]).expect_stack(&[31]);
+    build_op_test!(asm_op, &[(1 << 32) + 1]).expect_stack(&[32]);
+    build_op_test!(asm_op, &[(1 << 63) - 1]).expect_stack(&[62]);
+
+    // --- random values --------------------------------------------------------------------------
+    let value = (rand_value::<u64>() >> 1) | 1;
+    build_op_test!(asm_op, &[value]).expect_stack(&[value.ilog2() as u64]);
+
+    // --- the rest of the stack is not modified --------------------------------------------------
+    build_op_test!(asm_op, &[7, 1 << 40]).expect_stack(&[40, 7]);
+}
+
+#[test]
+fn ilog2_fail() {
+    let asm_op = "ilog2";
+
+    // --- zero -----------------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // --- values greater than or equal to 2^63 ---------------------------------------------------
+    build_op_test!(asm_op, &[1 << 63]).expect_error(TestError::ExecutionError("FailedAssertion"));
+    build_op_test!(asm_op, &[Felt::MODULUS - 1])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
 #[test]
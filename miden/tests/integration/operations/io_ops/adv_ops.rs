@@ -32,6 +32,14 @@ fn adv_push_invalid() {
     test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
 }
 
+#[test]
+fn adv_push_underflow() {
+    // reading more values than the advice stack contains should throw an error rather than pad
+    // the missing values with zeros
+    let test = build_op_test!("adv_push.4", &[], &[1, 2, 3]);
+    test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
+}
+
 // OVERWRITING VALUES ON THE STACK (LOAD)
 // ================================================================================================
 
@@ -51,6 +59,11 @@ fn adv_loadw_invalid() {
     // attempting to read from empty advice stack should throw an error
     let test = build_op_test!("adv_loadw", &[0, 0, 0, 0]);
     test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
+
+    // attempting to read a word from an advice stack containing fewer than 4 values should throw
+    // an error as well
+    let test = build_op_test!("adv_loadw", &[0, 0, 0, 0], &[1, 2, 3]);
+    test.expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
 }
 
 // MOVING ELEMENTS TO MEMORY VIA THE STACK (PIPE)
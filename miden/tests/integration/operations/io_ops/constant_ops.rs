@@ -1,4 +1,4 @@
-use super::build_op_test;
+use super::{build_op_test, build_test, Felt, StarkField, TestError};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -83,3 +83,36 @@ fn push_without_separator() {
     let test = build_op_test!(asm_op);
     test.expect_stack(&expected);
 }
+
+#[test]
+fn push_hex_word() {
+    let word = [1, 0xabcd, Felt::MODULUS - 1, u32::MAX as u64 + 7];
+    let hex = word.iter().map(|value| format!("{value:016x}")).collect::<String>();
+
+    // the limbs of the word are pushed in order, and thus, the word stored in memory matches the
+    // word provided via the stack inputs
+    let source = format!(
+        "begin
+            push.0x{hex}
+            mem_storew.100
+            eqw assert
+            dropw dropw
+        end"
+    );
+
+    let test = build_test!(source, &word);
+    test.expect_stack_and_memory(&[], 100, &word);
+
+    // --- the same word can be pushed via four separate values -----------------------------------
+    let asm_op = format!("push.{}.{}.{}.{}", word[0], word[1], word[2], word[3]);
+    let test = build_op_test!(asm_op);
+    test.expect_stack(&[word[3], word[2], word[1], word[0]]);
+}
+
+#[test]
+fn push_hex_word_invalid_limb() {
+    // the third limb of the word is not a valid field element
+    let asm_op = format!("push.0x{:016x}{:016x}{:016x}{:016x}", 1, 2, Felt::MODULUS, 4);
+    let test = build_op_test!(asm_op);
+    test.expect_error(TestError::AssemblyError("limb 2"));
+}
@@ -24,6 +24,35 @@ fn sdepth() {
     let source = format!("begin push.1 push.1 {test_op} end");
     let test = build_test!(&source, &[0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
     test.expect_stack(&[18, 1, 1, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4, 3]);
+
+    // --- procedure executed at different stack depths -------------------------------------------
+    let source = format!(
+        "
+        proc.foo
+            {test_op}
+        end
+        begin
+            exec.foo
+            push.1 push.2
+            exec.foo
+        end"
+    );
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&[19, 2, 1, 16]);
+
+    // --- depth includes the overflow entries below the caller's frame ---------------------------
+    let source = format!(
+        "
+        proc.foo
+            {test_op}
+        end
+        begin
+            push.1.2.3
+            exec.foo
+        end"
+    );
+    let test = build_test!(&source, &[0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    test.expect_stack(&[19, 3, 2, 1, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4]);
 }
 
 // LOCADDR INSTRUCTION
@@ -191,4 +220,18 @@ fn clk() {
 
     let test = build_test!(source, &[]);
     test.expect_stack(&[3, 4, 5]);
+
+    // --- procedure executed at different cycles -------------------------------------------------
+    let source = "
+        proc.foo
+            clk
+        end
+        begin
+            exec.foo
+            push.5
+            exec.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[3, 5, 1]);
 }
@@ -1,4 +1,4 @@
-use super::build_test;
+use super::{build_test, TestError};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -281,6 +281,32 @@ fn nested_procedures() {
 
     let test = build_test!(source, &inputs);
     test.expect_stack(&[3, 2, 1, 0, 1, 0]);
+
+    // --- test nested procedures with overlapping local indices ----------------------------------
+    let source = "
+        proc.foo.2
+            loc_store.0
+            loc_store.1
+            loc_load.1
+            loc_load.0
+            add
+        end
+        proc.bar.3
+            loc_store.0
+            loc_store.1
+            loc_store.2
+            exec.foo
+            loc_load.2
+            loc_load.1
+            loc_load.0
+        end
+        begin
+            exec.bar
+        end";
+    let inputs = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&[8, 7, 6, 9, 3, 2, 1]);
 }
 
 #[test]
@@ -307,3 +333,20 @@ fn free_memory_pointer() {
     let test = build_test!(source, &inputs);
     test.expect_stack(&[7, 6, 5, 4, 1]);
 }
+
+#[test]
+fn locals_not_allocated() {
+    let source = "
+        proc.foo
+            loc_load.0
+        end
+        begin
+            exec.foo
+        end";
+    let test = build_test!(source);
+    test.expect_error(TestError::AssemblyError("LocalsNotAllocated"));
+
+    let source = "begin locaddr.0 end";
+    let test = build_test!(source);
+    test.expect_error(TestError::AssemblyError("LocalsNotAllocated"));
+}
@@ -213,3 +213,34 @@ fn read_after_write() {
     let test = build_op_test!("mem_storew.0 dropw mem_loadw.0", &[1, 2, 3, 4, 5, 6, 7, 8]);
     test.expect_stack(&[8, 7, 6, 5]);
 }
+
+// FINAL MEMORY STATE
+// ================================================================================================
+
+#[test]
+fn last_memory_state() {
+    let source = "
+        begin
+            push.1.2.3.4
+            mem_storew.0
+            dropw
+            push.5
+            mem_store.3
+            push.6.7.8.9
+            mem_storew.3
+            dropw
+            mem_load.10
+            drop
+        end";
+
+    let trace = build_test!(source).execute().unwrap();
+    let memory = trace
+        .last_memory_state()
+        .iter()
+        .map(|(&addr, word)| (addr, word.iter().map(|v| v.as_int()).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    // overwritten values are replaced, and addresses which were only read hold zeros
+    let expected = vec![(0, vec![1, 2, 3, 4]), (3, vec![6, 7, 8, 9]), (10, vec![0, 0, 0, 0])];
+    assert_eq!(expected, memory);
+}
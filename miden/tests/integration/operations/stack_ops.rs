@@ -10,6 +10,10 @@ fn drop() {
     // --- simple case ----------------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
     test.expect_stack(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 0]);
+
+    // --- dropping past the minimum stack depth pads the stack with zeros ------------------------
+    let test = build_op_test!("repeat.20 drop end", &[3, 2, 1]);
+    test.expect_stack(&[0; STACK_TOP_SIZE]);
 }
 
 #[test]
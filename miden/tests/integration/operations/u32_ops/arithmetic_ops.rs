@@ -1,5 +1,8 @@
 use super::{test_param_out_of_bounds, test_unchecked_execution};
-use test_utils::{build_op_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
+use test_utils::{
+    build_op_test, proptest::prelude::*, rand::rand_value, ExecutionError, Felt, TestError,
+    U32_BOUND, ZERO,
+};
 
 // U32 OPERATIONS TESTS - MANUAL - ARITHMETIC OPERATIONS
 // ================================================================================================
@@ -983,6 +986,61 @@ fn u32unchecked_divmod_fail() {
     test.expect_error(TestError::ExecutionError("DivideByZero"));
 }
 
+#[test]
+fn u32wrapping_div_mod_divmod() {
+    // the wrapping variants are aliases of the unchecked variants
+    test_div("u32wrapping_div");
+    test_mod("u32wrapping_mod");
+    test_divmod("u32wrapping_divmod");
+
+    // --- test max values of a and b -------------------------------------------------------------
+    let max = U32_BOUND - 1;
+    for b in [1, 2, max - 1, max] {
+        let test = build_op_test!("u32wrapping_div", &[max, b]);
+        test.expect_stack(&[max / b]);
+        let test = build_op_test!("u32wrapping_mod", &[max, b]);
+        test.expect_stack(&[max % b]);
+        let test = build_op_test!("u32wrapping_divmod", &[max, b]);
+        test.expect_stack(&[max % b, max / b]);
+
+        let test = build_op_test!(format!("u32wrapping_div.{b}").as_str(), &[max]);
+        test.expect_stack(&[max / b]);
+        let test = build_op_test!(format!("u32wrapping_mod.{b}").as_str(), &[max]);
+        test.expect_stack(&[max % b]);
+        let test = build_op_test!(format!("u32wrapping_divmod.{b}").as_str(), &[max]);
+        test.expect_stack(&[max % b, max / b]);
+    }
+
+    // should fail if b == 0.
+    let test = build_op_test!("u32wrapping_div", &[1, 0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+    let test = build_op_test!("u32wrapping_div.0");
+    test.expect_error(TestError::AssemblyError("division by zero"));
+}
+
+#[test]
+fn u32checked_fail_clk() {
+    // the error returned by a checked operation carries the clock cycle at which the check failed;
+    // the first operation of a program is executed at clock cycle 1.
+    for (asm_op, inputs, expected_clk) in [
+        ("u32checked_add", vec![U32_BOUND, 1], 1),
+        ("u32checked_div", vec![1, U32_BOUND], 1),
+        ("u32checked_and", vec![U32_BOUND, 1], 1),
+        // the shift amount is pushed onto the stack before the inputs are checked
+        ("u32checked_shr.31", vec![U32_BOUND], 2),
+    ] {
+        let test = build_op_test!(asm_op, &inputs);
+        match test.execute() {
+            Err(ExecutionError::NotU32Value(value, clk, err_code)) => {
+                assert_eq!(Felt::new(U32_BOUND), value);
+                assert_eq!(expected_clk, clk, "unexpected clock cycle for {asm_op}");
+                assert_eq!(ZERO, err_code);
+            }
+            result => panic!("expected NotU32Value error for {asm_op}, but got {:?}", result.err()),
+        }
+    }
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - ARITHMETIC OPERATIONS
 // ================================================================================================
 proptest! {
@@ -327,6 +327,65 @@ fn u32unchecked_shl_b() {
     assert!(test.execute().is_ok());
 }
 
+#[test]
+fn u32overflowing_shl() {
+    // left shift: pops a from the stack and pushes (a * 2^b) mod 2^32 followed by the bits
+    // shifted out of a
+    let asm_op = "u32overflowing_shl";
+    let get_asm_op = |b: u32| format!("{asm_op}.{b}");
+    let expected = |a: u32, b: u32| {
+        let result = (a as u64) << b;
+        [result >> 32, result as u32 as u64]
+    };
+
+    // --- test simple case -----------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[5, 1, 1]);
+    test.expect_stack(&[0, 2, 5]);
+    let test = build_op_test!(get_asm_op(1).as_str(), &[5, 1]);
+    test.expect_stack(&[0, 2, 5]);
+
+    // --- test max values of a and b -------------------------------------------------------------
+    let a = (U32_BOUND - 1) as u32;
+    let b = 31;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&expected(a, b));
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&expected(a, b));
+
+    // --- test b = 0 -----------------------------------------------------------------------------
+    let a = (U32_BOUND - 1) as u32;
+    let b = 0;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[0, a as u64]);
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&[0, a as u64]);
+
+    // --- test random values ---------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&expected(a, b));
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&expected(a, b));
+}
+
+#[test]
+fn u32wrapping_shl() {
+    // u32wrapping_shl is an alias of u32unchecked_shl
+    let a = (U32_BOUND - 1) as u32;
+    for b in [0, 1, 31] {
+        let test = build_op_test!("u32wrapping_shl", &[a as u64, b as u64]);
+        test.expect_stack(&[a.wrapping_shl(b) as u64]);
+
+        let asm_op = format!("u32wrapping_shl.{b}");
+        let test = build_op_test!(asm_op.as_str(), &[a as u64]);
+        test.expect_stack(&[a.wrapping_shl(b) as u64]);
+    }
+}
+
 #[test]
 fn u32checked_shr() {
     // right shift: pops a from the stack and pushes a / 2^b for a provided value b
@@ -491,6 +550,61 @@ fn u32unchecked_shr_b() {
     assert!(test.execute().is_ok());
 }
 
+#[test]
+fn u32overflowing_shr() {
+    // right shift: pops a from the stack and pushes a / 2^b followed by the bits shifted out of a
+    let asm_op = "u32overflowing_shr";
+    let get_asm_op = |b: u32| format!("{asm_op}.{b}");
+    let expected = |a: u32, b: u32| [(a as u64) % (1 << b), (a >> b) as u64];
+
+    // --- test simple case -----------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[5, 5, 2]);
+    test.expect_stack(&[1, 1, 5]);
+    let test = build_op_test!(get_asm_op(2).as_str(), &[5, 5]);
+    test.expect_stack(&[1, 1, 5]);
+
+    // --- test max values of a and b -------------------------------------------------------------
+    let a = (U32_BOUND - 1) as u32;
+    let b = 31;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&expected(a, b));
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&expected(a, b));
+
+    // --- test b = 0 -----------------------------------------------------------------------------
+    let a = (U32_BOUND - 1) as u32;
+    let b = 0;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[0, a as u64]);
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&[0, a as u64]);
+
+    // --- test random values ---------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&expected(a, b));
+    let test = build_op_test!(get_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&expected(a, b));
+}
+
+#[test]
+fn u32wrapping_shr() {
+    // u32wrapping_shr is an alias of u32unchecked_shr
+    let a = (U32_BOUND - 1) as u32;
+    for b in [0, 1, 31] {
+        let test = build_op_test!("u32wrapping_shr", &[a as u64, b as u64]);
+        test.expect_stack(&[a.wrapping_shr(b) as u64]);
+
+        let asm_op = format!("u32wrapping_shr.{b}");
+        let test = build_op_test!(asm_op.as_str(), &[a as u64]);
+        test.expect_stack(&[a.wrapping_shr(b) as u64]);
+    }
+}
+
 #[test]
 fn u32checked_rotl() {
     // Computes c by rotating a 32-bit representation of a to the left by b bits.
@@ -794,6 +908,25 @@ fn u32unchecked_rotr() {
     assert!(test.execute().is_ok());
 }
 
+#[test]
+fn u32wrapping_rotl_rotr() {
+    // u32wrapping_rotl and u32wrapping_rotr are aliases of their unchecked counterparts
+    let a = (U32_BOUND - 1) as u32 - 1;
+    for b in [0, 1, 31] {
+        let test = build_op_test!("u32wrapping_rotl", &[a as u64, b as u64]);
+        test.expect_stack(&[a.rotate_left(b) as u64]);
+        let asm_op = format!("u32wrapping_rotl.{b}");
+        let test = build_op_test!(asm_op.as_str(), &[a as u64]);
+        test.expect_stack(&[a.rotate_left(b) as u64]);
+
+        let test = build_op_test!("u32wrapping_rotr", &[a as u64, b as u64]);
+        test.expect_stack(&[a.rotate_right(b) as u64]);
+        let asm_op = format!("u32wrapping_rotr.{b}");
+        let test = build_op_test!(asm_op.as_str(), &[a as u64]);
+        test.expect_stack(&[a.rotate_right(b) as u64]);
+    }
+}
+
 #[test]
 fn u32checked_popcnt() {
     let asm_op = "u32checked_popcnt";
@@ -1,7 +1,7 @@
 use super::{prop_randw, test_inputs_out_of_bounds};
 use test_utils::{
-    build_op_test, proptest::prelude::*, rand::rand_value, Felt, StarkField, TestError, U32_BOUND,
-    WORD_SIZE,
+    build_op_test, proptest::prelude::*, prove, rand::rand_value, ExecutionError, Felt,
+    MemAdviceProvider, ProofOptions, StarkField, TestError, U32_BOUND, WORD_SIZE,
 };
 
 // U32 OPERATIONS TESTS - MANUAL - CONVERSIONS AND TESTS
@@ -157,6 +157,37 @@ fn u32assert2_fail() {
     test.expect_error(TestError::ExecutionError(err));
 }
 
+#[test]
+fn u32assert_with_error_code() {
+    // assertion passes and leaves the stack unchanged if the values are u32
+    let test = build_op_test!("u32assert.err=4096", &[1]);
+    test.expect_stack(&[1]);
+
+    let test = build_op_test!("u32assert.2.err=4096", &[1, 2]);
+    test.expect_stack(&[2, 1]);
+
+    // the error code of the failed assertion is surfaced by the prover
+    for (asm_op, inputs) in [
+        ("u32assert.err=4096", vec![U32_BOUND]),
+        ("u32assert.2.err=4096", vec![U32_BOUND, 1]),
+    ] {
+        let test = build_op_test!(asm_op, &inputs);
+        let result = prove(
+            &test.compile(),
+            test.stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            ProofOptions::default(),
+        );
+        match result {
+            Err(ExecutionError::NotU32Value(value, _, err_code)) => {
+                assert_eq!(Felt::new(U32_BOUND), value);
+                assert_eq!(Felt::new(4096), err_code);
+            }
+            _ => panic!("expected NotU32Value error, but got {result:?}"),
+        }
+    }
+}
+
 #[test]
 fn u32assertn_fail() {
     let asm_op = "u32assert.3";
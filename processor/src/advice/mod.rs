@@ -1,4 +1,6 @@
-use super::{ExecutionError, Felt, InputError, StarkField, Word};
+use super::{
+    AdviceError, ExecutionError, Felt, InputError, StackTopState, StarkField, VmState, Word,
+};
 use vm_core::{
     crypto::{
         hash::RpoDigest,
@@ -8,17 +10,24 @@ use vm_core::{
         collections::{BTreeMap, KvMap, RecordingMap, Vec},
         IntoBytes,
     },
+    DebugOptions,
 };
 
 mod inputs;
 pub use inputs::AdviceInputs;
 
 mod providers;
-pub use providers::{MemAdviceProvider, RecAdviceProvider};
+pub use providers::{AdviceSnapshot, CallbackAdviceProvider, MemAdviceProvider, RecAdviceProvider};
 
 mod source;
 pub use source::AdviceSource;
 
+mod stream;
+pub use stream::{AdviceRequest, AdviceStream};
+
+#[cfg(test)]
+mod tests;
+
 // ADVICE PROVIDER
 // ================================================================================================
 
@@ -188,6 +197,32 @@ pub trait AdviceProvider {
     /// TODO: keeping track of the clock cycle is used primarily for attaching clock cycle to error
     /// messages generated by the advice provider; consider refactoring.
     fn advance_clock(&mut self);
+
+    // DEBUGGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles a debug request issued by the program being executed.
+    ///
+    /// This is invoked only when the VM is running in debug mode. The `state` contains the state
+    /// of the VM at the clock cycle at which the request was issued, and `options` specify which
+    /// part of this state was requested. By default, the requested part of the state is printed
+    /// to stderr (this requires the `std` feature).
+    fn on_debug(&mut self, state: &VmState, options: &DebugOptions) {
+        #[cfg(feature = "std")]
+        crate::debug::print_vm_state(state, options);
+        #[cfg(not(feature = "std"))]
+        let _ = (state, options);
+    }
+
+    /// Handles a trace event emitted by the program being executed.
+    ///
+    /// This is invoked only when tracing is enabled for the execution. `trace_id` is the
+    /// identifier specified by the `trace` instruction which emitted the event, `clk` is the clock
+    /// cycle at which the event was emitted, and `stack_top` is the state of the top 16 stack
+    /// items at this clock cycle. By default, trace events are ignored.
+    fn on_trace(&mut self, trace_id: u32, clk: u32, stack_top: &StackTopState) {
+        let _ = (trace_id, clk, stack_top);
+    }
 }
 
 impl<'a, T> AdviceProvider for &'a mut T
@@ -258,4 +293,12 @@ where
     fn advance_clock(&mut self) {
         T::advance_clock(self)
     }
+
+    fn on_debug(&mut self, state: &VmState, options: &DebugOptions) {
+        T::on_debug(self, state, options)
+    }
+
+    fn on_trace(&mut self, trace_id: u32, clk: u32, stack_top: &StackTopState) {
+        T::on_trace(self, trace_id, clk, stack_top)
+    }
 }
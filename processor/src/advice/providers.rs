@@ -1,6 +1,7 @@
 use super::{
-    AdviceInputs, AdviceProvider, AdviceSource, BTreeMap, ExecutionError, Felt, IntoBytes, KvMap,
-    MerklePath, MerkleStore, NodeIndex, RecordingMap, RpoDigest, StarkField, StoreNode, Vec, Word,
+    AdviceError, AdviceInputs, AdviceProvider, AdviceRequest, AdviceSource, AdviceStream, BTreeMap,
+    ExecutionError, Felt, IntoBytes, KvMap, MerklePath, MerkleStore, NodeIndex, RecordingMap,
+    RpoDigest, StarkField, StoreNode, Vec, Word,
 };
 
 // TYPE ALIASES
@@ -208,6 +209,46 @@ impl From<AdviceInputs> for MemAdviceProvider {
     }
 }
 
+impl MemAdviceProvider {
+    /// Returns this provider with the specified values added to the advice stack.
+    ///
+    /// The values are read by the program in the order in which they are provided, i.e., the
+    /// first value is at the top of the advice stack. Values added to a provider which already
+    /// holds advice stack elements are placed below these elements; thus, the elements of the
+    /// advice stack are read in the same order as they are added via this method (this is also
+    /// the order in which [AdviceInputs] places the elements of the advice stack).
+    pub fn with_stack(mut self, values: &[Felt]) -> Self {
+        self.provider.stack.splice(0..0, values.iter().rev().copied());
+        self
+    }
+
+    /// Returns this provider with the specified values added to the advice map under the specified
+    /// key, replacing the values previously inserted under this key.
+    ///
+    /// The values can be moved onto the advice stack by the program (e.g., via `adv.push_mapval`)
+    /// in which case the first value ends up at the top of the advice stack.
+    pub fn with_map(mut self, key: Word, values: Vec<Felt>) -> Self {
+        self.provider.map.insert(key.into_bytes(), values);
+        self
+    }
+
+    /// Returns a snapshot of the current state of this provider.
+    ///
+    /// The snapshot can later be passed to [MemAdviceProvider::restore()] to reset the provider to
+    /// this state, e.g., between speculative executions of a program.
+    pub fn snapshot(&self) -> AdviceSnapshot {
+        AdviceSnapshot {
+            provider: self.provider.clone(),
+        }
+    }
+
+    /// Resets the state of this provider (i.e., the advice stack, the advice map, and the Merkle
+    /// store) to the state captured by the specified snapshot.
+    pub fn restore(&mut self, snapshot: AdviceSnapshot) {
+        self.provider = snapshot.provider;
+    }
+}
+
 /// Accessors to internal data structures of the provider used for testing purposes.
 #[cfg(any(test, feature = "internals"))]
 impl MemAdviceProvider {
@@ -282,6 +323,18 @@ impl AdviceProvider for MemAdviceProvider {
     }
 }
 
+// ADVICE SNAPSHOT
+// ================================================================================================
+
+/// A snapshot of the state of a [MemAdviceProvider].
+///
+/// A snapshot is created via [MemAdviceProvider::snapshot()] and can be restored via
+/// [MemAdviceProvider::restore()].
+#[derive(Debug, Clone)]
+pub struct AdviceSnapshot {
+    provider: BaseAdviceProvider<SimpleAdviceMap, SimpleMerkleMap>,
+}
+
 // RECORDING ADVICE PROVIDER
 // ================================================================================================
 
@@ -407,3 +460,116 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.advance_clock()
     }
 }
+
+// CALLBACK ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] implementation which pulls advice stack elements from an [AdviceStream] on
+/// demand.
+///
+/// The advice map and the Merkle store of this provider are kept in memory, same as for
+/// [MemAdviceProvider]. Reads from the advice stack first consume the elements currently on the
+/// stack (i.e., the initial advice stack and the elements pushed onto it during execution), and
+/// only when these are exhausted is the stream queried for more elements. Thus, from the point
+/// of view of the executed program, the elements yielded by the stream form the bottom of the
+/// advice stack.
+pub struct CallbackAdviceProvider<F: AdviceStream> {
+    provider: BaseAdviceProvider<SimpleAdviceMap, SimpleMerkleMap>,
+    stream: F,
+}
+
+impl<F: AdviceStream> CallbackAdviceProvider<F> {
+    /// Returns a new [CallbackAdviceProvider] instantiated from the specified advice inputs and
+    /// pulling the remaining advice stack elements from the specified stream.
+    pub fn new(inputs: AdviceInputs, stream: F) -> Self {
+        Self {
+            provider: inputs.into(),
+            stream,
+        }
+    }
+
+    /// Consumes this provider and returns the underlying advice stream.
+    pub fn into_stream(self) -> F {
+        self.stream
+    }
+
+    /// Makes sure that the advice stack contains at least `num_elements` elements by pulling the
+    /// missing elements from the stream.
+    ///
+    /// # Errors
+    /// Returns an error if the stream is exhausted before enough elements have been pulled, or if
+    /// the stream fails to generate the advice.
+    fn ensure_stack_len(&mut self, num_elements: usize) -> Result<(), ExecutionError> {
+        let step = self.provider.step;
+        while self.provider.stack.len() < num_elements {
+            let request = AdviceRequest {
+                step,
+                num_elements: num_elements - self.provider.stack.len(),
+            };
+            let values = match self.stream.pull_advice(request) {
+                Ok(values) if !values.is_empty() => values,
+                Ok(_) | Err(AdviceError::Exhausted) => {
+                    return Err(ExecutionError::AdviceStackReadFailed(step))
+                }
+                Err(err) => return Err(ExecutionError::AdviceStreamFailed(step, err)),
+            };
+
+            // the top of the stack is at the end of the vector, and thus, the pulled elements are
+            // placed at its start with the first element closest to the top
+            self.provider.stack.splice(0..0, values.into_iter().rev());
+        }
+        Ok(())
+    }
+}
+
+/// Pass-through implementations of [AdviceProvider] methods, except for the methods reading from
+/// the advice stack which query the stream when needed.
+#[rustfmt::skip]
+impl<F: AdviceStream> AdviceProvider for CallbackAdviceProvider<F> {
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        self.ensure_stack_len(1)?;
+        self.provider.pop_stack()
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        self.ensure_stack_len(4)?;
+        self.provider.pop_stack_word()
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        self.ensure_stack_len(8)?;
+        self.provider.pop_stack_dword()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.provider.push_stack(source)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.provider.insert_into_map(key, values)
+    }
+
+    fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
+        self.provider.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(&self, root: Word, depth: &Felt, index: &Felt) -> Result<MerklePath, ExecutionError> {
+        self.provider.get_merkle_path(root, depth, index)
+    }
+
+    fn get_leaf_depth(&self, root: Word, tree_depth: &Felt, index: &Felt) -> Result<u8, ExecutionError> {
+        self.provider.get_leaf_depth(root, tree_depth, index)
+    }
+
+    fn update_merkle_node(&mut self, root: Word, depth: &Felt, index: &Felt, value: Word) -> Result<MerklePath, ExecutionError> {
+        self.provider.update_merkle_node(root, depth, index, value)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.provider.merge_roots(lhs, rhs)
+    }
+
+    fn advance_clock(&mut self) {
+        self.provider.advance_clock()
+    }
+}
@@ -0,0 +1,48 @@
+use super::{AdviceError, Felt, Vec};
+
+// ADVICE REQUEST
+// ================================================================================================
+
+/// Describes advice requested by the VM from an [AdviceStream].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdviceRequest {
+    /// Clock cycle at which the advice was requested.
+    pub step: u32,
+    /// Minimum number of elements needed to satisfy the request.
+    pub num_elements: usize,
+}
+
+// ADVICE STREAM
+// ================================================================================================
+
+/// Defines a source of advice stack elements which are generated on demand.
+///
+/// An advice stream can be used to feed programs which consume large amounts of advice without
+/// having to keep all of it in memory. The stream is queried only when the advice stack does not
+/// contain enough elements to satisfy a read.
+///
+/// This trait is implemented for all closures with the signature of
+/// [pull_advice()](AdviceStream::pull_advice).
+pub trait AdviceStream {
+    /// Returns the next elements of the advice stack.
+    ///
+    /// The first element of the returned vector is the first one to be read by the VM. The stream
+    /// should return at least `request.num_elements` elements; if fewer elements are returned, the
+    /// stream is queried again. Elements which are returned in excess of the request are buffered
+    /// by the advice provider and consumed by subsequent reads.
+    ///
+    /// An empty vector (or [AdviceError::Exhausted]) signals that the stream has no more elements.
+    ///
+    /// # Errors
+    /// Returns an error if the advice could not be generated.
+    fn pull_advice(&mut self, request: AdviceRequest) -> Result<Vec<Felt>, AdviceError>;
+}
+
+impl<F> AdviceStream for F
+where
+    F: FnMut(AdviceRequest) -> Result<Vec<Felt>, AdviceError>,
+{
+    fn pull_advice(&mut self, request: AdviceRequest) -> Result<Vec<Felt>, AdviceError> {
+        self(request)
+    }
+}
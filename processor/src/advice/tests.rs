@@ -0,0 +1,136 @@
+use super::{
+    super::{execute, ExecutionError, StackInputs},
+    AdviceError, AdviceInputs, AdviceProvider, AdviceRequest, CallbackAdviceProvider, Felt,
+    MemAdviceProvider, Vec,
+};
+use miden_assembly::Assembler;
+
+// MEMORY ADVICE PROVIDER
+// ================================================================================================
+
+#[test]
+fn mem_provider_snapshot_and_restore() {
+    // each execution consumes two elements from the advice stack and inserts an entry into the
+    // advice map
+    let program = Assembler::default().compile("begin adv_push.2 adv.insert_hdword end").unwrap();
+
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3, 4, 5, 6]).unwrap();
+    let mut provider = MemAdviceProvider::from(advice_inputs);
+    let snapshot = provider.snapshot();
+
+    let trace = execute(&program, StackInputs::default(), &mut provider).unwrap();
+    let first_outputs = trace.stack_outputs().clone();
+    let first_stack = provider.stack().to_vec();
+    let first_map = provider.map().clone();
+    assert_eq!(&[2, 1], first_outputs.stack_truncated(2));
+    assert_eq!(1, first_map.len());
+
+    // the second execution observes the state mutated by the first one
+    let trace = execute(&program, StackInputs::default(), &mut provider).unwrap();
+    assert_eq!(&[4, 3], trace.stack_outputs().stack_truncated(2));
+    assert_eq!(2, provider.map().len());
+
+    // after the snapshot is restored, the provider yields the same advice as in the first run
+    provider.restore(snapshot);
+    let trace = execute(&program, StackInputs::default(), &mut provider).unwrap();
+    assert_eq!(&first_outputs, trace.stack_outputs());
+    assert_eq!(first_stack, provider.stack());
+    assert_eq!(&first_map, provider.map());
+}
+
+#[test]
+fn mem_provider_builder() {
+    // reads 3 values from the advice stack, and then 3 more values from the advice map
+    let program = Assembler::default()
+        .compile("begin adv_push.3 push.1.2.3.4 adv.push_mapval dropw adv_push.3 end")
+        .unwrap();
+
+    let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let mut provider = MemAdviceProvider::default()
+        .with_stack(&[Felt::new(1), Felt::new(2)])
+        .with_stack(&[Felt::new(3)])
+        .with_map(key, vec![Felt::new(7), Felt::new(8), Felt::new(9)]);
+
+    // the values are read in the order in which they were added to the provider
+    let trace = execute(&program, StackInputs::default(), &mut provider).unwrap();
+    assert_eq!(&[9, 8, 7, 3, 2, 1], trace.stack_outputs().stack_truncated(6));
+    assert!(provider.stack().is_empty());
+
+    // the provider is equivalent to the one built from advice inputs
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3]).unwrap();
+    let mut expected = MemAdviceProvider::from(advice_inputs);
+    expected
+        .insert_into_map(key, vec![Felt::new(7), Felt::new(8), Felt::new(9)])
+        .unwrap();
+    let expected_trace = execute(&program, StackInputs::default(), &mut expected).unwrap();
+    assert_eq!(expected_trace.stack_outputs(), trace.stack_outputs());
+}
+
+// CALLBACK ADVICE PROVIDER
+// ================================================================================================
+
+#[test]
+fn callback_provider_pulls_advice_lazily() {
+    let program = Assembler::default().compile("begin adv_push.5 adv_loadw end").unwrap();
+
+    // the stream yields 1, 2, 3, ... one element at a time
+    let mut requests = Vec::new();
+    let mut next = 0;
+    let stream = |request: AdviceRequest| -> Result<Vec<Felt>, AdviceError> {
+        requests.push(request.num_elements);
+        next += 1;
+        Ok(vec![Felt::new(next)])
+    };
+    let provider = CallbackAdviceProvider::new(AdviceInputs::default(), stream);
+
+    let trace = execute(&program, StackInputs::default(), provider).unwrap();
+    assert_eq!(&[9, 8, 7, 6, 1], trace.stack_outputs().stack_truncated(5));
+
+    // adv_push requests one element at a time; adv_loadw requests a full word, which is pulled
+    // from the stream element by element
+    assert_eq!(vec![1, 1, 1, 1, 1, 4, 3, 2, 1], requests);
+}
+
+#[test]
+fn callback_provider_reads_initial_advice_first() {
+    let program = Assembler::default().compile("begin adv_push.6 end").unwrap();
+
+    // elements returned in excess of the request are buffered for subsequent reads
+    let mut num_requests = 0;
+    let advice_inputs = AdviceInputs::default().with_stack_values([10, 11]).unwrap();
+    let stream = |request: AdviceRequest| -> Result<Vec<Felt>, AdviceError> {
+        num_requests += 1;
+        assert_eq!(1, request.num_elements);
+        Ok([1, 2, 3].map(Felt::new).to_vec())
+    };
+    let provider = CallbackAdviceProvider::new(advice_inputs, stream);
+
+    let trace = execute(&program, StackInputs::default(), provider).unwrap();
+    assert_eq!(&[1, 3, 2, 1, 11, 10], trace.stack_outputs().stack_truncated(6));
+    assert_eq!(2, num_requests);
+}
+
+#[test]
+fn callback_provider_stream_errors() {
+    let program = Assembler::default().compile("begin adv_push.3 end").unwrap();
+
+    // an empty response means the stream is exhausted
+    let mut values = [1, 2].into_iter();
+    let stream = |_: AdviceRequest| -> Result<Vec<Felt>, AdviceError> {
+        Ok(values.next().map(Felt::new).into_iter().collect())
+    };
+    let provider = CallbackAdviceProvider::new(AdviceInputs::default(), stream);
+    let result = execute(&program, StackInputs::default(), provider);
+    assert!(matches!(result, Err(ExecutionError::AdviceStackReadFailed(_))));
+
+    // errors other than exhaustion of the stream are propagated
+    let stream = |_: AdviceRequest| -> Result<Vec<Felt>, AdviceError> {
+        Err(AdviceError::GenerationFailed("no connection".into()))
+    };
+    let provider = CallbackAdviceProvider::new(AdviceInputs::default(), stream);
+    let result = execute(&program, StackInputs::default(), provider);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::AdviceStreamFailed(_, AdviceError::GenerationFailed(_)))
+    ));
+}
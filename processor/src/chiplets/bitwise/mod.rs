@@ -77,14 +77,17 @@ impl Bitwise {
     // TRACE MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Computes a bitwise AND of `a` and `b` and returns the result. We assume that `a` and `b`
-    /// are 32-bit values. If that's not the case, the result of the computation is undefined.
+    /// Computes a bitwise AND of `a` and `b` and returns the result.
     ///
     /// This also adds 8 rows to the internal execution trace table required for computing the
     /// operation.
-    pub fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let a = assert_u32(a)?.as_int();
-        let b = assert_u32(b)?.as_int();
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value; the error is reported at the
+    /// specified clock cycle.
+    pub fn u32and(&mut self, a: Felt, b: Felt, clk: u32) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a, clk)?.as_int();
+        let b = assert_u32(b, clk)?.as_int();
         let mut result = 0u64;
 
         // append 8 rows to the trace, each row computing bitwise AND in 4 bit limbs starting with
@@ -112,14 +115,17 @@ impl Bitwise {
         Ok(Felt::new(result))
     }
 
-    /// Computes a bitwise XOR of `a` and `b` and returns the result. We assume that `a` and `b`
-    /// are 32-bit values. If that's not the case, the result of the computation is undefined.
+    /// Computes a bitwise XOR of `a` and `b` and returns the result.
     ///
     /// This also adds 8 rows to the internal execution trace table required for computing the
     /// operation.
-    pub fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let a = assert_u32(a)?.as_int();
-        let b = assert_u32(b)?.as_int();
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value; the error is reported at the
+    /// specified clock cycle.
+    pub fn u32xor(&mut self, a: Felt, b: Felt, clk: u32) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a, clk)?.as_int();
+        let b = assert_u32(b, clk)?.as_int();
         let mut result = 0u64;
 
         // append 8 rows to the trace, each row computing bitwise XOR in 4 bit limbs starting with
@@ -231,10 +237,10 @@ impl Default for Bitwise {
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
-pub fn assert_u32(value: Felt) -> Result<Felt, ExecutionError> {
+pub fn assert_u32(value: Felt, clk: u32) -> Result<Felt, ExecutionError> {
     let val_u64 = value.as_int();
     if val_u64 > u32::MAX.into() {
-        Err(ExecutionError::NotU32Value(value))
+        Err(ExecutionError::NotU32Value(value, clk, Felt::ZERO))
     } else {
         Ok(value)
     }
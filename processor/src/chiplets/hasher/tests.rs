@@ -108,6 +108,34 @@ fn hasher_permute() {
     assert!(aux_hints.rows().is_empty());
 }
 
+#[test]
+fn hasher_rounds_compose_to_permutation() {
+    // the round function operates over the same 64-bit field as the rest of the VM; applying all
+    // rounds in sequence must be the same as applying the full permutation
+    let init_state: HasherState = rand_array();
+    let mut expected = init_state;
+    hasher::apply_permutation(&mut expected);
+
+    let mut state = init_state;
+    for i in 0..NUM_ROUNDS {
+        hasher::apply_round(&mut state, i);
+    }
+    assert_eq!(expected, state);
+
+    // the permutation computed by the hasher chiplet must match as well
+    let mut lookups = Vec::new();
+    let (_, final_state) = Hasher::default().permute(init_state, &mut lookups);
+    assert_eq!(expected, final_state);
+
+    // a 2-to-1 hash is a single permutation of a state initialized from the two words
+    let w1: Word = rand_array();
+    let w2: Word = rand_array();
+    let mut state = init_state_from_words(&w1, &w2);
+    hasher::apply_permutation(&mut state);
+    let digest: Word = hasher::merge(&[w1.into(), w2.into()]).into();
+    assert_eq!(digest, state[4..8]);
+}
+
 // MERKLE TREE TESTS
 // ================================================================================================
 
@@ -320,7 +320,7 @@ impl Chiplets {
     /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
     /// computation is undefined.
     pub fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let result = self.bitwise.u32and(a, b)?;
+        let result = self.bitwise.u32and(a, b, self.clk)?;
 
         let bitwise_lookup = BitwiseLookup::new(BITWISE_AND_LABEL, a, b, result);
         self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
@@ -332,7 +332,7 @@ impl Chiplets {
     /// We assume that `a` and `b` are 32-bit values. If that's not the case, the result of the
     /// computation is undefined.
     pub fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let result = self.bitwise.u32xor(a, b)?;
+        let result = self.bitwise.u32xor(a, b, self.clk)?;
 
         let bitwise_lookup = BitwiseLookup::new(BITWISE_XOR_LABEL, a, b, result);
         self.bus.request_bitwise_operation(bitwise_lookup, self.clk);
@@ -537,42 +537,45 @@ impl Chiplets {
         trace[2][kernel_rom_start..].fill(ONE);
         trace[3][padding_start..].fill(ONE);
 
-        // allocate fragments to be filled with the respective execution traces of each chiplet
-        let mut hasher_fragment = TraceFragment::new(CHIPLETS_WIDTH);
-        let mut bitwise_fragment = TraceFragment::new(CHIPLETS_WIDTH);
-        let mut memory_fragment = TraceFragment::new(CHIPLETS_WIDTH);
-        let mut kernel_rom_fragment = TraceFragment::new(CHIPLETS_WIDTH);
-
-        // add the hasher, bitwise, memory, and kernel ROM segments to their respective fragments
-        // so they can be filled with the chiplet traces
+        // split the columns into the segments of the hasher, bitwise, memory, and kernel ROM
+        // chiplets, so that fragments can be built from them
+        let mut hasher_columns = Vec::with_capacity(CHIPLETS_WIDTH);
+        let mut bitwise_columns = Vec::with_capacity(CHIPLETS_WIDTH);
+        let mut memory_columns = Vec::with_capacity(CHIPLETS_WIDTH);
+        let mut kernel_rom_columns = Vec::with_capacity(CHIPLETS_WIDTH);
         for (column_num, column) in trace.iter_mut().enumerate().skip(1) {
+            let (hasher_column, rest) = column.split_at_mut(hasher.trace_len());
+            let (bitwise_column, rest) = rest.split_at_mut(bitwise.trace_len());
+            let (memory_column, rest) = rest.split_at_mut(memory.trace_len());
+            let kernel_rom_column = &mut rest[..kernel_rom.trace_len()];
+
+            // all columns starting from column 1 are relevant for the hasher
+            hasher_columns.push(hasher_column);
             match column_num {
-                1 | 15..=17 => {
-                    // columns 1 and 15 - 17 are relevant only for the hasher
-                    hasher_fragment.push_column_slice(column, hasher.trace_len());
-                }
-                2 => {
-                    // column 2 is relevant to the hasher and to bitwise chiplet
-                    let rest = hasher_fragment.push_column_slice(column, hasher.trace_len());
-                    bitwise_fragment.push_column_slice(rest, bitwise.trace_len());
-                }
+                // columns 15 - 17 are relevant only for the hasher
+                1 | 15..=17 => (),
+                // column 2 is relevant to the hasher and to bitwise chiplet
+                2 => bitwise_columns.push(bitwise_column),
+                // columns 3 and 10 - 14 are relevant for hasher, bitwise, and memory chiplets
                 3 | 10..=14 => {
-                    // columns 3 and 10 - 14 are relevant for hasher, bitwise, and memory chiplets
-                    let rest = hasher_fragment.push_column_slice(column, hasher.trace_len());
-                    let rest = bitwise_fragment.push_column_slice(rest, bitwise.trace_len());
-                    memory_fragment.push_column_slice(rest, memory.trace_len());
+                    bitwise_columns.push(bitwise_column);
+                    memory_columns.push(memory_column);
                 }
+                // columns 4 - 9 are relevant to all chiplets
                 4..=9 => {
-                    // columns 4 - 9 are relevant to all chiplets
-                    let rest = hasher_fragment.push_column_slice(column, hasher.trace_len());
-                    let rest = bitwise_fragment.push_column_slice(rest, bitwise.trace_len());
-                    let rest = memory_fragment.push_column_slice(rest, memory.trace_len());
-                    kernel_rom_fragment.push_column_slice(rest, kernel_rom.trace_len());
+                    bitwise_columns.push(bitwise_column);
+                    memory_columns.push(memory_column);
+                    kernel_rom_columns.push(kernel_rom_column);
                 }
                 _ => panic!("invalid column index"),
             }
         }
 
+        let mut hasher_fragment = TraceFragment::from_columns(hasher_columns);
+        let mut bitwise_fragment = TraceFragment::from_columns(bitwise_columns);
+        let mut memory_fragment = TraceFragment::from_columns(memory_columns);
+        let mut kernel_rom_fragment = TraceFragment::from_columns(kernel_rom_columns);
+
         // fill the fragments with the execution trace from each chiplet
         // TODO: this can be parallelized to fill the traces in multiple threads
         let mut table_builder = hasher.fill_trace(&mut hasher_fragment);
@@ -1,6 +1,6 @@
 use crate::{
-    advice::AdviceProvider, Chiplets, Decoder, ExecutionError, Felt, Process, Stack, StarkField,
-    System, Vec,
+    advice::AdviceProvider, Chiplets, ChipletsLengths, Decoder, ExecutionError, Felt, Process,
+    Stack, StarkField, System, TraceLenSummary, Vec,
 };
 use core::fmt;
 use vm_core::{
@@ -8,6 +8,9 @@ use vm_core::{
     AssemblyOp, Operation, StackOutputs, Word,
 };
 
+#[cfg(feature = "std")]
+use vm_core::DebugOptions;
+
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VmState {
@@ -42,6 +45,39 @@ impl fmt::Display for VmState {
     }
 }
 
+// DEBUG REQUESTS
+// ================================================================================================
+
+/// Prints the part of the provided VM state specified by the debug options to stderr.
+///
+/// For memory intervals, only the addresses which have been initialized are printed.
+#[cfg(feature = "std")]
+pub(crate) fn print_vm_state(state: &VmState, options: &DebugOptions) {
+    match options {
+        DebugOptions::StackAll | DebugOptions::StackTop(_) => {
+            let num_items = match options {
+                DebugOptions::StackTop(n) => *n as usize,
+                _ => state.stack.len(),
+            };
+            eprintln!("Stack state at clk={}, ctx={}:", state.clk, state.ctx);
+            for (i, value) in state.stack.iter().take(num_items).enumerate() {
+                eprintln!("  {i}: {}", value.as_int());
+            }
+        }
+        DebugOptions::MemAll | DebugOptions::MemInterval(..) => {
+            let (start, end) = match options {
+                DebugOptions::MemInterval(addr, len) => (*addr as u64, *addr as u64 + *len as u64),
+                _ => (0, u64::MAX),
+            };
+            eprintln!("Memory state at clk={}, ctx={}:", state.clk, state.ctx);
+            for (addr, word) in state.memory.iter().filter(|(addr, _)| (start..end).contains(addr))
+            {
+                eprintln!("  {addr}: {:?}", word_to_ints(word));
+            }
+        }
+    }
+}
+
 /// Iterator that iterates through vm state at each step of the execution.
 /// This allows debugging or replaying ability to view various process state
 /// at each clock cycle.
@@ -56,6 +92,7 @@ pub struct VmStateIterator {
     clk: u32,
     asmop_idx: usize,
     forward: bool,
+    trace_len_summary: TraceLenSummary,
 }
 
 impl VmStateIterator {
@@ -63,7 +100,19 @@ impl VmStateIterator {
     where
         A: AdviceProvider,
     {
-        let (system, decoder, stack, _, chiplets, _) = process.into_parts();
+        let range_check_mode = process.options.range_check_mode();
+        let (system, decoder, stack, mut range, chiplets, _) = process.into_parts();
+
+        // the range checker is not needed for stepping through the execution; it is used only to
+        // determine the length of the range checker segment of the execution trace
+        chiplets.append_range_checks(&mut range);
+        range.set_mode(range_check_mode);
+        let trace_len_summary = TraceLenSummary::new(
+            system.clk() as usize,
+            range.build_8bit_lookup().len,
+            ChipletsLengths::new(&chiplets),
+        );
+
         Self {
             chiplets,
             decoder,
@@ -73,6 +122,7 @@ impl VmStateIterator {
             clk: 0,
             asmop_idx: 0,
             forward: true,
+            trace_len_summary,
         }
     }
 
@@ -130,6 +180,51 @@ impl VmStateIterator {
         }
     }
 
+    /// Returns the assembly instruction which was being executed when the VM stopped, or None if
+    /// the program was not executed in debug mode.
+    ///
+    /// If the execution failed, this is the instruction which caused the error; this can be used
+    /// to map the error back to the procedure and source line it originated from.
+    pub fn last_asmop(&self) -> Option<&AssemblyOp> {
+        let clk = self.system.clk() as usize;
+        self.decoder
+            .debug_info()
+            .assembly_ops()
+            .iter()
+            .rev()
+            .find(|(op_clk, _)| *op_clk <= clk)
+            .map(|(_, asmop)| asmop)
+    }
+
+    /// Returns the lengths of the segments of the execution trace which would be built for the
+    /// executed program, before padding.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Positions this iterator at the specified clock cycle, so that the next call to
+    /// [Iterator::next()] returns the VM state at this cycle and the iteration continues forward
+    /// from it.
+    ///
+    /// VM states are reconstructed from the execution history recorded by the processor; thus,
+    /// seeking to an arbitrary cycle takes the same time regardless of the distance to it, and
+    /// neither buffers VM states nor re-executes the program. Cycles past the last executed cycle
+    /// are clamped to the cycle following it, at which the iterator yields the execution error
+    /// (if any) and then stops.
+    pub fn seek(&mut self, clk: u32) {
+        let clk = clk.min(self.system.clk() + 1);
+        // the index of the next asmop is the number of asmops which started before the cycle
+        // preceding the specified one; this is the same index as the one reached by iterating
+        // forward from the first cycle
+        self.asmop_idx = self
+            .decoder
+            .debug_info()
+            .assembly_ops()
+            .partition_point(|(op_clk, _)| (*op_clk as u32) + 1 < clk);
+        self.clk = clk;
+        self.forward = true;
+    }
+
     pub fn back(&mut self) -> Option<VmState> {
         if self.clk == 0 {
             return None;
@@ -253,6 +348,17 @@ impl AsmOpInfo {
         self.asmop.context_name()
     }
 
+    /// Returns the path of the module containing the assembly instruction, or None if the
+    /// instruction belongs to the executable module of a program.
+    pub fn module_path(&self) -> Option<&str> {
+        self.asmop.module_path()
+    }
+
+    /// Returns the source line of the assembly instruction, if known.
+    pub fn line(&self) -> Option<u32> {
+        self.asmop.line()
+    }
+
     /// Returns the assembly instruction corresponding to this state.
     pub fn op(&self) -> &str {
         self.asmop.op()
@@ -148,6 +148,7 @@ impl BlockInfo {
             BlockType::Loop(is_entered) => u32::from(is_entered),
             BlockType::Call => 1,
             BlockType::SysCall => 1,
+            BlockType::Dyn => 1,
             BlockType::Span => 0,
         }
     }
@@ -203,5 +204,6 @@ pub enum BlockType {
     Loop(bool), // internal value set to false if the loop is never entered
     Call,
     SysCall,
+    Dyn,
     Span,
 }
@@ -1,5 +1,5 @@
 use super::{
-    AdviceProvider, Call, ColMatrix, ExecutionError, Felt, FieldElement, Join, Loop, OpBatch,
+    AdviceProvider, Call, ColMatrix, Dyn, ExecutionError, Felt, FieldElement, Join, Loop, OpBatch,
     Operation, Process, Span, Split, StarkField, Vec, Word, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE,
     ZERO,
 };
@@ -241,6 +241,44 @@ where
         self.execute_op(Operation::Noop)
     }
 
+    // DYN BLOCK
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts decoding of a DYN block.
+    ///
+    /// The hash of the block to be executed is provided by the caller (it is read from the top
+    /// of the stack), and is not a part of the DYN block hash.
+    pub(super) fn start_dyn_block(
+        &mut self,
+        block: &Dyn,
+        callee_hash: Word,
+    ) -> Result<(), ExecutionError> {
+        // use the hasher to compute the hash of the DYN block; the row address returned by the
+        // hasher is used as the ID of the block; the result of the hash is expected to be in
+        // row addr + 7. the hash of a DYN block does not depend on its target, and thus, both
+        // hasher inputs are set to ZEROs.
+        let addr =
+            self.chiplets
+                .hash_control_block([ZERO; 4], [ZERO; 4], Dyn::DOMAIN, block.hash());
+
+        // start decoding the DYN block; this appends a row with DYN operation to the decoder
+        // trace. when DYN operation is executed, the rest of the VM state does not change
+        self.decoder.start_dyn(callee_hash, addr);
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Ends decoding of a DYN block.
+    pub(super) fn end_dyn_block(&mut self, block: &Dyn) -> Result<(), ExecutionError> {
+        // this appends a row with END operation to the decoder trace. when END operation is
+        // executed the rest of the VM state does not change
+        self.decoder.end_control_block(block.hash().into());
+
+        // send the end of control block to the chiplets bus to handle the final hash request.
+        self.chiplets.read_hash_result();
+
+        self.execute_op(Operation::Noop)
+    }
+
     // SPAN BLOCK
     // --------------------------------------------------------------------------------------------
 
@@ -520,6 +558,28 @@ impl Decoder {
         self.debug_info.append_operation(Operation::SysCall);
     }
 
+    /// Starts decoding of a DYN block.
+    ///
+    /// This pushes a block with ID=addr onto the block stack and appends execution of a DYN
+    /// operation to the trace. The first 4 hasher state registers of the DYN row are set to the
+    /// hash of the dynamically-specified block.
+    pub fn start_dyn(&mut self, callee_hash: Word, addr: Felt) {
+        // get the current clock cycle here (before the trace table is updated)
+        let clk = self.trace_len() as u32;
+
+        // push DYN block info onto the block stack and append a DYN row to the execution trace
+        let parent_addr = self.block_stack.push(addr, BlockType::Dyn, None);
+        self.trace
+            .append_block_start(parent_addr, Operation::Dyn, callee_hash, [ZERO; 4]);
+
+        // mark this cycle as the cycle at which a new DYN block began execution (this affects
+        // block stack and block hash tables). A DYN block has only a single child.
+        self.aux_hints
+            .block_started(clk, self.block_stack.peek(), Some(callee_hash), None);
+
+        self.debug_info.append_operation(Operation::Dyn);
+    }
+
     /// Ends decoding of a control block (i.e., a non-SPAN block).
     ///
     /// This appends an execution of an END operation to the trace. The top block on the block
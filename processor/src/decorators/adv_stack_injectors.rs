@@ -154,6 +154,51 @@ where
         Ok(())
     }
 
+    /// Pushes the result of `u256` division (both the quotient and the remainder) onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+    /// respectively (with a0 representing the 32 least significant bits and a7 representing the
+    /// 32 most significant bits). Similarly, (q0, ..., q7) and (r0, ..., r7) represent the
+    /// quotient and the remainder respectively.
+    ///
+    /// # Errors
+    /// Returns an error if the divisor is ZERO, or if any of the limbs is not a 32-bit value.
+    pub(super) fn push_u256_div_result(&mut self) -> Result<(), ExecutionError> {
+        let clk = self.system.clk();
+        let get_limb = |depth: usize| {
+            let value = self.stack.get(depth);
+            u32::try_from(value.as_int()).map_err(|_| ExecutionError::NotU32Value(value, clk, ZERO))
+        };
+
+        let mut divisor = [0_u32; 8];
+        let mut dividend = [0_u32; 8];
+        for i in 0..8 {
+            divisor[7 - i] = get_limb(i)?;
+            dividend[7 - i] = get_limb(8 + i)?;
+        }
+
+        if divisor == [0; 8] {
+            return Err(ExecutionError::DivideByZero(clk));
+        }
+
+        let (quotient, remainder) = u256_div_rem(&dividend, &divisor);
+
+        for &limb in remainder.iter().rev().chain(quotient.iter().rev()) {
+            self.advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+        }
+
+        Ok(())
+    }
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -186,6 +231,27 @@ where
         Ok(())
     }
 
+    /// Pushes the base 2 logarithm (rounded down) of the element at the top of the operand stack
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [floor(log2(a)), ...]
+    ///
+    /// The logarithm of ZERO is undefined; in this case ZERO is pushed onto the advice stack and
+    /// it is up to the program to reject the result.
+    pub(super) fn push_ilog2_result(&mut self) -> Result<(), ExecutionError> {
+        let value = self.stack.get(0).as_int();
+        let result = value.checked_ilog2().unwrap_or(0);
+        self.advice_provider.push_stack(AdviceSource::Value(Felt::from(result)))?;
+
+        Ok(())
+    }
+
     /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
     ///  into a polynomial in coefficient form and pushes the result into the advice stack.
     ///
@@ -345,6 +411,45 @@ where
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Computes the quotient and the remainder of dividing `a` by `b` via binary long division, where
+/// the values are represented by 32-bit limbs with the least significant limb first.
+///
+/// The divisor is assumed to be non-zero.
+fn u256_div_rem(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let mut quotient = [0_u32; 8];
+    let mut remainder = [0_u32; 8];
+
+    for i in (0..256).rev() {
+        // shift the next bit of the dividend into the remainder; since the remainder is smaller
+        // than the divisor before the shift, the bit shifted out of it implies remainder >= b
+        let mut carry = (a[i / 32] >> (i % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        if carry == 1 || u256_ge(&remainder, b) {
+            let mut borrow = false;
+            for (limb, &b_limb) in remainder.iter_mut().zip(b.iter()) {
+                let (diff, borrow1) = limb.overflowing_sub(b_limb);
+                let (diff, borrow2) = diff.overflowing_sub(borrow as u32);
+                *limb = diff;
+                borrow = borrow1 || borrow2;
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+/// Returns true if `a` >= `b`, where the values are represented by 32-bit limbs with the least
+/// significant limb first.
+fn u256_ge(a: &[u32; 8], b: &[u32; 8]) -> bool {
+    a.iter().rev().cmp(b.iter().rev()) != core::cmp::Ordering::Less
+}
+
 fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let hi = Felt::new(value >> 32);
     let lo = Felt::new((value as u32) as u64);
@@ -1,4 +1,7 @@
-use super::{AdviceInjector, AdviceProvider, AdviceSource, Decorator, ExecutionError, Process};
+use super::{
+    AdviceInjector, AdviceProvider, AdviceSource, DebugOptions, Decorator, ExecutionError, Process,
+    VmState,
+};
 
 mod adv_map_injectors;
 mod adv_stack_injectors;
@@ -25,10 +28,52 @@ where
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
             }
+            Decorator::Debug(options) => {
+                if self.decoder.in_debug_mode() {
+                    self.dec_debug(options);
+                }
+            }
+            Decorator::Trace(trace_id) => {
+                if self.options.enable_tracing() {
+                    self.dec_trace(*trace_id);
+                }
+            }
         }
         Ok(())
     }
 
+    // DEBUGGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Reports the current state of the VM to the advice provider.
+    ///
+    /// The reported state is the state of the VM before the operation at the current clock cycle
+    /// is executed; `options` specify which part of this state was requested by the program.
+    fn dec_debug(&mut self, options: &DebugOptions) {
+        let clk = self.system.clk();
+        let ctx = self.system.ctx();
+        let state = VmState {
+            clk,
+            ctx,
+            op: None,
+            asmop: None,
+            fmp: self.system.fmp(),
+            stack: self.stack.get_state_at(clk),
+            memory: self.chiplets.get_mem_state_at(ctx, clk),
+        };
+        self.advice_provider.on_debug(&state, options);
+    }
+
+    /// Delivers a trace event with the specified identifier to the advice provider.
+    ///
+    /// The event is tagged with the current clock cycle and the current state of the top 16
+    /// stack items.
+    fn dec_trace(&mut self, trace_id: u32) {
+        let clk = self.system.clk();
+        let stack_top = self.stack.trace_state();
+        self.advice_provider.on_trace(trace_id, clk, &stack_top);
+    }
+
     // ADVICE INJECTION
     // --------------------------------------------------------------------------------------------
 
@@ -42,7 +87,9 @@ where
                 key_offset,
             } => self.copy_map_value_to_adv_stack(*include_len, *key_offset),
             AdviceInjector::DivU64 => self.push_u64_div_result(),
+            AdviceInjector::DivU256 => self.push_u256_div_result(),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(),
+            AdviceInjector::ILog2 => self.push_ilog2_result(),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(),
             AdviceInjector::SmtGet => self.push_smtget_inputs(),
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(),
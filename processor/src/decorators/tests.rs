@@ -1,15 +1,16 @@
 use super::{
     super::{AdviceInputs, Felt, FieldElement, Kernel, Operation, StarkField},
-    Process,
+    AdviceProvider, AdviceSource, DebugOptions, ExecutionError, Process, VmState,
 };
-use crate::{MemAdviceProvider, StackInputs, Word};
+use crate::{execute_iter, MemAdviceProvider, StackInputs, Word};
+use miden_assembly::Assembler;
 use test_utils::{crypto::get_smt_remaining_key, rand::seeded_word};
 use vm_core::{
     crypto::{
         hash::{Rpo256, RpoDigest},
-        merkle::{EmptySubtreeRoots, MerkleStore, MerkleTree, NodeIndex},
+        merkle::{EmptySubtreeRoots, MerklePath, MerkleStore, MerkleTree, NodeIndex},
     },
-    utils::IntoBytes,
+    utils::{collections::Vec, IntoBytes},
     AdviceInjector, Decorator, ONE, ZERO,
 };
 
@@ -143,6 +144,54 @@ fn push_smtget() {
     }
 }
 
+#[test]
+fn debug_decorators_report_vm_state() {
+    use DebugOptions::*;
+
+    let source = "\
+        begin
+            push.3 push.5 debug.stack.2
+            push.7.8.9.10 mem_storew.100 dropw debug.mem debug.stack
+            add debug.mem.100.1
+        end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+
+    let mut recorder = DebugRecorder::default();
+    let states = execute_iter(&program, StackInputs::default(), &mut recorder)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // debug requests are reported in the order in which they were issued
+    let options = recorder.reports.iter().map(|(_, options)| *options).collect::<Vec<_>>();
+    assert_eq!(vec![StackTop(2), MemAll, StackAll, MemInterval(100, 1)], options);
+
+    // the reported state must be the same as the state of the VM at the same clock cycle
+    for (report, _) in recorder.reports.iter() {
+        let state = &states[report.clk as usize];
+        assert_eq!(state.clk, report.clk);
+        assert_eq!(state.ctx, report.ctx);
+        assert_eq!(state.fmp, report.fmp);
+        assert_eq!(state.stack, report.stack);
+        assert_eq!(state.memory, report.memory);
+    }
+
+    let first = &recorder.reports[0].0;
+    assert_eq!(&[Felt::new(5), Felt::new(3)], &first.stack[..2]);
+    assert!(first.memory.is_empty());
+
+    // the last request is issued after all operations of the span block have been executed
+    let last = &recorder.reports[3].0;
+    assert_eq!(Felt::new(8), last.stack[0]);
+    assert_eq!(100, last.memory[0].0);
+
+    // outside of debug mode, debug requests are ignored
+    let mut recorder = DebugRecorder::default();
+    let program = Assembler::default().compile(source).unwrap();
+    let mut process = Process::new(Kernel::default(), StackInputs::default(), &mut recorder);
+    process.execute(&program).unwrap();
+    assert!(recorder.reports.is_empty());
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
@@ -200,3 +249,81 @@ fn assert_case_smtget(
     }
     assert_eq!(build_expected(expected_stack), process.stack.trace_state());
 }
+
+/// An advice provider which records all debug requests issued by the program.
+#[derive(Default)]
+struct DebugRecorder {
+    provider: MemAdviceProvider,
+    reports: Vec<(VmState, DebugOptions)>,
+}
+
+impl AdviceProvider for DebugRecorder {
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        self.provider.pop_stack()
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        self.provider.pop_stack_word()
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        self.provider.pop_stack_dword()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.provider.push_stack(source)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.provider.insert_into_map(key, values)
+    }
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.provider.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.get_merkle_path(root, depth, index)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        self.provider.get_leaf_depth(root, tree_depth, index)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.update_merkle_node(root, depth, index, value)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.provider.merge_roots(lhs, rhs)
+    }
+
+    fn advance_clock(&mut self) {
+        self.provider.advance_clock()
+    }
+
+    fn on_debug(&mut self, state: &VmState, options: &DebugOptions) {
+        self.reports.push((state.clone(), *options));
+    }
+}
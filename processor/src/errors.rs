@@ -4,7 +4,10 @@ use super::{
     CodeBlock, Digest, Felt, QuadFelt, Word,
 };
 use core::fmt::{Display, Formatter};
-use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{string::String, to_hex},
+};
 use winter_prover::{math::FieldElement, ProverError};
 
 #[cfg(feature = "std")]
@@ -16,12 +19,14 @@ use std::error::Error;
 #[derive(Debug)]
 pub enum ExecutionError {
     AdviceKeyNotFound(Word),
+    AdviceStreamFailed(u32, AdviceError),
     AdviceStackReadFailed(u32),
     CallerNotInSyscall,
     CodeBlockNotFound(Digest),
     DivideByZero(u32),
+    DynamicNodeNotFound(Digest),
     Ext2InttError(Ext2InttError),
-    FailedAssertion(u32),
+    FailedAssertion { clk: u32, err_code: Felt },
     InvalidFmpValue(Felt, Felt),
     InvalidFriDomainSegment(u64),
     InvalidFriLayerFolding(QuadFelt, QuadFelt),
@@ -35,7 +40,7 @@ pub enum ExecutionError {
     MerkleStoreLookupFailed(MerkleError),
     MerkleStoreUpdateFailed(MerkleError),
     NotBinaryValue(Felt),
-    NotU32Value(Felt),
+    NotU32Value(Felt, u32, Felt),
     ProverError(ProverError),
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
@@ -51,6 +56,9 @@ impl Display for ExecutionError {
                 write!(f, "Can't push values onto the advice stack: value for key {hex} not present in the advice map.")
             }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            AdviceStreamFailed(step, err) => {
+                write!(f, "Failed to pull advice from the advice stream at step {step}: {err}")
+            }
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
@@ -62,8 +70,17 @@ impl Display for ExecutionError {
                 )
             }
             DivideByZero(clk) => write!(f, "Division by zero at clock cycle {clk}"),
+            DynamicNodeNotFound(digest) => {
+                let hex = to_hex(&digest.as_bytes())?;
+                write!(
+                    f,
+                    "Failed to execute dynamic code block with root {hex}; the block could not be found"
+                )
+            }
             Ext2InttError(err) => write!(f, "Failed to execute Ext2Intt operation: {err}"),
-            FailedAssertion(clk) => write!(f, "Assertion failed at clock cycle {clk}"),
+            FailedAssertion { clk, err_code } => {
+                write!(f, "Assertion failed at clock cycle {clk} with error code {err_code}")
+            }
             InvalidFmpValue(old, new) => {
                 write!(f, "Updating FMP register from {old} to {new} failed because {new} is outside of {FMP_MIN}..{FMP_MAX}")
             }
@@ -106,8 +123,11 @@ impl Display for ExecutionError {
             NotBinaryValue(v) => {
                 write!(f, "An operation expected a binary value, but received {v}")
             }
-            NotU32Value(v) => {
-                write!(f, "An operation expected a u32 value, but received {v}")
+            NotU32Value(v, clk, err_code) => {
+                write!(
+                    f,
+                    "An operation at clock cycle {clk} expected a u32 value, but received {v} (error code: {err_code})"
+                )
             }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
             SyscallTargetNotInKernel(proc) => {
@@ -130,6 +150,32 @@ impl From<Ext2InttError> for ExecutionError {
     }
 }
 
+// ADVICE ERROR
+// ================================================================================================
+
+/// An error returned by an [AdviceStream](crate::AdviceStream).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdviceError {
+    /// The stream has no more elements.
+    Exhausted,
+    /// Advice could not be generated; the message describes the reason.
+    GenerationFailed(String),
+}
+
+impl Display for AdviceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        use AdviceError::*;
+
+        match self {
+            Exhausted => write!(f, "the advice stream is exhausted"),
+            GenerationFailed(reason) => write!(f, "advice generation failed: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AdviceError {}
+
 // EXT2INTT ERROR
 // ================================================================================================
 
@@ -9,16 +9,17 @@ use miden_air::trace::{
     SYS_TRACE_WIDTH,
 };
 pub use vm_core::{
-    chiplets::hasher::Digest, errors::InputError, utils::DeserializationError, AssemblyOp, Kernel,
-    Operation, Program, ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word,
+    chiplets::hasher::Digest, errors::InputError, utils::DeserializationError, AssemblyOp,
+    DebugOptions, Kernel, OpCategory, Operation, Program, ProgramInfo, QuadExtension, StackInputs,
+    StackOutputs, StackTopState, Word,
 };
 use vm_core::{
     code_blocks::{
-        Call, CodeBlock, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE,
+        Call, CodeBlock, Dyn, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE,
     },
     utils::collections::{BTreeMap, Vec},
-    AdviceInjector, CodeBlockTable, Decorator, DecoratorIterator, Felt, FieldElement,
-    StackTopState, StarkField, ONE, ZERO,
+    AdviceInjector, CodeBlockTable, Decorator, DecoratorIterator, Felt, FieldElement, StarkField,
+    ONE, ZERO,
 };
 
 use winter_prover::ColMatrix;
@@ -41,18 +42,25 @@ use range::RangeChecker;
 
 mod advice;
 pub use advice::{
-    AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider,
+    AdviceInputs, AdviceProvider, AdviceRequest, AdviceSnapshot, AdviceSource, AdviceStream,
+    CallbackAdviceProvider, MemAdviceProvider, RecAdviceProvider,
 };
 
 mod chiplets;
 use chiplets::Chiplets;
 
 mod trace;
-pub use trace::ExecutionTrace;
+#[cfg(any(test, feature = "internals"))]
+pub use trace::TraceExtension;
 use trace::TraceFragment;
+pub use trace::{ChipletsLengths, ExecutionTrace, SegmentRanges, TraceLenSummary};
 
 mod errors;
-pub use errors::{ExecutionError, Ext2InttError};
+pub use errors::{AdviceError, ExecutionError, Ext2InttError};
+
+mod options;
+pub use miden_air::RangeCheckMode;
+pub use options::ExecutionOptions;
 
 pub mod utils;
 
@@ -115,9 +123,75 @@ pub fn execute<A>(
 where
     A: AdviceProvider,
 {
-    let mut process = Process::new(program.kernel().clone(), stack_inputs, advice_provider);
+    execute_with_options(program, stack_inputs, advice_provider, ExecutionOptions::default())
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs using the specified execution options.
+///
+/// Apart from the range checker mode, the execution options do not affect the returned trace;
+/// i.e., with the default range checker mode, the trace is identical to the one returned by
+/// [execute()].
+pub fn execute_with_options<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ExecutionOptions,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new_with_options(
+        program.kernel().clone(),
+        stack_inputs.clone(),
+        advice_provider,
+        options,
+    );
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new(process, stack_inputs, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, without the hints needed to build the auxiliary trace segment.
+///
+/// The main trace is identical to the one returned by [execute()]. This is intended for tools
+/// which inspect only the main trace; the returned trace cannot be used to generate a proof.
+pub fn execute_main_only<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs.clone(), advice_provider);
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new_main_only(process, stack_inputs, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, with the columns built by the provided [TraceExtension] appended to the main trace.
+///
+/// The AIR of the VM is not aware of the extension columns, and thus, the returned trace cannot be
+/// used to generate a proof with the default prover.
+#[cfg(any(test, feature = "internals"))]
+pub fn execute_with_extension<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    extension: &dyn TraceExtension<A>,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs.clone(), advice_provider);
     let stack_outputs = process.execute(program)?;
-    let trace = ExecutionTrace::new(process, stack_outputs);
+    let trace =
+        ExecutionTrace::new_with_extension(process, stack_inputs, stack_outputs, Some(extension));
     assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
     Ok(trace)
 }
@@ -158,6 +232,7 @@ where
     range: RangeChecker,
     chiplets: Chiplets,
     advice_provider: A,
+    options: ExecutionOptions,
 }
 
 impl<A> Process<A>
@@ -168,18 +243,32 @@ where
     // --------------------------------------------------------------------------------------------
     /// Creates a new process with the provided inputs.
     pub fn new(kernel: Kernel, stack_inputs: StackInputs, advice_provider: A) -> Self {
-        Self::initialize(kernel, stack_inputs, advice_provider, false)
+        Self::new_with_options(kernel, stack_inputs, advice_provider, ExecutionOptions::default())
+    }
+
+    /// Creates a new process with the provided inputs and execution options.
+    pub fn new_with_options(
+        kernel: Kernel,
+        stack_inputs: StackInputs,
+        advice_provider: A,
+        options: ExecutionOptions,
+    ) -> Self {
+        Self::initialize(kernel, stack_inputs, advice_provider, options, false)
     }
 
     /// Creates a new process with provided inputs and debug options enabled.
+    ///
+    /// Tracing is always enabled for processes running in debug mode.
     pub fn new_debug(kernel: Kernel, stack_inputs: StackInputs, advice_provider: A) -> Self {
-        Self::initialize(kernel, stack_inputs, advice_provider, true)
+        let options = ExecutionOptions::default().with_tracing();
+        Self::initialize(kernel, stack_inputs, advice_provider, options, true)
     }
 
     fn initialize(
         kernel: Kernel,
         stack: StackInputs,
         advice_provider: A,
+        options: ExecutionOptions,
         in_debug_mode: bool,
     ) -> Self {
         Self {
@@ -189,6 +278,7 @@ where
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             advice_provider,
+            options,
         }
     }
 
@@ -220,6 +310,7 @@ where
             CodeBlock::Split(block) => self.execute_split_block(block, cb_table),
             CodeBlock::Loop(block) => self.execute_loop_block(block, cb_table),
             CodeBlock::Call(block) => self.execute_call_block(block, cb_table),
+            CodeBlock::Dyn(block) => self.execute_dyn_block(block, cb_table),
             CodeBlock::Span(block) => self.execute_span_block(block),
             CodeBlock::Proxy(_) => Err(ExecutionError::UnexecutableCodeBlock(block.clone())),
         }
@@ -312,15 +403,41 @@ where
 
         self.start_call_block(block)?;
 
+        // if this is a dynamic call, execute the dynamic code block in the new context; otherwise,
         // get function body from the code block table and execute it
-        let fn_body = cb_table
-            .get(block.fn_hash())
-            .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
-        self.execute_code_block(fn_body, cb_table)?;
+        if block.fn_hash() == Dyn::dyn_hash() {
+            self.execute_dyn_block(&Dyn::new(), cb_table)?;
+        } else {
+            let fn_body = cb_table
+                .get(block.fn_hash())
+                .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
+            self.execute_code_block(fn_body, cb_table)?;
+        }
 
         self.end_call_block(block)
     }
 
+    /// Executes the specified [Dyn] block.
+    ///
+    /// The hash of the code block to be executed is read from the top word of the stack; the stack
+    /// is not modified.
+    #[inline(always)]
+    fn execute_dyn_block(
+        &mut self,
+        block: &Dyn,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // get target code block from the code block table using the hash on top of the stack
+        let callee_hash = self.stack.get_word(0);
+        let callee = cb_table
+            .get(callee_hash.into())
+            .ok_or_else(|| ExecutionError::DynamicNodeNotFound(callee_hash.into()))?;
+
+        self.start_dyn_block(block, callee_hash)?;
+        self.execute_code_block(callee, cb_table)?;
+        self.end_dyn_block(block)
+    }
+
     /// Executes the specified [Span] block.
     #[inline(always)]
     fn execute_span_block(&mut self, block: &Span) -> Result<(), ExecutionError> {
@@ -343,6 +460,12 @@ where
             op_offset += op_batch.ops().len();
         }
 
+        // execute decorators which appear after all operations in the block; these could not
+        // have been executed during execution of the operation batches
+        while let Some(decorator) = decorators.next(op_offset) {
+            self.execute_decorator(decorator)?;
+        }
+
         self.end_span_block(block)
     }
 
@@ -470,4 +593,5 @@ where
     pub range: RangeChecker,
     pub chiplets: Chiplets,
     pub advice_provider: A,
+    pub options: ExecutionOptions,
 }
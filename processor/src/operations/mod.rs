@@ -30,7 +30,7 @@ where
         match op {
             // ----- system operations ------------------------------------------------------------
             Operation::Noop => self.stack.copy_state(0),
-            Operation::Assert => self.op_assert()?,
+            Operation::Assert(err_code) => self.op_assert(err_code)?,
 
             Operation::FmpAdd => self.op_fmpadd()?,
             Operation::FmpUpdate => self.op_fmpupdate()?,
@@ -47,6 +47,7 @@ where
             Operation::Loop => unreachable!("control flow operation"),
             Operation::Call => unreachable!("control flow operation"),
             Operation::SysCall => unreachable!("control flow operation"),
+            Operation::Dyn => unreachable!("control flow operation"),
             Operation::Span => unreachable!("control flow operation"),
             Operation::Repeat => unreachable!("control flow operation"),
             Operation::Respan => unreachable!("control flow operation"),
@@ -83,7 +84,7 @@ where
 
             Operation::U32and => self.op_u32and()?,
             Operation::U32xor => self.op_u32xor()?,
-            Operation::U32assert2 => self.op_u32assert2()?,
+            Operation::U32assert2(err_code) => self.op_u32assert2(err_code)?,
 
             // ----- stack manipulation -----------------------------------------------------------
             Operation::Pad => self.op_pad()?,
@@ -13,10 +13,14 @@ where
     /// Pops a value off the stack and asserts that it is equal to ONE.
     ///
     /// # Errors
-    /// Returns an error if the popped value is not ONE.
-    pub(super) fn op_assert(&mut self) -> Result<(), ExecutionError> {
+    /// Returns an error if the popped value is not ONE; the error carries the specified error
+    /// code.
+    pub(super) fn op_assert(&mut self, err_code: Felt) -> Result<(), ExecutionError> {
         if self.stack.get(0) != Felt::ONE {
-            return Err(ExecutionError::FailedAssertion(self.system.clk()));
+            return Err(ExecutionError::FailedAssertion {
+                clk: self.system.clk(),
+                err_code,
+            });
         }
         self.stack.shift_left(1);
         Ok(())
@@ -127,7 +131,7 @@ mod tests {
         process.execute_op(Operation::Swap).unwrap();
         process.execute_op(Operation::Drop).unwrap();
 
-        assert!(process.execute_op(Operation::Assert).is_ok());
+        assert!(process.execute_op(Operation::Assert(Felt::ZERO)).is_ok());
     }
 
     #[test]
@@ -0,0 +1,55 @@
+use miden_air::RangeCheckMode;
+
+// EXECUTION OPTIONS
+// ================================================================================================
+
+/// A set of parameters specifying how a program is to be executed by the VM.
+///
+/// Most execution options affect only the interaction between the VM and the host (i.e., the
+/// advice provider). The only exception is the range checker mode, which determines how the range
+/// checker segment of the execution trace is built.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct ExecutionOptions {
+    enable_tracing: bool,
+    range_check_mode: RangeCheckMode,
+}
+
+impl ExecutionOptions {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns these options with tracing enabled.
+    ///
+    /// When tracing is enabled, events emitted by `trace` instructions are delivered to the
+    /// advice provider via [AdviceProvider::on_trace()](crate::AdviceProvider::on_trace);
+    /// otherwise, these events are ignored.
+    pub fn with_tracing(mut self) -> Self {
+        self.enable_tracing = true;
+        self
+    }
+
+    /// Returns these options with the specified range checker mode.
+    ///
+    /// The 8-bit mode shortens the range checker segment of the execution trace for programs
+    /// which range-check only 8-bit values. If a program range-checks a value which does not fit
+    /// into 8 bits, the trace is built in the default 16-bit mode regardless of this option. The
+    /// mode the trace was built in can be retrieved via
+    /// [ExecutionTrace::range_check_mode()](crate::ExecutionTrace::range_check_mode).
+    pub fn with_range_check_mode(mut self, mode: RangeCheckMode) -> Self {
+        self.range_check_mode = mode;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if tracing is enabled.
+    pub fn enable_tracing(&self) -> bool {
+        self.enable_tracing
+    }
+
+    /// Returns the requested range checker mode.
+    pub fn range_check_mode(&self) -> RangeCheckMode {
+        self.range_check_mode
+    }
+}
@@ -1,7 +1,7 @@
 use super::{
     trace::{build_lookup_table_row_values, LookupTableRow, NUM_RAND_ROWS},
     utils::uninit_vector,
-    BTreeMap, ColMatrix, Felt, FieldElement, RangeCheckTrace, Vec, ONE, ZERO,
+    BTreeMap, ColMatrix, Felt, FieldElement, RangeCheckMode, RangeCheckTrace, Vec, ONE, ZERO,
 };
 
 mod aux_trace;
@@ -75,6 +75,10 @@ pub struct RangeCheckTraceTable {
 /// If, on the other hand, the value was range-checked 5 times, we'll need two rows in the table:
 /// (1, 1, 1, v) and (1, 1, 0, v). The first row specifies that there was 4 lookups and the second
 /// row add the fifth lookup.
+///
+/// In the 8-bit [RangeCheckMode], the 16-bit segment ends with value 255 rather than 65535. This
+/// removes the "bridge" rows between 255 and 65535 from the table, but can be used only if all
+/// range-checked values fit into 8 bits.
 pub struct RangeChecker {
     /// Tracks lookup count for each checked value.
     lookups: BTreeMap<u16, usize>,
@@ -82,6 +86,8 @@ pub struct RangeChecker {
     // cycle is mapped to a single CycleRangeChecks instance which includes lookups from the stack,
     // memory, or both.
     cycle_range_checks: BTreeMap<u32, CycleRangeChecks>,
+    /// The mode in which the trace of this range checker is built.
+    mode: RangeCheckMode,
 }
 
 impl RangeChecker {
@@ -97,6 +103,7 @@ impl RangeChecker {
         Self {
             lookups,
             cycle_range_checks: BTreeMap::new(),
+            mode: RangeCheckMode::Bits16,
         }
     }
 
@@ -133,6 +140,29 @@ impl RangeChecker {
             .or_insert_with(|| CycleRangeChecks::new_from_memory(values));
     }
 
+    /// Switches this range checker into the specified mode, and returns the mode in which the
+    /// trace of this range checker will be built.
+    ///
+    /// The 8-bit mode can be used only if all values range-checked so far fit into 8 bits;
+    /// otherwise, the range checker remains in the 16-bit mode. Thus, this should be called only
+    /// after all range checks have been added to this range checker.
+    pub fn set_mode(&mut self, mode: RangeCheckMode) -> RangeCheckMode {
+        if mode == RangeCheckMode::Bits8 && self.mode == RangeCheckMode::Bits16 {
+            let max_value = mode.max_value();
+            let only_8bit_checks = self.lookups.get(&u16::MAX) == Some(&0)
+                && self.lookups.range(max_value + 1..u16::MAX).next().is_none();
+
+            if only_8bit_checks {
+                // the last row of the 16-bit segment must now be initialized for value 255
+                // instead of u16::MAX.
+                self.lookups.remove(&u16::MAX);
+                self.lookups.entry(max_value).or_insert(0);
+                self.mode = mode;
+            }
+        }
+        self.mode
+    }
+
     // EXECUTION TRACE GENERATION (INTERNAL)
     // --------------------------------------------------------------------------------------------
 
@@ -213,12 +243,13 @@ impl RangeChecker {
             prev_value = value;
         }
 
-        // pad the trace with an extra row of 0 lookups for u16::MAX so that when b_range is built
-        // there is space for the inclusion of u16::MAX range check lookups before the trace ends.
+        // pad the trace with an extra row of 0 lookups for the max value (u16::MAX in the 16-bit
+        // mode) so that when b_range is built there is space for the inclusion of the max value
+        // range check lookups before the trace ends.
         // (When there is data at the end of the main trace, auxiliary bus columns always need to be
         // one row longer than the main trace, since values in the bus column are based on data from
         // the "current" row of the main trace but placed into the "next" row of the bus column.)
-        write_value(&mut trace, &mut i, 0, (u16::MAX).into(), &mut row_flags);
+        write_value(&mut trace, &mut i, 0, self.mode.max_value().into(), &mut row_flags);
 
         RangeCheckTrace {
             trace,
@@ -235,11 +266,11 @@ impl RangeChecker {
     pub fn build_8bit_lookup(&self) -> RangeCheckTraceTable {
         let mut lookups_8bit = [0; 256];
 
-        // pad the trace length by one, to account for an extra row of the u16::MAX value at the end
-        // of the 16-bit segment of the trace, required for building the `b_range` column.
+        // pad the trace length by one, to account for an extra row of the max value at the end of
+        // the 16-bit segment of the trace, required for building the `b_range` column.
         let mut num_16bit_rows = 1;
 
-        // add a lookup for ZERO to account for the extra row of the u16::MAX value
+        // add a lookup for ZERO to account for the extra row of the max value
         lookups_8bit[0] = 1;
 
         let mut prev_value = 0u16;
@@ -1,4 +1,4 @@
-use super::{BTreeMap, Felt, RangeChecker, Vec, ONE, ZERO};
+use super::{BTreeMap, Felt, RangeCheckMode, RangeChecker, Vec, ONE, ZERO};
 use crate::{utils::get_trace_len, RangeCheckTrace};
 use rand_utils::rand_array;
 use vm_core::{utils::ToElements, StarkField};
@@ -17,7 +17,7 @@ fn range_checks() {
         trace,
         aux_builder: _,
     } = checker.into_trace(1024, 0);
-    validate_trace(&trace, &values);
+    validate_trace(&trace, &values, RangeCheckMode::Bits16);
 
     // skip the 8-bit portion of the trace
     let mut i = 0;
@@ -53,7 +53,40 @@ fn range_checks_rand() {
         trace,
         aux_builder: _,
     } = checker.into_trace(trace_len, 0);
-    validate_trace(&trace, &values);
+    validate_trace(&trace, &values, RangeCheckMode::Bits16);
+}
+
+#[test]
+fn range_checks_8bit_mode() {
+    let values = [0, 1, 2, 2, 2, 2, 3, 3, 3, 4, 4, 100, 200, 255].to_elements();
+
+    let mut checker_16bit = RangeChecker::new();
+    let mut checker_8bit = RangeChecker::new();
+    for &value in values.iter() {
+        checker_16bit.add_value(value.as_int() as u16);
+        checker_8bit.add_value(value.as_int() as u16);
+    }
+    assert_eq!(RangeCheckMode::Bits8, checker_8bit.set_mode(RangeCheckMode::Bits8));
+
+    // the 16-bit segment of the table no longer needs to span all 16-bit values
+    let trace_len = checker_8bit.trace_len();
+    assert!(trace_len < checker_16bit.trace_len());
+
+    let RangeCheckTrace {
+        trace,
+        aux_builder: _,
+    } = checker_8bit.into_trace(trace_len.next_power_of_two(), 0);
+    validate_trace(&trace, &values, RangeCheckMode::Bits8);
+}
+
+#[test]
+fn range_checks_8bit_mode_fallback() {
+    let mut checker = RangeChecker::new();
+    checker.add_value(3);
+    checker.add_value(256);
+
+    // 256 does not fit into 8 bits
+    assert_eq!(RangeCheckMode::Bits16, checker.set_mode(RangeCheckMode::Bits8));
 }
 
 // HELPER FUNCTIONS
@@ -73,7 +106,7 @@ fn validate_row(trace: &[Vec<Felt>], row_idx: usize, value: u64, num_lookups: u6
     assert_eq!(Felt::new(value), trace[3][row_idx]);
 }
 
-fn validate_trace(trace: &[Vec<Felt>], lookups: &[Felt]) {
+fn validate_trace(trace: &[Vec<Felt>], lookups: &[Felt], mode: RangeCheckMode) {
     assert_eq!(4, trace.len());
 
     // trace length must be a power of two
@@ -156,9 +189,9 @@ fn validate_trace(trace: &[Vec<Felt>], lookups: &[Felt]) {
         prev_value = value;
     }
 
-    // validate the last row (must be 65535)
+    // validate the last row (must be 65535 in the 16-bit mode and 255 in the 8-bit mode)
     let last_value = trace[3][i - 1].as_int();
-    assert_eq!(65535, last_value);
+    assert_eq!(mode.max_value() as u64, last_value);
 
     // at the end, 8-bit table should be empty
     for &value in lookups_8bit.values() {
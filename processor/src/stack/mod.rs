@@ -121,6 +121,12 @@ impl Stack {
         self.trace.peek_at(self.clk)
     }
 
+    /// Returns state of stack item columns at the current clock cycle. This does not include stack
+    /// values in the overflow table.
+    pub fn trace_state(&self) -> [Felt; STACK_TOP_SIZE] {
+        self.trace.get_stack_state_at(self.clk)
+    }
+
     /// Returns stack state at the specified clock cycle. This includes the top 16 items of the
     /// stack + overflow entries.
     ///
@@ -322,13 +328,6 @@ impl Stack {
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns state of stack item columns at the current clock cycle. This does not include stack
-    /// values in the overflow table.
-    #[cfg(any(test, feature = "internals"))]
-    pub fn trace_state(&self) -> [Felt; STACK_TOP_SIZE] {
-        self.trace.get_stack_state_at(self.clk)
-    }
-
     /// Returns state of helper columns at the current clock cycle.
     #[cfg(test)]
     pub fn helpers_state(&self) -> [Felt; miden_air::trace::stack::NUM_STACK_HELPER_COLS] {
@@ -172,11 +172,14 @@ impl OverflowTable {
 
     /// Appends the state of the overflow table at the specified clock cycle to the provided vector.
     ///
+    /// This is the state of the table before the operation at the specified clock cycle is
+    /// executed; it is consistent with the state of the stack top at the same clock cycle.
+    ///
     /// # Panics
     /// Panics when this overflow table was not initialized with `enable_trace` set to true.
     pub fn append_state_into(&self, target: &mut Vec<Felt>, clk: u64) {
         assert!(self.trace_enabled, "overflow trace not enabled");
-        if let Some(x) = self.trace.range(0..=clk).last() {
+        if let Some(x) = self.trace.range(0..clk).last() {
             for item in x.1.iter().rev() {
                 target.push(*item);
             }
@@ -1,11 +1,12 @@
 use super::{
-    super::StackTopState, Felt, OverflowTableRow, Stack, StackInputs, Vec, ONE, STACK_TOP_SIZE,
-    ZERO,
+    super::{execute, MemAdviceProvider, StackTopState},
+    Felt, OverflowTableRow, Stack, StackInputs, Vec, ONE, STACK_TOP_SIZE, ZERO,
 };
 use miden_air::trace::{
     stack::{B0_COL_IDX, B1_COL_IDX, H0_COL_IDX, NUM_STACK_HELPER_COLS},
     STACK_TRACE_WIDTH,
 };
+use miden_assembly::Assembler;
 use vm_core::{FieldElement, StarkField};
 
 // TYPE ALIASES
@@ -70,6 +71,60 @@ fn initialize_overflow() {
     assert_eq!(stack.overflow.all_rows(), expected_overflow_rows);
 }
 
+#[test]
+fn stack_inputs_ordering() {
+    // a program which leaves the stack unchanged
+    let program = Assembler::default().compile("begin push.0 drop end").unwrap();
+    let run = |inputs: StackInputs| {
+        let trace = execute(&program, inputs, MemAdviceProvider::default()).unwrap();
+        trace.stack_outputs().stack_truncated(4).to_vec()
+    };
+
+    // the last input value ends up at the top of the stack (position 0)
+    let inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+    assert_eq!(Felt::new(3), inputs.values()[0]);
+    assert_eq!(vec![3, 2, 1, 0], run(inputs));
+
+    // reversing the inputs puts the first input value at the top of the stack
+    let inputs = StackInputs::try_from_values([1, 2, 3]).unwrap().reversed();
+    assert_eq!(Felt::new(1), inputs.values()[0]);
+    assert_eq!(vec![1, 2, 3, 0], run(inputs));
+
+    // values can be pushed onto the top or placed at the bottom of the stack
+    let inputs = StackInputs::default()
+        .push_top(Felt::new(2))
+        .push_top(Felt::new(3))
+        .push_bottom(Felt::new(1));
+    assert_eq!(vec![3, 2, 1, 0], run(inputs));
+}
+
+#[test]
+fn stack_outputs_overflow() {
+    // a program which leaves 19 values on the stack
+    let program = Assembler::default().compile("begin push.17 push.18 push.19 end").unwrap();
+    let inputs = StackInputs::try_from_values(1..=16).unwrap();
+    let trace = execute(&program, inputs, MemAdviceProvider::default()).unwrap();
+    let outputs = trace.stack_outputs();
+
+    // the full stack is reported, including the values in the overflow table
+    let expected = (1..=19).rev().collect::<Vec<u64>>();
+    assert_eq!(expected, outputs.to_ints());
+    assert_eq!(expected[..STACK_TOP_SIZE], outputs.stack_top().map(|v| v.as_int()));
+
+    // the deepest 3 values are in the overflow table
+    assert!(outputs.has_overflow());
+    assert_eq!(&[3, 2, 1], outputs.stack_overflow_values());
+    let overflow_values =
+        outputs.stack_overflow().iter().map(|(_, v)| v.as_int()).collect::<Vec<_>>();
+    assert_eq!(vec![1, 2, 3], overflow_values);
+
+    // no values are in the overflow table if the stack depth does not exceed 16
+    let program = Assembler::default().compile("begin push.17 drop end").unwrap();
+    let inputs = StackInputs::try_from_values(1..=16).unwrap();
+    let trace = execute(&program, inputs, MemAdviceProvider::default()).unwrap();
+    assert!(trace.stack_outputs().stack_overflow_values().is_empty());
+}
+
 // SHIFT LEFT TEST
 // ================================================================================================
 
@@ -274,6 +329,36 @@ fn start_restore_context() {
     assert_eq!(stack.helpers_state(), build_helpers_partial(0, 0));
 }
 
+// STATE ACCESSOR TESTS
+// ================================================================================================
+
+#[test]
+fn get_state_at() {
+    let stack_inputs = (1..=16).collect::<Vec<_>>();
+    let stack = StackInputs::try_from_values(stack_inputs).unwrap();
+    let mut stack = Stack::new(&stack, 4, true);
+
+    // make sure the first right shift is not executed at clk = 0
+    stack.copy_state(0);
+    stack.advance_clock();
+
+    // move the deepest item into the overflow table at clk = 1, and pull it back at clk = 2
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_left(1);
+    stack.advance_clock();
+
+    // the state at a given clock cycle is the state before the operation at that cycle is
+    // executed; thus, the overflow table is empty at clk = 1 and clk = 3
+    let state = (1..=16).rev().map(Felt::new).collect::<Vec<_>>();
+    assert_eq!(stack.get_state_at(1), state);
+    assert_eq!(stack.get_state_at(3), state);
+
+    let mut state = (1..=16).rev().map(Felt::new).collect::<Vec<_>>();
+    state.insert(0, ZERO);
+    assert_eq!(stack.get_state_at(2), state);
+}
+
 // TRACE GENERATION
 // ================================================================================================
 
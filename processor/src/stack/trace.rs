@@ -47,6 +47,15 @@ impl StackTrace {
         self.stack[0][clk as usize]
     }
 
+    /// Returns the stack trace state at the specified clock cycle.
+    pub fn get_stack_state_at(&self, clk: u32) -> [Felt; STACK_TOP_SIZE] {
+        let mut result = [ZERO; STACK_TOP_SIZE];
+        for (result, column) in result.iter_mut().zip(self.stack.iter()) {
+            *result = column[clk as usize];
+        }
+        result
+    }
+
     /// Returns the value located at the specified position on the stack at the specified clock
     /// cycle.
     #[inline(always)]
@@ -199,16 +208,6 @@ impl StackTrace {
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the stack trace state at the specified clock cycle.
-    #[cfg(any(test, feature = "internals"))]
-    pub fn get_stack_state_at(&self, clk: u32) -> [Felt; STACK_TOP_SIZE] {
-        let mut result = [ZERO; STACK_TOP_SIZE];
-        for (result, column) in result.iter_mut().zip(self.stack.iter()) {
-            *result = column[clk as usize];
-        }
-        result
-    }
-
     /// Returns the trace state of the stack helper columns at the specified clock cycle.
     #[cfg(test)]
     pub fn get_helpers_state_at(&self, clk: u32) -> [Felt; NUM_STACK_HELPER_COLS] {
@@ -2,22 +2,25 @@ use super::{
     chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
     decoder::AuxTraceHints as DecoderAuxTraceHints,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
-    stack::AuxTraceBuilder as StackAuxTraceBuilder, AdviceProvider, ColMatrix, Digest, Felt,
-    FieldElement, Process, StackTopState, Vec,
+    stack::AuxTraceBuilder as StackAuxTraceBuilder, AdviceProvider, BTreeMap, Chiplets, ColMatrix,
+    Digest, Felt, FieldElement, Process, RangeCheckMode, StackTopState, Vec,
 };
 use miden_air::trace::{
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
     AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
-    STACK_TRACE_OFFSET, TRACE_WIDTH,
+    STACK_TRACE_OFFSET,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
+use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackInputs, StackOutputs, Word, ZERO};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
 #[cfg(feature = "std")]
 use vm_core::StarkField;
 
 mod utils;
-pub use utils::{build_lookup_table_row_values, AuxColumnBuilder, LookupTableRow, TraceFragment};
+pub use utils::{
+    build_lookup_table_row_values, AuxColumnBuilder, ChipletsLengths, LookupTableRow,
+    SegmentRanges, TraceFragment, TraceLenSummary,
+};
 
 mod decoder;
 
@@ -30,6 +33,30 @@ mod tests;
 /// Number of rows at the end of an execution trace which are injected with random values.
 pub const NUM_RAND_ROWS: usize = 1;
 
+/// Value at which the polynomials defined by trace columns are evaluated to compute column
+/// checksums.
+const CHECKSUM_CHALLENGE: Felt = Felt::new(0x9e3779b97f4a7c15);
+
+// TRACE EXTENSION
+// ================================================================================================
+
+/// Defines additional columns to be appended to the main execution trace.
+///
+/// This is an extension point for experimenting with the VM (e.g., with constraints over
+/// advice-derived values). The columns built by an extension are placed after the chiplets columns
+/// of the main trace, padded to the length of the trace with their last value, and injected with
+/// random values in the same way as all other columns of the main trace.
+///
+/// The AIR of the VM is not aware of the extension columns, and thus, traces with extension
+/// columns cannot be proven by the default prover.
+pub trait TraceExtension<A: AdviceProvider> {
+    /// Returns the extension columns for the trace of the provided process.
+    ///
+    /// The process is passed to this method after the program has been executed. Columns can be
+    /// of any length not greater than the number of executed cycles.
+    fn build_columns(&self, process: &Process<A>) -> Vec<Vec<Felt>>;
+}
+
 // VM EXECUTION TRACE
 // ================================================================================================
 
@@ -47,13 +74,27 @@ pub struct AuxTraceHints {
 ///   components.
 /// - Hints used during auxiliary trace segment construction.
 /// - Metadata needed by the STARK prover.
+///
+/// Auxiliary trace hints are collected while the main trace is built, and are much smaller than
+/// the main trace (their size depends on the number of executed control flow, stack overflow,
+/// range check and chiplet operations rather than on the trace length). Tools which need only the
+/// main trace can drop them via [ExecutionTrace::without_aux_trace_hints()]; such traces cannot be
+/// used to build the auxiliary trace segment, and thus, cannot be proven.
+///
+/// The auxiliary trace segment can be built over the base field as well as over any of its
+/// extensions; in particular, quadratic and cubic extensions (the extensions supported by the
+/// prover) are supported.
 pub struct ExecutionTrace {
     meta: Vec<u8>,
     layout: TraceLayout,
     main_trace: ColMatrix<Felt>,
-    aux_trace_hints: AuxTraceHints,
+    aux_trace_hints: Option<AuxTraceHints>,
     program_info: ProgramInfo,
+    stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    memory_state: BTreeMap<u64, Word>,
+    trace_len_summary: TraceLenSummary,
+    inject_random_rows: bool,
 }
 
 impl ExecutionTrace {
@@ -65,8 +106,31 @@ impl ExecutionTrace {
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Builds an execution trace for the provided process.
-    pub(super) fn new<A>(process: Process<A>, stack_outputs: StackOutputs) -> Self
+    /// Builds an execution trace for the provided process. The process is expected to have been
+    /// started with the provided stack inputs and to have terminated with the provided stack
+    /// outputs.
+    pub(super) fn new<A>(
+        process: Process<A>,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+    ) -> Self
+    where
+        A: AdviceProvider,
+    {
+        Self::new_with_extension(process, stack_inputs, stack_outputs, None)
+    }
+
+    /// Builds an execution trace for the provided process with the columns of the specified
+    /// [TraceExtension] (if any) appended to the main trace.
+    ///
+    /// Without an extension, the returned trace is identical to the trace built by
+    /// [ExecutionTrace::new()].
+    pub(super) fn new_with_extension<A>(
+        process: Process<A>,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+        extension: Option<&dyn TraceExtension<A>>,
+    ) -> Self
     where
         A: AdviceProvider,
     {
@@ -80,16 +144,73 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash, kernel);
-        let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
+
+        // the memory state must be read before the process is consumed by trace finalization
+        let memory_state =
+            process.chiplets.get_mem_state_at(0, process.system.clk()).into_iter().collect();
+
+        let (main_trace, aux_trace_hints, range_check_mode, trace_len_summary) =
+            finalize_trace(process, rng, extension);
+        let main_trace_width = main_trace.len();
 
         Self {
-            meta: Vec::new(),
-            layout: TraceLayout::new(TRACE_WIDTH, [AUX_TRACE_WIDTH], [AUX_TRACE_RAND_ELEMENTS]),
+            meta: range_check_mode.to_trace_meta(),
+            layout: TraceLayout::new(
+                main_trace_width,
+                [AUX_TRACE_WIDTH],
+                [AUX_TRACE_RAND_ELEMENTS],
+            ),
             main_trace: ColMatrix::new(main_trace),
-            aux_trace_hints,
+            aux_trace_hints: Some(aux_trace_hints),
             program_info,
+            stack_inputs,
             stack_outputs,
+            memory_state,
+            trace_len_summary,
+            inject_random_rows: true,
+        }
+    }
+
+    /// Builds an execution trace for the provided process without retaining the hints needed to
+    /// build the auxiliary trace segment.
+    ///
+    /// The main trace of the returned execution trace is identical to the main trace built by
+    /// [ExecutionTrace::new()].
+    pub(super) fn new_main_only<A>(
+        process: Process<A>,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+    ) -> Self
+    where
+        A: AdviceProvider,
+    {
+        Self::new(process, stack_inputs, stack_outputs).without_aux_trace_hints()
+    }
+
+    /// Returns this execution trace with the hints used for building the auxiliary trace segment
+    /// dropped.
+    ///
+    /// This frees the memory occupied by the hints for tools which only need the main trace.
+    /// Building the auxiliary trace segment of the returned trace will panic.
+    pub fn without_aux_trace_hints(mut self) -> Self {
+        self.aux_trace_hints = None;
+        self
+    }
+
+    /// Returns this execution trace with the last [NUM_RAND_ROWS] rows of the main trace set to
+    /// zeros. The same rows of the auxiliary trace segment are set to zeros as well when the
+    /// segment is built.
+    ///
+    /// Random values are injected into these rows to stabilize the degrees of trace polynomials;
+    /// without them the trace depends only on the executed program and its inputs, which is useful
+    /// for testing. Traces without random rows are not meant to be used for proof generation.
+    pub fn without_random_rows(mut self) -> Self {
+        let zero_row = vec![ZERO; self.main_trace.num_cols()];
+        for i in self.length() - NUM_RAND_ROWS..self.length() {
+            self.main_trace.update_row(i, &zero_row);
         }
+        self.inject_random_rows = false;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -105,6 +226,19 @@ impl ExecutionTrace {
         self.program_info.program_hash()
     }
 
+    /// Returns the mode in which the range checker segment of this execution trace was built.
+    ///
+    /// This may differ from the mode requested via [ExecutionOptions](crate::ExecutionOptions)
+    /// if the executed program range-checked values which do not fit into 8 bits.
+    pub fn range_check_mode(&self) -> RangeCheckMode {
+        RangeCheckMode::from_trace_meta(&self.meta)
+    }
+
+    /// Returns inputs of the program execution which resulted in this execution trace.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
     /// Returns outputs of the program execution which resulted in this execution trace.
     pub fn stack_outputs(&self) -> &StackOutputs {
         &self.stack_outputs
@@ -112,19 +246,35 @@ impl ExecutionTrace {
 
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
-        let mut result = [ZERO; STACK_TOP_SIZE];
-        for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[0];
-        }
-        result
+        self.stack_state_at(0)
     }
 
     /// Returns the final state of the top 16 stack registers.
     pub fn last_stack_state(&self) -> StackTopState {
+        self.stack_state_at(self.last_step())
+    }
+
+    /// Returns the final memory state of the root context as a map from memory addresses to the
+    /// words stored at them.
+    ///
+    /// Only addresses which have been accessed during the execution are included; all other
+    /// addresses hold four ZERO elements.
+    pub fn last_memory_state(&self) -> &BTreeMap<u64, Word> {
+        &self.memory_state
+    }
+
+    /// Returns the state of the top 16 stack registers at the specified step of the execution.
+    ///
+    /// # Panics
+    /// Panics if the specified step falls into the random rows at the end of the trace. Steps in
+    /// the padding rows are accepted; these rows hold the stack state at the end of the execution.
+    pub fn stack_state_at(&self, step: usize) -> StackTopState {
         let last_step = self.last_step();
+        assert!(step <= last_step, "step {step} is out of bounds; the last step is {last_step}");
+
         let mut result = [ZERO; STACK_TOP_SIZE];
         for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[last_step];
+            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[step];
         }
         result
     }
@@ -143,6 +293,73 @@ impl ExecutionTrace {
         self.main_trace.num_rows()
     }
 
+    /// Returns the lengths of the trace segments of this execution trace before padding.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the ranges of columns of the main segment of this trace occupied by the individual
+    /// components of the VM (i.e., system, decoder, stack, range checker, and chiplets), as well
+    /// as by the trace extension (if any).
+    ///
+    /// The ranges can be used to slice the columns of [ExecutionTrace::main_matrix()].
+    pub fn segment_ranges(&self) -> SegmentRanges {
+        SegmentRanges::new(self.main_trace.num_cols())
+    }
+
+    /// Returns the main segment of this trace as a column-major matrix.
+    ///
+    /// This is the same matrix as returned by [Trace::main_segment()], but does not require the
+    /// [Trace] trait to be in scope. The matrix is borrowed immutably: the trace cannot be
+    /// modified through it, as the columns must stay consistent with the program info and the
+    /// stack outputs of this trace.
+    pub fn main_matrix(&self) -> &ColMatrix<Felt> {
+        &self.main_trace
+    }
+
+    /// Returns true if this trace retains the hints needed to build the auxiliary trace segment.
+    pub fn has_aux_trace_hints(&self) -> bool {
+        self.aux_trace_hints.is_some()
+    }
+
+    /// Returns true if random values have been injected into the last rows of this trace.
+    pub fn has_random_rows(&self) -> bool {
+        self.inject_random_rows
+    }
+
+    // INTEGRITY CHECKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a checksum for each column of the main trace.
+    ///
+    /// A checksum of a column is computed by interpreting column values as coefficients of a
+    /// polynomial (the value in the first row being the leading coefficient), and evaluating this
+    /// polynomial at a fixed point via Horner's method. A change to any single cell of a column
+    /// is guaranteed to change the checksum of this column.
+    ///
+    /// Checksums are meant to detect accidental corruption of a trace (e.g., in transit between
+    /// components of a proving pipeline); since the evaluation point is fixed, they provide no
+    /// protection against deliberate tampering.
+    pub fn column_checksums(&self) -> Vec<Felt> {
+        (0..self.main_trace.num_cols())
+            .map(|i| {
+                self.main_trace
+                    .get_column(i)
+                    .iter()
+                    .fold(ZERO, |acc, &value| acc * CHECKSUM_CHALLENGE + value)
+            })
+            .collect()
+    }
+
+    /// Returns true if the checksums of the main trace columns are equal to the expected
+    /// checksums.
+    ///
+    /// This also returns false if the number of expected checksums is different from the number
+    /// of columns in the main trace.
+    pub fn verify_checksums(&self, expected: &[Felt]) -> bool {
+        self.column_checksums() == expected
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -156,7 +373,7 @@ impl ExecutionTrace {
     #[cfg(feature = "std")]
     #[allow(dead_code)]
     pub fn print(&self) {
-        let mut row = [ZERO; TRACE_WIDTH];
+        let mut row = vec![ZERO; self.main_trace.num_cols()];
         for i in 0..self.length() {
             self.main_trace.read_row_into(i, &mut row);
             println!("{:?}", row.iter().map(|v| v.as_int()).collect::<Vec<_>>());
@@ -169,7 +386,21 @@ impl ExecutionTrace {
         A: AdviceProvider,
     {
         let rng = RpoRandomCoin::new(&[ZERO; 4]);
-        finalize_trace(process, rng)
+        let (trace, aux_trace_hints, ..) = finalize_trace(process, rng, None);
+        (trace, aux_trace_hints)
+    }
+
+    #[cfg(test)]
+    pub fn test_finalize_trace_with_extension<A>(
+        process: Process<A>,
+        extension: &dyn TraceExtension<A>,
+    ) -> (Vec<Vec<Felt>>, AuxTraceHints)
+    where
+        A: AdviceProvider,
+    {
+        let rng = RpoRandomCoin::new(&[ZERO; 4]);
+        let (trace, aux_trace_hints, ..) = finalize_trace(process, rng, Some(extension));
+        (trace, aux_trace_hints)
     }
 }
 
@@ -205,26 +436,27 @@ impl Trace for ExecutionTrace {
             return None;
         }
 
+        let aux_trace_hints = self
+            .aux_trace_hints
+            .as_ref()
+            .expect("auxiliary trace hints have been dropped from this trace");
+
         // TODO: build auxiliary columns in multiple threads
 
         // add decoder's running product columns
-        let decoder_aux_columns = decoder::build_aux_columns(
-            &self.main_trace,
-            &self.aux_trace_hints.decoder,
-            rand_elements,
-        );
+        let decoder_aux_columns =
+            decoder::build_aux_columns(&self.main_trace, &aux_trace_hints.decoder, rand_elements);
 
         // add stack's running product columns
         let stack_aux_columns =
-            self.aux_trace_hints.stack.build_aux_columns(&self.main_trace, rand_elements);
+            aux_trace_hints.stack.build_aux_columns(&self.main_trace, rand_elements);
 
         // add the range checker's running product columns
         let range_aux_columns =
-            self.aux_trace_hints.range.build_aux_columns(&self.main_trace, rand_elements);
+            aux_trace_hints.range.build_aux_columns(&self.main_trace, rand_elements);
 
         // add the running product columns for the chiplets
-        let chiplets =
-            self.aux_trace_hints.chiplets.build_aux_columns(&self.main_trace, rand_elements);
+        let chiplets = aux_trace_hints.chiplets.build_aux_columns(&self.main_trace, rand_elements);
 
         // combine all auxiliary columns into a single vector
         let mut aux_columns = decoder_aux_columns
@@ -234,11 +466,16 @@ impl Trace for ExecutionTrace {
             .chain(chiplets)
             .collect::<Vec<_>>();
 
-        // inject random values into the last rows of the trace
+        // inject random values into the last rows of the trace, or set them to zeros if random
+        // values have been disabled for this trace
         let mut rng = RpoRandomCoin::new(self.program_hash().as_elements());
         for i in self.length() - NUM_RAND_ROWS..self.length() {
             for column in aux_columns.iter_mut() {
-                column[i] = rng.draw().expect("failed to draw a random value");
+                column[i] = if self.inject_random_rows {
+                    rng.draw().expect("failed to draw a random value")
+                } else {
+                    E::ZERO
+                };
             }
         }
 
@@ -263,10 +500,27 @@ impl Trace for ExecutionTrace {
 /// - Inserting random values in the last row of all columns. This helps ensure that there
 ///   are no repeating patterns in each column and each column contains a least two distinct
 ///   values. This, in turn, ensures that polynomial degrees of all columns are stable.
-fn finalize_trace<A>(process: Process<A>, mut rng: RpoRandomCoin) -> (Vec<Vec<Felt>>, AuxTraceHints)
+///
+/// If a [TraceExtension] is provided, the columns built by it are appended after the chiplets
+/// columns, and are padded and injected with random values in the same way as all other columns.
+///
+/// The range checker segment is built in the mode requested by the execution options of the
+/// process if possible; the mode actually used is returned together with the trace, as well as
+/// the lengths of the trace segments before padding.
+fn finalize_trace<A>(
+    process: Process<A>,
+    mut rng: RpoRandomCoin,
+    extension: Option<&dyn TraceExtension<A>>,
+) -> (Vec<Vec<Felt>>, AuxTraceHints, RangeCheckMode, TraceLenSummary)
 where
     A: AdviceProvider,
 {
+    let requested_range_check_mode = process.options.range_check_mode();
+
+    // extension columns must be built before the process is broken into its components
+    let mut extension_trace =
+        extension.map(|extension| extension.build_columns(&process)).unwrap_or_default();
+
     let (system, decoder, stack, mut range, chiplets, _) = process.into_parts();
 
     let clk = system.clk();
@@ -275,25 +529,31 @@ where
     assert_eq!(clk as usize, system.trace_len(), "inconsistent system trace lengths");
     assert_eq!(clk as usize, decoder.trace_len(), "inconsistent decoder trace length");
     assert_eq!(clk as usize, stack.trace_len(), "inconsistent stack trace lengths");
+    for column in extension_trace.iter() {
+        assert!(column.len() <= clk as usize, "extension column is longer than the execution");
+    }
 
     // Add the range checks required by the chiplets to the range checker.
     chiplets.append_range_checks(&mut range);
 
+    // all range checks have been added, so the range checker mode can be determined
+    let range_check_mode = range.set_mode(requested_range_check_mode);
+
     // Generate the 8bit tables for the range trace.
     let range_table = range.build_8bit_lookup();
 
-    // Get the trace length required to hold all execution trace steps.
-    let max_len = range_table.len.max(clk as usize).max(chiplets.trace_len());
-
-    // pad the trace length to the next power of two and ensure that there is space for the
-    // rows to hold random values
-    let trace_len = (max_len + NUM_RAND_ROWS).next_power_of_two();
+    // Get the trace length required to hold all execution trace steps, and pad it to the next
+    // power of two making sure that there is space for the rows to hold random values
+    let trace_len_summary =
+        TraceLenSummary::new(clk as usize, range_table.len, ChipletsLengths::new(&chiplets));
+    let trace_len = trace_len_summary.padded_trace_len();
     assert!(
         trace_len >= MIN_TRACE_LEN,
         "trace length must be at least {MIN_TRACE_LEN}, but was {trace_len}",
     );
 
-    // combine all trace segments into the main trace
+    // combine all trace segments into the main trace; the order of the segments must match the
+    // column ranges reported by SegmentRanges
     let system_trace = system.into_trace(trace_len, NUM_RAND_ROWS);
     let decoder_trace = decoder.into_trace(trace_len, NUM_RAND_ROWS);
     let stack_trace = stack.into_trace(trace_len, NUM_RAND_ROWS);
@@ -302,12 +562,19 @@ where
     // combine the range trace segment using the support lookup table
     let range_check_trace = range.into_trace_with_table(range_table, trace_len, NUM_RAND_ROWS);
 
+    // pad the extension columns with their last values
+    for column in extension_trace.iter_mut() {
+        let last_value = column.last().copied().unwrap_or(ZERO);
+        column.resize(trace_len, last_value);
+    }
+
     let mut trace = system_trace
         .into_iter()
         .chain(decoder_trace.trace)
         .chain(stack_trace.trace)
         .chain(range_check_trace.trace)
         .chain(chiplets_trace.trace)
+        .chain(extension_trace)
         .collect::<Vec<_>>();
 
     // inject random values into the last rows of the trace
@@ -324,5 +591,5 @@ where
         chiplets: chiplets_trace.aux_builder,
     };
 
-    (trace, aux_trace_hints)
+    (trace, aux_trace_hints, range_check_mode, trace_len_summary)
 }
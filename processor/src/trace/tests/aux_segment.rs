@@ -0,0 +1,46 @@
+use super::{build_trace_from_ops, rand_array, Felt, Operation, Trace, Vec};
+use miden_air::trace::AUX_TRACE_RAND_ELEMENTS;
+use vm_core::{CubeExtension, QuadExtension};
+
+/// Checks that auxiliary columns built over extension fields of degree 2 and 3 are consistent
+/// with the columns built over the base field when the random elements are in the base field.
+#[test]
+fn aux_segment_over_field_extensions() {
+    let stack = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    let operations = vec![
+        Operation::U32add,
+        Operation::U32and,
+        Operation::Pad,
+        Operation::MStoreW,
+        Operation::Pad,
+        Operation::Pad,
+        Operation::Drop,
+        Operation::HPerm,
+        Operation::Drop,
+    ];
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let mut trace = build_trace_from_ops(operations, &stack).without_random_rows();
+    let base = trace.build_aux_segment(&[], &rand_elements).unwrap();
+
+    let quad_elements: Vec<QuadExtension<Felt>> = rand_elements.iter().map(|&e| e.into()).collect();
+    let quad = trace.build_aux_segment(&[], &quad_elements).unwrap();
+
+    let cube_elements: Vec<CubeExtension<Felt>> = rand_elements.iter().map(|&e| e.into()).collect();
+    let cube = trace.build_aux_segment(&[], &cube_elements).unwrap();
+
+    assert_eq!(base.num_cols(), quad.num_cols());
+    assert_eq!(base.num_cols(), cube.num_cols());
+    for i in 0..base.num_cols() {
+        let expected_quad: Vec<QuadExtension<Felt>> =
+            base.get_column(i).iter().map(|&e| e.into()).collect();
+        let expected_cube: Vec<CubeExtension<Felt>> =
+            base.get_column(i).iter().map(|&e| e.into()).collect();
+        assert_eq!(
+            expected_quad,
+            quad.get_column(i),
+            "column {i} differs over quadratic extension"
+        );
+        assert_eq!(expected_cube, cube.get_column(i), "column {i} differs over cubic extension");
+    }
+}
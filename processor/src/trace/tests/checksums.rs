@@ -0,0 +1,31 @@
+use super::{build_trace_from_ops, Felt, Operation, Trace, ONE};
+
+#[test]
+fn column_checksums_detect_modified_cells() {
+    let operations = vec![Operation::Push(Felt::new(7)), Operation::Add, Operation::Pad];
+    let stack = [1, 2, 3];
+
+    let trace = build_trace_from_ops(operations.clone(), &stack);
+    let checksums = trace.column_checksums();
+    assert_eq!(trace.main_segment().num_cols(), checksums.len());
+    assert!(trace.verify_checksums(&checksums));
+
+    // a trace built from the same program and inputs has the same checksums
+    let trace = build_trace_from_ops(operations.clone(), &stack);
+    assert!(trace.verify_checksums(&checksums));
+
+    // modifying a single cell of any column is detected
+    for (col_idx, row_idx) in [(0, 0), (5, 3), (checksums.len() - 1, trace.length() - 1)] {
+        let mut trace = build_trace_from_ops(operations.clone(), &stack);
+        trace.main_trace.get_column_mut(col_idx)[row_idx] += ONE;
+        assert!(!trace.verify_checksums(&checksums));
+
+        let modified = trace.column_checksums();
+        for (i, (&expected, &actual)) in checksums.iter().zip(modified.iter()).enumerate() {
+            assert_eq!(i != col_idx, expected == actual);
+        }
+    }
+
+    // checksums for a different number of columns are rejected
+    assert!(!trace.verify_checksums(&checksums[1..]));
+}
@@ -0,0 +1,68 @@
+use super::{super::TraceExtension, ExecutionTrace, Felt, Process, Vec, NUM_RAND_ROWS, ZERO};
+use crate::{
+    crypto::{RandomCoin, RpoRandomCoin},
+    AdviceProvider, MemAdviceProvider, StackInputs,
+};
+use miden_air::trace::TRACE_WIDTH;
+use vm_core::{code_blocks::CodeBlock, CodeBlockTable, Kernel, Operation};
+
+// TEST EXTENSION
+// ================================================================================================
+
+/// A trace extension which adds a single column containing the clock cycle of every executed step.
+struct ClkExtension;
+
+impl<A: AdviceProvider> TraceExtension<A> for ClkExtension {
+    fn build_columns(&self, process: &Process<A>) -> Vec<Vec<Felt>> {
+        let clk = process.system.clk();
+        vec![(0..clk).map(Felt::from).collect()]
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn trace_extension_columns() {
+    let (trace, _) = ExecutionTrace::test_finalize_trace(build_process());
+
+    let process = build_process();
+    let clk = process.system.clk() as usize;
+    let (extended_trace, _) =
+        ExecutionTrace::test_finalize_trace_with_extension(process, &ClkExtension);
+
+    // the extension column is appended after all other columns, which are not affected by it
+    assert_eq!(TRACE_WIDTH + 1, extended_trace.len());
+    assert_eq!(trace[..], extended_trace[..TRACE_WIDTH]);
+
+    // the extension column is padded to the length of the trace with its last value
+    let trace_len = extended_trace[0].len();
+    let column = &extended_trace[TRACE_WIDTH];
+    assert_eq!(trace_len, column.len());
+    for (i, &value) in column.iter().enumerate().take(clk) {
+        assert_eq!(Felt::from(i as u32), value);
+    }
+    let last_clk = Felt::from(clk as u32 - 1);
+    assert!(column[clk..trace_len - NUM_RAND_ROWS].iter().all(|&value| value == last_clk));
+
+    // random values are injected into the last rows of the extension column in the same way as
+    // into all other columns; the trace is finalized with a random coin seeded with zeros
+    let mut rng = RpoRandomCoin::new(&[ZERO; 4]);
+    for i in trace_len - NUM_RAND_ROWS..trace_len {
+        for column in extended_trace.iter() {
+            assert_eq!(rng.draw::<Felt>().unwrap(), column[i]);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_process() -> Process<MemAdviceProvider> {
+    let stack_inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+    let mut process = Process::new(Kernel::default(), stack_inputs, MemAdviceProvider::default());
+    let program =
+        CodeBlock::new_span(vec![Operation::Push(Felt::new(7)), Operation::Add, Operation::Pad]);
+    process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
+    process
+}
@@ -0,0 +1,68 @@
+use super::{Felt, FieldElement, TraceFragment, Vec};
+
+#[test]
+fn fragment_from_columns() {
+    let mut columns = vec![vec![Felt::ZERO; 4]; 3];
+
+    let mut fragment =
+        TraceFragment::from_columns(columns.iter_mut().map(|c| c.as_mut_slice()).collect());
+    assert_eq!(3, fragment.width());
+    assert_eq!(4, fragment.len());
+
+    // writes through the fragment should be reflected in the underlying columns
+    fragment.set(0, 0, Felt::new(1));
+    fragment.set(3, 1, Felt::new(2));
+    fragment.set(2, 2, Felt::new(3));
+
+    let expected: Vec<Vec<Felt>> = vec![
+        vec![Felt::new(1), Felt::ZERO, Felt::ZERO, Felt::ZERO],
+        vec![Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::new(2)],
+        vec![Felt::ZERO, Felt::ZERO, Felt::new(3), Felt::ZERO],
+    ];
+    assert_eq!(expected, columns);
+}
+
+#[test]
+#[should_panic(expected = "all columns of a trace fragment must have the same length")]
+fn fragment_from_columns_of_different_lengths() {
+    let mut column_a = vec![Felt::ZERO; 4];
+    let mut column_b = vec![Felt::ZERO; 3];
+    TraceFragment::from_columns(vec![column_a.as_mut_slice(), column_b.as_mut_slice()]);
+}
+
+#[test]
+fn fragment_clear() {
+    let mut columns = vec![vec![Felt::ZERO; 4]; 3];
+
+    let mut fragment =
+        TraceFragment::from_columns(columns.iter_mut().map(|c| c.as_mut_slice()).collect());
+    for row in 0..4 {
+        for col in 0..3 {
+            fragment.set(row, col, Felt::new((row * 3 + col + 1) as u64));
+        }
+    }
+
+    fragment.clear();
+    assert_eq!(3, fragment.width());
+    assert_eq!(4, fragment.len());
+    assert!(columns.iter().flatten().all(|&value| value == Felt::ZERO));
+}
+
+#[test]
+fn fragment_zeroed() {
+    // columns hold stale data, e.g., left over from a previous trace
+    let mut columns = vec![vec![Felt::new(7); 4]; 3];
+
+    let fragment = TraceFragment::zeroed(columns.iter_mut().map(|c| c.as_mut_slice()).collect());
+    assert_eq!(3, fragment.width());
+    assert_eq!(4, fragment.len());
+    assert!(columns.iter().flatten().all(|&value| value == Felt::ZERO));
+}
+
+#[test]
+#[should_panic(expected = "all columns of a trace fragment must have the same length")]
+fn fragment_zeroed_columns_of_different_lengths() {
+    let mut column_a = vec![Felt::new(7); 4];
+    let mut column_b = vec![Felt::new(7); 3];
+    TraceFragment::zeroed(vec![column_a.as_mut_slice(), column_b.as_mut_slice()]);
+}
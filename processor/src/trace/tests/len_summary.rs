@@ -0,0 +1,23 @@
+use super::{build_trace_from_ops, Operation, Trace};
+
+#[test]
+fn trace_len_summary() {
+    let operations = vec![Operation::U32and, Operation::Pad, Operation::Drop];
+    let trace = build_trace_from_ops(operations, &[1, 2, 3]);
+    let summary = trace.trace_len_summary();
+
+    // the span block is hashed in a single hash cycle, and the bitwise operation takes a full
+    // bitwise cycle; memory and kernel ROM are not used
+    let chiplets = summary.chiplets_trace_len();
+    assert_eq!(8, chiplets.hash_chiplet_len());
+    assert_eq!(8, chiplets.bitwise_chiplet_len());
+    assert_eq!(0, chiplets.memory_chiplet_len());
+    assert_eq!(0, chiplets.kernel_rom_len());
+    assert_eq!(17, chiplets.trace_len());
+
+    // the trace is padded to the next power of two after the longest segment
+    assert!(summary.trace_len() >= summary.main_trace_len());
+    assert!(summary.trace_len() >= summary.range_trace_len());
+    assert!(summary.trace_len() >= chiplets.trace_len());
+    assert_eq!(trace.length(), summary.padded_trace_len());
+}
@@ -1,16 +1,23 @@
 use super::{
     super::chiplets::init_state_from_words, ExecutionTrace, Felt, FieldElement, LookupTableRow,
-    Process, Trace, Vec, NUM_RAND_ROWS,
+    Process, Trace, TraceFragment, Vec, NUM_RAND_ROWS,
 };
-use crate::{AdviceInputs, MemAdviceProvider, StackInputs};
+use crate::{AdviceInputs, ExecutionOptions, MemAdviceProvider, StackInputs};
 use rand_utils::rand_array;
 use vm_core::{
     code_blocks::CodeBlock, CodeBlockTable, Kernel, Operation, StackOutputs, Word, ONE, ZERO,
 };
 
+mod aux_segment;
+mod checksums;
 mod chiplets;
+mod extension;
+mod fragment;
 mod hasher;
+mod len_summary;
+mod random_rows;
 mod range;
+mod segments;
 mod stack;
 
 // TEST HELPERS
@@ -20,9 +27,9 @@ mod stack;
 pub fn build_trace_from_block(program: &CodeBlock, stack_inputs: &[u64]) -> ExecutionTrace {
     let stack_inputs = StackInputs::try_from_values(stack_inputs.iter().copied()).unwrap();
     let advice_provider = MemAdviceProvider::default();
-    let mut process = Process::new(Kernel::default(), stack_inputs, advice_provider);
+    let mut process = Process::new(Kernel::default(), stack_inputs.clone(), advice_provider);
     process.execute_code_block(program, &CodeBlockTable::default()).unwrap();
-    ExecutionTrace::new(process, StackOutputs::default())
+    ExecutionTrace::new(process, stack_inputs, StackOutputs::default())
 }
 
 /// Builds a sample trace by executing a span block containing the specified operations. This
@@ -32,6 +39,37 @@ pub fn build_trace_from_ops(operations: Vec<Operation>, stack: &[u64]) -> Execut
     build_trace_from_block(&program, stack)
 }
 
+/// Builds a sample trace by executing a span block containing the specified operations, without
+/// retaining the hints needed to build the auxiliary trace segment.
+pub fn build_trace_from_ops_main_only(operations: Vec<Operation>, stack: &[u64]) -> ExecutionTrace {
+    let stack_inputs = StackInputs::try_from_values(stack.iter().copied()).unwrap();
+    let advice_provider = MemAdviceProvider::default();
+    let mut process = Process::new(Kernel::default(), stack_inputs.clone(), advice_provider);
+    let program = CodeBlock::new_span(operations);
+    process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
+    ExecutionTrace::new_main_only(process, stack_inputs, StackOutputs::default())
+}
+
+/// Builds a sample trace by executing a span block containing the specified operations using the
+/// specified execution options.
+pub fn build_trace_from_ops_with_options(
+    operations: Vec<Operation>,
+    stack: &[u64],
+    options: ExecutionOptions,
+) -> ExecutionTrace {
+    let stack_inputs = StackInputs::try_from_values(stack.iter().copied()).unwrap();
+    let advice_provider = MemAdviceProvider::default();
+    let mut process = Process::new_with_options(
+        Kernel::default(),
+        stack_inputs.clone(),
+        advice_provider,
+        options,
+    );
+    let program = CodeBlock::new_span(operations);
+    process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
+    ExecutionTrace::new(process, stack_inputs, StackOutputs::default())
+}
+
 /// Builds a sample trace by executing a span block containing the specified operations. Unlike the
 /// function above, this function accepts the full [AdviceInputs] object, which means it can run
 /// the programs with initialized advice provider.
@@ -41,8 +79,8 @@ pub fn build_trace_from_ops_with_inputs(
     advice_inputs: AdviceInputs,
 ) -> ExecutionTrace {
     let advice_provider = MemAdviceProvider::from(advice_inputs);
-    let mut process = Process::new(Kernel::default(), stack_inputs, advice_provider);
+    let mut process = Process::new(Kernel::default(), stack_inputs.clone(), advice_provider);
     let program = CodeBlock::new_span(operations);
     process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
-    ExecutionTrace::new(process, StackOutputs::default())
+    ExecutionTrace::new(process, stack_inputs, StackOutputs::default())
 }
@@ -0,0 +1,79 @@
+use super::{
+    build_trace_from_ops, build_trace_from_ops_main_only, rand_array, ExecutionTrace, Felt,
+    Operation, Trace, Vec, NUM_RAND_ROWS, ZERO,
+};
+use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, MIN_TRACE_LEN, TRACE_WIDTH};
+
+#[test]
+fn trace_without_random_rows_is_deterministic() {
+    let operations = vec![Operation::Push(Felt::new(7)), Operation::Add, Operation::Pad];
+    let stack = [1, 2, 3];
+
+    let mut trace_a = build_trace_from_ops(operations.clone(), &stack).without_random_rows();
+    let mut trace_b = build_trace_from_ops(operations, &stack).without_random_rows();
+    assert!(!trace_a.has_random_rows());
+
+    // the main trace segments are identical, and the last rows contain only zeros
+    assert_eq!(main_columns(&trace_a), main_columns(&trace_b));
+    for column in main_columns(&trace_a) {
+        assert!(column[column.len() - NUM_RAND_ROWS..].iter().all(|&value| value == ZERO));
+    }
+
+    // the same holds for the auxiliary trace segments built with the same random elements
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_a = trace_a.build_aux_segment(&[], &rand_elements).unwrap();
+    let aux_b = trace_b.build_aux_segment(&[], &rand_elements).unwrap();
+    for i in 0..aux_a.num_cols() {
+        let column = aux_a.get_column(i);
+        assert_eq!(column, aux_b.get_column(i));
+        assert!(column[column.len() - NUM_RAND_ROWS..].iter().all(|&value| value == ZERO));
+    }
+}
+
+#[test]
+fn trace_with_random_rows() {
+    let trace = build_trace_from_ops(vec![Operation::Pad, Operation::Drop], &[]);
+    assert!(trace.has_random_rows());
+
+    // random values are injected into the last rows of the trace
+    let columns = main_columns(&trace);
+    assert!(columns.iter().any(|column| column[column.len() - 1] != ZERO));
+}
+
+#[test]
+fn trace_without_aux_trace_hints() {
+    let operations = vec![Operation::Push(Felt::new(7)), Operation::Add, Operation::Pad];
+    let stack = [1, 2, 3];
+
+    let trace = build_trace_from_ops(operations.clone(), &stack);
+    assert!(trace.has_aux_trace_hints());
+
+    // dropping the hints does not affect the main trace
+    let main_only = build_trace_from_ops_main_only(operations, &stack);
+    assert!(!main_only.has_aux_trace_hints());
+    assert_eq!(main_columns(&trace), main_columns(&main_only));
+    assert_eq!(trace.program_hash(), main_only.program_hash());
+}
+
+#[test]
+fn main_matrix_dimensions() {
+    let trace = build_trace_from_ops(vec![Operation::Pad, Operation::Drop], &[]);
+
+    // the matrix spans the full width and length of the main trace segment
+    let matrix = trace.main_matrix();
+    assert_eq!(TRACE_WIDTH, matrix.num_cols());
+    assert_eq!(MIN_TRACE_LEN, matrix.num_rows());
+    assert_eq!(trace.get_trace_len(), matrix.num_rows());
+    assert_eq!(
+        main_columns(&trace),
+        (0..TRACE_WIDTH).map(|i| matrix.get_column(i).to_vec()).collect::<Vec<_>>()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn main_columns(trace: &ExecutionTrace) -> Vec<Vec<Felt>> {
+    let main_trace = trace.main_segment();
+    (0..main_trace.num_cols()).map(|i| main_trace.get_column(i).to_vec()).collect()
+}
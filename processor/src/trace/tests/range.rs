@@ -1,11 +1,15 @@
-use super::{build_trace_from_ops, Felt, FieldElement, Trace, NUM_RAND_ROWS, ONE, ZERO};
+use super::{
+    build_trace_from_ops, build_trace_from_ops_with_options, ExecutionTrace, Felt, FieldElement,
+    Trace, NUM_RAND_ROWS, ONE, ZERO,
+};
+use crate::{ExecutionOptions, RangeCheckMode};
 use miden_air::trace::{
     chiplets::hasher::HASH_CYCLE_LEN,
-    range::{P0_COL_IDX, P1_COL_IDX, Q_COL_IDX},
+    range::{P0_COL_IDX, P1_COL_IDX, Q_COL_IDX, T_COL_IDX, V_COL_IDX},
     AUX_TRACE_RAND_ELEMENTS,
 };
 use rand_utils::rand_array;
-use vm_core::Operation;
+use vm_core::{CubeExtension, Operation};
 
 #[test]
 fn p0_trace() {
@@ -252,3 +256,82 @@ fn p1_trace_mem() {
         assert_eq!(ONE, p1[i]);
     }
 }
+
+/// This test checks that the range checker's auxiliary columns are built correctly over a cubic
+/// extension of the base field, i.e., that range check lookups from stack and memory operations
+/// are balanced by the range checks processed in the Range Checker.
+#[test]
+fn aux_columns_cubic_extension() {
+    let stack = [0, 1, 2, 3, 4, 0, 1, 255];
+    let operations = vec![Operation::U32add, Operation::Drop, Operation::Drop, Operation::MStoreW];
+    let mut trace = build_trace_from_ops(operations, &stack);
+
+    let rand_elements = rand_array::<CubeExtension<Felt>, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let p0 = aux_columns.get_column(P0_COL_IDX);
+    let p1 = aux_columns.get_column(P1_COL_IDX);
+    let q = aux_columns.get_column(Q_COL_IDX);
+
+    assert_eq!(trace.length(), p0.len());
+
+    // all running products start and end with one
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+    for column in [p0, p1, q] {
+        assert_eq!(CubeExtension::ONE, column[0]);
+        assert_eq!(CubeExtension::ONE, column[last_row]);
+    }
+
+    // the range check lookups of U32add are divided out of p1 after it is executed
+    let alpha = rand_elements[0];
+    let lookup_product = alpha * (alpha + CubeExtension::from(Felt::new(256))) * alpha.square();
+    assert_eq!(lookup_product.inv(), p1[2]);
+}
+
+#[test]
+fn range_checker_8bit_mode() {
+    // --- Range check 3_u32 (4 16-bit range checks: 0, 3 and 0, 0) -------------------------------
+    let stack = [1, 2];
+    let operations = vec![Operation::U32add];
+    let options = ExecutionOptions::default().with_range_check_mode(RangeCheckMode::Bits8);
+
+    let trace_16bit = build_trace_from_ops(operations.clone(), &stack);
+    let mut trace_8bit = build_trace_from_ops_with_options(operations, &stack, options);
+    assert_eq!(RangeCheckMode::Bits16, trace_16bit.range_check_mode());
+    assert_eq!(RangeCheckMode::Bits8, trace_8bit.range_check_mode());
+
+    // 261 16-bit rows are needed in the 16-bit mode: 0 (in 2 rows for a total of 3 lookups), 3,
+    // 256 "bridge" values, 65535, and an extra row to pad the u16::MAX value.
+    assert_eq!(261, num_16bit_rows(&trace_16bit));
+
+    // in the 8-bit mode, no bridge rows are needed, and the table ends with 255 instead.
+    assert_eq!(5, num_16bit_rows(&trace_8bit));
+    let v = trace_8bit.main_segment().get_column(V_COL_IDX);
+    assert_eq!(Felt::new(255), v[v.len() - 1 - NUM_RAND_ROWS]);
+
+    // the running products must still be reduced to one at the end of the table.
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace_8bit.build_aux_segment(&[], &rand_elements).unwrap();
+    let p0 = aux_columns.get_column(P0_COL_IDX);
+    let p1 = aux_columns.get_column(P1_COL_IDX);
+    assert_eq!(ONE, p0[p0.len() - 1 - NUM_RAND_ROWS]);
+    assert_eq!(ONE, p1[p1.len() - 1 - NUM_RAND_ROWS]);
+}
+
+#[test]
+fn range_checker_8bit_mode_fallback() {
+    // --- Range check 256_u32 (4 16-bit range checks: 0, 256 and 0, 0) ---------------------------
+    let stack = [1, 255];
+    let operations = vec![Operation::U32add];
+    let options = ExecutionOptions::default().with_range_check_mode(RangeCheckMode::Bits8);
+
+    // 256 does not fit into 8 bits, so the trace is built in the 16-bit mode.
+    let trace = build_trace_from_ops_with_options(operations, &stack, options);
+    assert_eq!(RangeCheckMode::Bits16, trace.range_check_mode());
+    assert!(trace.meta().is_empty());
+}
+
+/// Returns the number of rows in the 16-bit segment of the range checker table of the trace.
+fn num_16bit_rows(trace: &ExecutionTrace) -> usize {
+    let t = trace.main_segment().get_column(T_COL_IDX);
+    t[..t.len() - NUM_RAND_ROWS].iter().filter(|&&value| value == ONE).count()
+}
@@ -0,0 +1,39 @@
+use super::{build_trace_from_ops, Operation};
+use crate::SegmentRanges;
+use miden_air::trace::TRACE_WIDTH;
+
+#[test]
+fn segment_ranges_tile_trace() {
+    let trace = build_trace_from_ops(vec![Operation::Pad, Operation::Add], &[1, 2, 3]);
+    let ranges = trace.segment_ranges();
+
+    // the components occupy all columns of the trace without gaps or overlaps
+    let mut next_column = 0;
+    for range in ranges.to_array() {
+        assert_eq!(next_column, range.start);
+        assert!(range.start <= range.end);
+        next_column = range.end;
+    }
+    assert_eq!(TRACE_WIDTH, next_column);
+    assert_eq!(TRACE_WIDTH, trace.main_matrix().num_cols());
+
+    // the trace was built without an extension
+    assert!(ranges.extension().is_empty());
+
+    // the first column of the stack segment holds the top of the stack
+    let stack_top = trace.main_matrix().get_column(ranges.stack().start);
+    assert_eq!(trace.init_stack_state()[0], stack_top[0]);
+}
+
+#[test]
+fn segment_ranges_with_extension() {
+    let ranges = SegmentRanges::new(TRACE_WIDTH + 2);
+    assert_eq!(ranges.chiplets().end, ranges.extension().start);
+    assert_eq!(TRACE_WIDTH..TRACE_WIDTH + 2, ranges.extension());
+}
+
+#[test]
+#[should_panic(expected = "main trace must have at least")]
+fn segment_ranges_too_narrow() {
+    SegmentRanges::new(TRACE_WIDTH - 1);
+}
@@ -4,6 +4,7 @@ use super::{
 };
 use crate::stack::OverflowTableRow;
 use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, STACK_AUX_TRACE_OFFSET};
+use vm_core::stack::STACK_TOP_SIZE;
 
 // CONSTANTS
 // ================================================================================================
@@ -11,6 +12,45 @@ use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, STACK_AUX_TRACE_OFFSET};
 const P1_COL_IDX: usize = STACK_AUX_TRACE_OFFSET;
 const TWO: Felt = Felt::new(2);
 
+// STACK STATE TESTS
+// ================================================================================================
+
+#[test]
+fn stack_state_at() {
+    let ops = vec![
+        Operation::Push(Felt::new(5)), // clk 1
+        Operation::Add,                // clk 2
+        Operation::Swap,               // clk 3
+        Operation::Drop,               // clk 4
+    ];
+    let trace = build_trace_from_ops(ops, &[1, 2, 3]);
+
+    // the stack at a given step is the state of the stack after the operation executed in the
+    // previous cycle; the first row of the trace is the SPAN operation which does not modify
+    // the stack
+    let expected = [
+        vec![3, 2, 1],
+        vec![3, 2, 1],
+        vec![5, 3, 2, 1],
+        vec![8, 2, 1],
+        vec![2, 8, 1],
+        vec![8, 1],
+    ];
+    for (step, expected) in expected.iter().enumerate() {
+        assert_eq!(build_stack_state(expected), trace.stack_state_at(step), "step {step}");
+    }
+
+    assert_eq!(trace.init_stack_state(), trace.stack_state_at(0));
+    assert_eq!(build_stack_state(&[8, 1]), trace.last_stack_state());
+}
+
+#[test]
+#[should_panic(expected = "is out of bounds")]
+fn stack_state_at_random_row() {
+    let trace = build_trace_from_ops(vec![Operation::Pad], &[]);
+    trace.stack_state_at(trace.length() - NUM_RAND_ROWS);
+}
+
 // OVERFLOW TABLE TESTS
 // ================================================================================================
 
@@ -94,3 +134,16 @@ fn p1_trace() {
         assert_eq!(ONE, p1[i]);
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the state of the top 16 stack registers with the specified values on top of the stack
+/// and zeros in the remaining registers.
+fn build_stack_state(values: &[u64]) -> [Felt; STACK_TOP_SIZE] {
+    let mut result = [ZERO; STACK_TOP_SIZE];
+    for (result, &value) in result.iter_mut().zip(values) {
+        *result = Felt::new(value);
+    }
+    result
+}
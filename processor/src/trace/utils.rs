@@ -1,5 +1,9 @@
-use super::{ColMatrix, Felt, FieldElement, Vec};
-use core::slice;
+use super::{Chiplets, ColMatrix, Felt, FieldElement, Vec, MIN_TRACE_LEN, NUM_RAND_ROWS};
+use core::{ops::Range, slice};
+use miden_air::trace::{
+    CHIPLETS_RANGE, DECODER_TRACE_RANGE, RANGE_CHECK_TRACE_RANGE, STACK_TRACE_RANGE,
+    SYS_TRACE_RANGE,
+};
 use vm_core::utils::uninit_vector;
 
 // TRACE FRAGMENT
@@ -11,11 +15,34 @@ pub struct TraceFragment<'a> {
 }
 
 impl<'a> TraceFragment<'a> {
-    /// Creates a new TraceFragment with its data allocated to the specified capacity.
-    pub fn new(capacity: usize) -> Self {
-        TraceFragment {
-            data: Vec::with_capacity(capacity),
+    /// Creates a new TraceFragment backed by the provided columns.
+    ///
+    /// # Panics
+    /// Panics if the provided columns do not all have the same length.
+    pub fn from_columns(columns: Vec<&'a mut [Felt]>) -> Self {
+        if let Some(first) = columns.first() {
+            let len = first.len();
+            assert!(
+                columns.iter().all(|column| column.len() == len),
+                "all columns of a trace fragment must have the same length"
+            );
         }
+        TraceFragment { data: columns }
+    }
+
+    /// Creates a new TraceFragment backed by the provided columns, and sets all cells of these
+    /// columns to ZERO.
+    ///
+    /// This is useful when the columns were used to build a trace before, as it guarantees that
+    /// no data left over from the previous trace leaks into the new one.
+    ///
+    /// # Panics
+    /// Panics if the provided columns do not all have the same length.
+    #[allow(dead_code)]
+    pub fn zeroed(columns: Vec<&'a mut [Felt]>) -> Self {
+        let mut fragment = Self::from_columns(columns);
+        fragment.clear();
+        fragment
     }
 
     // PUBLIC ACCESSORS
@@ -40,30 +67,237 @@ impl<'a> TraceFragment<'a> {
         self.data[col_idx][row_idx] = value;
     }
 
+    /// Sets all cells in this fragment to ZERO, so that the underlying columns can be reused for
+    /// building another trace.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        for column in self.data.iter_mut() {
+            column.fill(Felt::ZERO);
+        }
+    }
+
     /// Returns a mutable iterator to the columns of this fragment.
     pub fn columns(&mut self) -> slice::IterMut<'_, &'a mut [Felt]> {
         self.data.iter_mut()
     }
 
-    /// Adds a new column to this fragment by pushing a mutable slice with the first `len`
-    /// elements of the provided column. Returns the rest of the provided column as a separate
-    /// mutable slice.
-    pub fn push_column_slice(&mut self, column: &'a mut [Felt], len: usize) -> &'a mut [Felt] {
-        let (column_fragment, rest) = column.split_at_mut(len);
-        self.data.push(column_fragment);
-        rest
-    }
-
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
     #[cfg(test)]
     pub fn trace_to_fragment(trace: &'a mut [Vec<Felt>]) -> Self {
-        let mut data = Vec::new();
-        for column in trace.iter_mut() {
-            data.push(column.as_mut_slice());
+        Self::from_columns(trace.iter_mut().map(|column| column.as_mut_slice()).collect())
+    }
+}
+
+// TRACE LENGTH SUMMARY
+// ================================================================================================
+
+/// Contains the lengths of the main, range checker, and chiplets segments of an execution trace
+/// before the trace is padded.
+///
+/// The length of the resulting execution trace is determined by the longest of these segments:
+/// it is padded to the next power of two, leaving room for the rows injected with random values.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct TraceLenSummary {
+    main_trace_len: usize,
+    range_trace_len: usize,
+    chiplets_trace_len: ChipletsLengths,
+}
+
+impl TraceLenSummary {
+    pub fn new(
+        main_trace_len: usize,
+        range_trace_len: usize,
+        chiplets_trace_len: ChipletsLengths,
+    ) -> Self {
+        TraceLenSummary {
+            main_trace_len,
+            range_trace_len,
+            chiplets_trace_len,
         }
-        Self { data }
+    }
+
+    /// Returns the number of rows of the main trace segment, i.e., the number of executed cycles.
+    pub fn main_trace_len(&self) -> usize {
+        self.main_trace_len
+    }
+
+    /// Returns the number of rows of the range checker segment.
+    pub fn range_trace_len(&self) -> usize {
+        self.range_trace_len
+    }
+
+    /// Returns the numbers of rows of the individual chiplets.
+    pub fn chiplets_trace_len(&self) -> ChipletsLengths {
+        self.chiplets_trace_len
+    }
+
+    /// Returns the number of rows of the longest trace segment.
+    pub fn trace_len(&self) -> usize {
+        self.main_trace_len
+            .max(self.range_trace_len)
+            .max(self.chiplets_trace_len.trace_len())
+    }
+
+    /// Returns the length of the execution trace after padding; the padded trace is never shorter
+    /// than [MIN_TRACE_LEN].
+    pub fn padded_trace_len(&self) -> usize {
+        (self.trace_len() + NUM_RAND_ROWS).next_power_of_two().max(MIN_TRACE_LEN)
+    }
+
+    /// Returns the share of padding rows in the execution trace, in percent (rounded down).
+    pub fn padding_percentage(&self) -> usize {
+        (self.padded_trace_len() - self.trace_len()) * 100 / self.padded_trace_len()
+    }
+}
+
+/// Contains the numbers of rows of the individual chiplets in an execution trace.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct ChipletsLengths {
+    hash_chiplet_len: usize,
+    bitwise_chiplet_len: usize,
+    memory_chiplet_len: usize,
+    kernel_rom_len: usize,
+}
+
+impl ChipletsLengths {
+    pub fn new(chiplets: &Chiplets) -> Self {
+        ChipletsLengths {
+            hash_chiplet_len: chiplets.bitwise_start(),
+            bitwise_chiplet_len: chiplets.memory_start() - chiplets.bitwise_start(),
+            memory_chiplet_len: chiplets.kernel_rom_start() - chiplets.memory_start(),
+            kernel_rom_len: chiplets.padding_start() - chiplets.kernel_rom_start(),
+        }
+    }
+
+    pub fn from_parts(
+        hash_chiplet_len: usize,
+        bitwise_chiplet_len: usize,
+        memory_chiplet_len: usize,
+        kernel_rom_len: usize,
+    ) -> Self {
+        ChipletsLengths {
+            hash_chiplet_len,
+            bitwise_chiplet_len,
+            memory_chiplet_len,
+            kernel_rom_len,
+        }
+    }
+
+    /// Returns the number of rows of the hash chiplet.
+    pub fn hash_chiplet_len(&self) -> usize {
+        self.hash_chiplet_len
+    }
+
+    /// Returns the number of rows of the bitwise chiplet.
+    pub fn bitwise_chiplet_len(&self) -> usize {
+        self.bitwise_chiplet_len
+    }
+
+    /// Returns the number of rows of the memory chiplet.
+    pub fn memory_chiplet_len(&self) -> usize {
+        self.memory_chiplet_len
+    }
+
+    /// Returns the number of rows of the kernel ROM chiplet.
+    pub fn kernel_rom_len(&self) -> usize {
+        self.kernel_rom_len
+    }
+
+    /// Returns the number of rows of the chiplets segment, including the mandatory padding row.
+    pub fn trace_len(&self) -> usize {
+        self.hash_chiplet_len
+            + self.bitwise_chiplet_len
+            + self.memory_chiplet_len
+            + self.kernel_rom_len
+            + 1
+    }
+}
+
+// TRACE SEGMENT RANGES
+// ================================================================================================
+
+/// Contains the ranges of columns occupied by the individual components of the main segment of
+/// an execution trace.
+///
+/// The columns of the components are placed one after another in the following order: system,
+/// decoder, stack, range checker, and chiplets; the columns of a
+/// [TraceExtension](super::TraceExtension) (if any) are placed after the chiplets columns.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SegmentRanges {
+    system: Range<usize>,
+    decoder: Range<usize>,
+    stack: Range<usize>,
+    range: Range<usize>,
+    chiplets: Range<usize>,
+    extension: Range<usize>,
+}
+
+impl SegmentRanges {
+    /// Returns the ranges of columns of a main trace segment with the specified number of
+    /// columns.
+    ///
+    /// # Panics
+    /// Panics if `main_trace_width` is smaller than the number of columns occupied by the
+    /// components of the VM.
+    pub fn new(main_trace_width: usize) -> Self {
+        assert!(
+            main_trace_width >= CHIPLETS_RANGE.end,
+            "main trace must have at least {} columns, but had {main_trace_width}",
+            CHIPLETS_RANGE.end
+        );
+        Self {
+            system: SYS_TRACE_RANGE,
+            decoder: DECODER_TRACE_RANGE,
+            stack: STACK_TRACE_RANGE,
+            range: RANGE_CHECK_TRACE_RANGE,
+            chiplets: CHIPLETS_RANGE,
+            extension: CHIPLETS_RANGE.end..main_trace_width,
+        }
+    }
+
+    /// Returns the columns of the system component.
+    pub fn system(&self) -> Range<usize> {
+        self.system.clone()
+    }
+
+    /// Returns the columns of the decoder.
+    pub fn decoder(&self) -> Range<usize> {
+        self.decoder.clone()
+    }
+
+    /// Returns the columns of the stack.
+    pub fn stack(&self) -> Range<usize> {
+        self.stack.clone()
+    }
+
+    /// Returns the columns of the range checker.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the columns of the chiplets.
+    pub fn chiplets(&self) -> Range<usize> {
+        self.chiplets.clone()
+    }
+
+    /// Returns the columns of the trace extension; the range is empty if the trace was built
+    /// without an extension.
+    pub fn extension(&self) -> Range<usize> {
+        self.extension.clone()
+    }
+
+    /// Returns the ranges of all components in the order in which they are placed in the trace.
+    pub fn to_array(&self) -> [Range<usize>; 6] {
+        [
+            self.system(),
+            self.decoder(),
+            self.stack(),
+            self.range(),
+            self.chiplets(),
+            self.extension(),
+        ]
     }
 }
 
@@ -1,7 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
-use core::marker::PhantomData;
+use core::{
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
 use processor::{
     crypto::{
         Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, WinterRandomCoin,
@@ -11,10 +18,12 @@ use processor::{
 };
 use winter_prover::{ProofOptions as WinterProofOptions, Prover};
 
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
 #[cfg(feature = "std")]
 use log::debug;
 #[cfg(feature = "std")]
-use std::time::Instant;
+use std::{error::Error, sync::Arc, time::Instant};
 #[cfg(feature = "std")]
 use winter_prover::Trace;
 
@@ -24,7 +33,9 @@ mod gpu;
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofMetadata, ProofOptions,
+};
 pub use processor::{
     crypto, math, utils, AdviceInputs, AdviceProvider, Digest, ExecutionError, InputError,
     MemAdviceProvider, Program, StackInputs, StackOutputs, Word,
@@ -52,18 +63,62 @@ pub fn prove<A>(
 where
     A: AdviceProvider,
 {
-    // execute the program to create an execution trace
-    #[cfg(feature = "std")]
-    let now = Instant::now();
-    let trace = processor::execute(program, stack_inputs.clone(), advice_provider)?;
-    #[cfg(feature = "std")]
-    debug!(
-        "Generated execution trace of {} columns and {} steps in {} ms",
-        trace.layout().main_trace_width(),
-        trace.length(),
-        now.elapsed().as_millis()
-    );
+    let trace = execute(program, stack_inputs, advice_provider)?;
+    let stack_outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, options)?;
+
+    Ok((stack_outputs, proof))
+}
 
+/// Executes and proves the specified `program` in the same way as [prove()], but gives up if the
+/// provided cancellation `token` is cancelled before STARK proof generation starts.
+///
+/// The token is checked only before the program is executed and after the execution trace is
+/// generated. Cancelling the token while the program is being executed does not interrupt the
+/// execution, and once STARK proof generation has started (i.e., during the trace LDE, constraint
+/// evaluation and FRI, which take up most of the proving time), the proof is built and returned
+/// regardless of the token.
+///
+/// # Errors
+/// Returns [ProveError::Cancelled] if the token is cancelled at one of the cancellation points,
+/// or [ProveError::Execution] if program execution or STARK proof generation fails for any
+/// reason.
+pub fn prove_with_early_cancel<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    token: CancellationToken,
+) -> Result<(StackOutputs, ExecutionProof), ProveError>
+where
+    A: AdviceProvider,
+{
+    token.check()?;
+    let trace = execute(program, stack_inputs, advice_provider)?;
+
+    token.check()?;
+    let stack_outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, options)?;
+
+    Ok((stack_outputs, proof))
+}
+
+/// Proves the program execution captured by the provided `trace` and returns a STARK-based proof
+/// of this execution.
+///
+/// This can be used to prove a program which has already been executed (e.g., via
+/// [processor::execute()]) without executing it again. Stack inputs and outputs of the execution
+/// are taken from the trace.
+///
+/// * `options` defines parameters for STARK proof generation.
+///
+/// # Errors
+/// Returns an error if STARK proof generation fails for any reason.
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    options: ProofOptions,
+) -> Result<ExecutionProof, ExecutionError> {
+    let stack_inputs = trace.stack_inputs().clone();
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
 
@@ -72,30 +127,116 @@ where
         HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Blake3_256 => ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Rpo256 => {
-            let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
-                options,
-                stack_inputs,
-                stack_outputs.clone(),
-            );
+            let prover =
+                ExecutionProver::<Rpo256, RpoRandomCoin>::new(options, stack_inputs, stack_outputs);
             #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
             let prover = gpu::GpuRpoExecutionProver(prover);
             prover.prove(trace)
         }
     }
     .map_err(ExecutionError::ProverError)?;
-    let proof = ExecutionProof::new(proof, hash_fn);
 
-    Ok((stack_outputs, proof))
+    Ok(ExecutionProof::new(proof, hash_fn))
+}
+
+/// Executes the specified `program` to create an execution trace for proof generation.
+fn execute<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let trace = processor::execute(program, stack_inputs, advice_provider)?;
+    #[cfg(feature = "std")]
+    debug!(
+        "Generated execution trace of {} columns and {} steps in {} ms",
+        trace.layout().main_trace_width(),
+        trace.length(),
+        now.elapsed().as_millis()
+    );
+
+    Ok(trace)
+}
+
+// PROVE ERROR
+// ================================================================================================
+
+/// An error which can occur during proof generation via [prove_with_early_cancel()].
+#[derive(Debug)]
+pub enum ProveError {
+    /// Proof generation was cancelled via a [CancellationToken].
+    Cancelled,
+    /// Program execution or STARK proof generation failed.
+    Execution(ExecutionError),
+}
+
+impl Display for ProveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Cancelled => write!(f, "Proof generation was cancelled"),
+            Self::Execution(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ProveError {}
+
+impl From<ExecutionError> for ProveError {
+    fn from(err: ExecutionError) -> Self {
+        Self::Execution(err)
+    }
+}
+
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to cancel proof generation started via [prove_with_early_cancel()].
+///
+/// Clones of a token share the same state; i.e., cancelling any of the clones cancels all proofs
+/// generated with the token. A token cannot be reset once cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels proof generation for all proofs generated with this token (or its clones) for
+    /// which STARK proof generation has not started yet.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns an error if this token has been cancelled.
+    fn check(&self) -> Result<(), ProveError> {
+        if self.is_cancelled() {
+            Err(ProveError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 // PROVER
@@ -56,3 +56,13 @@ fn test_compile() {
 
     assert!(exists);
 }
+
+#[test]
+fn test_procedure_docs() {
+    let stdlib = StdLibrary::default();
+    let docs = stdlib.procedure_docs("std::math::u64::checked_add").unwrap();
+    assert!(docs.starts_with("Performs addition of two unsigned 64 bit integers"));
+
+    // docs of local procedures are not available
+    assert!(stdlib.procedure_docs("std::math::u64::u32assert4").is_none());
+}
@@ -0,0 +1,88 @@
+use assembly::{
+    ast::{masm_fmt_with_options, AstSerdeOptions, FormatOptions, ModuleAst, ProgramAst},
+    parse_module, parse_program, Assembler, Library,
+};
+use miden_stdlib::StdLibrary;
+
+// PARSE -> COMPILE
+// ================================================================================================
+
+#[test]
+fn compile_ast_matches_compile_for_stdlib() {
+    let stdlib = StdLibrary::default();
+    let assembler = Assembler::default().with_library(&stdlib).unwrap();
+    let serde_options = AstSerdeOptions {
+        serialize_imports: true,
+    };
+
+    // invoke every procedure exported from the stdlib via both compilation paths
+    for module in stdlib.modules() {
+        for proc in module.ast.procs().iter().filter(|proc| proc.is_export) {
+            let source = format!(
+                "
+                use.{}
+                begin
+                    exec.{}::{}
+                end",
+                module.path.as_str(),
+                module.path.last(),
+                proc.name.as_str()
+            );
+            let expected = assembler.compile(&source).unwrap();
+
+            let ast = parse_program(&source).unwrap();
+            let program = assembler.compile_ast(&ast).unwrap();
+            assert_eq!(expected.hash(), program.hash(), "{source}");
+
+            // code generators can skip the text representation entirely
+            let ast = ProgramAst::from_bytes(&ast.to_bytes(serde_options)).unwrap();
+            let program = assembler.compile_ast(&ast).unwrap();
+            assert_eq!(expected.hash(), program.hash(), "{source}");
+        }
+    }
+}
+
+// STACK DEPTH ANALYSIS
+// ================================================================================================
+
+#[test]
+fn stdlib_passes_stack_depth_checks() {
+    // all procedures exported from the stdlib must have balanced control flow, unless they are
+    // explicitly marked as stack-unsafe
+    let stdlib = StdLibrary::default();
+    Assembler::default()
+        .with_stack_depth_checks(true)
+        .with_precompiled_library(&stdlib)
+        .unwrap();
+}
+
+// FORMATTING
+// ================================================================================================
+
+#[test]
+fn stdlib_formatting_round_trip() {
+    let stdlib = StdLibrary::default();
+    let serde_options = AstSerdeOptions {
+        serialize_imports: true,
+    };
+
+    // source locations are not a part of the canonical form, and thus, the modules are compared
+    // after a serialization round-trip which drops all locations
+    let without_locations =
+        |module: &ModuleAst| ModuleAst::from_bytes(&module.to_bytes(serde_options)).unwrap();
+
+    for module in stdlib.modules() {
+        let path = module.path.as_str();
+        let expected = without_locations(&module.ast);
+
+        for options in [FormatOptions::default(), FormatOptions::new(4)] {
+            let formatted = masm_fmt_with_options(&module.ast, options);
+            let reparsed = parse_module(&formatted)
+                .unwrap_or_else(|err| panic!("failed to parse formatted {path}: {err}"));
+            assert_eq!(expected, without_locations(&reparsed), "{path}");
+
+            // formatting is idempotent
+            assert_eq!(formatted, masm_fmt_with_options(&reparsed, options), "{path}");
+        }
+    }
+}
@@ -52,7 +52,9 @@ fn get() {
         let expected_output = build_expected_stack(value, smt.root().into());
 
         let store = MerkleStore::from(&smt);
-        build_test!(source, &initial_stack, &[], store, vec![]).expect_stack(&expected_output);
+        build_test!(source, &initial_stack)
+            .with_merkle_store(&store)
+            .expect_stack(&expected_output);
     }
 }
 
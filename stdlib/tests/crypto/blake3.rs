@@ -18,18 +18,8 @@ fn blake3_hash_64_bytes() {
     ibytes[..32].copy_from_slice(&input0);
     ibytes[32..].copy_from_slice(&input1);
 
-    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
-        .iter()
-        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
-        .rev()
-        .collect::<Vec<u64>>();
-
-    let hasher = blake3::hash(&ibytes);
-    let obytes = hasher.as_bytes();
-    let ofelts = group_slice_elements::<u8, 4>(obytes)
-        .iter()
-        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
-        .collect::<Vec<u64>>();
+    let ifelts = bytes_to_stack_inputs(&ibytes);
+    let ofelts = bytes_to_words(blake3::hash(&ibytes).as_bytes());
 
     let test = build_test!(source, &ifelts);
     test.expect_stack(&ofelts);
@@ -46,19 +36,117 @@ fn blake3_hash_32_bytes() {
     ";
 
     let ibytes = rand_array::<Felt, 4>().into_bytes();
-    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
-        .iter()
-        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
-        .rev()
-        .collect::<Vec<u64>>();
 
-    let hasher = blake3::hash(&ibytes);
-    let obytes = hasher.as_bytes();
-    let ofelts = group_slice_elements::<u8, 4>(obytes)
-        .iter()
-        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
-        .collect::<Vec<u64>>();
+    let ifelts = bytes_to_stack_inputs(&ibytes);
+    let ofelts = bytes_to_words(blake3::hash(&ibytes).as_bytes());
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+#[test]
+fn blake3_merkle_root() {
+    // hashes two leaves and then hashes the concatenation of their digests; the digests are left
+    // on the stack in the same layout as the message words, and thus can be hashed directly
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        exec.blake3::hash_1to1
+        swapdw
+        exec.blake3::hash_1to1
+        swapdw
+        exec.blake3::hash_2to1
+    end
+    ";
+
+    let leaf0 = rand_array::<Felt, 4>().into_bytes();
+    let leaf1 = rand_array::<Felt, 4>().into_bytes();
+
+    let mut node = [0u8; 64];
+    node[..32].copy_from_slice(blake3::hash(&leaf0).as_bytes());
+    node[32..].copy_from_slice(blake3::hash(&leaf1).as_bytes());
+
+    let mut ifelts = bytes_to_stack_inputs(&leaf1);
+    ifelts.extend(bytes_to_stack_inputs(&leaf0));
+    let ofelts = bytes_to_words(blake3::hash(&node).as_bytes());
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+// OFFICIAL TEST VECTORS
+// ================================================================================================
+
+/// The inputs of the official BLAKE3 test vectors consist of a repeating byte pattern, where the
+/// byte at position i is equal to i % 251; the digests below are the `hash` values for inputs of
+/// length 64 and 32 from `test_vectors.json` of the BLAKE3 reference implementation.
+const TEST_VECTOR_64_BYTES: &str =
+    "4eed7141ea4a5cd4b788606bd23f46e212af9cacebacdc7d1f4c6dc7f2511b98";
+const TEST_VECTOR_32_BYTES: &str =
+    "e528e95798037df410543d9f31e396ecdd458d71b157d6014398bae32fb56c65";
+
+#[test]
+fn blake3_hash_64_bytes_test_vector() {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        exec.blake3::hash_2to1
+    end
+    ";
+
+    let ifelts = bytes_to_stack_inputs(&test_vector_input(64));
+    let ofelts = bytes_to_words(&hex_to_bytes(TEST_VECTOR_64_BYTES));
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+#[test]
+fn blake3_hash_32_bytes_test_vector() {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        exec.blake3::hash_1to1
+    end
+    ";
+
+    let ifelts = bytes_to_stack_inputs(&test_vector_input(32));
+    let ofelts = bytes_to_words(&hex_to_bytes(TEST_VECTOR_32_BYTES));
 
     let test = build_test!(source, &ifelts);
     test.expect_stack(&ofelts);
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the input of an official BLAKE3 test vector of the specified length.
+fn test_vector_input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+/// Converts the specified bytes into 32-bit message words arranged as stack inputs for blake3
+/// procedures, i.e., such that the first message word is at the top of the stack.
+fn bytes_to_stack_inputs(bytes: &[u8]) -> Vec<u64> {
+    let mut words = bytes_to_words(bytes);
+    words.reverse();
+    words
+}
+
+/// Converts the specified bytes into little-endian 32-bit words.
+fn bytes_to_words(bytes: &[u8]) -> Vec<u64> {
+    group_slice_elements::<u8, 4>(bytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .collect()
+}
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("invalid hex string"))
+        .collect()
+}
@@ -1,25 +1,24 @@
-use crate::build_test;
-use test_utils::test_case;
-
-// Wrapper types for ease of writing parameterized test cases
-struct FieldElement([u32; 8]);
-
-type BaseField = FieldElement;
-type ScalarField = FieldElement;
-
-struct Point([BaseField; 3]);
-
-/// Because this test is pretty expensive, it's by default ignored. If you're interested in
-/// running this test, issue
-///
-/// cargo test --release -p miden-stdlib secp256k1 -- --include-ignored
-///
-/// from root directory of Miden repository.
-///
-/// What's being done in this routine is adapted from https://github.com/itzmeanjan/secp256k1/blob/37b339db3e03d24c2977399eb8896ef515ebb09b/test/test_ecdsa.py#L14-L16
-#[test_case(Point([FieldElement([1187647059, 1135132293, 1524607722, 3257770169, 1812770566, 4163599075, 3343690625, 2983146250]), FieldElement([694970425, 3961647168, 2962892522, 3871680339, 479244527, 2106589630, 3531004100, 487738481]), FieldElement([1718928786, 2222219308, 1537333708, 969814285, 1600645591, 2744076726, 1359599981, 1095895041])]), FieldElement([1915140291, 1682821516, 1088031394, 2866424576, 2852209138, 1159876682, 234168247, 3360002988]), FieldElement([1494159694, 3668493121, 2315165624, 353127114, 974571799, 2051320959, 3421809437, 3258836281]), FieldElement([1259054195, 60155476, 2236955964, 2106542718, 1332177784, 1407189293, 11489664, 3695133146]) ; "0")]
-#[ignore]
-fn verify(pubkey: Point, h: ScalarField, r: ScalarField, s: ScalarField) {
+use crate::{
+    build_test,
+    helpers::{
+        secp256k1::{base_field_prime, scalar_order},
+        to_mont,
+    },
+};
+use k256::{
+    ecdsa::{hazmat::SignPrimitive, signature::hazmat::PrehashVerifier, SigningKey},
+    elliptic_curve::{ops::Reduce, point::AffineCoordinates, scalar::IsHigh, sec1::ToEncodedPoint},
+    AffinePoint, ProjectivePoint, Scalar, U256,
+};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use test_utils::{bigint::u256_limbs, rand::rand_vector, test_case};
+
+// VERIFICATION OF SIGNATURES OVER PROJECTIVE PUBLIC KEYS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn verify() {
     let source = "
     use.std::crypto::dsa::ecdsa::secp256k1
 
@@ -27,22 +26,158 @@ fn verify(pubkey: Point, h: ScalarField, r: ScalarField, s: ScalarField) {
         exec.secp256k1::verify
     end";
 
-    let mut stack = [0u64; 48];
+    let (pubkey, h, r, s) = crafted_signature(b"miden", 5, 3);
+    let (x, y) = coordinates(&pubkey);
+
+    // public key is passed in projective coordinates with Z = 1, and all values in Montgomery form
+    let p = base_field_prime();
+    let n = scalar_order();
+    let mut stack = Vec::with_capacity(48);
+    for v in [to_mont(&x, &p), to_mont(&y, &p), to_mont(&BigUint::from(1u32), &p)] {
+        stack.extend(u256_limbs(&v));
+    }
+    for v in [h, r, s] {
+        stack.extend(u256_limbs(&to_mont(&to_biguint(&v), &n)));
+    }
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    assert!(test.execute_process().is_ok());
+}
+
+// VERIFICATION OF SIGNATURES OVER AFFINE PUBLIC KEYS
+// ------------------------------------------------------------------------------------------------
+// Keys and signatures are produced by the k256 crate. As scalar multiplication is expensive for
+// scalars with many set bits, signatures are crafted s.t. both u1 = h * s^-1 and u2 = r * s^-1
+// ( mod n ) are small, which is done by picking the nonce, u1 and u2 first and deriving the secret
+// key from them. Programs are executed without building execution traces, to keep memory usage of
+// these tests down.
+
+#[test]
+fn verify_sig() {
+    let (pubkey, h, r, s) = crafted_signature(b"miden", 5, 3);
+    assert!(build_verify_sig_test(&pubkey, &h, &r, &s).execute_process().is_ok());
+}
+
+#[test]
+fn verify_sig_wrong_message() {
+    // h + s is used in place of h, as it keeps u1 small
+    let (pubkey, h, r, s) = crafted_signature(b"miden", 5, 3);
+    let h = h + s;
+    assert!(build_verify_sig_test(&pubkey, &h, &r, &s).execute_process().is_err());
+}
+
+#[test]
+fn verify_sig_wrong_pubkey() {
+    let (pubkey, h, r, s) = crafted_signature(b"miden", 5, 3);
+    let pubkey = (ProjectivePoint::from(pubkey) + ProjectivePoint::GENERATOR).to_affine();
+    assert!(build_verify_sig_test(&pubkey, &h, &r, &s).execute_process().is_err());
+}
+
+#[test_case("0", "1" ; "r is zero")]
+#[test_case("1", "0" ; "s is zero")]
+#[test_case("n", "1" ; "r is n")]
+#[test_case("1", "n" ; "s is n")]
+fn verify_sig_out_of_range(r: &str, s: &str) {
+    let to_scalar = |v: &str| if v == "n" { scalar_order() } else { v.parse().unwrap() };
 
-    // copy public key ( expressed in projective coordinate system )
-    stack[0..8].copy_from_slice(&pubkey.0[0].0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
-    stack[8..16].copy_from_slice(&pubkey.0[1].0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
-    stack[16..24].copy_from_slice(&pubkey.0[2].0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
+    let (pubkey, h, ..) = crafted_signature(b"miden", 5, 3);
+    let test = build_verify_sig_test_raw(&pubkey, &to_biguint(&h), &to_scalar(r), &to_scalar(s));
+    assert!(test.execute_process().is_err());
+}
+
+#[test]
+fn verify_sig_random_key() {
+    let seed = rand_vector::<u64>(4).iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>();
+    // u1 and u2 are kept below 8, as cost of verification grows with their bit lengths
+    let [u1, u2] = [0, 1].map(|i| (seed[i] % 7) as u64 + 1);
+    let (pubkey, h, r, s) = crafted_signature(&seed, u1, u2);
+    assert!(build_verify_sig_test(&pubkey, &h, &r, &s).execute_process().is_ok());
 
-    // copy hash of message
-    stack[24..32].copy_from_slice(&h.0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
-    // copy `r` part of signature
-    stack[32..40].copy_from_slice(&r.0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
-    // copy `s` part of signature
-    stack[40..48].copy_from_slice(&s.0.iter().map(|v| *v as u64).collect::<Vec<u64>>());
+    // signature must be rejected for another message hash, with h + s keeping u1 small
+    let h = h + s;
+    assert!(build_verify_sig_test(&pubkey, &h, &r, &s).execute_process().is_err());
+}
 
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+fn to_biguint(v: &Scalar) -> BigUint {
+    BigUint::from_bytes_be(&v.to_bytes())
+}
+
+/// Returns the affine coordinates of the specified point.
+fn coordinates(point: &AffinePoint) -> (BigUint, BigUint) {
+    let point = point.to_encoded_point(false);
+    (
+        BigUint::from_bytes_be(point.x().unwrap()),
+        BigUint::from_bytes_be(point.y().unwrap()),
+    )
+}
+
+/// Returns a (public key, message hash, r, s) tuple s.t. h * s^-1 = u1 and r * s^-1 = u2 ( mod n ),
+/// with the nonce k derived from `seed`. The secret key is solved for from
+/// s = k^-1 * (h + r * secret) ( mod n ), after which k256 signs the message hash using nonce k.
+fn crafted_signature(seed: &[u8], u1: u64, u2: u64) -> (AffinePoint, Scalar, Scalar, Scalar) {
+    // k256 only produces signatures with s <= n / 2, so nonces are tried until s is low
+    for i in 0u32.. {
+        let mut hasher = Sha256::new();
+        hasher.update(seed);
+        hasher.update(i.to_le_bytes());
+        let k = <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize());
+
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(
+            &(ProjectivePoint::GENERATOR * k).to_affine().x(),
+        );
+        let s = r * Scalar::from(u2).invert().unwrap();
+        if bool::from(s.is_high()) {
+            continue;
+        }
+        let h = s * Scalar::from(u1);
+        let secret = (s * k - h) * r.invert().unwrap();
+
+        let (sig, _) = secret.try_sign_prehashed(k, &h.to_bytes()).unwrap();
+        assert_eq!((*sig.r().as_ref(), *sig.s().as_ref()), (r, s));
+
+        let signing_key = SigningKey::from_bytes(&secret.to_bytes()).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        assert!(verifying_key.verify_prehash(&h.to_bytes(), &sig).is_ok());
+
+        return (*verifying_key.as_affine(), h, r, s);
+    }
+    unreachable!()
+}
+
+fn build_verify_sig_test(
+    pubkey: &AffinePoint,
+    h: &Scalar,
+    r: &Scalar,
+    s: &Scalar,
+) -> test_utils::Test {
+    build_verify_sig_test_raw(pubkey, &to_biguint(h), &to_biguint(r), &to_biguint(s))
+}
+
+/// Builds a verify_sig test from values of h, r and s which may lie outside of the scalar field.
+fn build_verify_sig_test_raw(
+    pubkey: &AffinePoint,
+    h: &BigUint,
+    r: &BigUint,
+    s: &BigUint,
+) -> test_utils::Test {
+    let source = "
+    use.std::crypto::dsa::ecdsa::secp256k1
+
+    begin
+        exec.secp256k1::verify_sig
+    end";
+
+    let (x, y) = coordinates(pubkey);
+
+    let mut stack = Vec::with_capacity(40);
+    for v in [&x, &y, h, r, s] {
+        stack.extend(u256_limbs(v));
+    }
     stack.reverse();
 
-    let test = build_test!(source, &stack);
-    assert!(test.execute().is_ok());
+    build_test!(source, &stack)
 }
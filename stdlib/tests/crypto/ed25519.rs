@@ -0,0 +1,289 @@
+use crate::build_test;
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT, edwards::CompressedEdwardsY, EdwardsPoint, Scalar,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use num_bigint::BigUint;
+use test_utils::{
+    bigint::{rand_u256, u256_limbs},
+    rand::rand_vector,
+    test_case,
+};
+
+// FIELD ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn fe_ops() {
+    let p = base_field_prime();
+    let max = BigUint::new(vec![u32::MAX; 8]);
+    let mut values = vec![
+        BigUint::from(0u32),
+        BigUint::from(1u32),
+        &p - 1u32,
+        p.clone(),
+        &p + 1u32,
+        max.clone(),
+        &max - 37u32,
+    ];
+    values.extend((0..2).map(|_| rand_u256()));
+
+    let ops: [(&str, FieldOp); 3] = [
+        ("fe_add", |a, b, p| (a + b) % p),
+        ("fe_sub", |a, b, p| (a % p + p - b % p) % p),
+        ("fe_mul", |a, b, p| (a * b) % p),
+    ];
+
+    for (proc_name, reference) in ops {
+        let source = format!(
+            "
+            use.std::crypto::dsa::ed25519
+
+            begin
+                exec.ed25519::{proc_name}
+                exec.ed25519::fe_reduce
+            end"
+        );
+
+        for a in values.iter() {
+            for b in values.iter() {
+                let mut operands = u256_limbs(a);
+                operands.extend(u256_limbs(b));
+                let mut expected = u256_limbs(&reference(a, b, &p));
+                expected.reverse();
+
+                build_test!(&source, &operands).expect_stack(&expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn fe_inv() {
+    let source = "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        exec.ed25519::fe_inv
+        exec.ed25519::fe_reduce
+    end";
+
+    let p = base_field_prime();
+    let a = rand_u256();
+    let mut expected = u256_limbs(&a.modpow(&(&p - 2u32), &p));
+    expected.reverse();
+
+    build_test!(source, &u256_limbs(&a)).expect_stack(&expected);
+}
+
+// POINT ENCODING
+// ================================================================================================
+
+#[test]
+fn decompress() {
+    let source = "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        push.1000
+        movdn.8
+        exec.ed25519::ge_decompress
+        push.1000
+        exec.ed25519::ge_compress
+    end";
+
+    // the base point and a point with an odd x-coordinate
+    let points = [ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_POINT * Scalar::from(5u32)];
+    assert!(points.iter().any(|point| point.compress().to_bytes()[31] & 0x80 != 0));
+
+    for point in points {
+        let encoding = point.compress().to_bytes();
+        let mut expected = encoding_limbs(&encoding);
+        expected.reverse();
+
+        build_test!(source, &encoding_limbs(&encoding)).expect_stack(&expected);
+    }
+}
+
+#[test_case("y is p" ; "non-canonical y")]
+#[test_case("y is 2" ; "no square root")]
+#[test_case("x is -0" ; "negative zero")]
+fn decompress_invalid(case: &str) {
+    let source = "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        push.1000
+        movdn.8
+        exec.ed25519::ge_decompress
+    end";
+
+    let mut encoding = [0u8; 32];
+    match case {
+        "y is p" => encoding.copy_from_slice(&base_field_prime().to_bytes_le()),
+        "y is 2" => {
+            encoding[0] = 2;
+            assert!(CompressedEdwardsY(encoding).decompress().is_none());
+        }
+        _ => {
+            encoding[0] = 1;
+            encoding[31] = 0x80;
+        }
+    }
+
+    assert!(build_test!(source, &encoding_limbs(&encoding)).execute().is_err());
+}
+
+// SCALAR MULTIPLICATION
+// ================================================================================================
+
+#[test]
+fn double_scalarmul_small() {
+    let point = ED25519_BASEPOINT_POINT * Scalar::from(7u32);
+    let a = Scalar::from(5u32);
+    let b = Scalar::from(3u32);
+    build_double_scalarmul_test(&point, &a, &b)
+        .expect_stack(&expected_double_scalarmul(&point, &a, &b));
+
+    // the result is the identity when both scalars are zero
+    build_double_scalarmul_test(&point, &Scalar::ZERO, &Scalar::ZERO)
+        .expect_stack(&expected_double_scalarmul(&point, &Scalar::ZERO, &Scalar::ZERO));
+}
+
+#[test]
+fn double_scalarmul() {
+    let point = ED25519_BASEPOINT_POINT * rand_scalar();
+    let a = rand_scalar();
+    let b = rand_scalar();
+    build_double_scalarmul_test(&point, &a, &b)
+        .expect_stack(&expected_double_scalarmul(&point, &a, &b));
+}
+
+// SIGNATURE VERIFICATION
+// ================================================================================================
+
+#[test]
+fn verify_sig() {
+    let (pubkey, msg, sig) = signed_message(&[1; 32]);
+    assert!(build_verify_sig_test(&pubkey, &msg, &sig).execute().is_ok());
+}
+
+#[test]
+fn verify_sig_wrong_message() {
+    let (pubkey, mut msg, sig) = signed_message(&[1; 32]);
+    msg[0] ^= 1;
+    assert!(build_verify_sig_test(&pubkey, &msg, &sig).execute().is_err());
+}
+
+#[test]
+fn verify_sig_wrong_pubkey() {
+    let (_, msg, sig) = signed_message(&[1; 32]);
+    let (pubkey, ..) = signed_message(&[2; 32]);
+    assert!(build_verify_sig_test(&pubkey, &msg, &sig).execute().is_err());
+}
+
+#[test]
+fn verify_sig_out_of_range() {
+    let (pubkey, msg, mut sig) = signed_message(&[1; 32]);
+
+    // s = s + L encodes the same scalar, but must be rejected
+    let s = BigUint::from_bytes_le(&sig[32..]) + scalar_order();
+    sig[32..].copy_from_slice(&s.to_bytes_le());
+    assert!(build_verify_sig_test(&pubkey, &msg, &sig).execute().is_err());
+}
+
+#[test]
+fn verify_sig_invalid_pubkey() {
+    let (_, msg, sig) = signed_message(&[1; 32]);
+    let mut pubkey = [0u8; 32];
+    pubkey[0] = 2;
+    assert!(build_verify_sig_test(&pubkey, &msg, &sig).execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Binary operation over the base field, with the modulus passed as the last argument.
+type FieldOp = fn(&BigUint, &BigUint, &BigUint) -> BigUint;
+
+fn base_field_prime() -> BigUint {
+    (BigUint::from(1u32) << 255) - 19u32
+}
+
+fn scalar_order() -> BigUint {
+    (BigUint::from(1u32) << 252)
+        + BigUint::parse_bytes(b"14def9dea2f79cd65812631a5cf5d3ed", 16).unwrap()
+}
+
+/// Returns a public key derived from the specified secret key, a 32 byte message, and a valid
+/// signature of the message under the public key.
+fn signed_message(secret: &[u8; 32]) -> ([u8; 32], [u8; 32], [u8; 64]) {
+    let signing_key = SigningKey::from_bytes(secret);
+    let msg = *blake3::hash(b"miden").as_bytes();
+    let sig = signing_key.sign(&msg);
+    (signing_key.verifying_key().to_bytes(), msg, sig.to_bytes())
+}
+
+fn build_verify_sig_test(pubkey: &[u8; 32], msg: &[u8; 32], sig: &[u8; 64]) -> test_utils::Test {
+    let source = "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        exec.ed25519::verify
+    end";
+
+    // s is the deepest value on the stack, and the public key is at the top
+    let mut stack = Vec::with_capacity(32);
+    for bytes in [&sig[32..], &sig[..32], &msg[..], &pubkey[..]] {
+        stack.extend(encoding_limbs(bytes));
+    }
+
+    build_test!(source, &stack)
+}
+
+fn build_double_scalarmul_test(point: &EdwardsPoint, a: &Scalar, b: &Scalar) -> test_utils::Test {
+    let source = "
+    use.std::crypto::dsa::ed25519
+
+    begin
+        push.1000
+        movdn.8
+        exec.ed25519::ge_decompress
+        push.2000
+        push.1000
+        exec.ed25519::ge_double_scalarmul_vartime
+        push.2000
+        exec.ed25519::ge_compress
+    end";
+
+    let mut stack = encoding_limbs(b.as_bytes());
+    stack.extend(encoding_limbs(a.as_bytes()));
+    stack.extend(encoding_limbs(point.compress().as_bytes()));
+
+    build_test!(source, &stack)
+}
+
+/// Returns the stack expected after encoding [a]P + [b]B.
+fn expected_double_scalarmul(point: &EdwardsPoint, a: &Scalar, b: &Scalar) -> Vec<u64> {
+    let res = EdwardsPoint::vartime_double_scalar_mul_basepoint(a, point, b);
+    let mut expected = encoding_limbs(res.compress().as_bytes());
+    expected.reverse();
+    expected
+}
+
+/// Returns the 8 32-bit limbs of the specified 32 byte string read as a little-endian integer,
+/// least significant limb first.
+fn encoding_limbs(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as u64)
+        .collect()
+}
+
+fn rand_scalar() -> Scalar {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(rand_vector::<u64>(4)) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    Scalar::from_bytes_mod_order(bytes)
+}
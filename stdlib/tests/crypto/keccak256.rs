@@ -1,8 +1,8 @@
 use crate::build_test;
 use sha3::{Digest, Keccak256};
 use test_utils::{
-    rand::{rand_array, rand_value},
-    Felt, IntoBytes, STACK_TOP_SIZE,
+    rand::{rand_array, rand_value, rand_vector},
+    test_case, Felt, IntoBytes, STACK_TOP_SIZE,
 };
 
 /// Equivalent to https://github.com/itzmeanjan/merklize-sha/blob/1d35aae/include/test_bit_interleaving.hpp#L12-L34
@@ -70,6 +70,58 @@ fn keccak256_2_to_1_hash() {
     test.expect_stack(&expected_stack);
 }
 
+#[test_case(0 ; "empty string")]
+#[test_case(100 ; "single block")]
+#[test_case(135 ; "single block with both padding bytes in the same byte")]
+#[test_case(136 ; "padding in a separate block")]
+#[test_case(300 ; "multiple blocks")]
+fn keccak256_hash_memory(len: usize) {
+    let addr = 1000;
+
+    // the string is followed by random bytes in the same memory word, which must be ignored
+    let mut bytes = rand_vector::<u8>(len + 16);
+    bytes.truncate((len + 15) & !15);
+
+    // store the string in memory, packing each 16 -bytes into four little endian u32 elements
+    let mut source = String::from(
+        "
+    use.std::crypto::hashes::keccak256
+
+    begin",
+    );
+    for (k, chunk) in bytes.chunks(16).enumerate() {
+        let elements = chunk
+            .chunks(4)
+            .map(|v| u32::from_le_bytes(v.try_into().unwrap()).to_string())
+            .collect::<Vec<_>>();
+        source.push_str(&format!(
+            "
+        push.{}
+        push.{}
+        mem_storew
+        dropw",
+            elements.join("."),
+            addr + k
+        ));
+    }
+    source.push_str(&format!(
+        "
+        push.{len}
+        push.{addr}
+        exec.keccak256::hash_memory
+    end"
+    ));
+
+    let digest = Keccak256::digest(&bytes[..len]);
+    let expected_stack = digest
+        .chunks(4)
+        .map(|v| u32::from_le_bytes(v.try_into().unwrap()) as u64)
+        .collect::<Vec<_>>();
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&expected_stack);
+}
+
 /// Given N -many bytes ( such that N % 8 == 0 ), this function considers
 /// each block of contiguous 8 -bytes as little endian 64 -bit unsigned
 /// integer word and converts each u64 into two u32s such that first one holds
@@ -1,8 +1,10 @@
 mod blake3;
 mod ecdsa_secp256k1;
+mod ed25519;
 mod falcon;
 mod fri;
 mod keccak256;
 mod native;
 mod sha256;
+mod sha512;
 mod stark;
@@ -0,0 +1,36 @@
+use crate::build_test;
+use sha2::{Digest, Sha512};
+use test_utils::{group_slice_elements, rand::rand_array, Felt, IntoBytes};
+
+#[test]
+fn sha512_3_to_2_hash() {
+    let source = "
+    use.std::crypto::hashes::sha512
+
+    begin
+        exec.sha512::hash_3to2
+    end";
+
+    let mut ibytes = [0u8; 96];
+    for chunk in ibytes.chunks_mut(32) {
+        chunk.copy_from_slice(&rand_array::<Felt, 4>().into_bytes());
+    }
+
+    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .rev()
+        .collect::<Vec<u64>>();
+
+    let mut hasher = Sha512::new();
+    hasher.update(ibytes);
+
+    let obytes = hasher.finalize();
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
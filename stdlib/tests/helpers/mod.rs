@@ -0,0 +1,85 @@
+use num_bigint::BigUint;
+
+// MONTGOMERY FORM
+// ================================================================================================
+
+/// Returns Montgomery form of `v` modulo `m`, with the Montgomery radix being 2^256.
+pub fn to_mont(v: &BigUint, m: &BigUint) -> BigUint {
+    (v << 256) % m
+}
+
+// SECP256K1
+// ================================================================================================
+
+pub mod secp256k1 {
+    use super::BigUint;
+
+    /// Returns the prime p of the secp256k1 base field.
+    pub fn base_field_prime() -> BigUint {
+        (BigUint::from(1u32) << 256) - (BigUint::from(1u32) << 32) - 977u32
+    }
+
+    /// Returns the order n of the secp256k1 group, which is the prime of its scalar field.
+    pub fn scalar_order() -> BigUint {
+        BigUint::parse_bytes(
+            b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            16,
+        )
+        .unwrap()
+    }
+}
+
+// PROPERTY TESTING STRATEGIES
+// ================================================================================================
+
+/// Strategies generating stack inputs for [Test::prop_check()](test_utils::Test::prop_check).
+pub mod strategies {
+    use test_utils::{
+        proptest::{collection::vec, prelude::*, sample::select},
+        Felt, StarkField,
+    };
+
+    /// Returns a strategy for u64 values biased towards edge cases, such as 0 and values around
+    /// the limb boundaries.
+    pub fn u64_value() -> impl Strategy<Value = u64> {
+        let edge_cases = vec![0, 1, u32::MAX as u64, 1 << 32, u64::MAX - 1, u64::MAX];
+        prop_oneof![
+            1 => select(edge_cases),
+            1 => any::<u32>().prop_map(u64::from),
+            2 => any::<u64>(),
+        ]
+    }
+
+    /// Returns a strategy for the limbs of two u64 values a and b arranged as stack inputs for
+    /// u64 procedures, i.e., [a_lo, a_hi, b_lo, b_hi]. The value a is drawn from [u64_value()],
+    /// and b from the specified strategy.
+    pub fn u64_operands(b: impl Strategy<Value = u64>) -> impl Strategy<Value = Vec<u64>> {
+        (u64_value(), b).prop_map(|(a, b)| vec![a as u32 as u64, a >> 32, b as u32 as u64, b >> 32])
+    }
+
+    /// Returns a strategy for the limbs of two 256-bit values arranged as stack inputs for u256
+    /// procedures. The limbs are biased towards edge cases (0, 1, and 2^32 - 1), and the second
+    /// value is equal to the first one in some cases.
+    pub fn u256_operands() -> impl Strategy<Value = Vec<u64>> {
+        let limb = prop_oneof![
+            1 => select(vec![0, 1, u32::MAX as u64]),
+            3 => any::<u32>().prop_map(u64::from),
+        ];
+        (vec(limb, 16), prop::bool::weighted(0.125)).prop_map(|(mut limbs, equal)| {
+            if equal {
+                limbs.copy_within(..8, 8);
+            }
+            limbs
+        })
+    }
+
+    /// Returns a strategy for the coordinates of an extension field element arranged as stack
+    /// inputs for ext2 procedures. The coordinates are biased towards edge cases (0, 1, and p - 1).
+    pub fn ext2_element() -> impl Strategy<Value = Vec<u64>> {
+        let coordinate = prop_oneof![
+            1 => select(vec![0, 1, Felt::MODULUS - 1]),
+            3 => 0..Felt::MODULUS,
+        ];
+        vec(coordinate, 2)
+    }
+}
@@ -8,8 +8,10 @@ macro_rules! build_test {
     }}
 }
 
+mod ast;
 mod collections;
 mod crypto;
+mod helpers;
 mod math;
 mod mem;
 mod sys;
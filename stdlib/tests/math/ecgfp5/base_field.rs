@@ -268,6 +268,15 @@ impl Ext5 {
         let flg = flg0 & flg1 & flg2 & flg3 & flg4;
         Felt::new(flg as u64)
     }
+
+    pub fn conjugates(self) -> [Self; 5] {
+        let t1 = self.frobenius_once();
+        let t2 = self.frobenius_twice();
+        let t3 = t1.frobenius_twice();
+        let t4 = t2.frobenius_twice();
+
+        [self, t1, t2, t3, t4]
+    }
 }
 
 impl Add for Ext5 {
@@ -615,3 +624,82 @@ fn test_ext5_sqrt() {
     assert_eq!(strace[4], b.a4);
     assert_eq!(strace[5], c);
 }
+
+#[test]
+fn test_ext5_norm() {
+    let source = "
+    use.std::math::ecgfp5::base_field
+
+    begin
+        exec.base_field::norm
+    end";
+
+    let a = Ext5::rand();
+    let b = a.conjugates().into_iter().fold(Ext5::from_int(1), |acc, c| acc * c);
+
+    // product of all conjugates must belong to the base field
+    assert_eq!(b.a1, Felt::ZERO);
+    assert_eq!(b.a2, Felt::ZERO);
+    assert_eq!(b.a3, Felt::ZERO);
+    assert_eq!(b.a4, Felt::ZERO);
+
+    let mut stack = [a.a0.as_int(), a.a1.as_int(), a.a2.as_int(), a.a3.as_int(), a.a4.as_int()];
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    let strace = test.get_last_stack_state();
+
+    assert_eq!(strace[0], b.a0);
+    assert_eq!(strace[1], Felt::ZERO);
+}
+
+#[test]
+fn test_ext5_norm_multiplicative() {
+    let source = "
+    use.std::math::ecgfp5::base_field
+
+    begin
+        exec.base_field::norm
+    end";
+
+    let a = Ext5::rand();
+    let b = Ext5::rand();
+
+    let norms = [a, b, a * b].map(|x| {
+        let mut stack = [x.a0.as_int(), x.a1.as_int(), x.a2.as_int(), x.a3.as_int(), x.a4.as_int()];
+        stack.reverse();
+
+        let test = build_test!(source, &stack);
+        test.get_last_stack_state()[0]
+    });
+
+    assert_eq!(norms[2], norms[0] * norms[1]);
+}
+
+#[test]
+fn test_ext5_trace() {
+    let source = "
+    use.std::math::ecgfp5::base_field
+
+    begin
+        exec.base_field::trace
+    end";
+
+    let a = Ext5::rand();
+    let b = a.conjugates().into_iter().fold(Ext5::zero(), |acc, c| acc + c);
+
+    // sum of all conjugates must belong to the base field
+    assert_eq!(b.a1, Felt::ZERO);
+    assert_eq!(b.a2, Felt::ZERO);
+    assert_eq!(b.a3, Felt::ZERO);
+    assert_eq!(b.a4, Felt::ZERO);
+
+    let mut stack = [a.a0.as_int(), a.a1.as_int(), a.a2.as_int(), a.a3.as_int(), a.a4.as_int()];
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    let strace = test.get_last_stack_state();
+
+    assert_eq!(strace[0], b.a0);
+    assert_eq!(strace[1], Felt::ZERO);
+}
@@ -39,7 +39,6 @@ impl ECExt5 {
     }
 
     // Taken from https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1003
-    #[allow(dead_code)]
     pub fn b_prime() -> Ext5 {
         let a = Self::a();
         let two = Ext5::from_int(2);
@@ -59,6 +58,14 @@ impl ECExt5 {
         }
     }
 
+    // Checks whether a point given as Weierstraß coordinates satisfies the curve equation
+    // Y^2 = X^3 + A'*X + B', treating point-at-infinity as being on the curve
+    pub fn is_on_curve(self) -> Felt {
+        let lhs = self.y.square();
+        let rhs = self.x * (self.x.square() + Self::a_prime()) + Self::b_prime();
+        bv_or(Felt::new((lhs == rhs) as u64), self.point_at_infinity)
+    }
+
     // Validates an encoded elliptic curve point, verifying whether it can be decoded successfully or not, denoted by boolean return value
     //
     // Taken from https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1043-L1052
@@ -198,6 +205,89 @@ impl Add for ECExt5 {
     }
 }
 
+fn check_is_on_curve(p: ECExt5, expected: bool) {
+    let source = "
+    use.std::math::ecgfp5::group
+
+    begin
+        exec.group::is_on_curve
+    end";
+
+    assert_eq!(p.is_on_curve(), Felt::new(expected as u64));
+
+    let mut stack = [
+        p.x.a0.as_int(),
+        p.x.a1.as_int(),
+        p.x.a2.as_int(),
+        p.x.a3.as_int(),
+        p.x.a4.as_int(),
+        p.y.a0.as_int(),
+        p.y.a1.as_int(),
+        p.y.a2.as_int(),
+        p.y.a3.as_int(),
+        p.y.a4.as_int(),
+        p.point_at_infinity.as_int(),
+    ];
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    let strace = test.get_last_stack_state();
+
+    assert_eq!(strace[0], Felt::new(expected as u64));
+}
+
+#[test]
+fn test_ec_ext5_point_is_on_curve() {
+    // Conventional generator point of this group, converted to Weierstraß coordinates
+    // Taken from https://github.com/pornin/ecgfp5/blob/ce059c6/rust/src/curve.rs#L67-L83
+    // Note, (x, u) = (x, 1/4) on the double-odd curve, so X = x + a/3 and Y = -4x
+    let x = Ext5::new(
+        0xb2ca178ecf4453a1,
+        0x3c757788836d3ea4,
+        0x48d7f28a26dafd0b,
+        0x1e0f15c7fd44c28e,
+        0x21fa7ffcc8252211,
+    );
+    let gen = ECExt5 {
+        x: x + ECExt5::adiv3(),
+        y: -(x * Ext5::from_int(4)),
+        point_at_infinity: Felt::ZERO,
+    };
+    check_is_on_curve(gen, true);
+
+    // points obtained by decoding valid encodings are on the curve
+    let w = Ext5::new(
+        12539254003028696409,
+        15524144070600887654,
+        15092036948424041984,
+        11398871370327264211,
+        10958391180505708567,
+    );
+    let (p, _) = ECExt5::decode(w);
+    check_is_on_curve(p, true);
+    check_is_on_curve(p.double(), true);
+
+    // the point at infinity is considered to be on the curve
+    check_is_on_curve(ECExt5::neutral(), true);
+
+    // changing a single coordinate moves the point off the curve
+    let mut off = gen;
+    off.y.a0 += Felt::ONE;
+    check_is_on_curve(off, false);
+
+    let mut off = p;
+    off.x.a4 += Felt::ONE;
+    check_is_on_curve(off, false);
+
+    // (0, 0) is not on the curve when it doesn't denote point-at-infinity
+    let zero = ECExt5 {
+        x: Ext5::zero(),
+        y: Ext5::zero(),
+        point_at_infinity: Felt::ZERO,
+    };
+    check_is_on_curve(zero, false);
+}
+
 // Test vectors taken from https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1528-L1556
 #[test_case(0, 0, 0, 0, 0, true; "[0] should validate")]
 #[test_case(12539254003028696409, 15524144070600887654, 15092036948424041984, 11398871370327264211, 10958391180505708567, true; "[1] should validate")]
@@ -645,3 +735,150 @@ fn test_ec_ext5_gen_multiplication() {
     assert_eq!(strace[9], res.y.a4);
     assert_eq!(strace[10], res.point_at_infinity);
 }
+
+// PROJECTIVE COORDINATES
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_ec_ext5_point_affine_projective_roundtrip() {
+    let source = "
+    use.std::math::ecgfp5::group
+
+    begin
+        exec.group::from_affine
+        exec.group::to_affine
+    end";
+
+    for p in [test_point(0), ECExt5::neutral()] {
+        let test = build_test!(source, &to_stack(&[p]));
+        assert_point_eq(&test.get_last_stack_state(), p);
+    }
+}
+
+#[test]
+fn test_ec_ext5_point_projective_addition() {
+    let (p0, p1, p2) = (test_point(0), test_point(1), test_point(2));
+    let neg_p0 = ECExt5 { y: -p0.y, ..p0 };
+
+    // distinct points
+    check_projective_sum(&[p0, p1, p2, p0]);
+    // same points, which requires doubling
+    check_projective_sum(&[p0, p0, p1]);
+    // points which are negation of each other, producing point-at-infinity
+    check_projective_sum(&[p0, neg_p0]);
+    check_projective_sum(&[p0, neg_p0, p1]);
+    // point-at-infinity as either operand
+    check_projective_sum(&[ECExt5::neutral(), p0, ECExt5::neutral(), p1]);
+}
+
+#[test]
+fn test_ec_ext5_point_projective_doubling() {
+    let source = "
+    use.std::math::ecgfp5::group
+
+    begin
+        exec.group::from_affine
+        exec.group::double_projective
+        exec.group::double_projective
+        exec.group::to_affine
+    end";
+
+    let p = test_point(1);
+    let test = build_test!(source, &to_stack(&[p]));
+    assert_point_eq(&test.get_last_stack_state(), p.double().double());
+
+    let test = build_test!(source, &to_stack(&[ECExt5::neutral()]));
+    assert_point_eq(&test.get_last_stack_state(), ECExt5::neutral());
+}
+
+/// Adds given points in projective coordinates, converting the sum to Weierstraß coordinates only
+/// once at the end, and checks that the result matches the sum computed in affine coordinates.
+fn check_projective_sum(points: &[ECExt5]) {
+    let mut source = String::from(
+        "
+    use.std::math::ecgfp5::group
+
+    begin",
+    );
+    for (i, p) in points.iter().enumerate() {
+        source.push_str(&format!(
+            "\n        push.{}\n        exec.group::from_affine",
+            to_push_args(p)
+        ));
+        if i > 0 {
+            source.push_str("\n        exec.group::add_projective");
+        }
+    }
+    source.push_str("\n        exec.group::to_affine\n    end");
+
+    let expected = points[1..].iter().fold(points[0], |acc, &p| acc + p);
+
+    let test = build_test!(&source, &[]);
+    assert_point_eq(&test.get_last_stack_state(), expected);
+}
+
+/// Decodes one of the test vectors, taken from
+/// https://github.com/pornin/ecgfp5/blob/ce059c6/python/ecGFp5.py#L1528-L1548
+fn test_point(idx: usize) -> ECExt5 {
+    let w = [
+        Ext5::new(
+            12539254003028696409,
+            15524144070600887654,
+            15092036948424041984,
+            11398871370327264211,
+            10958391180505708567,
+        ),
+        Ext5::new(
+            11001943240060308920,
+            17075173755187928434,
+            3940989555384655766,
+            15017795574860011099,
+            5548543797011402287,
+        ),
+        Ext5::new(
+            8058035104653144162,
+            16041715455419993830,
+            7448530016070824199,
+            11253639182222911208,
+            6228757819849640866,
+        ),
+    ];
+
+    let (p, flg) = ECExt5::decode(w[idx]);
+    assert_eq!(flg, Felt::ONE);
+    p
+}
+
+/// Returns arguments of `push` instruction, which places the point at the top of the stack.
+fn to_push_args(p: &ECExt5) -> String {
+    // values of `push` instruction are pushed one by one, so the last one ends up at the top
+    to_stack(&[*p]).iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".")
+}
+
+/// Returns stack inputs s.t. the first point is at the top of the stack.
+fn to_stack(points: &[ECExt5]) -> Vec<u64> {
+    let mut stack = Vec::new();
+    for p in points {
+        stack.extend([p.x.a0, p.x.a1, p.x.a2, p.x.a3, p.x.a4].iter().map(|v| v.as_int()));
+        stack.extend([p.y.a0, p.y.a1, p.y.a2, p.y.a3, p.y.a4].iter().map(|v| v.as_int()));
+        stack.push(p.point_at_infinity.as_int());
+    }
+    stack.reverse();
+    stack
+}
+
+/// Checks that the point at the top of the stack is the expected one. As coordinates of the
+/// point-at-infinity are not canonical, only the flag is compared in that case.
+fn assert_point_eq(strace: &[Felt], expected: ECExt5) {
+    assert_eq!(strace[10], expected.point_at_infinity);
+    if expected.point_at_infinity == Felt::ZERO {
+        assert_eq!(
+            strace[..5],
+            [expected.x.a0, expected.x.a1, expected.x.a2, expected.x.a3, expected.x.a4]
+        );
+        assert_eq!(
+            strace[5..10],
+            [expected.y.a0, expected.y.a1, expected.y.a2, expected.y.a3, expected.y.a4]
+        );
+    }
+}
@@ -159,6 +159,18 @@ impl Scalar {
         r_mont.from_mont()
     }
 
+    /// Reduces an arbitrary 320 -bit number, represented in radix-2^32 form, modulo N
+    ///
+    /// As 2^320 < 3 * N, at most two subtractions of N are required.
+    fn reduce(self) -> Self {
+        let mut r = self;
+        for _ in 0..2 {
+            let (t, c) = r.sub_inner(&Self::get_n());
+            r = Self::select(c, t, r);
+        }
+        r
+    }
+
     /// Computes multiplicative inverse ( say a' ) of scalar field element a | a * a' = 1 ( mod N )
     ///
     /// Note, if a = 0, then a' = 0.
@@ -357,3 +369,75 @@ fn test_ec_ext5_scalar_inv() {
         assert_eq!(strace[i].as_int(), b.limbs[i] as u64);
     }
 }
+
+#[test]
+fn test_ec_ext5_scalar_reduce() {
+    let n = Scalar::get_n();
+    let mut n_minus_one = n;
+    n_minus_one.limbs[0] -= 1;
+    let mut n_plus_one = n;
+    n_plus_one.limbs[0] += 1;
+    let max = Scalar {
+        limbs: [u32::MAX; 10],
+    };
+
+    // values just below, at and just above the modulus, as well as the extremes
+    let cases = [
+        (Scalar::zero(), Scalar::zero()),
+        (n_minus_one, n_minus_one),
+        (n, Scalar::zero()),
+        (n_plus_one, Scalar::one()),
+        (max, max.reduce()),
+    ];
+    for (a, expected) in cases {
+        assert_eq!(a.reduce(), expected);
+        check_reduce(a, expected);
+    }
+
+    // random values, which may be greater than the modulus
+    let a = Scalar {
+        limbs: [(); 10].map(|_| rand_value::<u32>()),
+    };
+    check_reduce(a, a.reduce());
+}
+
+#[test]
+fn test_ec_ext5_scalar_from_u64() {
+    let source = "
+    use.std::math::ecgfp5::scalar_field
+
+    begin
+        exec.scalar_field::from_u64
+    end";
+
+    for a in [0, 1, u64::MAX, rand_value::<u64>()] {
+        let test = build_test!(source, &[a & 0xffff_ffff, a >> 32]);
+        let strace = test.get_last_stack_state();
+
+        let mut expected = [0u64; 10];
+        expected[0] = a & 0xffff_ffff;
+        expected[1] = a >> 32;
+        for i in 0..10 {
+            assert_eq!(strace[i].as_int(), expected[i]);
+        }
+    }
+}
+
+fn check_reduce(a: Scalar, expected: Scalar) {
+    let source = "
+    use.std::math::ecgfp5::scalar_field
+
+    begin
+        exec.scalar_field::reduce
+    end";
+
+    let mut stack = a.limbs.map(|limb| limb as u64);
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    let strace = test.get_last_stack_state();
+
+    for (value, limb) in strace.iter().zip(expected.limbs) {
+        assert_eq!(value.as_int(), limb as u64);
+    }
+}
@@ -0,0 +1,289 @@
+use super::PROP_CHECK_CASES;
+use crate::{build_test, helpers::strategies::ext2_element};
+use test_utils::{
+    proptest::prelude::*, rand::rand_value, ExecutionErrorMatcher, Felt, FieldElement, QuadFelt,
+    StarkField,
+};
+
+// CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn from_base() {
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::from_base
+        end";
+
+    let a = rand_value::<u64>() % Felt::MODULUS;
+    let test = build_test!(source, &[a]);
+    test.expect_stack(&[0, a]);
+}
+
+#[test]
+fn from_base_is_field_embedding() {
+    // multiplying by a lifted base element is the same as scaling both coordinates
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::from_base
+            ext2mul
+        end";
+
+    let a = rand_value::<Felt>();
+    let b = rand_value::<QuadFelt>();
+    let c = b * QuadFelt::from(a);
+
+    let (b0, b1) = ext_element_to_ints(b);
+    let (c0, c1) = ext_element_to_ints(c);
+    let test = build_test!(source, &[b0, b1, a.as_int()]);
+    test.expect_stack(&[c1, c0]);
+}
+
+#[test]
+fn base_round_trip() {
+    let source = "
+        use.std::math::ext2
+        begin
+            dup
+            exec.ext2::from_base
+            exec.ext2::to_base
+            exec.ext2::from_base
+            exec.ext2::try_to_base
+        end";
+
+    let a = rand_value::<u64>() % Felt::MODULUS;
+    let test = build_test!(source, &[a]);
+    test.expect_stack(&[1, a, a]);
+}
+
+#[test]
+fn to_base_fails_on_extension_element() {
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::to_base
+        end";
+
+    let test = build_test!(source, &[3, 5]);
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
+}
+
+#[test]
+fn try_to_base() {
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::try_to_base
+        end";
+
+    // element of the base field
+    let test = build_test!(source, &[7, 0]);
+    test.expect_stack(&[1, 7]);
+
+    // element with a non-zero imaginary coordinate
+    let test = build_test!(source, &[7, 1]);
+    test.expect_stack(&[0, 7]);
+}
+
+// ARITHMETIC
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn add() {
+    check_binary_op("add", "ext2add", |a, b| a + b);
+}
+
+#[test]
+fn sub() {
+    check_binary_op("sub", "ext2sub", |a, b| a - b);
+}
+
+#[test]
+fn mul() {
+    check_binary_op("mul", "ext2mul", |a, b| a * b);
+}
+
+#[test]
+fn div() {
+    check_binary_op("div", "ext2div", |a, b| a / b);
+}
+
+#[test]
+fn neg() {
+    check_unary_op("neg", "ext2neg", |a| -a);
+}
+
+#[test]
+fn inv() {
+    check_unary_op("inv", "ext2inv", |a| a.inv());
+}
+
+#[test]
+fn inv_and_div_fail_on_zero() {
+    for source in [
+        "use.std::math::ext2 begin exec.ext2::inv end",
+        "use.std::math::ext2 begin exec.ext2::div end",
+    ] {
+        let test = build_test!(source, &[3, 5, 0, 0]);
+        test.expect_error(ExecutionErrorMatcher::divide_by_zero());
+    }
+}
+
+#[test]
+fn conjugate() {
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::conjugate
+        end";
+
+    let a = rand_value::<QuadFelt>();
+    let (a0, a1) = ext_element_to_ints(a);
+    let (b0, b1) = ext_element_to_ints(conjugate_of(a));
+    let test = build_test!(source, &[a0, a1]);
+    test.expect_stack(&[b1, b0]);
+
+    // the conjugate of a base field element is the element itself
+    let test = build_test!(source, &[a0, 0]);
+    test.expect_stack(&[0, a0]);
+}
+
+#[test]
+fn norm() {
+    let source = "
+        use.std::math::ext2
+        begin
+            exec.ext2::norm
+        end";
+
+    let a = rand_value::<QuadFelt>();
+    let (a0, a1) = ext_element_to_ints(a);
+    let test = build_test!(source, &[a0, a1]);
+    test.expect_stack(&[norm_of(a).as_int()]);
+
+    // the norm is multiplicative
+    let b = rand_value::<QuadFelt>();
+    let (c0, c1) = ext_element_to_ints(a * b);
+    let test = build_test!(source, &[c0, c1]);
+    test.expect_stack(&[(norm_of(a) * norm_of(b)).as_int()]);
+}
+
+#[test]
+fn norm_is_product_with_conjugate() {
+    // computes x * conjugate(x), asserts that it lands in the base field, and compares it with
+    // the norm of x
+    let source = "
+        use.std::math::ext2
+        begin
+            dup.1 dup.1 dup.1 dup.1
+            exec.ext2::conjugate
+            ext2mul
+            exec.ext2::to_base
+            movdn.2
+            exec.ext2::norm
+        end";
+
+    build_test!(source).prop_check(PROP_CHECK_CASES, ext2_element(), |inputs| {
+        let a = QuadFelt::new(Felt::new(inputs[0]), Felt::new(inputs[1]));
+        assert_eq!(QuadFelt::from(norm_of(a)), a * conjugate_of(a));
+        vec![norm_of(a).as_int(), norm_of(a).as_int()]
+    });
+}
+
+// DIFFERENTIAL TESTS
+// ------------------------------------------------------------------------------------------------
+
+/// Binary operation over the quadratic extension field.
+type BinaryOp = fn(QuadFelt, QuadFelt) -> QuadFelt;
+
+#[test]
+fn binary_ops_prop_check() {
+    let ops: [(&str, &str, BinaryOp); 4] = [
+        ("add", "ext2add", |a, b| a + b),
+        ("sub", "ext2sub", |a, b| a - b),
+        ("mul", "ext2mul", |a, b| a * b),
+        ("div", "ext2div", |a, b| a / b),
+    ];
+
+    // the second operand is the divisor of div, and thus must not be zero
+    let b = ext2_element().prop_filter("divisor must not be zero", |b| b != &[0, 0]);
+    let inputs = (ext2_element(), b).prop_map(|(a, b)| [a, b].concat());
+
+    for (proc_name, op, reference) in ops {
+        for source in [
+            format!("use.std::math::ext2 begin exec.ext2::{proc_name} end"),
+            format!("begin {op} end"),
+        ] {
+            build_test!(&source).prop_check(PROP_CHECK_CASES, &inputs, |inputs| {
+                let a = QuadFelt::new(Felt::new(inputs[0]), Felt::new(inputs[1]));
+                let b = QuadFelt::new(Felt::new(inputs[2]), Felt::new(inputs[3]));
+                let (c0, c1) = ext_element_to_ints(reference(a, b));
+                vec![c1, c0]
+            });
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Returns the conjugate of the specified extension field element. Since the extension field is
+/// defined by the polynomial x^2 - x + 2, the conjugate of a0 + a1 * x is (a0 + a1) - a1 * x.
+fn conjugate_of(a: QuadFelt) -> QuadFelt {
+    let base_elements = a.to_base_elements();
+    let (a0, a1) = (base_elements[0], base_elements[1]);
+    QuadFelt::new(a0 + a1, -a1)
+}
+
+/// Returns the norm of the specified extension field element, i.e., a0^2 + a0 * a1 + 2 * a1^2.
+fn norm_of(a: QuadFelt) -> Felt {
+    let base_elements = a.to_base_elements();
+    let (a0, a1) = (base_elements[0], base_elements[1]);
+    a0.square() + a0 * a1 + a1.square().double()
+}
+
+/// Returns the base field coordinates (a0, a1) of the specified extension field element.
+fn ext_element_to_ints(ext_elem: QuadFelt) -> (u64, u64) {
+    let base_elements = ext_elem.to_base_elements();
+    (base_elements[0].as_int(), base_elements[1].as_int())
+}
+
+/// Checks that both the `ext2::<proc_name>` procedure and the native `op` instruction compute the
+/// same result as the `expected` function on random operands.
+fn check_binary_op(proc_name: &str, op: &str, expected: fn(QuadFelt, QuadFelt) -> QuadFelt) {
+    let a = rand_value::<QuadFelt>();
+    let b = rand_value::<QuadFelt>();
+    let c = expected(a, b);
+
+    let (a0, a1) = ext_element_to_ints(a);
+    let (b0, b1) = ext_element_to_ints(b);
+    let (c0, c1) = ext_element_to_ints(c);
+
+    for source in [
+        format!("use.std::math::ext2 begin exec.ext2::{proc_name} end"),
+        format!("begin {op} end"),
+    ] {
+        let test = build_test!(&source, &[a0, a1, b0, b1]);
+        test.expect_stack(&[c1, c0]);
+    }
+}
+
+/// Checks that both the `ext2::<proc_name>` procedure and the native `op` instruction compute the
+/// same result as the `expected` function on a random operand.
+fn check_unary_op(proc_name: &str, op: &str, expected: fn(QuadFelt) -> QuadFelt) {
+    let a = rand_value::<QuadFelt>();
+    let b = expected(a);
+
+    let (a0, a1) = ext_element_to_ints(a);
+    let (b0, b1) = ext_element_to_ints(b);
+
+    for source in [
+        format!("use.std::math::ext2 begin exec.ext2::{proc_name} end"),
+        format!("begin {op} end"),
+    ] {
+        let test = build_test!(&source, &[a0, a1]);
+        test.expect_stack(&[b1, b0]);
+    }
+}
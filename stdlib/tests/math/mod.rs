@@ -1,6 +1,11 @@
 mod ecgfp5;
+mod ext2;
 mod ntt512;
 mod poly512;
 mod secp256k1;
 mod u256_mod;
+mod u64_diff;
 mod u64_mod;
+
+/// Number of random cases checked by differential tests against Rust reference implementations.
+const PROP_CHECK_CASES: u32 = 100;
@@ -1,6 +1,6 @@
 use crate::build_test;
 use core::fmt::Write;
-use test_utils::rand::rand_array;
+use test_utils::{rand::rand_array, Felt, StarkField, ZERO};
 
 #[test]
 fn test_ntt512() {
@@ -80,3 +80,258 @@ fn generate_test_script_ntt512() -> String {
     );
     script
 }
+
+#[test]
+fn test_ntt512_inplace() {
+    let source = generate_test_script_ntt512_inplace();
+
+    let test = build_test!(&source, &[]);
+    assert!(test.execute().is_ok());
+}
+
+fn generate_test_script_ntt512_inplace() -> String {
+    const POLYNOMIAL_LENGTH: usize = 512;
+    const WORDS: usize = 128;
+    const Q: u64 = (((1u64 << 32) - 1) << 32) + 1; // Miden Field Prime
+
+    let polynomial = rand_array::<u64, POLYNOMIAL_LENGTH>().map(|v| v % Q);
+
+    let mut polynomial_script = String::new();
+    let mut check_result_script = String::new();
+
+    for i in 0..WORDS {
+        let _ = writeln!(
+            polynomial_script,
+            "push.{}.{}.{}.{}",
+            polynomial[4 * i + 3],
+            polynomial[4 * i + 2],
+            polynomial[4 * i + 1],
+            polynomial[4 * i]
+        );
+        let _ = writeln!(polynomial_script, "loc_storew.{i}");
+        let _ = writeln!(polynomial_script, "loc_storew.{}", i + WORDS);
+        polynomial_script.push_str("dropw\n");
+
+        check_result_script.push_str("dup\n");
+        check_result_script.push_str("push.0.0.0.0\n");
+        check_result_script.push_str("movup.4\n");
+        check_result_script.push_str("mem_loadw\n");
+        let _ = writeln!(check_result_script, "push.{}", polynomial[4 * i]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", polynomial[4 * i + 1]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", polynomial[4 * i + 2]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", polynomial[4 * i + 3]);
+        check_result_script.push_str("assert_eq\n");
+        check_result_script.push_str("add.1\n");
+    }
+
+    let script = format!(
+        "
+    use.std::math::ntt512
+
+    proc.wrapper.256
+        # prepare two copies of the input vector
+
+        {polynomial_script}
+
+        # apply forward NTT in place to the second copy; the address of the copy is returned
+
+        locaddr.128
+        exec.ntt512::ntt_inplace
+
+        # apply forward NTT to the first copy, which is not mutated; the result is stored in the
+        # local memory of the NTT procedure, and thus, it is compared to the result of in-place
+        # NTT before invoking any other procedure
+
+        locaddr.0
+        exec.ntt512::forward
+
+        repeat.128
+            padw
+            dup.4
+            mem_loadw
+            padw
+            dup.9
+            mem_loadw
+            assert_eqw
+
+            add.1
+            swap
+            add.1
+            swap
+        end
+
+        drop
+        drop
+
+        # test that v == v' | v -> ntt_inplace -> intt_inplace -> v'
+        # where v = input vector
+        #       v' = vector holding result of iNTT(NTT(v)) at the same memory addresses as v
+
+        locaddr.128
+        exec.ntt512::intt_inplace
+
+        {check_result_script}
+
+        drop
+
+        # the first copy of the input vector is left intact by forward NTT
+
+        locaddr.0
+
+        {check_result_script}
+
+        drop
+    end
+
+    begin
+        exec.wrapper
+    end
+    "
+    );
+    script
+}
+
+#[test]
+fn test_ntt512_pointwise_mul() {
+    let source = generate_test_script_pointwise_mul();
+
+    let test = build_test!(&source, &[]);
+    assert!(test.execute().is_ok());
+}
+
+fn generate_test_script_pointwise_mul() -> String {
+    const POLYNOMIAL_LENGTH: usize = 512;
+    const WORDS: usize = 128;
+
+    let polynomial_1 = rand_array::<u64, POLYNOMIAL_LENGTH>().map(Felt::new);
+    let polynomial_2 = rand_array::<u64, POLYNOMIAL_LENGTH>().map(Felt::new);
+
+    // iNTT(NTT(f) * NTT(g)) is the product of f and g modulo X^512 + 1 (i.e., negacyclic
+    // convolution of f and g)
+    let mut result_polynomial = [ZERO; POLYNOMIAL_LENGTH];
+    for (i, &a) in polynomial_1.iter().enumerate() {
+        for (j, &b) in polynomial_2.iter().enumerate() {
+            if i + j < POLYNOMIAL_LENGTH {
+                result_polynomial[i + j] += a * b;
+            } else {
+                result_polynomial[i + j - POLYNOMIAL_LENGTH] -= a * b;
+            }
+        }
+    }
+    let result_polynomial = result_polynomial.map(|v| v.as_int());
+
+    let mut polynomial_script = String::new();
+    let mut check_result_script = String::new();
+
+    for i in 0..WORDS {
+        let _ = writeln!(
+            polynomial_script,
+            "push.{}.{}.{}.{}",
+            polynomial_1[4 * i + 3],
+            polynomial_1[4 * i + 2],
+            polynomial_1[4 * i + 1],
+            polynomial_1[4 * i]
+        );
+        let _ = writeln!(polynomial_script, "loc_storew.{i}");
+        polynomial_script.push_str("dropw\n");
+
+        let _ = writeln!(
+            polynomial_script,
+            "push.{}.{}.{}.{}",
+            polynomial_2[4 * i + 3],
+            polynomial_2[4 * i + 2],
+            polynomial_2[4 * i + 1],
+            polynomial_2[4 * i]
+        );
+        let _ = writeln!(polynomial_script, "loc_storew.{}", i + WORDS);
+        polynomial_script.push_str("dropw\n");
+
+        check_result_script.push_str("dup\n");
+        check_result_script.push_str("push.0.0.0.0\n");
+        check_result_script.push_str("movup.4\n");
+        check_result_script.push_str("mem_loadw\n");
+        let _ = writeln!(check_result_script, "push.{}", result_polynomial[4 * i]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", result_polynomial[4 * i + 1]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", result_polynomial[4 * i + 2]);
+        check_result_script.push_str("assert_eq\n");
+        let _ = writeln!(check_result_script, "push.{}", result_polynomial[4 * i + 3]);
+        check_result_script.push_str("assert_eq\n");
+        check_result_script.push_str("add.1\n");
+    }
+
+    let script = format!(
+        "
+    use.std::math::ntt512
+
+    # copies a vector of length 512 from src to dst | [src, dst, ...] -> [...]
+    proc.copy
+        push.0.0.0.0
+
+        repeat.128
+            dup.4
+            mem_loadw
+
+            dup.5
+            mem_storew
+
+            movup.4
+            add.1
+            movdn.4
+
+            movup.5
+            add.1
+            movdn.5
+        end
+
+        dropw
+        drop
+        drop
+    end
+
+    proc.wrapper.512
+        # prepare input vectors f and g
+
+        {polynomial_script}
+
+        # F = NTT(f), G = NTT(g); the results of forward NTT are copied out of the local memory
+        # of the NTT procedure, as it is reused by subsequent procedure calls
+
+        locaddr.256
+        locaddr.0
+        exec.ntt512::forward
+        exec.copy
+
+        locaddr.384
+        locaddr.128
+        exec.ntt512::forward
+        exec.copy
+
+        # H = F * G, stored in place of F
+
+        locaddr.256
+        locaddr.384
+        locaddr.256
+        exec.ntt512::pointwise_mul
+
+        # test that iNTT(H) == f * g mod (X^512 + 1)
+
+        locaddr.256
+        exec.ntt512::backward
+
+        {check_result_script}
+
+        drop
+    end
+
+    begin
+        exec.wrapper
+    end
+    "
+    );
+    script
+}
@@ -339,3 +339,89 @@ fn generate_test_script_mul_zq() -> String {
     );
     script
 }
+
+#[test]
+fn test_poly512_add() {
+    let reference = |a: Felt, b: Felt| a + b;
+
+    // --- random polynomials ---------------------------------------------------------------------
+    let f = rand_array::<u64, POLYNOMIAL_LENGTH>().map(|v| v % Felt::MODULUS);
+    let g = rand_array::<u64, POLYNOMIAL_LENGTH>().map(|v| v % Felt::MODULUS);
+    check_poly512_field_op("add", &f, &g, reference);
+
+    // --- adding the zero polynomial -------------------------------------------------------------
+    check_poly512_field_op("add", &f, &[0; POLYNOMIAL_LENGTH], reference);
+
+    // --- coefficients wrap around the modulus ---------------------------------------------------
+    let f: [u64; POLYNOMIAL_LENGTH] = core::array::from_fn(|i| Felt::MODULUS - 1 - i as u64);
+    let g: [u64; POLYNOMIAL_LENGTH] = core::array::from_fn(|i| 2 * i as u64 + 1);
+    check_poly512_field_op("add", &f, &g, reference);
+}
+
+#[test]
+fn test_poly512_sub() {
+    let reference = |a: Felt, b: Felt| a - b;
+
+    // --- random polynomials ---------------------------------------------------------------------
+    let f = rand_array::<u64, POLYNOMIAL_LENGTH>().map(|v| v % Felt::MODULUS);
+    let g = rand_array::<u64, POLYNOMIAL_LENGTH>().map(|v| v % Felt::MODULUS);
+    check_poly512_field_op("sub", &f, &g, reference);
+
+    // --- subtracting the zero polynomial and subtracting from the zero polynomial ---------------
+    check_poly512_field_op("sub", &f, &[0; POLYNOMIAL_LENGTH], reference);
+    check_poly512_field_op("sub", &[0; POLYNOMIAL_LENGTH], &g, reference);
+
+    // --- coefficients wrap around the modulus ---------------------------------------------------
+    let f: [u64; POLYNOMIAL_LENGTH] = core::array::from_fn(|i| i as u64);
+    let g: [u64; POLYNOMIAL_LENGTH] = core::array::from_fn(|i| Felt::MODULUS - 1 - 2 * i as u64);
+    check_poly512_field_op("sub", &f, &g, reference);
+}
+
+/// Loads polynomials f and g from the advice stack into memory, executes the specified procedure
+/// from the `poly512` module, and checks the resulting polynomial against the coefficient-wise
+/// application of the reference function.
+fn check_poly512_field_op<F>(proc_name: &str, f: &[u64], g: &[u64], reference: F)
+where
+    F: Fn(Felt, Felt) -> Felt,
+{
+    let source = format!(
+        "
+        use.std::math::poly512
+
+        proc.load_poly
+            repeat.{WORDS}
+                padw
+                adv_loadw
+                dup.4
+                mem_storew
+                dropw
+                add.1
+            end
+            drop
+        end
+
+        begin
+            push.0 exec.load_poly
+            push.128 exec.load_poly
+
+            push.256 # output
+            push.128 # input 1
+            push.0 # input 0
+
+            exec.poly512::{proc_name}
+        end
+        "
+    );
+
+    // memory words are filled with the coefficients in the order in which they are read from the
+    // advice stack, and thus, the memory layout of h matches the order of its coefficients
+    let advice_stack = f.iter().chain(g.iter()).copied().collect::<Vec<_>>();
+    let expected = f
+        .iter()
+        .zip(g.iter())
+        .map(|(&a, &b)| reference(Felt::new(a), Felt::new(b)).as_int())
+        .collect::<Vec<_>>();
+
+    let test = build_test!(&source).with_advice_stack(&advice_stack);
+    test.expect_stack_and_memory(&[], 256, &expected);
+}
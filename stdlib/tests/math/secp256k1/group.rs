@@ -1,5 +1,17 @@
-use crate::build_test;
-use test_utils::test_case;
+use crate::{
+    build_test,
+    helpers::{secp256k1::base_field_prime, to_mont},
+};
+use k256::{
+    elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint},
+    FieldBytes, ProjectivePoint, Scalar, U256,
+};
+use num_bigint::BigUint;
+use test_utils::{
+    bigint::u256_limbs,
+    rand::{seeded_element, seeded_word},
+    test_case, StarkField,
+};
 
 // Wrapper types introduced for parameterized testing
 struct FieldElement([u32; 8]);
@@ -813,3 +825,420 @@ fn test_secp256k1_generator_multiplication(scalar: FieldElement, point: Point) {
     let test = build_test!(&source, &[]);
     assert!(test.execute().is_ok());
 }
+
+// SEC1 POINT COMPRESSION
+// ------------------------------------------------------------------------------------------------
+// Test vectors are SEC1 compressed encodings of kG, for small values of k. Expected coordinates
+// are computed using the curve equation, over big integers.
+
+#[test_case("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798" ; "1G")]
+#[test_case("02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5" ; "2G")]
+#[test_case("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9" ; "3G")]
+#[test_case("03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556" ; "6G")]
+#[test_case("03acd484e2f0c7f65309ad178a9f559abde09796974c57e714c35f110dfc27ccbe" ; "9G")]
+fn test_secp256k1_point_decompression(key: &str) {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::decompress_point
+    end";
+
+    let (prefix, x) = parse_compressed_point(key);
+    let p = base_field_prime();
+
+    // recover y -coordinate with parity matching the prefix
+    let rhs = (x.pow(3) + 7u32) % &p;
+    let mut y = rhs.modpow(&((&p + 1u32) >> 2), &p);
+    assert_eq!(&y * &y % &p, rhs);
+    if y.bit(0) != (prefix == 3) {
+        y = &p - y;
+    }
+
+    let mut expected_mem = to_mem_words(&to_mont(&x, &p));
+    expected_mem.extend(to_mem_words(&to_mont(&y, &p)));
+    expected_mem.extend(to_mem_words(&to_mont(&BigUint::from(1u32), &p)));
+
+    let test = build_test!(source, &decompression_inputs(prefix, &x));
+    test.expect_stack_and_memory(&[1, 0, 1, 2, 3, 4, 5], 0, &expected_mem);
+}
+
+#[test_case(4, "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798" ; "invalid prefix")]
+#[test_case(2, "05" ; "x not on curve")]
+#[test_case(3, "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f" ; "x not in field")]
+fn test_secp256k1_point_decompression_invalid(prefix: u64, x: &str) {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::decompress_point
+    end";
+
+    let x = BigUint::parse_bytes(x.as_bytes(), 16).unwrap();
+
+    // point at infinity (0, 1, 0) is written for an invalid encoding
+    let mut expected_mem = to_mem_words(&BigUint::from(0u32));
+    expected_mem.extend(to_mem_words(&to_mont(&BigUint::from(1u32), &base_field_prime())));
+    expected_mem.extend(to_mem_words(&BigUint::from(0u32)));
+
+    let test = build_test!(source, &decompression_inputs(prefix, &x));
+    test.expect_stack_and_memory(&[0, 0, 1, 2, 3, 4, 5], 0, &expected_mem);
+}
+
+#[test_case("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798" ; "1G")]
+#[test_case("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9" ; "3G")]
+#[test_case("03acd484e2f0c7f65309ad178a9f559abde09796974c57e714c35f110dfc27ccbe" ; "9G")]
+fn test_secp256k1_point_compression_roundtrip(key: &str) {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::decompress_point
+        assert
+        exec.group::compress_point
+    end";
+
+    let (prefix, x) = parse_compressed_point(key);
+
+    let mut expected = vec![1, prefix];
+    expected.extend(u256_limbs(&x));
+
+    let test = build_test!(source, &decompression_inputs(prefix, &x));
+    test.expect_stack(&expected);
+}
+
+#[test_case(2, "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5" ; "2G")]
+#[test_case(6, "03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556" ; "6G")]
+fn test_secp256k1_point_compression(scalar: u64, key: &str) {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::gen_mul
+        exec.group::compress_point
+    end";
+
+    // resulting point of generator multiplication is not normalized i.e. z != 1
+    let (prefix, x) = parse_compressed_point(key);
+
+    let mut expected = vec![1, prefix];
+    expected.extend(u256_limbs(&x));
+
+    let test = build_test!(source, &[5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, scalar]);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn test_secp256k1_point_at_infinity_compression() {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::gen_mul
+        exec.group::compress_point
+    end";
+
+    // 0 * G is the point at infinity, which doesn't have a compressed encoding
+    let test = build_test!(source, &[5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test.expect_stack(&[0; 10]);
+}
+
+// EDGE CASES OF POINT ADDITION
+// ------------------------------------------------------------------------------------------------
+// Point addition uses exception-free formula, so it's expected to work when both points are same,
+// when they are negation of each other, or when either of them is point at infinity. Points are
+// given as SEC1 compressed encodings, while "inf" stands for point at infinity.
+
+#[test_case(&[GENERATOR, NEG_GENERATOR], None ; "P + (-P)")]
+#[test_case(&[GENERATOR, GENERATOR], Some(G2) ; "P + P")]
+#[test_case(&[G3, G3], Some(G6) ; "3G + 3G")]
+#[test_case(&["inf", GENERATOR], Some(GENERATOR) ; "O + P")]
+#[test_case(&[GENERATOR, "inf"], Some(GENERATOR) ; "P + O")]
+#[test_case(&["inf", "inf"], None ; "O + O")]
+#[test_case(&[GENERATOR, G2, G3, G3], Some(G9) ; "chain")]
+#[test_case(&[GENERATOR, NEG_GENERATOR, G3, G6], Some(G9) ; "chain through O")]
+fn test_secp256k1_point_addition_edge_cases(points: &[&str], expected: Option<&str>) {
+    // running sum is kept in addresses 0..6 or 12..18 ( alternately ), while next point is
+    // written to addresses 6..12
+    let mut source = String::from("use.std::math::secp256k1::group\n\nbegin\n");
+    source.push_str(&write_point(points[0], 0));
+
+    let mut acc = 0;
+    for point in &points[1..] {
+        let res = 12 - acc;
+        source.push_str(&write_point(point, 6));
+        source.push_str(&format!(
+            "    {}\n    {}\n    {}\n    exec.group::add\n    dropw\n    drop\n    drop\n",
+            point_addrs(res),
+            point_addrs(6),
+            point_addrs(acc)
+        ));
+        acc = res;
+    }
+    source.push_str(&format!("    {}\n    exec.group::compress_point\nend", point_addrs(acc)));
+
+    let expected = match expected {
+        Some(key) => {
+            let (prefix, x) = parse_compressed_point(key);
+            let mut expected = vec![1, prefix];
+            expected.extend(u256_limbs(&x));
+            expected
+        }
+        None => vec![0; 10],
+    };
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&expected);
+}
+
+// RANDOMIZED POINT ARITHMETIC
+// ------------------------------------------------------------------------------------------------
+// Chains of point additions and doublings over random multiples of the generator are compared
+// against k256, after normalizing the resulting point to affine coordinates. Random values are
+// derived from a fixed seed, so that failures are reproducible.
+
+#[test]
+fn test_secp256k1_random_add_double_chains() {
+    let mut seed = 0x5ec9_256b_u64;
+
+    for _ in 0..2 {
+        // random points are kept in addresses 24..30, 30..36 and 36..42, while running result is
+        // kept in addresses 0..6 or 12..18 ( alternately )
+        let points = (0..3)
+            .map(|_| {
+                let bytes = seeded_word(&mut seed)
+                    .iter()
+                    .flat_map(|v| v.as_int().to_be_bytes())
+                    .collect::<Vec<_>>();
+                ProjectivePoint::GENERATOR
+                    * <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(&bytes))
+            })
+            .collect::<Vec<_>>();
+
+        let mut source = String::from("use.std::math::secp256k1::group\n\nbegin\n");
+        for (i, point) in points.iter().enumerate() {
+            source.push_str(&write_point(&compressed_key(point), 24 + 6 * i as u64));
+        }
+
+        let mut expected = points[0];
+        source.push_str(&write_point(&compressed_key(&expected), 0));
+
+        let mut acc = 0;
+        for _ in 0..6 {
+            let res = 12 - acc;
+            let op = seeded_element(&mut seed).as_int() as usize % 4;
+            if op == 3 {
+                expected = expected.double();
+                source.push_str(&format!(
+                    "    {}\n    {}\n    exec.group::double\n",
+                    point_addrs(res),
+                    point_addrs(acc)
+                ));
+            } else {
+                expected += points[op];
+                source.push_str(&format!(
+                    "    {}\n    {}\n    {}\n    exec.group::add\n",
+                    point_addrs(res),
+                    point_addrs(24 + 6 * op as u64),
+                    point_addrs(acc)
+                ));
+            }
+            source.push_str("    dropw\n    drop\n    drop\n");
+            acc = res;
+        }
+        source.push_str(&format!(
+            "    {}\n    exec.group::normalize_to_affine\nend",
+            point_addrs(acc)
+        ));
+
+        let expected = expected.to_affine().to_encoded_point(false);
+        let mut expected_stack = vec![1];
+        expected_stack.extend(u256_limbs(&BigUint::from_bytes_be(expected.x().unwrap())));
+        expected_stack.extend(u256_limbs(&BigUint::from_bytes_be(expected.y().unwrap())));
+
+        let test = build_test!(&source, &[]);
+        test.expect_stack(&expected_stack);
+    }
+}
+
+// CURVE MEMBERSHIP AND NORMALIZATION
+// ------------------------------------------------------------------------------------------------
+
+#[test_case(GENERATOR, true ; "G")]
+#[test_case(G9, true ; "9G")]
+#[test_case("inf", true ; "point at infinity")]
+#[test_case("zero", false ; "all zero coordinates")]
+fn test_secp256k1_is_on_curve(point: &str, expected: bool) {
+    let source = format!(
+        "
+    use.std::math::secp256k1::group
+
+    begin
+    {}
+        {}
+        exec.group::is_on_curve
+    end",
+        write_point(point, 0),
+        point_addrs(0)
+    );
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&[expected as u64]);
+}
+
+#[test]
+fn test_secp256k1_is_on_curve_not_normalized() {
+    // resulting point of generator multiplication is not normalized i.e. z != 1
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::gen_mul
+        exec.group::is_on_curve
+    end";
+
+    let test = build_test!(source, &[5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 6]);
+    test.expect_stack(&[1]);
+}
+
+#[test]
+fn test_secp256k1_is_on_curve_tampered() {
+    // overwrite lowest limbs of x -coordinate of G ( in Montgomery form ) with 1
+    let source = format!(
+        "
+    use.std::math::secp256k1::group
+
+    begin
+    {}
+        push.0.0.0.1
+        mem_storew.0
+        dropw
+
+        {}
+        exec.group::is_on_curve
+    end",
+        write_point(GENERATOR, 0),
+        point_addrs(0)
+    );
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&[0]);
+}
+
+#[test_case(GENERATOR, 1 ; "G")]
+#[test_case(G6, 6 ; "6G")]
+fn test_secp256k1_normalize_to_affine(key: &str, scalar: u64) {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::gen_mul
+        exec.group::normalize_to_affine
+    end";
+
+    let (x, y) = affine_coordinates(key);
+    let mut expected = vec![1];
+    expected.extend(u256_limbs(&x));
+    expected.extend(u256_limbs(&y));
+
+    let test = build_test!(source, &[5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, scalar]);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn test_secp256k1_normalize_point_at_infinity() {
+    let source = "
+    use.std::math::secp256k1::group
+
+    begin
+        exec.group::gen_mul
+        exec.group::normalize_to_affine
+    end";
+
+    let test = build_test!(source, &[5, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test.expect_stack(&[0; 16]);
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// SEC1 compressed encoding of secp256k1 generator point.
+const GENERATOR: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+/// SEC1 compressed encodings of -G and small multiples of G.
+const NEG_GENERATOR: &str = "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const G2: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+const G3: &str = "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+const G6: &str = "03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556";
+const G9: &str = "03acd484e2f0c7f65309ad178a9f559abde09796974c57e714c35f110dfc27ccbe";
+
+/// Returns SEC1 compressed encoding of a point, hex encoded.
+fn compressed_key(point: &ProjectivePoint) -> String {
+    point
+        .to_affine()
+        .to_encoded_point(true)
+        .as_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Splits a hex encoded SEC1 compressed point into its prefix byte and x -coordinate.
+fn parse_compressed_point(key: &str) -> (u64, BigUint) {
+    let prefix = u64::from_str_radix(&key[..2], 16).unwrap();
+    let x = BigUint::parse_bytes(&key.as_bytes()[2..], 16).unwrap();
+    (prefix, x)
+}
+
+/// Returns limbs of a 256 -bit number, in the order they are expected to be found in two
+/// consecutive memory words.
+fn to_mem_words(v: &BigUint) -> Vec<u64> {
+    let limbs = u256_limbs(v);
+    limbs[..4].iter().rev().chain(limbs[4..].iter().rev()).copied().collect()
+}
+
+/// Returns stack inputs of `decompress_point`, which writes the resulting point to memory
+/// addresses 0..6.
+fn decompression_inputs(prefix: u64, x: &BigUint) -> Vec<u64> {
+    let mut inputs = vec![5, 4, 3, 2, 1, 0];
+    inputs.extend(u256_limbs(x).iter().rev());
+    inputs.push(prefix);
+    inputs
+}
+
+/// Returns affine coordinates of a point given as SEC1 compressed encoding.
+fn affine_coordinates(key: &str) -> (BigUint, BigUint) {
+    let (prefix, x) = parse_compressed_point(key);
+    let p = base_field_prime();
+
+    let mut y = ((x.pow(3) + 7u32) % &p).modpow(&((&p + 1u32) >> 2), &p);
+    if y.bit(0) != (prefix == 3) {
+        y = &p - y;
+    }
+    (x, y)
+}
+
+/// Returns instructions writing a point, given as SEC1 compressed encoding, to memory addresses
+/// base..base + 6, in projective coordinate system. Besides compressed encodings, "inf" stands
+/// for point at infinity i.e. (0, 1, 0), while "zero" stands for (0, 0, 0).
+fn write_point(key: &str, base: u64) -> String {
+    match key {
+        // memory is zero initialized, so only y -coordinate of point at infinity needs to be set
+        "inf" => format!("    push.0.0.1.977\n    mem_storew.{}\n    dropw\n", base + 2),
+        "zero" => String::new(),
+        _ => {
+            let (prefix, x) = parse_compressed_point(key);
+            let x = u256_limbs(&x);
+            format!(
+                "    {}\n    push.{}.{}.{}.{}\n    push.{}.{}.{}.{}\n    push.{prefix}\n    exec.group::decompress_point\n    assert\n    dropw\n    drop\n    drop\n",
+                point_addrs(base),
+                x[7], x[6], x[5], x[4], x[3], x[2], x[1], x[0],
+            )
+        }
+    }
+}
+
+/// Returns instruction placing memory addresses base..base + 6 on stack, s.t. base is at the top.
+fn point_addrs(base: u64) -> String {
+    format!("push.{}.{}.{}.{}.{}.{}", base + 5, base + 4, base + 3, base + 2, base + 1, base)
+}
@@ -309,3 +309,33 @@ fn test_secp256k1_scalar_field_inv() {
     let test = build_test!(source, &stack);
     test.expect_stack(&elm2.limbs.map(|v| v as u64));
 }
+
+#[test]
+fn test_secp256k1_scalar_field_to_mont() {
+    let source = "
+    use.std::math::secp256k1::scalar_field
+
+    begin
+        exec.scalar_field::to_mont
+    end";
+
+    // R2 = 2^512 mod n, multiplication by which converts an element into Montgomery form
+    let r2 = ScalarField {
+        limbs: [
+            1742197056, 2305618452, 243071096, 1947506370, 1540163526, 3868718564, 2177276869,
+            2640780501,
+        ],
+    };
+
+    let elm0 = ScalarField {
+        limbs: rand_array::<u32, 8>(),
+    };
+    let elm1 = elm0 * r2;
+
+    let mut stack = [0u64; 8];
+    stack.copy_from_slice(&elm0.limbs.map(|v| v as u64));
+    stack.reverse();
+
+    let test = build_test!(source, &stack);
+    test.expect_stack(&elm1.limbs.map(|v| v as u64));
+}
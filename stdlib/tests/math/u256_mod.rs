@@ -1,6 +1,114 @@
-use crate::build_test;
+use super::PROP_CHECK_CASES;
+use crate::{build_test, helpers::strategies};
 use num_bigint::BigUint;
-use test_utils::rand::rand_vector;
+use test_utils::{
+    bigint::{rand_u256, u256_limbs},
+    rand::rand_vector,
+    ExecutionErrorMatcher, Felt, StarkField,
+};
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn cmp() {
+    let max = BigUint::new(vec![u32::MAX; 8]);
+    let one = BigUint::from(1_u32);
+    let high_limb = BigUint::from(1_u32) << 224;
+
+    // equal values
+    let a = rand_u256();
+    assert_cmp(&a, &a);
+    assert_cmp(&max, &max);
+    assert_cmp(&BigUint::from(0_u32), &BigUint::from(0_u32));
+
+    // values differing only in the low limb
+    let b = a.clone() ^ &one;
+    assert_cmp(&a, &b);
+    assert_cmp(&b, &a);
+    assert_cmp(&max, &(&max - &one));
+    assert_cmp(&(&max - &one), &max);
+
+    // values differing in the high limb
+    let b = a.clone() ^ &high_limb;
+    assert_cmp(&a, &b);
+    assert_cmp(&b, &a);
+    assert_cmp(&max, &(&max - &high_limb));
+    assert_cmp(&(&max - &high_limb), &max);
+
+    // the high limb takes precedence over the low limbs
+    assert_cmp(&high_limb, &(&high_limb - &one));
+    assert_cmp(&(&high_limb - &one), &high_limb);
+
+    // random values
+    assert_cmp(&rand_u256(), &rand_u256());
+}
+
+// MODULAR ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn add_mod() {
+    for m in test_moduli() {
+        for (a, b) in mod_operands(&m) {
+            let expected = (&a + &b) % &m;
+            assert_mod_op("add_mod", &a, &b, &m, &expected);
+        }
+    }
+}
+
+#[test]
+fn sub_mod() {
+    for m in test_moduli() {
+        for (a, b) in mod_operands(&m) {
+            let expected = (&a + &m - &b) % &m;
+            assert_mod_op("sub_mod", &a, &b, &m, &expected);
+        }
+    }
+}
+
+#[test]
+fn mul_mod() {
+    for m in test_moduli() {
+        let max = BigUint::new(vec![u32::MAX; 8]);
+        let mut operands = mod_operands(&m);
+        operands.truncate(4);
+        // only b is required to be smaller than the modulus
+        operands.push((max, &m - 1_u32));
+
+        for (a, b) in operands {
+            let expected = (&a * &b) % &m;
+            assert_mod_op("mul_mod", &a, &b, &m, &expected);
+        }
+    }
+}
+
+#[test]
+fn montgomery() {
+    for m in test_moduli() {
+        let (n_prime, r2) = mont_params(&m);
+        let (push_m, push_n_prime, push_r2) = (push_u256(&m), push_u256(&n_prime), push_u256(&r2));
+
+        // a and b are converted into Montgomery form, multiplied, and converted back
+        let source = format!(
+            "
+            use.std::math::u256
+            begin
+                {push_r2} {push_n_prime} {push_m} exec.u256::to_mont
+                swapdw
+                {push_r2} {push_n_prime} {push_m} exec.u256::to_mont
+                {push_n_prime} {push_m} exec.u256::mont_mul
+                {push_n_prime} {push_m} exec.u256::from_mont
+            end"
+        );
+
+        for (a, b) in mod_operands(&m) {
+            let mut expected = u256_limbs(&((&a * &b) % &m));
+            expected.reverse();
+            build_test!(&source, &u256_operands(&a, &b)).expect_stack(&expected);
+        }
+    }
+}
 
 // MULTIPLICATION
 // ================================================================================================
@@ -33,10 +141,311 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+#[test]
+fn mul_wide() {
+    let max = BigUint::new(vec![u32::MAX; 8]);
+    let cases = [
+        (rand_u256(), rand_u256()),
+        (max.clone(), max.clone()),
+        (max, BigUint::from(1_u32)),
+        (rand_u256(), BigUint::from(0_u32)),
+    ];
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::mul_wide
+        end";
+
+    for (a, b) in cases {
+        let addr = 1000;
+        let mut operands = vec![addr];
+        operands.extend(u256_operands(&a, &b));
+
+        let mut expected_mem =
+            (a * b).to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+        expected_mem.resize(16, 0);
+
+        build_test!(source, &operands).expect_stack_and_memory(&[], addr as u32, &expected_mem);
+    }
+}
+
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn div_unsafe() {
+    let one = BigUint::from(1_u32);
+    let max = BigUint::new(vec![u32::MAX; 8]);
+    let small = BigUint::from(rand_vector::<u64>(1)[0] as u32 | 1);
+    let cases = [
+        // b = 1
+        (rand_u256(), one.clone()),
+        (max.clone(), one.clone()),
+        // a < b
+        (small.clone(), max.clone()),
+        (BigUint::from(0_u32), rand_u256() | &one),
+        // a = b
+        (max.clone(), max),
+        // random values, including divisors consisting of a single limb
+        (rand_u256(), rand_u256()),
+        (rand_u256(), rand_u256() >> 128),
+        (rand_u256(), small),
+    ];
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div_unsafe
+        end";
+
+    for (a, b) in cases {
+        let (q, r) = (&a / &b, &a % &b);
+        let mut expected = u256_limbs(&q);
+        expected.extend(u256_limbs(&r));
+        expected.reverse();
+
+        build_test!(source, &u256_operands(&a, &b)).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn div_unsafe_by_zero() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div_unsafe
+        end";
+
+    let operands = u256_operands(&rand_u256(), &BigUint::from(0_u32));
+    build_test!(source, &operands).expect_error(ExecutionErrorMatcher::divide_by_zero());
+}
+
+#[test]
+fn div_from_advice() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div_from_advice
+        end";
+
+    // the dividend is much larger than the divisor, so that the quotient is not zero
+    let a = rand_u256() | (BigUint::from(1_u32) << 255);
+    let b = rand_u256() >> 64;
+    let (q, r) = (&a / &b, &a % &b);
+    let operands = u256_operands(&a, &b);
+
+    // correct quotient and remainder are accepted
+    let mut expected = u256_limbs(&q);
+    expected.extend(u256_limbs(&r));
+    let advice_stack = expected.clone();
+    expected.reverse();
+    build_test!(source, &operands, &advice_stack).expect_stack(&expected);
+
+    // hints which are consistent with the dividend but do not satisfy r < b are rejected
+    let one = BigUint::from(1_u32);
+    let invalid_hints = [
+        // quotient is off by one, remainder is increased by b to compensate
+        (&q - &one, &r + &b),
+        // quotient and remainder are off by one
+        (&q + &one, r.clone()),
+        (q.clone(), &r + &one),
+    ];
+    for (q, r) in invalid_hints {
+        let mut advice_stack = u256_limbs(&q);
+        advice_stack.extend(u256_limbs(&r));
+        build_test!(source, &operands, &advice_stack)
+            .expect_error(ExecutionErrorMatcher::failed_assertion());
+    }
+
+    // limbs of the quotient must be 32-bit values
+    let mut advice_stack = u256_limbs(&q);
+    advice_stack[0] += 1 << 32;
+    advice_stack.extend(u256_limbs(&r));
+    build_test!(source, &operands, &advice_stack)
+        .expect_error(ExecutionErrorMatcher::not_u32_value());
+}
+
+#[test]
+fn reduce_mod() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::reduce_mod
+        end";
+
+    for m in test_moduli() {
+        for a in [rand_u256(), &m - 1_u32, m.clone(), &m + 1_u32, BigUint::new(vec![u32::MAX; 8])] {
+            // m + 1 overflows 256 bits for m = 2^256 - 1
+            let a = a % (BigUint::from(1_u32) << 256);
+            let mut expected = u256_limbs(&(&a % &m));
+            expected.reverse();
+
+            build_test!(source, &u256_operands(&a, &m)).expect_stack(&expected);
+        }
+    }
+}
+
+// DIFFERENTIAL TESTS
+// ================================================================================================
+
+/// Binary operation over unbounded integers.
+type BinaryOp = fn(&BigUint, &BigUint) -> BigUint;
+
+/// Binary operation over 256-bit integers returning the wrapped result and the overflow flag.
+type OverflowingOp = fn(&BigUint, &BigUint) -> (BigUint, bool);
+
+#[test]
+fn wrapping_ops_prop_check() {
+    let ops: [(&str, BinaryOp); 3] = [
+        ("add_unsafe", |a, b| a + b),
+        ("sub_unsafe", |a, b| (BigUint::from(1_u32) << 256) + a - b),
+        ("mul_unsafe", |a, b| a * b),
+    ];
+
+    for (proc_name, reference) in ops {
+        let source = format!("use.std::math::u256 begin exec.u256::{proc_name} end");
+        build_test!(&source).prop_check(PROP_CHECK_CASES, strategies::u256_operands(), |inputs| {
+            let a = u256_from_limbs(&inputs[..8]);
+            let b = u256_from_limbs(&inputs[8..]);
+            let c = reference(&a, &b) % (BigUint::from(1_u32) << 256);
+            let mut result = u256_limbs(&c);
+            result.reverse();
+            result
+        });
+    }
+}
+
+#[test]
+fn overflowing_ops_prop_check() {
+    let ops: [(&str, OverflowingOp); 2] = [
+        ("overflowing_add", |a, b| {
+            let c = a + b;
+            (c.clone() % (BigUint::from(1_u32) << 256), c.bits() > 256)
+        }),
+        ("overflowing_sub", |a, b| ((BigUint::from(1_u32) << 256) + a - b, a < b)),
+    ];
+
+    for (proc_name, reference) in ops {
+        let source = format!("use.std::math::u256 begin exec.u256::{proc_name} end");
+        build_test!(&source).prop_check(PROP_CHECK_CASES, strategies::u256_operands(), |inputs| {
+            let a = u256_from_limbs(&inputs[..8]);
+            let b = u256_from_limbs(&inputs[8..]);
+            let (c, flag) = reference(&a, &b);
+            let mut result = u256_limbs(&(c % (BigUint::from(1_u32) << 256)));
+            result.push(flag as u64);
+            result.reverse();
+            result
+        });
+    }
+}
+
+#[test]
+fn cmp_prop_check() {
+    let source = "use.std::math::u256 begin exec.u256::cmp end";
+    build_test!(source).prop_check(PROP_CHECK_CASES, strategies::u256_operands(), |inputs| {
+        let a = u256_from_limbs(&inputs[..8]);
+        let b = u256_from_limbs(&inputs[8..]);
+        match a.cmp(&b) {
+            core::cmp::Ordering::Greater => vec![1],
+            core::cmp::Ordering::Equal => vec![0],
+            core::cmp::Ordering::Less => vec![Felt::MODULUS - 1],
+        }
+    });
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn rand_u256() -> BigUint {
-    let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
-    BigUint::new(limbs)
+/// Checks that `u256::cmp` returns 1 when a > b, 0 when a = b, and -1 when a < b.
+fn assert_cmp(a: &BigUint, b: &BigUint) {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::cmp
+        end";
+
+    let expected = match a.cmp(b) {
+        core::cmp::Ordering::Greater => 1,
+        core::cmp::Ordering::Equal => 0,
+        core::cmp::Ordering::Less => Felt::MODULUS - 1,
+    };
+
+    build_test!(source, &u256_operands(a, b)).expect_stack(&[expected]);
+}
+
+/// Returns the limbs of a and b arranged as stack inputs for u256 procedures, i.e., such that
+/// the most significant limb of b is at the top of the stack.
+fn u256_operands(a: &BigUint, b: &BigUint) -> Vec<u64> {
+    let mut operands = u256_limbs(a);
+    operands.extend(u256_limbs(b));
+    operands
+}
+
+/// Checks that the specified modular operation of `u256` returns the expected result for operands
+/// a and b and modulus m.
+fn assert_mod_op(op: &str, a: &BigUint, b: &BigUint, m: &BigUint, expected: &BigUint) {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{op}
+        end"
+    );
+
+    let mut operands = u256_operands(a, b);
+    operands.extend(u256_limbs(m));
+    let mut result = u256_limbs(expected);
+    result.reverse();
+
+    build_test!(&source, &operands).expect_stack(&result);
+}
+
+/// Returns moduli for testing modular operations: a small prime, the prime of the secp256k1 base
+/// field, and 2^256 - 1.
+fn test_moduli() -> Vec<BigUint> {
+    let secp256k1_prime = (BigUint::from(1_u32) << 256) - (BigUint::from(1_u32) << 32) - 977_u32;
+    vec![BigUint::from(65521_u32), secp256k1_prime, BigUint::new(vec![u32::MAX; 8])]
+}
+
+/// Returns the parameters n' = -m^-1 mod 2^256 and R^2 mod m, with R = 2^256, required for
+/// Montgomery arithmetic modulo the specified odd modulus.
+fn mont_params(m: &BigUint) -> (BigUint, BigUint) {
+    let r = BigUint::from(1_u32) << 256;
+
+    // each Newton iteration doubles the number of correct low bits of m^-1 mod 2^256
+    let mut m_inv = BigUint::from(1_u32);
+    for _ in 0..8 {
+        m_inv = (&m_inv * (&r + 2_u32 - (m * &m_inv) % &r)) % &r;
+    }
+    let n_prime = (&r - m_inv) % &r;
+
+    (n_prime, (&r * &r) % m)
+}
+
+/// Returns a push instruction placing the limbs of the specified 256-bit value onto the stack,
+/// with the most significant limb at the top of the stack.
+fn push_u256(value: &BigUint) -> String {
+    let limbs = u256_limbs(value).iter().map(|limb| limb.to_string()).collect::<Vec<_>>();
+    format!("push.{}", limbs.join("."))
+}
+
+/// Returns pairs of operands smaller than the specified modulus, including edge cases around 0 and
+/// the modulus.
+fn mod_operands(m: &BigUint) -> Vec<(BigUint, BigUint)> {
+    let zero = BigUint::from(0_u32);
+    let one = BigUint::from(1_u32);
+    let max = m - 1_u32;
+    vec![
+        (rand_u256() % m, rand_u256() % m),
+        (max.clone(), max.clone()),
+        (max.clone(), one.clone()),
+        (one, max),
+        (zero.clone(), zero),
+    ]
+}
+
+/// Returns the value represented by the specified 32-bit limbs, least significant limb first.
+fn u256_from_limbs(limbs: &[u64]) -> BigUint {
+    BigUint::new(limbs.iter().map(|&limb| limb as u32).collect())
 }
@@ -0,0 +1,135 @@
+use super::PROP_CHECK_CASES;
+use crate::{
+    build_test,
+    helpers::strategies::{u64_operands, u64_value},
+};
+use test_utils::proptest::prelude::*;
+
+// DIFFERENTIAL TESTS
+// ------------------------------------------------------------------------------------------------
+// Every procedure below is executed against random inputs and its output is compared with the
+// result of the equivalent native Rust operation. The inputs are generated from a fixed seed, which
+// can be overridden via the MIDEN_PROP_CHECK_SEED environment variable. On failure, the program
+// and the minimal failing input (as shrunk by proptest) are reported, together with the seed.
+
+/// Computes the expected stack of a binary operation from its operands `a` and `b`.
+type BinaryOp = fn(u64, u64) -> Vec<u64>;
+
+/// Computes the result of shifting `a` by `b` bits.
+type ShiftOp = fn(u64, u32) -> u64;
+
+/// Computes the expected top of the stack of a unary operation from its operand `a`.
+type UnaryOp = fn(u64) -> u64;
+
+/// Procedures with the signature [b_hi, b_lo, a_hi, a_lo, ...] -> [...], paired with a function
+/// computing the expected stack from `a` and `b`.
+const BINARY_OPS: [(&str, BinaryOp); 12] = [
+    ("wrapping_add", |a, b| limbs(a.wrapping_add(b))),
+    ("overflowing_add", |a, b| with_flag(a.overflowing_add(b))),
+    ("wrapping_sub", |a, b| limbs(a.wrapping_sub(b))),
+    ("overflowing_sub", |a, b| with_flag(a.overflowing_sub(b))),
+    ("wrapping_mul", |a, b| limbs(a.wrapping_mul(b))),
+    ("overflowing_mul", |a, b| wide_limbs(a as u128 * b as u128)),
+    ("unchecked_div", |a, b| limbs(a / b)),
+    ("unchecked_mod", |a, b| limbs(a % b)),
+    ("unchecked_divmod", |a, b| [limbs(a % b), limbs(a / b)].concat()),
+    ("checked_and", |a, b| limbs(a & b)),
+    ("checked_or", |a, b| limbs(a | b)),
+    ("checked_xor", |a, b| limbs(a ^ b)),
+];
+
+/// Procedures with the signature [b, a_hi, a_lo, ...] -> [c_hi, c_lo, ...] where b < 64, paired
+/// with a function computing `c` from `a` and `b`.
+const SHIFT_OPS: [(&str, ShiftOp); 4] = [
+    ("unchecked_shl", u64::wrapping_shl),
+    ("unchecked_shr", u64::wrapping_shr),
+    ("unchecked_rotl", u64::rotate_left),
+    ("unchecked_rotr", u64::rotate_right),
+];
+
+/// Procedures with the signature [a_hi, a_lo, ...] -> [c, ...], paired with a function computing
+/// `c` from `a`.
+const UNARY_OPS: [(&str, UnaryOp); 3] = [
+    ("popcount", |a| a.count_ones() as u64),
+    ("leading_zeros", |a| a.leading_zeros() as u64),
+    ("trailing_zeros", |a| a.trailing_zeros() as u64),
+];
+
+#[test]
+fn u64_binary_ops_match_rust() {
+    for (name, expected) in BINARY_OPS {
+        // division by zero is not defined, so the divisor is drawn from non-zero values only
+        let b = match name {
+            "unchecked_div" | "unchecked_mod" | "unchecked_divmod" => {
+                u64_value().prop_filter("divisor must not be zero", |b| *b != 0).boxed()
+            }
+            _ => u64_value().boxed(),
+        };
+
+        build_test!(&build_source(name)).prop_check(PROP_CHECK_CASES, u64_operands(b), |inputs| {
+            expected(join_u64(inputs[1], inputs[0]), join_u64(inputs[3], inputs[2]))
+        });
+    }
+}
+
+#[test]
+fn u64_shift_ops_match_rust() {
+    // an extra element is placed below the operands to make sure it is left untouched
+    let inputs = (u64_value(), 0..64_u64).prop_map(|(a, b)| vec![5, a as u32 as u64, a >> 32, b]);
+
+    for (name, expected) in SHIFT_OPS {
+        build_test!(&build_source(name)).prop_check(PROP_CHECK_CASES, &inputs, |inputs| {
+            let c = expected(join_u64(inputs[2], inputs[1]), inputs[3] as u32);
+            [limbs(c), vec![5]].concat()
+        });
+    }
+}
+
+#[test]
+fn u64_unary_ops_match_rust() {
+    let inputs = u64_value().prop_map(|a| vec![5, a as u32 as u64, a >> 32]);
+
+    for (name, expected) in UNARY_OPS {
+        build_test!(&build_source(name)).prop_check(PROP_CHECK_CASES, &inputs, |inputs| {
+            vec![expected(join_u64(inputs[2], inputs[1])), 5]
+        });
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+fn build_source(name: &str) -> String {
+    format!(
+        "
+        use.std::math::u64
+        begin
+            exec.u64::{name}
+        end"
+    )
+}
+
+/// Returns [c_hi, c_lo] for the specified value.
+fn limbs(value: u64) -> Vec<u64> {
+    let (hi, lo) = split_u64(value);
+    vec![hi, lo]
+}
+
+/// Returns [flag, c_hi, c_lo] for the result of an overflowing operation.
+fn with_flag((value, flag): (u64, bool)) -> Vec<u64> {
+    [vec![flag as u64], limbs(value)].concat()
+}
+
+/// Returns the 4 32-bit limbs of the specified value, most significant limb first.
+fn wide_limbs(value: u128) -> Vec<u64> {
+    [limbs((value >> 64) as u64), limbs(value as u64)].concat()
+}
+
+/// Returns the value with the specified high and low 32-bit limbs.
+fn join_u64(hi: u64, lo: u64) -> u64 {
+    (hi << 32) | lo
+}
+
+fn split_u64(value: u64) -> (u64, u64) {
+    (value >> 32, value as u32 as u64)
+}
@@ -1,6 +1,10 @@
-use crate::build_test;
+use super::PROP_CHECK_CASES;
+use crate::{
+    build_test,
+    helpers::strategies::{u64_operands, u64_value},
+};
 use core::cmp;
-use test_utils::{proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
+use test_utils::{proptest::prelude::*, rand::rand_value, ExecutionErrorMatcher, U32_BOUND};
 
 // ADDITION
 // ------------------------------------------------------------------------------------------------
@@ -65,7 +69,7 @@ fn checked_add_fail() {
     let b1 = u32::MAX as u64;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
 
     // u32 limb assertion failure
     let a0 = rand_value::<u64>();
@@ -74,7 +78,7 @@ fn checked_add_fail() {
     let b1 = U32_BOUND;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test.expect_error(ExecutionErrorMatcher::not_u32_value());
 }
 
 #[test]
@@ -174,7 +178,7 @@ fn checked_sub_fail() {
     let b1 = u32::MAX as u64;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
 
     // u32 limb assertion failure
     let a0 = rand_value::<u64>();
@@ -183,7 +187,7 @@ fn checked_sub_fail() {
     let b1 = U32_BOUND;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test.expect_error(ExecutionErrorMatcher::not_u32_value());
 }
 
 #[test]
@@ -303,7 +307,7 @@ fn checked_mul_fail() {
         let mut stack_init = [1, 2, 3, 4];
         stack_init[i] = U32_BOUND;
         let test = build_test!(source, &stack_init);
-        test.expect_error(TestError::ExecutionError("NotU32Value"));
+        test.expect_error(ExecutionErrorMatcher::not_u32_value());
     }
 
     // Higher bits assertion failure (a_hi * b_hi != 0)
@@ -314,7 +318,7 @@ fn checked_mul_fail() {
     let b1 = 3u64;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
 
     // result overflow
     let a0 = rand_value::<u64>() as u32 as u64;
@@ -323,7 +327,7 @@ fn checked_mul_fail() {
     let b1 = 0u64;
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
 }
 
 #[test]
@@ -363,6 +367,135 @@ fn overflowing_mul() {
     test.expect_stack(&[c3, c2, c1, c0]);
 }
 
+#[test]
+fn mulhi_mullo() {
+    let mulhi = "
+    use.std::math::u64
+    begin
+        exec.u64::mulhi
+    end";
+
+    let mullo = "
+    use.std::math::u64
+    begin
+        exec.u64::mullo
+    end";
+
+    let max = u64::MAX;
+    for (a, b) in [(max, max), (max, 1), (0, max), (rand_value::<u64>(), rand_value::<u64>())] {
+        let c = a as u128 * b as u128;
+        let (a1, a0) = split_u64(a);
+        let (b1, b0) = split_u64(b);
+        let (c3, c2, c1, c0) = split_u128(c);
+
+        build_test!(mulhi, &[a0, a1, b0, b1]).expect_stack(&[c3, c2]);
+        build_test!(mullo, &[a0, a1, b0, b1]).expect_stack(&[c1, c0]);
+    }
+}
+
+// EXPONENTIATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn wrapping_pow() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::wrapping_pow
+        end";
+
+    let a: u64 = rand_value();
+    let e = rand_value::<u64>() % 64;
+    let (a1, a0) = split_u64(a);
+    let (c1, c0) = split_u64(a.wrapping_pow(e as u32));
+
+    let test = build_test!(source, &[a0, a1, e]);
+    test.expect_stack(&[c1, c0]);
+
+    // 4^32 = 2^64 wraps around to zero
+    let test = build_test!(source, &[4, 0, 32]);
+    test.expect_stack(&[0, 0]);
+}
+
+#[test]
+fn checked_pow() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_pow
+        end";
+
+    // 2^63 is the largest power of two which fits into 64 bits
+    let test = build_test!(source, &[2, 0, 63]);
+    test.expect_stack(&[1 << 31, 0]);
+
+    // 3^40 < 2^64
+    let (c1, c0) = split_u64(3_u64.pow(40));
+    let test = build_test!(source, &[3, 0, 40]);
+    test.expect_stack(&[c1, c0]);
+
+    // exponent 0
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+    let test = build_test!(source, &[a0, a1, 0]);
+    test.expect_stack(&[0, 1]);
+
+    let test = build_test!(source, &[0, 0, 0]);
+    test.expect_stack(&[0, 1]);
+
+    // base 0
+    let e = rand_value::<u64>() % 63 + 1;
+    let test = build_test!(source, &[0, 0, e]);
+    test.expect_stack(&[0, 0]);
+}
+
+#[test]
+fn checked_pow_fail() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_pow
+        end";
+
+    // 4^32 = 2^64 overflows
+    let test = build_test!(source, &[4, 0, 32]);
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
+
+    // (2^32 + 1)^2 overflows even though the result wraps around to a small value
+    let test = build_test!(source, &[1, 1, 2]);
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
+
+    // the exponent must be smaller than 64
+    let test = build_test!(source, &[1, 0, 64]);
+    test.expect_error(ExecutionErrorMatcher::failed_assertion());
+}
+
+#[test]
+fn overflowing_pow() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::overflowing_pow
+        end";
+
+    // 2^63 does not overflow, but 4^32 = 2^64 does
+    let test = build_test!(source, &[2, 0, 63]);
+    test.expect_stack(&[0, 1 << 31, 0]);
+
+    let test = build_test!(source, &[4, 0, 32]);
+    test.expect_stack(&[1, 0, 0]);
+
+    // a random base likely overflows; compare against Rust semantics
+    let a: u64 = rand_value();
+    let e = rand_value::<u64>() % 64;
+    let (c, overflow) = a.overflowing_pow(e as u32);
+    let (a1, a0) = split_u64(a);
+    let (c1, c0) = split_u64(c);
+
+    let test = build_test!(source, &[a0, a1, e]);
+    test.expect_stack(&[overflow as u64, c1, c0]);
+}
+
 // COMPARISONS
 // ------------------------------------------------------------------------------------------------
 
@@ -601,6 +734,29 @@ fn unchecked_div() {
     test.expect_stack(&[d1, d0]);
 }
 
+#[test]
+fn unchecked_div_preserves_advice() {
+    let a: u64 = rand_value();
+    let b: u64 = rand_value();
+    let c = a / b;
+
+    // the quotient and the remainder are injected by the procedure itself; advice supplied by the
+    // caller is left for the caller to consume
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::unchecked_div
+            adv_push.2
+        end";
+
+    let (a1, a0) = split_u64(a);
+    let (b1, b0) = split_u64(b);
+    let (c1, c0) = split_u64(c);
+
+    let test = build_test!(source, &[a0, a1, b0, b1]).with_advice_stack(&[7, 8]);
+    test.expect_stack(&[8, 7, c1, c0]);
+}
+
 #[test]
 fn checked_div_fail() {
     let source = "
@@ -614,8 +770,12 @@ fn checked_div_fail() {
         let mut stack_init = [1, 2, 3, 4];
         stack_init[i] = U32_BOUND;
         let test = build_test!(source, &stack_init);
-        test.expect_error(TestError::ExecutionError("NotU32Value"));
+        test.expect_error(ExecutionErrorMatcher::not_u32_value());
     }
+
+    // division by zero
+    let test = build_test!(source, &[1, 2, 0, 0]);
+    test.expect_error(ExecutionErrorMatcher::divide_by_zero());
 }
 
 // MODULO OPERATION
@@ -660,7 +820,7 @@ fn checked_mod_fail() {
         let mut stack_init = [1, 2, 3, 4];
         stack_init[i] = U32_BOUND;
         let test = build_test!(source, &stack_init);
-        test.expect_error(TestError::ExecutionError("NotU32Value"));
+        test.expect_error(ExecutionErrorMatcher::not_u32_value());
     }
 }
 
@@ -687,6 +847,17 @@ fn unchecked_divmod() {
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
     test.expect_stack(&[r1, r0, q1, q0]);
+
+    // advice supplied by the caller is not consumed by the procedure
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::unchecked_divmod
+            adv_push.2
+        end";
+
+    let test = build_test!(source, &[a0, a1, b0, b1]).with_advice_stack(&[7, 8]);
+    test.expect_stack(&[8, 7, r1, r0, q1, q0]);
 }
 
 #[test]
@@ -702,7 +873,7 @@ fn checked_divmod_fail() {
         let mut stack_init = [1, 2, 3, 4];
         stack_init[i] = U32_BOUND;
         let test = build_test!(source, &stack_init);
-        test.expect_error(TestError::ExecutionError("NotU32Value"));
+        test.expect_error(ExecutionErrorMatcher::not_u32_value());
     }
 }
 
@@ -744,7 +915,7 @@ fn checked_and_fail() {
         end";
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test.expect_error(ExecutionErrorMatcher::not_u32_value());
 }
 
 #[test]
@@ -782,7 +953,7 @@ fn checked_or_fail() {
         end";
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test.expect_error(ExecutionErrorMatcher::not_u32_value());
 }
 
 #[test]
@@ -820,7 +991,7 @@ fn checked_xor_fail() {
         end";
 
     let test = build_test!(source, &[a0, a1, b0, b1]);
-    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    test.expect_error(ExecutionErrorMatcher::not_u32_value());
 }
 
 #[test]
@@ -1106,6 +1277,13 @@ fn unchecked_rotl() {
     let (c1, c0) = split_u64(c);
 
     build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+
+    // shift by 64 (the same as shift by 0)
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+    let b: u32 = 64;
+
+    build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[a1, a0, 5]);
 }
 
 #[test]
@@ -1156,12 +1334,137 @@ fn unchecked_rotr() {
     let (c1, c0) = split_u64(c);
 
     build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+
+    // shift by 64 (the same as shift by 0)
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+    let b: u32 = 64;
+
+    build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[a1, a0, 5]);
+}
+
+#[test]
+fn shifts_and_rotations_by_all_amounts() {
+    // amounts which move bits across the limb boundary (e.g., 31, 32, 33) are the most likely to
+    // be handled incorrectly, so all amounts are checked for values with bits set in both limbs
+    let ops: [(&str, fn(u64, u32) -> u64); 4] = [
+        ("unchecked_shl", u64::wrapping_shl),
+        ("unchecked_shr", u64::wrapping_shr),
+        ("unchecked_rotl", u64::rotate_left),
+        ("unchecked_rotr", u64::rotate_right),
+    ];
+    let values = [0, 1, u64::MAX, 0x8000_0001_8000_0001, 0x0123_4567_89ab_cdef, rand_value()];
+
+    for (name, expected) in ops {
+        let source = format!(
+            "
+            use.std::math::u64
+            begin
+                exec.u64::{name}
+            end"
+        );
+
+        for a in values {
+            let (a1, a0) = split_u64(a);
+            for b in 0..64 {
+                let (c1, c0) = split_u64(expected(a, b));
+                build_test!(&source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+            }
+        }
+    }
+}
+
+// BIT COUNTING
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn popcount() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::popcount
+        end";
+
+    for a in [0, 1, u64::MAX, 1 << 63, rand_value()] {
+        let (a1, a0) = split_u64(a);
+        build_test!(source, &[5, a0, a1]).expect_stack(&[a.count_ones() as u64, 5]);
+    }
+}
+
+#[test]
+fn leading_zeros() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::leading_zeros
+        end";
+
+    for a in [0, 1, u64::MAX, u32::MAX as u64, 1 << 32, rand_value()] {
+        let (a1, a0) = split_u64(a);
+        build_test!(source, &[5, a0, a1]).expect_stack(&[a.leading_zeros() as u64, 5]);
+    }
+}
+
+#[test]
+fn trailing_zeros() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::trailing_zeros
+        end";
+
+    for a in [0, 1, u64::MAX, 1 << 32, 1 << 63, rand_value()] {
+        let (a1, a0) = split_u64(a);
+        build_test!(source, &[5, a0, a1]).expect_stack(&[a.trailing_zeros() as u64, 5]);
+    }
+}
+
+#[test]
+fn bit_counting_fail() {
+    for name in ["popcount", "leading_zeros", "trailing_zeros"] {
+        let source = format!(
+            "
+            use.std::math::u64
+            begin
+                exec.u64::{name}
+            end"
+        );
+
+        let test = build_test!(&source, &[rand_value::<u32>() as u64, U32_BOUND]);
+        test.expect_error(ExecutionErrorMatcher::not_u32_value());
+    }
 }
 
 // RANDOMIZED TESTS
 // ================================================================================================
 
 proptest! {
+    #[test]
+    fn mulhi_mullo_proptest(a in any::<u64>(), b in any::<u64>()) {
+
+        let (a1, a0) = split_u64(a);
+        let (b1, b0) = split_u64(b);
+        let c = a as u128 * b as u128;
+
+        let source = "
+            use.std::math::u64
+            begin
+                exec.u64::mulhi
+            end";
+
+        let (c1, c0) = split_u64((c >> 64) as u64);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[c1, c0])?;
+
+        let source = "
+            use.std::math::u64
+            begin
+                exec.u64::mullo
+            end";
+
+        let (c1, c0) = split_u64(c as u64);
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[c1, c0])?;
+    }
+
     #[test]
     fn unchecked_lt_proptest(a in any::<u64>(), b in any::<u64>()) {
 
@@ -1296,7 +1599,7 @@ proptest! {
     }
 
     #[test]
-    fn rotl_proptest(a in any::<u64>(), b in 0_u32..64) {
+    fn rotl_proptest(a in any::<u64>(), b in 0_u32..=64) {
 
         let c = a.rotate_left(b);
 
@@ -1313,7 +1616,7 @@ proptest! {
     }
 
     #[test]
-    fn rotr_proptest(a in any::<u64>(), b in 0_u32..64) {
+    fn rotr_proptest(a in any::<u64>(), b in 0_u32..=64) {
 
         let c = a.rotate_right(b);
 
@@ -1330,9 +1633,64 @@ proptest! {
     }
 }
 
+// DIFFERENTIAL TESTS
+// ================================================================================================
+
+/// Binary operation over 64-bit integers.
+type BinaryOp = fn(u64, u64) -> u64;
+
+#[test]
+fn wrapping_ops_prop_check() {
+    let ops: [(&str, BinaryOp); 3] = [
+        ("wrapping_add", u64::wrapping_add),
+        ("wrapping_sub", u64::wrapping_sub),
+        ("wrapping_mul", u64::wrapping_mul),
+    ];
+
+    for (proc_name, reference) in ops {
+        let source = format!("use.std::math::u64 begin exec.u64::{proc_name} end");
+        build_test!(&source).prop_check(PROP_CHECK_CASES, u64_operands(u64_value()), |inputs| {
+            let (a, b) = join_u64_operands(inputs);
+            let (c1, c0) = split_u64(reference(a, b));
+            vec![c1, c0]
+        });
+    }
+}
+
+#[test]
+fn overflowing_mul_prop_check() {
+    let source = "use.std::math::u64 begin exec.u64::overflowing_mul end";
+    build_test!(source).prop_check(PROP_CHECK_CASES, u64_operands(u64_value()), |inputs| {
+        let (a, b) = join_u64_operands(inputs);
+        let (c3, c2, c1, c0) = split_u128(a as u128 * b as u128);
+        vec![c3, c2, c1, c0]
+    });
+}
+
+#[test]
+fn unchecked_divmod_prop_check() {
+    // the divisor must not be 0
+    let inputs = u64_operands(u64_value().prop_filter("divisor must not be zero", |b| *b != 0));
+
+    let source = "use.std::math::u64 begin exec.u64::unchecked_divmod end";
+    build_test!(source).prop_check(PROP_CHECK_CASES, inputs, |inputs| {
+        let (a, b) = join_u64_operands(inputs);
+        let (r1, r0) = split_u64(a % b);
+        let (q1, q0) = split_u64(a / b);
+        vec![r1, r0, q1, q0]
+    });
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Joins the limbs of two u64 values arranged as stack inputs for u64 procedures.
+fn join_u64_operands(inputs: &[u64]) -> (u64, u64) {
+    let a = (inputs[1] << 32) | inputs[0];
+    let b = (inputs[3] << 32) | inputs[2];
+    (a, b)
+}
+
 /// Split the provided u64 value into 32 high and low bits.
 fn split_u64(value: u64) -> (u64, u64) {
     (value >> 32, value as u32 as u64)
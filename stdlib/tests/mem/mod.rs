@@ -1,6 +1,6 @@
 use test_utils::{
-    build_expected_hash, build_expected_perm, stack_to_ints, AdviceInputs, MemAdviceProvider,
-    Process, StackInputs, ONE, ZERO,
+    build_expected_hash, build_expected_perm, rand::rand_vector, stack_to_ints, AdviceInputs, Felt,
+    MemAdviceProvider, Process, StackInputs, StarkField, ONE, ZERO,
 };
 
 #[test]
@@ -123,6 +123,33 @@ fn test_pipe_words_to_memory() {
     );
 }
 
+#[test]
+fn test_pipe_words_to_memory_random() {
+    let mem_addr = 1000;
+    let source = format!(
+        "use.std::mem
+
+        begin
+            push.{} # target address
+            push.64 # number of words
+
+            exec.mem::pipe_words_to_memory
+        end",
+        mem_addr
+    );
+
+    // load 256 random field elements and make sure the returned commitment matches the hash of
+    // these elements computed natively
+    let data = rand_vector::<Felt>(256).iter().map(|v| v.as_int()).collect::<Vec<_>>();
+    let mut expected_stack = stack_to_ints(&build_expected_hash(&data));
+    expected_stack.push(1064);
+    build_test!(&source).with_advice_stack(&data).expect_stack_and_memory(
+        &expected_stack,
+        mem_addr,
+        &data,
+    );
+}
+
 #[test]
 fn test_pipe_preimage_to_memory() {
     let mem_addr = 1000;
@@ -0,0 +1,19 @@
+use super::{rand::rand_vector, Vec};
+
+pub use num_bigint::BigUint;
+
+// 256-BIT INTEGERS
+// ================================================================================================
+
+/// Returns the 8 32-bit limbs of the specified value, least significant limb first.
+pub fn u256_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns a random 256-bit unsigned integer.
+pub fn rand_u256() -> BigUint {
+    let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
+    BigUint::new(limbs)
+}
@@ -0,0 +1,145 @@
+use super::{ExecutionError, StarkField, String};
+use core::{
+    fmt,
+    mem::{discriminant, Discriminant},
+    ops::RangeInclusive,
+};
+
+// EXECUTION ERROR MATCHER
+// ================================================================================================
+
+/// Describes the [ExecutionError] which a test is expected to fail with.
+///
+/// A matcher always checks the variant of the error, and can additionally check the error code
+/// and the clock cycle at which the error occurred (for the variants which carry them). For
+/// example, a failed assertion with error code 2 which occurs within the first 100 cycles can be
+/// expected as follows:
+///
+/// `ExecutionErrorMatcher::failed_assertion().with_err_code(2).with_clk_range(0..=100)`
+///
+/// Errors of variants without a dedicated constructor can be matched via
+/// [ExecutionErrorMatcher::variant_of()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionErrorMatcher {
+    variant: Discriminant<ExecutionError>,
+    variant_name: String,
+    err_code: Option<u64>,
+    clk_range: Option<RangeInclusive<u32>>,
+}
+
+impl ExecutionErrorMatcher {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a matcher for [ExecutionError::DivideByZero] errors.
+    pub fn divide_by_zero() -> Self {
+        Self::variant_of(&ExecutionError::DivideByZero(0))
+    }
+
+    /// Returns a matcher for [ExecutionError::FailedAssertion] errors.
+    pub fn failed_assertion() -> Self {
+        Self::variant_of(&ExecutionError::FailedAssertion {
+            clk: 0,
+            err_code: Default::default(),
+        })
+    }
+
+    /// Returns a matcher for [ExecutionError::NotBinaryValue] errors.
+    pub fn not_binary_value() -> Self {
+        Self::variant_of(&ExecutionError::NotBinaryValue(Default::default()))
+    }
+
+    /// Returns a matcher for [ExecutionError::NotU32Value] errors.
+    pub fn not_u32_value() -> Self {
+        Self::variant_of(&ExecutionError::NotU32Value(Default::default(), 0, Default::default()))
+    }
+
+    /// Returns a matcher for errors of the same variant as the specified error.
+    ///
+    /// Only the variant of `error` is used; the data it carries (e.g., the clock cycle at which
+    /// the error occurred) is ignored.
+    pub fn variant_of(error: &ExecutionError) -> Self {
+        // the name of the variant is the leading identifier of the debug representation
+        let debug = format!("{error:?}");
+        let variant_name = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+
+        Self {
+            variant: discriminant(error),
+            variant_name: variant_name.into(),
+            err_code: None,
+            clk_range: None,
+        }
+    }
+
+    // BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this matcher which additionally requires the error to carry the specified error
+    /// code.
+    pub fn with_err_code(mut self, err_code: u64) -> Self {
+        self.err_code = Some(err_code);
+        self
+    }
+
+    /// Returns this matcher which additionally requires the error to occur at the specified clock
+    /// cycle.
+    pub fn with_clk(self, clk: u32) -> Self {
+        self.with_clk_range(clk..=clk)
+    }
+
+    /// Returns this matcher which additionally requires the error to occur at a clock cycle within
+    /// the specified range.
+    pub fn with_clk_range(mut self, clk_range: RangeInclusive<u32>) -> Self {
+        self.clk_range = Some(clk_range);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the specified error satisfies all requirements of this matcher.
+    ///
+    /// An error which does not carry an error code (or a clock cycle) does not match a matcher
+    /// which requires one.
+    pub fn matches(&self, error: &ExecutionError) -> bool {
+        let (clk, err_code) = match error {
+            ExecutionError::AdviceStackReadFailed(clk)
+            | ExecutionError::AdviceStreamFailed(clk, _)
+            | ExecutionError::DivideByZero(clk) => (Some(*clk), None),
+            ExecutionError::FailedAssertion { clk, err_code }
+            | ExecutionError::NotU32Value(_, clk, err_code) => {
+                (Some(*clk), Some(err_code.as_int()))
+            }
+            // the remaining variants carry neither a clock cycle nor an error code
+            _ => (None, None),
+        };
+
+        let err_code_matches = match self.err_code {
+            Some(expected) => err_code == Some(expected),
+            None => true,
+        };
+        let clk_matches = match &self.clk_range {
+            Some(range) => clk.map_or(false, |clk| range.contains(&clk)),
+            None => true,
+        };
+
+        discriminant(error) == self.variant && err_code_matches && clk_matches
+    }
+}
+
+impl fmt::Display for ExecutionErrorMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.variant_name)?;
+        if let Some(err_code) = self.err_code {
+            write!(f, " with error code {err_code}")?;
+        }
+        if let Some(range) = &self.clk_range {
+            if range.start() == range.end() {
+                write!(f, " at clock cycle {}", range.start())?;
+            } else {
+                write!(f, " at clock cycle in {}..={}", range.start(), range.end())?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -43,11 +43,17 @@ pub mod serde {
     };
 }
 
+#[cfg(not(target_family = "wasm"))]
+pub mod bigint;
+
 pub mod crypto;
 
 #[cfg(not(target_family = "wasm"))]
 pub mod rand;
 
+mod error_matcher;
+pub use error_matcher::ExecutionErrorMatcher;
+
 mod test_builders;
 pub use test_builders::*;
 
@@ -65,16 +71,31 @@ pub type QuadFelt = vm_core::QuadExtension<Felt>;
 /// A value just over what a [u32] integer can hold.
 pub const U32_BOUND: u64 = u32::MAX as u64 + 1;
 
+/// Name of the environment variable which overrides the seed of the random generator used by
+/// [Test::prop_check()].
+pub const PROP_CHECK_SEED_VAR: &str = "MIDEN_PROP_CHECK_SEED";
+
+/// Seed of the random generator used by [Test::prop_check()] when [PROP_CHECK_SEED_VAR] is not
+/// set, so that property checks see the same inputs on every run.
+pub const DEFAULT_PROP_CHECK_SEED: u64 = 0x6d69_6465_6e76_6d00;
+
 // TEST HANDLER
 // ================================================================================================
 
 /// This is used to specify the expected error type when using Test to test errors.
 /// `Test::expect_error` will try to either compile or execute the test data, according to the
 /// provided TestError variant. Then it will validate that the resulting error contains the
-/// TestError variant's string slice.
+/// TestError variant's string slice, or that it satisfies the provided [ExecutionErrorMatcher].
 pub enum TestError<'a> {
     AssemblyError(&'a str),
     ExecutionError(&'a str),
+    ExecutionErrorMatcher(ExecutionErrorMatcher),
+}
+
+impl From<ExecutionErrorMatcher> for TestError<'_> {
+    fn from(matcher: ExecutionErrorMatcher) -> Self {
+        Self::ExecutionErrorMatcher(matcher)
+    }
 }
 
 /// This is a container for the data required to run tests, which allows for running several
@@ -89,7 +110,8 @@ pub enum TestError<'a> {
 /// - Assembly error test: check that attempting to compile the given source causes an
 /// AssemblyError which contains the specified substring.
 /// - Execution error test: check that running a program compiled from the given source causes
-///   an ExecutionError which contains the specified substring.
+///   an ExecutionError which contains the specified substring, or which satisfies the specified
+///   [ExecutionErrorMatcher].
 pub struct Test {
     pub source: String,
     pub kernel: Option<String>,
@@ -115,14 +137,60 @@ impl Test {
         }
     }
 
+    // BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this test with the specified values added to the advice stack.
+    ///
+    /// The values are read by the program in the order in which they are provided (i.e., the
+    /// first value is at the top of the advice stack), after the values added previously.
+    ///
+    /// # Panics
+    /// Panics if any of the values is not a valid field element.
+    pub fn with_advice_stack(mut self, values: &[u64]) -> Self {
+        self.advice_inputs = self
+            .advice_inputs
+            .with_stack_values(values.iter().copied())
+            .expect("invalid advice stack values");
+        self
+    }
+
+    /// Returns this test with the specified values added to the advice map under the specified
+    /// key, replacing the values previously added under this key.
+    ///
+    /// # Panics
+    /// Panics if any of the values is not a valid field element.
+    pub fn with_advice_map(mut self, key: Word, values: &[u64]) -> Self {
+        let values = values
+            .iter()
+            .map(|&value| {
+                assert!(value < Felt::MODULUS, "invalid advice map value: {value}");
+                Felt::new(value)
+            })
+            .collect();
+        self.advice_inputs.extend_map([(key.into_bytes(), values)]);
+        self
+    }
+
+    /// Returns this test with the nodes of the specified Merkle store added to the Merkle store
+    /// of the advice provider.
+    pub fn with_merkle_store(mut self, store: &crypto::MerkleStore) -> Self {
+        self.advice_inputs.extend_merkle_store(store.inner_nodes());
+        self
+    }
+
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
     /// Asserts that running the test for the expected TestError variant will result in an error
-    /// that contains the TestError's error substring in its error message.
+    /// that contains the TestError's error substring in its error message, or that satisfies the
+    /// TestError's [ExecutionErrorMatcher].
+    ///
+    /// An [ExecutionErrorMatcher] can be passed directly, e.g.,
+    /// `test.expect_error(ExecutionErrorMatcher::failed_assertion().with_err_code(2))`.
     #[cfg(all(feature = "std", not(target_family = "wasm")))]
-    pub fn expect_error(&self, error: TestError) {
-        match error {
+    pub fn expect_error<'a>(&self, error: impl Into<TestError<'a>>) {
+        match error.into() {
             TestError::AssemblyError(substr) => {
                 assert_eq!(
                     std::panic::catch_unwind(|| self.compile())
@@ -139,6 +207,13 @@ impl Test {
                     Some(true)
                 );
             }
+            TestError::ExecutionErrorMatcher(matcher) => match self.execute() {
+                Ok(_) => panic!("Expected execution to fail with {matcher}, but it succeeded"),
+                Err(err) => assert!(
+                    matcher.matches(&err),
+                    "Expected execution to fail with {matcher}, found {err:?}"
+                ),
+            },
         }
     }
 
@@ -186,6 +261,71 @@ impl Test {
         self.expect_stack(final_stack);
     }
 
+    /// Executes the test against `num_cases` sets of random stack inputs and asserts that the
+    /// resulting stack matches the one computed by the `reference` function for the same inputs.
+    ///
+    /// Stack inputs are generated by the `inputs` strategy (in the same order as for
+    /// `build_test!`, i.e., the last input ends up at the top of the stack), and `reference`
+    /// returns the expected top of the stack in the same format as [Test::expect_stack()].
+    ///
+    /// The random generator is seeded with [DEFAULT_PROP_CHECK_SEED], unless another seed is set
+    /// via the [PROP_CHECK_SEED_VAR] environment variable; thus, by default, the same inputs are
+    /// checked on every run. If a case fails, its inputs are shrunk by proptest. The panic message
+    /// contains the minimal failing inputs, as well as the seed of the random generator.
+    /// Other inputs can be checked by setting [PROP_CHECK_SEED_VAR] to a different seed.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn prop_check<S, R>(&self, num_cases: u32, inputs: S, reference: R)
+    where
+        S: Strategy<Value = Vec<u64>>,
+        R: Fn(&[u64]) -> Vec<u64>,
+    {
+        use proptest::{
+            prelude::TestCaseError,
+            test_runner::{Config, RngAlgorithm, TestRng, TestRunner},
+        };
+
+        let seed = match std::env::var(PROP_CHECK_SEED_VAR) {
+            Ok(seed) => seed.parse::<u64>().expect("invalid prop check seed"),
+            Err(_) => DEFAULT_PROP_CHECK_SEED,
+        };
+        let mut seed_bytes = [0_u8; 32];
+        seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
+        let config = Config {
+            cases: num_cases,
+            failure_persistence: None,
+            ..Config::default()
+        };
+        let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &seed_bytes);
+        let mut runner = TestRunner::new_with_rng(config, rng);
+
+        let program = self.compile();
+        let result = runner.run(&inputs, |inputs| {
+            let result = self
+                .execute_program(&program, &inputs)
+                .map_err(|err| TestCaseError::fail(format!("execution failed: {err}")))?;
+            proptest::prop_assert_eq!(result, stack_top_to_ints(&reference(&inputs)));
+            Ok(())
+        });
+
+        if let Err(err) = result {
+            panic!(
+                "Property check of program `{}` failed: {err}\n\
+                (reproduce with {PROP_CHECK_SEED_VAR}={seed})",
+                self.source.trim()
+            );
+        }
+    }
+
+    /// Asserts that executing the test twice results in identical execution traces; see
+    /// [assert_deterministic()].
+    pub fn expect_deterministic(&self) {
+        assert_deterministic(
+            &self.compile(),
+            self.stack_inputs.clone(),
+            self.advice_inputs.clone(),
+        );
+    }
+
     /// Asserts that executing the test inside a proptest results in the expected final stack state.
     /// The proptest will return a test failure instead of panicking if the assertion condition
     /// fails.
@@ -267,6 +407,18 @@ impl Test {
         processor::execute_iter(&program, self.stack_inputs.clone(), advice_provider)
     }
 
+    /// Executes the provided program with the specified stack inputs and the advice inputs of
+    /// this test, and returns the final state of the top of the stack.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    fn execute_program(&self, program: &Program, inputs: &[u64]) -> Result<Vec<u64>, String> {
+        let stack_inputs =
+            StackInputs::try_from_values(inputs.iter().copied()).map_err(|err| err.to_string())?;
+        let advice_provider = MemAdviceProvider::from(self.advice_inputs.clone());
+        let trace = processor::execute(program, stack_inputs, advice_provider)
+            .map_err(|err| err.to_string())?;
+        Ok(stack_to_ints(&trace.last_stack_state()))
+    }
+
     /// Returns the last state of the stack after executing a test.
     pub fn get_last_stack_state(&self) -> [Felt; STACK_TOP_SIZE] {
         let trace = self.execute().unwrap();
@@ -289,6 +441,49 @@ pub fn stack_top_to_ints(values: &[u64]) -> Vec<u64> {
     result
 }
 
+/// Executes the provided program twice against the same stack and advice inputs, and asserts
+/// that both executions result in the same program hash, stack outputs, and main trace.
+///
+/// Random values injected into the last rows of the main trace are derived from the program
+/// hash, and thus, are the same for both executions; nevertheless, these rows are zeroed before
+/// the traces are compared, so that any difference detected by this function is caused by the
+/// execution itself.
+///
+/// # Panics
+/// Panics if either of the executions fails, or if the executions result in different traces; in
+/// the latter case, the panic message contains the first column and row at which the main traces
+/// differ.
+pub fn assert_deterministic(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+) {
+    let execute = || {
+        let advice_provider = MemAdviceProvider::from(advice_inputs.clone());
+        processor::execute(program, stack_inputs.clone(), advice_provider)
+            .expect("failed to execute program")
+            .without_random_rows()
+    };
+    let first = execute();
+    let second = execute();
+
+    assert_eq!(first.program_hash(), second.program_hash(), "program hashes differ");
+    assert_eq!(first.stack_outputs(), second.stack_outputs(), "stack outputs differ");
+
+    let (first, second) = (first.main_matrix(), second.main_matrix());
+    assert_eq!(first.num_rows(), second.num_rows(), "main trace lengths differ");
+    assert_eq!(first.num_cols(), second.num_cols(), "main trace widths differ");
+    for col_idx in 0..first.num_cols() {
+        let (first_col, second_col) = (first.get_column(col_idx), second.get_column(col_idx));
+        if let Some(row_idx) = (0..first_col.len()).find(|&i| first_col[i] != second_col[i]) {
+            panic!(
+                "main traces differ in column {col_idx} at row {row_idx}: {} != {}",
+                first_col[row_idx], second_col[row_idx]
+            );
+        }
+    }
+}
+
 /// A proptest strategy for generating a random word with 4 values of type T.
 #[cfg(not(target_family = "wasm"))]
 pub fn prop_randw<T: Arbitrary>() -> impl Strategy<Value = Vec<T>> {
@@ -16,7 +16,7 @@ pub use winter_verifier::VerifierError;
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, ProofMetadata};
 
 // VERIFIER
 // ================================================================================================
@@ -26,6 +26,9 @@ pub use air::ExecutionProof;
 /// Specifically, verifies that if a program with the specified `program_hash` is executed against
 /// the provided `stack_inputs` and some secret inputs, the result is equal to the `stack_outputs`.
 ///
+/// The program is described by its [ProgramInfo]; for programs compiled against an empty kernel,
+/// the hash of the program can be passed instead.
+///
 /// Stack inputs are expected to be ordered as if they would be pushed onto the stack one by one.
 /// Thus, their expected order on the stack will be the reverse of the order in which they are
 /// provided, and the last value in the `stack_inputs` slice is expected to be the value at the top
@@ -37,13 +40,19 @@ pub use air::ExecutionProof;
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
 /// # Errors
-/// Returns an error if the provided proof does not prove a correct execution of the program.
+/// Returns an error if the provided proof does not prove a correct execution of the program. The
+/// error distinguishes between proofs which could not be read, proofs of an execution of a
+/// different program or against different inputs or outputs, and proofs which fail the FRI
+/// low-degree test.
 pub fn verify(
-    program_info: ProgramInfo,
+    program_info: impl Into<ProgramInfo>,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
+    let program_info = program_info.into();
+    let program_hash = *program_info.program_hash();
+
     // get security level of the proof
     let security_level = proof.security_level();
 
@@ -61,7 +70,7 @@ pub fn verify(
             verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs)
         }
     }
-    .map_err(VerificationError::VerifierError)?;
+    .map_err(|err| VerificationError::from_verifier_error(err, program_hash))?;
 
     Ok(security_level)
 }
@@ -69,21 +78,59 @@ pub fn verify(
 // ERRORS
 // ================================================================================================
 
-/// TODO: add docs
+/// Describes why a proof of program execution could not be verified.
 #[derive(Debug, PartialEq, Eq)]
 pub enum VerificationError {
-    VerifierError(VerifierError),
+    /// The proof passed the consistency checks, but failed the FRI low-degree test.
+    FriVerificationFailed(VerifierError),
+    /// A stack input is not a valid field element.
     InputNotFieldElement(u64),
+    /// The proof could not be deserialized, or was generated for a different field.
+    MalformedProof(VerifierError),
+    /// A stack output is not a valid field element.
     OutputNotFieldElement(u64),
+    /// The proof does not attest to an execution of the program with the specified hash against
+    /// the specified stack inputs and outputs.
+    ProgramMismatch(Digest),
+    /// The proof is inconsistent with its own commitments.
+    VerifierError(VerifierError),
+}
+
+impl VerificationError {
+    /// Classifies an error returned by the STARK verifier for a proof of the program with the
+    /// specified hash.
+    ///
+    /// The program hash and the stack inputs and outputs are public inputs of the proof. Any
+    /// mismatch between them and the proven execution shows up as an inconsistency of the
+    /// out-of-domain constraint evaluations, and thus, cannot be attributed to one of them.
+    fn from_verifier_error(err: VerifierError, program_hash: Digest) -> Self {
+        match err {
+            VerifierError::InconsistentOodConstraintEvaluations => {
+                Self::ProgramMismatch(program_hash)
+            }
+            VerifierError::FriVerificationFailed(_) => Self::FriVerificationFailed(err),
+            VerifierError::InconsistentBaseField
+            | VerifierError::UnsupportedFieldExtension(_)
+            | VerifierError::ProofDeserializationError(_) => Self::MalformedProof(err),
+            _ => Self::VerifierError(err),
+        }
+    }
 }
 
 impl fmt::Display for VerificationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use VerificationError::*;
         match self {
-            VerifierError(e) => write!(f, "{e}"),
+            FriVerificationFailed(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
+            MalformedProof(e) => write!(f, "malformed proof: {e}"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            ProgramMismatch(hash) => write!(
+                f,
+                "the proof does not attest to an execution of program {hash} against the specified \
+                stack inputs and outputs"
+            ),
+            VerifierError(e) => write!(f, "{e}"),
         }
     }
 }